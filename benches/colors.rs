@@ -0,0 +1,272 @@
+//! Benchmarks for the hot paths of this crate, parameterized over the
+//! collection sizes in `css_colors::fixtures::SIZES`.
+//!
+//! Run with `cargo bench`.
+
+#[macro_use]
+extern crate criterion;
+extern crate css_colors;
+
+use criterion::{black_box, BenchmarkId, Criterion};
+use css_colors::{fixtures, parse, percent, Color, SwatchIndex, RGB};
+use std::fmt::Write;
+
+fn conversions(c: &mut Criterion) {
+    let mut group = c.benchmark_group("conversions");
+
+    for &size in fixtures::SIZES.iter() {
+        let rgb_colors = fixtures::rgb_colors(size);
+        let hsl_colors = fixtures::hsl_colors(size);
+
+        group.bench_with_input(
+            BenchmarkId::new("rgb_to_hsl", size),
+            &rgb_colors,
+            |b, colors| {
+                b.iter(|| {
+                    colors
+                        .iter()
+                        .map(|color| color.to_hsl())
+                        .collect::<Vec<_>>()
+                })
+            },
+        );
+
+        group.bench_with_input(
+            BenchmarkId::new("hsl_to_rgb", size),
+            &hsl_colors,
+            |b, colors| {
+                b.iter(|| {
+                    colors
+                        .iter()
+                        .map(|color| color.to_rgb())
+                        .collect::<Vec<_>>()
+                })
+            },
+        );
+    }
+
+    group.finish();
+}
+
+fn operations(c: &mut Criterion) {
+    let mut group = c.benchmark_group("operations");
+
+    for &size in fixtures::SIZES.iter() {
+        let rgba_colors = fixtures::rgba_colors(size);
+        let hsla_colors = fixtures::hsla_colors(size);
+
+        group.bench_with_input(
+            BenchmarkId::new("rgba_mix", size),
+            &rgba_colors,
+            |b, colors| {
+                b.iter(|| {
+                    colors
+                        .windows(2)
+                        .map(|pair| pair[0].mix(pair[1], black_box(percent(30))))
+                        .collect::<Vec<_>>()
+                })
+            },
+        );
+
+        group.bench_with_input(
+            BenchmarkId::new("rgba_lighten", size),
+            &rgba_colors,
+            |b, colors| {
+                b.iter(|| {
+                    colors
+                        .iter()
+                        .map(|color| color.lighten(black_box(percent(10))))
+                        .collect::<Vec<_>>()
+                })
+            },
+        );
+
+        group.bench_with_input(
+            BenchmarkId::new("hsla_lighten", size),
+            &hsla_colors,
+            |b, colors| {
+                b.iter(|| {
+                    colors
+                        .iter()
+                        .map(|color| color.lighten(black_box(percent(10))))
+                        .collect::<Vec<_>>()
+                })
+            },
+        );
+    }
+
+    group.finish();
+}
+
+// Compares allocating a fresh `String` per color (`to_css`) against writing
+// into a single reused buffer through `Display`.
+fn serialization(c: &mut Criterion) {
+    let mut group = c.benchmark_group("serialization");
+
+    for &size in fixtures::SIZES.iter() {
+        let rgba_colors = fixtures::rgba_colors(size);
+        let hsla_colors = fixtures::hsla_colors(size);
+
+        group.bench_with_input(
+            BenchmarkId::new("rgba_to_css", size),
+            &rgba_colors,
+            |b, colors| {
+                b.iter(|| {
+                    colors
+                        .iter()
+                        .map(|color| color.to_css())
+                        .collect::<Vec<_>>()
+                })
+            },
+        );
+
+        group.bench_with_input(
+            BenchmarkId::new("rgba_write", size),
+            &rgba_colors,
+            |b, colors| {
+                let mut buffer = String::new();
+
+                b.iter(|| {
+                    buffer.clear();
+
+                    for color in colors {
+                        write!(buffer, "{}", color).unwrap();
+                    }
+
+                    buffer.len()
+                })
+            },
+        );
+
+        group.bench_with_input(
+            BenchmarkId::new("hsla_to_css", size),
+            &hsla_colors,
+            |b, colors| {
+                b.iter(|| {
+                    colors
+                        .iter()
+                        .map(|color| color.to_css())
+                        .collect::<Vec<_>>()
+                })
+            },
+        );
+
+        group.bench_with_input(
+            BenchmarkId::new("hsla_write", size),
+            &hsla_colors,
+            |b, colors| {
+                let mut buffer = String::new();
+
+                b.iter(|| {
+                    buffer.clear();
+
+                    for color in colors {
+                        write!(buffer, "{}", color).unwrap();
+                    }
+
+                    buffer.len()
+                })
+            },
+        );
+    }
+
+    group.finish();
+}
+
+// Parses the same colors written as hex colors, and as the `rgb()` and `hsl()`
+// functions.
+fn parsing(c: &mut Criterion) {
+    let mut group = c.benchmark_group("parsing");
+
+    for &size in fixtures::SIZES.iter() {
+        let rgb_colors = fixtures::rgb_colors(size);
+
+        let hex: Vec<String> = rgb_colors
+            .iter()
+            .map(|color| {
+                format!(
+                    "#{:02x}{:02x}{:02x}",
+                    color.r.as_u8(),
+                    color.g.as_u8(),
+                    color.b.as_u8()
+                )
+            })
+            .collect();
+        let rgb: Vec<String> = rgb_colors.iter().map(|color| color.to_css()).collect();
+        let hsl: Vec<String> = rgb_colors
+            .iter()
+            .map(|color| color.to_hsl().to_css())
+            .collect();
+
+        for &(name, inputs) in [("hex", &hex), ("rgb", &rgb), ("hsl", &hsl)].iter() {
+            group.bench_with_input(BenchmarkId::new(name, size), inputs, |b, inputs| {
+                b.iter(|| {
+                    inputs
+                        .iter()
+                        .map(|input| parse(input).unwrap())
+                        .collect::<Vec<_>>()
+                })
+            });
+        }
+    }
+
+    group.finish();
+}
+
+// Compares `SwatchIndex::nearest` against a full scan of the swatch library, as
+// an eyedropper would do on every mouse move.
+fn search(c: &mut Criterion) {
+    let mut group = c.benchmark_group("search");
+
+    let mut generator = fixtures::ColorGenerator::new(fixtures::DEFAULT_SEED);
+    let library: Vec<(String, RGB)> = (0..1500)
+        .map(|i| (format!("swatch-{}", i), generator.next_rgb()))
+        .collect();
+    let queries: Vec<RGB> = (0..64).map(|_| generator.next_rgb()).collect();
+
+    let index = SwatchIndex::new(&library);
+    let labs: Vec<_> = library.iter().map(|(_, color)| color.to_lab()).collect();
+
+    for &k in [1, 5].iter() {
+        group.bench_with_input(BenchmarkId::new("swatch_index", k), &k, |b, &k| {
+            b.iter(|| {
+                queries
+                    .iter()
+                    .map(|&query| index.nearest(query, k).len())
+                    .sum::<usize>()
+            })
+        });
+
+        group.bench_with_input(BenchmarkId::new("full_scan", k), &k, |b, &k| {
+            b.iter(|| {
+                queries
+                    .iter()
+                    .map(|query| {
+                        let query = query.to_lab();
+                        let mut distances: Vec<(usize, f32)> = labs
+                            .iter()
+                            .map(|lab| query.delta_e_2000(*lab))
+                            .enumerate()
+                            .collect();
+
+                        distances.sort_by(|lhs, rhs| lhs.1.partial_cmp(&rhs.1).unwrap());
+                        distances.truncate(k);
+                        distances.len()
+                    })
+                    .sum::<usize>()
+            })
+        });
+    }
+
+    group.finish();
+}
+
+criterion_group!(
+    benches,
+    conversions,
+    operations,
+    serialization,
+    parsing,
+    search
+);
+criterion_main!(benches);
@@ -0,0 +1,314 @@
+//! Worst-case errors of the conversions and operations of this crate.
+//!
+//! Most color models of this crate store their channels as bytes (see `Ratio`)
+//! and their hues as whole degrees (see `Angle`), so converting a color from
+//! one model into another and back can drift slightly. The constants of this
+//! module bound that drift, and are enforced by this module's tests.
+//!
+//! Unless stated otherwise, a round trip converts an `RGB` color into another
+//! model and back into `RGB`, and its error is the largest absolute difference
+//! of any red, green or blue channel, in bytes (`0-255`). The round trips are
+//! measured over every 5th value of each channel (including both ends of the
+//! range), which reaches the worst case of every model, and over all 16,777,216
+//! sRGB colors by an ignored test (run it with `cargo test --release -- --ignored`).
+//!
+//! The round trips through the models with a hue (`HSL`, `HSV` and `HWB`) can't
+//! be brought within 1 byte: their hues are whole degrees, and no HSL color
+//! with a whole-degree hue converts back into `rgb(0, 86, 250)` within less than
+//! 2 bytes per channel, whatever its saturation and lightness (and likewise for
+//! `rgb(0, 11, 252)` in HSV and HWB).
+//!
+//! # Examples
+//! ```
+//! use css_colors::{accuracy, rgb, Color};
+//!
+//! let tomato = rgb(255, 99, 71);
+//! let round_trip = tomato.to_hsl().to_rgb();
+//!
+//! let delta = (tomato.g.as_u8() as i16 - round_trip.g.as_u8() as i16).abs();
+//!
+//! assert!(delta <= accuracy::MAX_HSL_ROUNDTRIP_DELTA as i16);
+//! ```
+
+/// The largest error of a round trip through `HSL` (or `HSLA`).
+///
+/// The error comes from rounding the hue to a whole degree: a degree spans up
+/// to `255 / 60 = 4.25` bytes of a channel near the primary and secondary
+/// colors, so this can't be brought under 2 without storing fractional hues.
+/// The saturation and lightness are rounded to the nearest byte on top of the
+/// hue, which adds the third byte; picking them for the smallest round trip
+/// error instead would change the conversion of existing colors.
+pub const MAX_HSL_ROUNDTRIP_DELTA: u8 = 3;
+
+/// The largest error of a round trip through `HSV` (or `HSVA`). Like
+/// `MAX_HSL_ROUNDTRIP_DELTA`, it comes from rounding the hue to a whole degree,
+/// and can't be brought under 2.
+pub const MAX_HSV_ROUNDTRIP_DELTA: u8 = 2;
+
+/// The largest error of a round trip through `HWB`. Like
+/// `MAX_HSL_ROUNDTRIP_DELTA`, it comes from rounding the hue to a whole degree,
+/// and can't be brought under 2.
+pub const MAX_HWB_ROUNDTRIP_DELTA: u8 = 2;
+
+/// The largest error of a round trip through `CMYK`.
+pub const MAX_CMYK_ROUNDTRIP_DELTA: u8 = 0;
+
+/// The largest error of a round trip through `Lab` or `LCH`, which store their
+/// components as floats.
+pub const MAX_LAB_ROUNDTRIP_DELTA: u8 = 0;
+
+/// The largest error of a round trip through `OkLab`, which stores its
+/// components as floats.
+pub const MAX_OKLAB_ROUNDTRIP_DELTA: u8 = 0;
+
+/// The largest error, in whole percentage points, of converting a percentage
+/// into a `Ratio` (with `percent`) and back (with `Ratio::as_percentage`),
+/// measured over every percentage from `0` to `100`.
+pub const MAX_PERCENT_ROUNDTRIP_DELTA: u8 = 0;
+
+/// The largest error of any channel, including alpha, of `mix` at its endpoints:
+/// mixing `lhs` with a weight of `100%` gives `lhs`, and with a weight of `0%`
+/// gives the other color. Measured over pseudo-random pairs of `RGBA` colors.
+pub const MAX_MIX_ENDPOINT_DELTA: u8 = 0;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use {deg, percent, rgb, rgba, Angle, Color, Ratio, HSLA, HSVA, HWB, RGB, RGBA};
+
+    fn delta(lhs: RGBA, rhs: RGBA) -> u8 {
+        let channels = [
+            (lhs.r, rhs.r),
+            (lhs.g, rhs.g),
+            (lhs.b, rhs.b),
+            (lhs.a, rhs.a),
+        ];
+
+        channels
+            .iter()
+            .map(|&(lhs, rhs)| (lhs.as_u8() as i16 - rhs.as_u8() as i16).unsigned_abs() as u8)
+            .max()
+            .unwrap()
+    }
+
+    fn assert_within(delta: u8, bound: u8) {
+        assert!(
+            delta <= bound,
+            "error of {} exceeds the bound of {}",
+            delta,
+            bound
+        );
+    }
+
+    // Converts a color into another model and back.
+    type RoundTrip = fn(RGB) -> RGB;
+
+    // Measures the largest error of a round trip over every 5th value of each channel.
+    fn max_roundtrip_delta<F: Fn(RGB) -> RGB>(round_trip: F) -> u8 {
+        max_roundtrip_delta_by(5, round_trip)
+    }
+
+    // Measures the largest error of a round trip over every `step`th value of
+    // each channel.
+    fn max_roundtrip_delta_by<F: Fn(RGB) -> RGB>(step: usize, round_trip: F) -> u8 {
+        let mut max = 0;
+
+        for r in (0..=255).step_by(step) {
+            for g in (0..=255).step_by(step) {
+                for b in (0..=255).step_by(step) {
+                    let color = rgb(r as u8, g as u8, b as u8);
+                    let delta = delta(color.to_rgba(), round_trip(color).to_rgba());
+
+                    max = max.max(delta);
+                }
+            }
+        }
+
+        max
+    }
+
+    #[test]
+    fn hsl_roundtrip_is_within_bounds() {
+        assert_within(
+            max_roundtrip_delta(|color| color.to_hsl().to_rgb()),
+            MAX_HSL_ROUNDTRIP_DELTA,
+        );
+        assert_within(
+            max_roundtrip_delta(|color| color.to_hsla().to_rgb()),
+            MAX_HSL_ROUNDTRIP_DELTA,
+        );
+
+        let worst = rgb(0, 11, 255);
+        assert_eq!(
+            delta(worst.to_rgba(), worst.to_hsl().to_rgba()),
+            MAX_HSL_ROUNDTRIP_DELTA
+        );
+    }
+
+    #[test]
+    fn hsv_roundtrip_is_within_bounds() {
+        assert_within(
+            max_roundtrip_delta(|color| color.to_hsv().to_rgb()),
+            MAX_HSV_ROUNDTRIP_DELTA,
+        );
+        assert_within(
+            max_roundtrip_delta(|color| color.to_hsva().to_rgb()),
+            MAX_HSV_ROUNDTRIP_DELTA,
+        );
+
+        let worst = rgb(0, 2, 211);
+        assert_eq!(
+            delta(worst.to_rgba(), worst.to_hsv().to_rgba()),
+            MAX_HSV_ROUNDTRIP_DELTA
+        );
+    }
+
+    #[test]
+    fn hwb_roundtrip_is_within_bounds() {
+        assert_within(
+            max_roundtrip_delta(|color| color.to_hwb().to_rgb()),
+            MAX_HWB_ROUNDTRIP_DELTA,
+        );
+
+        let worst = rgb(0, 2, 211);
+        assert_eq!(
+            delta(worst.to_rgba(), worst.to_hwb().to_rgba()),
+            MAX_HWB_ROUNDTRIP_DELTA
+        );
+    }
+
+    #[test]
+    fn cmyk_roundtrip_is_within_bounds() {
+        assert_within(
+            max_roundtrip_delta(|color| color.to_cmyk().to_rgb()),
+            MAX_CMYK_ROUNDTRIP_DELTA,
+        );
+    }
+
+    #[test]
+    fn lab_roundtrip_is_within_bounds() {
+        assert_within(
+            max_roundtrip_delta(|color| color.to_lab().to_rgb()),
+            MAX_LAB_ROUNDTRIP_DELTA,
+        );
+        assert_within(
+            max_roundtrip_delta(|color| color.to_lch().to_rgb()),
+            MAX_LAB_ROUNDTRIP_DELTA,
+        );
+    }
+
+    #[test]
+    fn oklab_roundtrip_is_within_bounds() {
+        assert_within(
+            max_roundtrip_delta(|color| color.to_oklab().to_rgb()),
+            MAX_OKLAB_ROUNDTRIP_DELTA,
+        );
+    }
+
+    #[test]
+    #[ignore]
+    fn roundtrips_are_within_bounds_for_every_color() {
+        let bounds: [(RoundTrip, u8); 8] = [
+            (|color| color.to_hsl().to_rgb(), MAX_HSL_ROUNDTRIP_DELTA),
+            (|color| color.to_hsla().to_rgb(), MAX_HSL_ROUNDTRIP_DELTA),
+            (|color| color.to_hsv().to_rgb(), MAX_HSV_ROUNDTRIP_DELTA),
+            (|color| color.to_hwb().to_rgb(), MAX_HWB_ROUNDTRIP_DELTA),
+            (|color| color.to_cmyk().to_rgb(), MAX_CMYK_ROUNDTRIP_DELTA),
+            (|color| color.to_lab().to_rgb(), MAX_LAB_ROUNDTRIP_DELTA),
+            (|color| color.to_lch().to_rgb(), MAX_LAB_ROUNDTRIP_DELTA),
+            (|color| color.to_oklab().to_rgb(), MAX_OKLAB_ROUNDTRIP_DELTA),
+        ];
+
+        for &(round_trip, bound) in bounds.iter() {
+            assert_eq!(max_roundtrip_delta_by(1, round_trip), bound);
+        }
+    }
+
+    #[test]
+    fn hue_roundtrips_cannot_be_within_one_byte() {
+        // Whether any whole-degree hue near the one of `target` (a degree moves a
+        // channel by up to 4.25 bytes, so farther hues only miss it by more),
+        // combined with any pair of channels, converts back within 1 byte.
+        fn reaches<F: Fn(Angle, Ratio, Ratio) -> RGBA>(target: RGB, color: F) -> bool {
+            let hue = target.to_hsl().h.degrees() as i32;
+
+            (hue - 8..=hue + 8).any(|h| {
+                (0..=255).any(|first| {
+                    (0..=255).any(|second| {
+                        let color = color(deg(h), Ratio::from_u8(first), Ratio::from_u8(second));
+
+                        delta(color, target.to_rgba()) <= 1
+                    })
+                })
+            })
+        }
+
+        assert!(!reaches(rgb(0, 86, 250), |h, s, l| HSLA {
+            h,
+            s,
+            l,
+            a: percent(100)
+        }
+        .to_rgba()));
+        assert!(!reaches(rgb(0, 11, 252), |h, s, v| HSVA {
+            h,
+            s,
+            v,
+            a: percent(100)
+        }
+        .to_rgba()));
+        assert!(!reaches(rgb(0, 11, 252), |h, w, b| HWB { h, w, b }.to_rgba()));
+
+        // Its own conversion misses it by 2, the smallest possible error.
+        assert_eq!(
+            delta(
+                rgb(0, 86, 250).to_rgba(),
+                rgb(0, 86, 250).to_hsl().to_rgba()
+            ),
+            2
+        );
+    }
+
+    #[test]
+    fn percent_roundtrip_is_within_bounds() {
+        for percentage in 0..=100 {
+            let delta =
+                (percent(percentage).as_percentage() as i16 - percentage as i16).unsigned_abs();
+
+            assert_within(delta as u8, MAX_PERCENT_ROUNDTRIP_DELTA);
+        }
+    }
+
+    #[test]
+    fn mix_endpoints_are_within_bounds() {
+        // A linear congruential generator, to sample pairs of colors.
+        let mut state: u32 = 1;
+        let mut next = || {
+            state = state.wrapping_mul(1_664_525).wrapping_add(1_013_904_223);
+            (state >> 24) as u8
+        };
+
+        for _ in 0..20_000 {
+            let lhs = rgba(next(), next(), next(), next() as f32 / 255.0);
+            let rhs = rgba(next(), next(), next(), next() as f32 / 255.0);
+
+            assert_within(
+                delta(lhs.mix(rhs, percent(100)), lhs),
+                MAX_MIX_ENDPOINT_DELTA,
+            );
+            assert_within(delta(lhs.mix(rhs, percent(0)), rhs), MAX_MIX_ENDPOINT_DELTA);
+        }
+
+        // Both colors fully transparent or opaque, where the alpha weighting degenerates.
+        for &(lhs, rhs) in [(0.0, 1.0), (1.0, 0.0), (0.0, 0.0)].iter() {
+            let lhs = rgba(250, 128, 114, lhs);
+            let rhs = rgba(0, 0, 128, rhs);
+
+            assert_within(
+                delta(lhs.mix(rhs, percent(100)), lhs),
+                MAX_MIX_ENDPOINT_DELTA,
+            );
+            assert_within(delta(lhs.mix(rhs, percent(0)), rhs), MAX_MIX_ENDPOINT_DELTA);
+        }
+    }
+}
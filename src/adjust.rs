@@ -0,0 +1,437 @@
+use super::{deg, ops, percent, Color, Ratio, HSLA, RGBA};
+
+#[derive(Debug, Default, Copy, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+/// A struct of the signed offsets applied to the channels of a color by
+/// `Color::adjust`, like the arguments of Sass' [`adjust-color`][sass-adjust].
+/// Channels left to `None` are untouched, and every channel is clamped to its
+/// valid range once offset (the hue wraps around instead).
+///
+/// The RGB channels and the HSL channels can't be adjusted at once, since one
+/// set would have to be applied before the other: doing so will cause a panic.
+/// The alpha channel can be adjusted along with either set.
+///
+/// # Example
+/// ```
+/// use css_colors::{Color, ColorAdjustment, rgb};
+///
+/// let adjustment = ColorAdjustment {
+///     red: Some(15),
+///     ..Default::default()
+/// };
+///
+/// assert_eq!(rgb(107, 113, 127).adjust(adjustment), rgb(122, 113, 127));
+/// ```
+///
+/// [sass-adjust]: https://sass-lang.com/documentation/modules/color#adjust
+pub struct ColorAdjustment {
+    // offset of the red channel, from `-255` to `255`
+    pub red: Option<i16>,
+
+    // offset of the green channel, from `-255` to `255`
+    pub green: Option<i16>,
+
+    // offset of the blue channel, from `-255` to `255`
+    pub blue: Option<i16>,
+
+    // rotation of the hue, in degrees
+    pub hue: Option<i32>,
+
+    // offset of the saturation, in percentage points
+    pub saturation: Option<i8>,
+
+    // offset of the lightness, in percentage points
+    pub lightness: Option<i8>,
+
+    // offset of the alpha channel, from `-1.0` to `1.0`
+    pub alpha: Option<f32>,
+}
+
+impl ColorAdjustment {
+    // Returns `true` if any of the red, green and blue channels are adjusted.
+    pub(crate) fn adjusts_rgb(&self) -> bool {
+        self.red.is_some() || self.green.is_some() || self.blue.is_some()
+    }
+
+    // Returns `true` if any of the hue, saturation and lightness are adjusted.
+    pub(crate) fn adjusts_hsl(&self) -> bool {
+        self.hue.is_some() || self.saturation.is_some() || self.lightness.is_some()
+    }
+}
+
+#[derive(Debug, Default, Copy, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+/// A struct of the values assigned to the channels of a color by `Color::change`,
+/// like the arguments of Sass' [`change-color`][sass-change]. Channels left to
+/// `None` are untouched.
+///
+/// The hue is normalized into the `0-359°` range, while percentages above `100`
+/// and alpha values outside of the `0.0-1.0` range will cause a panic, like the
+/// constructors of the color models. As with `ColorAdjustment`, changing both RGB
+/// and HSL channels will cause a panic, while the alpha channel can be changed
+/// along with either set.
+///
+/// # Example
+/// ```
+/// use css_colors::{Color, ColorChange, hsla};
+///
+/// let change = ColorChange {
+///     hue: Some(270),
+///     alpha: Some(0.5),
+///     ..Default::default()
+/// };
+///
+/// assert_eq!(hsla(120, 80, 50, 1.0).change(change), hsla(270, 80, 50, 0.5));
+/// ```
+///
+/// [sass-change]: https://sass-lang.com/documentation/modules/color#change
+pub struct ColorChange {
+    // the red channel
+    pub red: Option<u8>,
+
+    // the green channel
+    pub green: Option<u8>,
+
+    // the blue channel
+    pub blue: Option<u8>,
+
+    // the hue, in degrees
+    pub hue: Option<i32>,
+
+    // the saturation, as a percentage
+    pub saturation: Option<u8>,
+
+    // the lightness, as a percentage
+    pub lightness: Option<u8>,
+
+    // the alpha channel, from `0.0` to `1.0`
+    pub alpha: Option<f32>,
+}
+
+impl ColorChange {
+    // Returns `true` if any of the red, green and blue channels are changed.
+    pub(crate) fn changes_rgb(&self) -> bool {
+        self.red.is_some() || self.green.is_some() || self.blue.is_some()
+    }
+
+    // Returns `true` if any of the hue, saturation and lightness are changed.
+    pub(crate) fn changes_hsl(&self) -> bool {
+        self.hue.is_some() || self.saturation.is_some() || self.lightness.is_some()
+    }
+}
+
+// Offsets a channel stored as a byte by `offset` bytes.
+fn offset_u8(channel: Ratio, offset: Option<i16>) -> Ratio {
+    match offset {
+        Some(offset) => Ratio::from_u8((channel.as_u8() as i16 + offset).clamp(0, 255) as u8),
+        None => channel,
+    }
+}
+
+// Offsets a channel by `offset` percentage points.
+fn offset_percent(channel: Ratio, offset: Option<i8>) -> Ratio {
+    match offset {
+        Some(offset) => ops::quantize(channel.as_f32() + offset as f32 / 100.0),
+        None => channel,
+    }
+}
+
+fn offset_alpha(alpha: Ratio, offset: Option<f32>) -> Ratio {
+    match offset {
+        Some(offset) => ops::quantize(alpha.as_f32() + offset),
+        None => alpha,
+    }
+}
+
+fn assert_consistent(adjustment: &ColorAdjustment) {
+    assert!(
+        !(adjustment.adjusts_rgb() && adjustment.adjusts_hsl()),
+        "Cannot adjust both RGB and HSL channels"
+    );
+}
+
+// Applies `adjustment` to the RGBA representation of `color`.
+pub(crate) fn adjust_rgb<T: Color>(color: T, adjustment: ColorAdjustment) -> T {
+    assert_consistent(&adjustment);
+
+    let RGBA { r, g, b, a } = color.to_rgba();
+
+    T::from_rgba(RGBA {
+        r: offset_u8(r, adjustment.red),
+        g: offset_u8(g, adjustment.green),
+        b: offset_u8(b, adjustment.blue),
+        a: offset_alpha(a, adjustment.alpha),
+    })
+}
+
+// Applies `adjustment` to the HSLA representation of `color`, in a single conversion.
+pub(crate) fn adjust_hsl<T: Color>(color: T, adjustment: ColorAdjustment) -> T {
+    assert_consistent(&adjustment);
+
+    ops::apply_to_hsl(color, |HSLA { h, s, l, a }| HSLA {
+        h: match adjustment.hue {
+            Some(hue) => deg(h.degrees() as i32 + hue.rem_euclid(360)),
+            None => h,
+        },
+        s: offset_percent(s, adjustment.saturation),
+        l: offset_percent(l, adjustment.lightness),
+        a: offset_alpha(a, adjustment.alpha),
+    })
+}
+
+fn assert_consistent_change(change: &ColorChange) {
+    assert!(
+        !(change.changes_rgb() && change.changes_hsl()),
+        "Cannot change both RGB and HSL channels"
+    );
+}
+
+// Assigns the channels of `change` in the RGBA representation of `color`.
+pub(crate) fn change_rgb<T: Color>(color: T, change: ColorChange) -> T {
+    assert_consistent_change(&change);
+
+    let RGBA { r, g, b, a } = color.to_rgba();
+
+    T::from_rgba(RGBA {
+        r: change.red.map_or(r, Ratio::from_u8),
+        g: change.green.map_or(g, Ratio::from_u8),
+        b: change.blue.map_or(b, Ratio::from_u8),
+        a: change.alpha.map_or(a, Ratio::from_f32),
+    })
+}
+
+// Assigns the channels of `change` in the HSLA representation of `color`.
+pub(crate) fn change_hsl<T: Color>(color: T, change: ColorChange) -> T {
+    assert_consistent_change(&change);
+
+    ops::apply_to_hsl(color, |HSLA { h, s, l, a }| HSLA {
+        h: change.hue.map_or(h, deg),
+        s: change.saturation.map_or(s, percent),
+        l: change.lightness.map_or(l, percent),
+        a: change.alpha.map_or(a, Ratio::from_f32),
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use {deg, hsl, hsla, percent, rgb, rgba, Color, ColorAdjustment, ColorChange, HSLA};
+
+    #[test]
+    fn can_adjust_rgb_channels() {
+        // Examples of the Sass documentation.
+        let adjustment = ColorAdjustment {
+            red: Some(15),
+            ..Default::default()
+        };
+        assert_eq!(rgb(107, 113, 127).adjust(adjustment), rgb(122, 113, 127));
+
+        let adjustment = ColorAdjustment {
+            red: Some(-10),
+            blue: Some(10),
+            ..Default::default()
+        };
+        assert_eq!(rgb(210, 225, 221).adjust(adjustment), rgb(200, 225, 231));
+
+        // Channels are clamped.
+        let adjustment = ColorAdjustment {
+            red: Some(255),
+            green: Some(-255),
+            alpha: Some(0.5),
+            ..Default::default()
+        };
+        assert_eq!(
+            rgba(100, 100, 100, 0.8).adjust(adjustment),
+            rgba(255, 0, 100, 1.0)
+        );
+    }
+
+    #[test]
+    fn can_adjust_hsl_channels() {
+        // Example of the Sass documentation: #998099 with `$lightness: -30%, $alpha: -0.4`.
+        let adjustment = ColorAdjustment {
+            lightness: Some(-30),
+            alpha: Some(-0.4),
+            ..Default::default()
+        };
+        let adjusted = rgba(153, 128, 153, 1.0).adjust(adjustment);
+        let expected = rgba(71, 57, 71, 0.6);
+
+        // Sass keeps the lightness as a float, which can differ by one byte.
+        for &(lhs, rhs) in [
+            (adjusted.r, expected.r),
+            (adjusted.g, expected.g),
+            (adjusted.b, expected.b),
+        ]
+        .iter()
+        {
+            assert!(
+                (lhs.as_u8() as i16 - rhs.as_u8() as i16).abs() <= 1,
+                "{}",
+                adjusted
+            );
+        }
+
+        assert_eq!(adjusted.a, expected.a);
+
+        let adjustment = ColorAdjustment {
+            hue: Some(-400),
+            saturation: Some(-5),
+            lightness: Some(10),
+            ..Default::default()
+        };
+        assert_eq!(hsl(25, 100, 80).adjust(adjustment), hsl(345, 95, 90));
+        assert_eq!(
+            hsla(25, 100, 80, 0.5).adjust(adjustment),
+            hsla(345, 95, 90, 0.5)
+        );
+    }
+
+    #[test]
+    fn adjusting_in_one_conversion_avoids_rounding_errors() {
+        let color = rgb(200, 90, 60);
+        let adjustment = ColorAdjustment {
+            hue: Some(40),
+            saturation: Some(-15),
+            lightness: Some(10),
+            ..Default::default()
+        };
+
+        let HSLA { h, s, l, a } = color.to_hsla();
+        let expected = HSLA {
+            h: h + deg(40),
+            s: percent(s.as_percentage() - 15),
+            l: percent(l.as_percentage() + 10),
+            a,
+        };
+
+        assert_eq!(color.adjust(adjustment), expected.to_rgb());
+    }
+
+    #[test]
+    fn adjusting_only_alpha_keeps_the_color_model() {
+        let adjustment = ColorAdjustment {
+            alpha: Some(-0.5),
+            ..Default::default()
+        };
+
+        assert_eq!(
+            hsla(6, 93, 71, 1.0).adjust(adjustment),
+            hsla(6, 93, 71, 0.5)
+        );
+        assert_eq!(
+            rgba(250, 128, 114, 1.0).adjust(adjustment),
+            rgba(250, 128, 114, 0.5)
+        );
+        assert_eq!(rgb(250, 128, 114).adjust(adjustment), rgb(250, 128, 114));
+        assert_eq!(hsl(6, 93, 71).adjust(Default::default()), hsl(6, 93, 71));
+    }
+
+    #[test]
+    #[should_panic(expected = "Cannot adjust both RGB and HSL channels")]
+    fn cannot_adjust_rgb_and_hsl_channels_at_once() {
+        let adjustment = ColorAdjustment {
+            red: Some(10),
+            hue: Some(10),
+            ..Default::default()
+        };
+
+        rgb(100, 100, 100).adjust(adjustment);
+    }
+
+    #[test]
+    fn can_change_single_channels() {
+        let hue = ColorChange {
+            hue: Some(270),
+            ..Default::default()
+        };
+        let alpha = ColorChange {
+            alpha: Some(0.5),
+            ..Default::default()
+        };
+        let red = ColorChange {
+            red: Some(0),
+            ..Default::default()
+        };
+
+        assert_eq!(hsl(120, 80, 50).change(hue), hsl(270, 80, 50));
+        assert_eq!(hsla(120, 80, 50, 0.3).change(hue), hsla(270, 80, 50, 0.3));
+        assert_eq!(rgb(255, 0, 0).change(hue).to_hsl(), hsl(270, 100, 50));
+        assert_eq!(rgba(255, 0, 0, 0.3).change(hue).a, rgba(255, 0, 0, 0.3).a);
+
+        // Colors without an alpha channel stay opaque.
+        assert_eq!(hsla(6, 93, 71, 1.0).change(alpha), hsla(6, 93, 71, 0.5));
+        assert_eq!(
+            rgba(250, 128, 114, 1.0).change(alpha),
+            rgba(250, 128, 114, 0.5)
+        );
+        assert_eq!(hsl(6, 93, 71).change(alpha), hsl(6, 93, 71));
+        assert_eq!(rgb(250, 128, 114).change(alpha), rgb(250, 128, 114));
+
+        assert_eq!(rgb(250, 128, 114).change(red), rgb(0, 128, 114));
+        assert_eq!(rgba(250, 128, 114, 0.5).change(red), rgba(0, 128, 114, 0.5));
+        assert_eq!(hsl(0, 0, 100).change(red), rgb(0, 255, 255).to_hsl());
+        assert_eq!(
+            hsla(0, 0, 100, 0.5).change(red),
+            rgba(0, 255, 255, 0.5).to_hsla()
+        );
+    }
+
+    #[test]
+    fn can_change_several_channels() {
+        let hsl_change = ColorChange {
+            hue: Some(-90),
+            saturation: Some(40),
+            lightness: Some(60),
+            alpha: Some(0.8),
+            ..Default::default()
+        };
+        let rgb_change = ColorChange {
+            red: Some(10),
+            blue: Some(200),
+            alpha: Some(0.8),
+            ..Default::default()
+        };
+
+        assert_eq!(hsl(120, 80, 50).change(hsl_change), hsl(270, 40, 60));
+        assert_eq!(
+            hsla(120, 80, 50, 1.0).change(hsl_change),
+            hsla(270, 40, 60, 0.8)
+        );
+        assert_eq!(rgb(255, 0, 0).change(hsl_change), hsl(270, 40, 60).to_rgb());
+        assert_eq!(
+            rgba(255, 0, 0, 1.0).change(hsl_change),
+            hsla(270, 40, 60, 0.8).to_rgba()
+        );
+
+        assert_eq!(rgb(1, 2, 3).change(rgb_change), rgb(10, 2, 200));
+        assert_eq!(rgba(1, 2, 3, 1.0).change(rgb_change), rgba(10, 2, 200, 0.8));
+        assert_eq!(hsl(0, 0, 0).change(rgb_change), rgb(10, 0, 200).to_hsl());
+        assert_eq!(
+            hsla(0, 0, 0, 1.0).change(rgb_change),
+            rgba(10, 0, 200, 0.8).to_hsla()
+        );
+    }
+
+    #[test]
+    #[should_panic(expected = "Cannot change both RGB and HSL channels")]
+    fn cannot_change_rgb_and_hsl_channels_at_once() {
+        let change = ColorChange {
+            green: Some(10),
+            lightness: Some(10),
+            ..Default::default()
+        };
+
+        hsl(100, 100, 100).change(change);
+    }
+
+    #[test]
+    #[should_panic]
+    fn cannot_change_to_invalid_percentages() {
+        let change = ColorChange {
+            saturation: Some(101),
+            ..Default::default()
+        };
+
+        hsl(100, 100, 50).change(change);
+    }
+}
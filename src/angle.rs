@@ -1,28 +1,84 @@
+use super::ops::interpolate_hue;
 use std::fmt;
 use std::ops;
 
 /// Construct an angle from degrees. Angles outside of the 0-359° range will be
-/// normalized accordingly.
+/// normalized accordingly, so any amount of degrees (including `i32::MIN`) is
+/// a valid rotation.
 ///
 /// # Example
 /// ```
 /// use css_colors::{deg};
 ///
-/// assert_eq!(deg(0).to_string(), "0deg");
-/// assert_eq!(deg(90).to_string(), "90deg");
-/// assert_eq!(deg(540).to_string(), "180deg");
-/// assert_eq!(deg(-90).to_string(), "270deg");
+/// assert_eq!(deg(0).to_string(), "0");
+/// assert_eq!(deg(90).to_string(), "90");
+/// assert_eq!(deg(540).to_string(), "180");
+/// assert_eq!(deg(-90).to_string(), "270");
+/// assert_eq!(deg(-450).to_string(), "270");
 /// ```
-pub fn deg(mut degrees: i32) -> Angle {
-    while degrees < 0 {
-        degrees += 360;
-    }
+pub fn deg(degrees: i32) -> Angle {
+    Angle::new(degrees.rem_euclid(360) as u16)
+}
 
-    while degrees >= 360 {
-        degrees -= 360;
-    }
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+/// An enum that determines which way around the circle `Angle::sweep_to` travels.
+pub enum SweepDirection {
+    // The shorter of the two arcs. Half-turns are swept clockwise.
+    Shorter,
+
+    // The longer of the two arcs. Half-turns are swept counterclockwise.
+    Longer,
+
+    // Towards increasing angles, wrapping from 359° to 0°.
+    Clockwise,
+
+    // Towards decreasing angles, wrapping from 0° to 359°.
+    CounterClockwise,
+}
 
-    Angle::new(degrees as u16)
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Hash, Default)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "kebab-case"))]
+/// An enum of the ways to interpolate between two hues, following the hue
+/// interpolation methods of CSS Color 4 (as in `in hsl longer hue`).
+///
+/// Unlike `SweepDirection`, which sweeps between angles, these follow the CSS
+/// rules exactly: a half-turn is interpolated in the direction of the hues, and
+/// `Longer` goes all the way around between two equal hues.
+///
+/// For more, see [CSS Color 4](https://www.w3.org/TR/css-color-4/#hue-interpolation).
+pub enum HuePath {
+    // the shorter of the two arcs between the hues (the default of CSS)
+    #[default]
+    Shorter,
+
+    // the longer of the two arcs between the hues
+    Longer,
+
+    // towards increasing angles, wrapping from 359° to 0°
+    Increasing,
+
+    // towards decreasing angles, wrapping from 0° to 359°
+    Decreasing,
+}
+
+impl HuePath {
+    // Interpolates between two hues (in degrees, `0-360°`) along `self`, with
+    // `t` from `0.0` (`from`) to `1.0` (`to`). Returns a hue in the `0-360°` range.
+    pub(crate) fn interpolate(self, from: f32, to: f32, t: f32) -> f32 {
+        let delta = to - from;
+
+        let delta = match self {
+            HuePath::Shorter => return interpolate_hue(from, to, t),
+            HuePath::Longer if delta > 0.0 && delta < 180.0 => delta - 360.0,
+            HuePath::Longer if delta > -180.0 && delta <= 0.0 => delta + 360.0,
+            HuePath::Increasing if delta < 0.0 => delta + 360.0,
+            HuePath::Decreasing if delta > 0.0 => delta - 360.0,
+            _ => delta,
+        };
+
+        (from + delta * t).rem_euclid(360.0)
+    }
 }
 
 #[derive(Debug, Copy, Clone, Eq, PartialEq, Ord, PartialOrd)]
@@ -42,11 +98,128 @@ impl Angle {
     pub fn degrees(self) -> u16 {
         self.degrees
     }
+
+    /// Sweeps from `self` to `end` in the given direction, yielding `steps` evenly
+    /// spaced angles (rounded to the nearest degree) inclusive of both endpoints.
+    ///
+    /// Zero steps yield nothing, and a single step yields `self`. When `self` and
+    /// `end` are equal, the sweep has no length in any direction and every step
+    /// yields `self`.
+    ///
+    /// # Example
+    /// ```
+    /// use css_colors::{deg, Angle, SweepDirection};
+    ///
+    /// let shorter: Vec<Angle> = deg(340).sweep_to(deg(20), 5, SweepDirection::Shorter).collect();
+    /// let longer: Vec<Angle> = deg(340).sweep_to(deg(20), 5, SweepDirection::Longer).collect();
+    ///
+    /// assert_eq!(shorter, [deg(340), deg(350), deg(0), deg(10), deg(20)]);
+    /// assert_eq!(longer, [deg(340), deg(260), deg(180), deg(100), deg(20)]);
+    /// ```
+    pub fn sweep_to(
+        self,
+        end: Angle,
+        steps: usize,
+        direction: SweepDirection,
+    ) -> impl Iterator<Item = Angle> {
+        let clockwise = (end.degrees as i32 - self.degrees as i32 + 360) % 360;
+
+        let distance = match direction {
+            SweepDirection::Clockwise => clockwise,
+            SweepDirection::CounterClockwise => (clockwise - 360) % 360,
+            SweepDirection::Shorter if clockwise > 180 => clockwise - 360,
+            SweepDirection::Shorter => clockwise,
+            SweepDirection::Longer if clockwise == 0 => 0,
+            SweepDirection::Longer if clockwise > 180 => clockwise,
+            SweepDirection::Longer => clockwise - 360,
+        };
+
+        let step = if steps > 1 {
+            distance as f32 / (steps - 1) as f32
+        } else {
+            0.0
+        };
+        let start = self.degrees as f32;
+
+        (0..steps).map(move |i| deg((start + step * i as f32).round() as i32))
+    }
+
+    /// Returns the angle as a fraction of a full turn.
+    ///
+    /// # Example
+    /// ```
+    /// use css_colors::{deg};
+    ///
+    /// assert_eq!(deg(180).to_turns(), 0.5);
+    /// ```
+    pub fn to_turns(self) -> f32 {
+        self.degrees as f32 / 360.0
+    }
+
+    /// Returns the angle in radians.
+    ///
+    /// # Example
+    /// ```
+    /// use css_colors::{deg};
+    /// use std::f32::consts::PI;
+    ///
+    /// assert_eq!(deg(180).to_radians(), PI);
+    /// ```
+    pub fn to_radians(self) -> f32 {
+        (self.degrees as f32).to_radians()
+    }
+
+    /// Returns the angle in gradians, where a full turn is `400grad`.
+    ///
+    /// # Example
+    /// ```
+    /// use css_colors::{deg};
+    ///
+    /// assert_eq!(deg(180).to_gradians(), 200.0);
+    /// ```
+    pub fn to_gradians(self) -> f32 {
+        self.degrees as f32 * 400.0 / 360.0
+    }
 }
 
+/// Formats the angle as a bare number of degrees (`90`). The alternate flag
+/// appends the CSS unit (`90deg`).
+///
+/// # Example
+/// ```
+/// use css_colors::{deg};
+///
+/// assert_eq!(format!("{}", deg(90)), "90");
+/// assert_eq!(format!("{:#}", deg(90)), "90deg");
+/// ```
 impl fmt::Display for Angle {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        write!(f, "{}deg", self.degrees)
+        if f.alternate() {
+            write!(f, "{}deg", self.degrees)
+        } else {
+            write!(f, "{}", self.degrees)
+        }
+    }
+}
+
+// Angles are serialized as their number of degrees.
+#[cfg(feature = "serde")]
+impl serde::Serialize for Angle {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_u16(self.degrees)
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<'de> serde::Deserialize<'de> for Angle {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let degrees = u16::deserialize(deserializer)?;
+
+        if degrees < 360 {
+            Ok(Angle::new(degrees))
+        } else {
+            Err(serde::de::Error::custom("invalid angle"))
+        }
     }
 }
 
@@ -107,7 +280,15 @@ impl ops::Div for Angle {
 
 #[cfg(test)]
 mod tests {
-    use Angle;
+    use std::f32::consts::PI;
+    use {deg, Angle, HuePath, SweepDirection};
+
+    fn sweep(start: u16, end: u16, steps: usize, direction: SweepDirection) -> Vec<u16> {
+        Angle::new(start)
+            .sweep_to(Angle::new(end), steps, direction)
+            .map(Angle::degrees)
+            .collect()
+    }
 
     #[test]
     fn can_have_degrees() {
@@ -115,10 +296,50 @@ mod tests {
         assert_eq!(Angle::new(47).degrees(), 47);
     }
 
+    #[test]
+    fn can_normalize_degrees() {
+        assert_eq!(deg(360), Angle::new(0));
+        assert_eq!(deg(400), Angle::new(40));
+        assert_eq!(deg(-450), deg(270));
+        assert_eq!(deg(-360), Angle::new(0));
+        assert_eq!(deg(i16::MIN as i32), Angle::new(352));
+        assert_eq!(deg(i16::MAX as i32), Angle::new(7));
+        assert_eq!(deg(i32::MIN), Angle::new(232));
+        assert_eq!(deg(i32::MAX), Angle::new(127));
+    }
+
     #[test]
     fn can_display_angles() {
-        assert_eq!("30deg", format!("{}", Angle::new(30)));
-        assert_eq!("30deg", Angle::new(30).to_string());
+        assert_eq!("30", format!("{}", Angle::new(30)));
+        assert_eq!("30", Angle::new(30).to_string());
+        assert_eq!("0", Angle::new(0).to_string());
+        assert_eq!("359", Angle::new(359).to_string());
+    }
+
+    #[test]
+    fn can_display_angles_with_units() {
+        assert_eq!("30deg", format!("{:#}", Angle::new(30)));
+        assert_eq!("0deg", format!("{:#}", Angle::new(0)));
+        assert_eq!("359deg", format!("{:#}", Angle::new(359)));
+    }
+
+    #[test]
+    fn can_convert_angle_units() {
+        assert_eq!(Angle::new(0).to_turns(), 0.0);
+        assert_eq!(Angle::new(0).to_radians(), 0.0);
+        assert_eq!(Angle::new(0).to_gradians(), 0.0);
+
+        assert_eq!(Angle::new(180).to_turns(), 0.5);
+        assert_eq!(Angle::new(180).to_radians(), PI);
+        assert_eq!(Angle::new(180).to_gradians(), 200.0);
+
+        assert_eq!(Angle::new(90).to_turns(), 0.25);
+        assert_eq!(Angle::new(90).to_radians(), PI / 2.0);
+        assert_eq!(Angle::new(90).to_gradians(), 100.0);
+
+        assert!((Angle::new(359).to_turns() - 0.997_222).abs() < 1e-6);
+        assert!((Angle::new(359).to_radians() - 6.265_732).abs() < 1e-6);
+        assert!((Angle::new(359).to_gradians() - 398.888_9).abs() < 1e-4);
     }
 
     #[test]
@@ -197,4 +418,131 @@ mod tests {
 
         assert_eq!(Angle::new(47) / Angle::new(2), Angle::new(23));
     }
+
+    #[test]
+    fn can_sweep_across_zero() {
+        assert_eq!(
+            sweep(350, 10, 5, SweepDirection::Shorter),
+            [350, 355, 0, 5, 10]
+        );
+        assert_eq!(
+            sweep(350, 10, 5, SweepDirection::Clockwise),
+            [350, 355, 0, 5, 10]
+        );
+        assert_eq!(
+            sweep(10, 350, 5, SweepDirection::Shorter),
+            [10, 5, 0, 355, 350]
+        );
+        assert_eq!(
+            sweep(10, 350, 5, SweepDirection::CounterClockwise),
+            [10, 5, 0, 355, 350]
+        );
+    }
+
+    #[test]
+    fn can_sweep_the_long_way() {
+        assert_eq!(sweep(350, 10, 3, SweepDirection::Longer), [350, 180, 10]);
+        assert_eq!(
+            sweep(350, 10, 3, SweepDirection::CounterClockwise),
+            [350, 180, 10]
+        );
+        assert_eq!(sweep(10, 350, 3, SweepDirection::Longer), [10, 180, 350]);
+        assert_eq!(sweep(10, 350, 3, SweepDirection::Clockwise), [10, 180, 350]);
+    }
+
+    #[test]
+    fn can_sweep_half_turns() {
+        assert_eq!(sweep(0, 180, 3, SweepDirection::Shorter), [0, 90, 180]);
+        assert_eq!(sweep(0, 180, 3, SweepDirection::Longer), [0, 270, 180]);
+        assert_eq!(sweep(90, 270, 3, SweepDirection::Clockwise), [90, 180, 270]);
+        assert_eq!(
+            sweep(90, 270, 3, SweepDirection::CounterClockwise),
+            [90, 0, 270]
+        );
+    }
+
+    #[test]
+    fn can_sweep_uneven_steps() {
+        assert_eq!(sweep(0, 10, 4, SweepDirection::Shorter), [0, 3, 7, 10]);
+        assert_eq!(
+            sweep(0, 10, 4, SweepDirection::CounterClockwise),
+            [0, 243, 127, 10]
+        );
+    }
+
+    #[test]
+    fn can_sweep_equal_angles() {
+        assert_eq!(sweep(45, 45, 3, SweepDirection::Shorter), [45, 45, 45]);
+        assert_eq!(sweep(45, 45, 3, SweepDirection::Longer), [45, 45, 45]);
+        assert_eq!(sweep(45, 45, 3, SweepDirection::Clockwise), [45, 45, 45]);
+        assert_eq!(
+            sweep(45, 45, 3, SweepDirection::CounterClockwise),
+            [45, 45, 45]
+        );
+    }
+
+    #[test]
+    fn can_sweep_zero_and_one_steps() {
+        assert!(sweep(350, 10, 0, SweepDirection::Shorter).is_empty());
+        assert_eq!(sweep(350, 10, 1, SweepDirection::Shorter), [350]);
+        assert_eq!(sweep(350, 10, 1, SweepDirection::Longer), [350]);
+    }
+
+    #[test]
+    fn can_interpolate_hues_along_a_path() {
+        let hues = |path: HuePath, from: f32, to: f32| -> Vec<f32> {
+            [0.0, 0.25, 0.5, 0.75, 1.0]
+                .iter()
+                .map(|&t| path.interpolate(from, to, t))
+                .collect()
+        };
+
+        // Straddling 0°, from both sides.
+        assert_eq!(
+            hues(HuePath::Shorter, 350.0, 10.0),
+            [350.0, 355.0, 0.0, 5.0, 10.0]
+        );
+        assert_eq!(
+            hues(HuePath::Shorter, 10.0, 350.0),
+            [10.0, 5.0, 0.0, 355.0, 350.0]
+        );
+        assert_eq!(
+            hues(HuePath::Longer, 350.0, 10.0),
+            [350.0, 265.0, 180.0, 95.0, 10.0]
+        );
+        assert_eq!(
+            hues(HuePath::Longer, 10.0, 350.0),
+            [10.0, 95.0, 180.0, 265.0, 350.0]
+        );
+        assert_eq!(
+            hues(HuePath::Increasing, 350.0, 10.0),
+            [350.0, 355.0, 0.0, 5.0, 10.0]
+        );
+        assert_eq!(
+            hues(HuePath::Increasing, 10.0, 350.0),
+            [10.0, 95.0, 180.0, 265.0, 350.0]
+        );
+        assert_eq!(
+            hues(HuePath::Decreasing, 350.0, 10.0),
+            [350.0, 265.0, 180.0, 95.0, 10.0]
+        );
+        assert_eq!(
+            hues(HuePath::Decreasing, 10.0, 350.0),
+            [10.0, 5.0, 0.0, 355.0, 350.0]
+        );
+
+        // Half-turns follow the direction of the hues.
+        assert_eq!(hues(HuePath::Shorter, 0.0, 180.0)[1], 45.0);
+        assert_eq!(hues(HuePath::Shorter, 180.0, 0.0)[1], 135.0);
+        assert_eq!(hues(HuePath::Longer, 0.0, 180.0)[1], 45.0);
+
+        // Between equal hues, only `Longer` moves, all the way around.
+        assert_eq!(hues(HuePath::Shorter, 90.0, 90.0), [90.0; 5]);
+        assert_eq!(hues(HuePath::Increasing, 90.0, 90.0), [90.0; 5]);
+        assert_eq!(hues(HuePath::Decreasing, 90.0, 90.0), [90.0; 5]);
+        assert_eq!(
+            hues(HuePath::Longer, 90.0, 90.0),
+            [90.0, 180.0, 270.0, 0.0, 90.0]
+        );
+    }
 }
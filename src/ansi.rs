@@ -0,0 +1,124 @@
+use super::RGB;
+
+// The channel levels of the 6×6×6 color cube (indices 16-231).
+const CUBE_LEVELS: [u8; 6] = [0, 95, 135, 175, 215, 255];
+
+// Finds the nearest entry of the xterm-256 palette, ignoring the 16 system
+// colors (indices 0-15), whose values vary between terminals.
+pub(crate) fn nearest_ansi256(color: RGB) -> u8 {
+    let channels = [color.r.as_u8(), color.g.as_u8(), color.b.as_u8()];
+
+    // The cube is a grid, so its nearest entry can be found channel by channel.
+    let cube = [
+        nearest_cube_level(channels[0]),
+        nearest_cube_level(channels[1]),
+        nearest_cube_level(channels[2]),
+    ];
+    let cube_index = 16 + 36 * cube[0] + 6 * cube[1] + cube[2];
+    let cube_distance = distance(
+        channels,
+        [
+            CUBE_LEVELS[cube[0] as usize],
+            CUBE_LEVELS[cube[1] as usize],
+            CUBE_LEVELS[cube[2] as usize],
+        ],
+    );
+
+    // The grayscale ramp (indices 232-255) runs from 8 to 238 in steps of 10.
+    let average = channels.iter().map(|&c| c as u32).sum::<u32>() / 3;
+    let step = (average.saturating_sub(3) / 10).min(23) as u8;
+    let grey = 8 + 10 * step;
+    let grey_distance = distance(channels, [grey, grey, grey]);
+
+    if grey_distance < cube_distance {
+        232 + step
+    } else {
+        cube_index
+    }
+}
+
+fn nearest_cube_level(channel: u8) -> u8 {
+    match channel {
+        0..=47 => 0,
+        48..=114 => 1,
+        _ => (channel - 35) / 40,
+    }
+}
+
+fn distance(lhs: [u8; 3], rhs: [u8; 3]) -> u32 {
+    lhs.iter()
+        .zip(rhs.iter())
+        .map(|(&l, &r)| {
+            let delta = l as i32 - r as i32;
+            (delta * delta) as u32
+        })
+        .sum()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{distance, nearest_ansi256, CUBE_LEVELS};
+    use {hsl, rgb, rgba, Color};
+
+    // Computes the palette entry for an index, for a brute-force search.
+    fn palette(index: u8) -> [u8; 3] {
+        if index >= 232 {
+            let grey = 8 + 10 * (index - 232);
+            [grey, grey, grey]
+        } else {
+            let cube = index - 16;
+            [
+                CUBE_LEVELS[(cube / 36) as usize],
+                CUBE_LEVELS[(cube / 6 % 6) as usize],
+                CUBE_LEVELS[(cube % 6) as usize],
+            ]
+        }
+    }
+
+    #[test]
+    fn can_map_known_colors() {
+        assert_eq!(rgb(255, 0, 0).to_ansi256(), 196);
+        assert_eq!(rgb(0, 255, 0).to_ansi256(), 46);
+        assert_eq!(rgb(0, 0, 255).to_ansi256(), 21);
+        assert_eq!(rgb(0, 0, 0).to_ansi256(), 16);
+        assert_eq!(rgb(255, 255, 255).to_ansi256(), 231);
+        assert_eq!(rgb(255, 99, 71).to_ansi256(), 203);
+        assert_eq!(hsl(0, 100, 50).to_ansi256(), 196);
+        assert_eq!(rgba(255, 0, 0, 0.5).to_ansi256(), 196);
+    }
+
+    #[test]
+    fn prefers_the_grayscale_ramp_for_greys() {
+        assert_eq!(rgb(128, 128, 128).to_ansi256(), 244);
+        assert_eq!(rgb(8, 8, 8).to_ansi256(), 232);
+        assert_eq!(rgb(238, 238, 238).to_ansi256(), 255);
+        assert_eq!(rgb(100, 100, 100).to_ansi256(), 241);
+
+        // Exact matches in the cube win over the ramp.
+        assert_eq!(rgb(135, 135, 135).to_ansi256(), 102);
+    }
+
+    #[test]
+    fn picks_the_nearest_entry() {
+        for r in (0..=255).step_by(5) {
+            for g in (0..=255).step_by(15) {
+                for b in (0..=255).step_by(17) {
+                    let channels = [r as u8, g as u8, b as u8];
+                    let index = nearest_ansi256(rgb(channels[0], channels[1], channels[2]));
+                    let best = (16..=255)
+                        .map(|index| distance(channels, palette(index)))
+                        .min()
+                        .unwrap();
+
+                    assert_eq!(distance(channels, palette(index)), best, "{:?}", channels);
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn can_format_escape_codes() {
+        assert_eq!(rgb(255, 0, 0).to_ansi256_fg(), "\u{1b}[38;5;196m");
+        assert_eq!(rgb(128, 128, 128).to_ansi256_fg(), "\u{1b}[38;5;244m");
+    }
+}
@@ -0,0 +1,437 @@
+use super::{contrast, cvd, Color, Cvd, RGB, RGBA};
+
+// Colors closer than this CIE76 color difference are hard to tell apart.
+const MIN_DELTA_E: f32 = 10.0;
+
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+/// The WCAG contrast ratio of every pair of colors in a palette.
+pub struct ContrastMatrix {
+    // the CSS representation of each color of the palette
+    pub colors: Vec<String>,
+
+    // the contrast ratio between the colors at indices `i` and `j`, in `ratios[i][j]`
+    pub ratios: Vec<Vec<f32>>,
+}
+
+#[derive(Debug, Copy, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+/// A pair of colors whose contrast ratio falls short of the targeted ratio.
+pub struct ContrastFailure {
+    // index of the foreground color
+    pub foreground: usize,
+
+    // index of the background color
+    pub background: usize,
+
+    // the contrast ratio between the two colors
+    pub ratio: f32,
+
+    // the targeted contrast ratio
+    pub target: f32,
+}
+
+#[derive(Debug, Copy, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "serde", serde(tag = "kind", rename_all = "snake_case"))]
+/// An issue with a color of a palette, independent of the other colors.
+pub enum PaletteIssue {
+    // The color is the same as an earlier color of the palette.
+    Duplicate { index: usize, duplicate_of: usize },
+
+    // The color is translucent, so its contrast depends on what is behind it.
+    // Translucent colors are audited as if they were rendered over white.
+    Translucent { index: usize, alpha: f32 },
+}
+
+#[derive(Debug, Copy, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+/// A pair of colors that can only be told apart with full color vision.
+pub struct CvdConflict {
+    // index of the first color
+    pub first: usize,
+
+    // index of the second color
+    pub second: usize,
+
+    // the CIE76 color difference between the two colors, as simulated
+    pub delta_e: f32,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+/// The colors of a palette that become indistinguishable with a color vision deficiency.
+pub struct ColorBlindnessCheck {
+    // the simulated color vision deficiency
+    pub deficiency: Cvd,
+
+    // the pairs of colors that become indistinguishable
+    pub conflicts: Vec<CvdConflict>,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+/// The result of `audit_palette`.
+pub struct AuditReport {
+    // the contrast ratio of every pair of colors
+    pub contrast_matrix: ContrastMatrix,
+
+    // the pairs of colors that don't reach the targeted contrast ratio
+    pub failing_pairs: Vec<ContrastFailure>,
+
+    // issues with individual colors
+    pub palette_issues: Vec<PaletteIssue>,
+
+    // one check for every simulated color vision deficiency
+    pub color_blindness: Vec<ColorBlindnessCheck>,
+}
+
+impl AuditReport {
+    /// Returns `true` if the audit found no failing pairs, palette issues or
+    /// color blindness conflicts.
+    pub fn is_clean(&self) -> bool {
+        self.failing_pairs.is_empty()
+            && self.palette_issues.is_empty()
+            && self
+                .color_blindness
+                .iter()
+                .all(|check| check.conflicts.is_empty())
+    }
+
+    /// Serializes the report into pretty-printed JSON.
+    ///
+    /// # Example
+    /// ```
+    /// use css_colors::{audit_palette, rgb};
+    ///
+    /// let report = audit_palette(&[rgb(0, 0, 0), rgb(255, 255, 255)], 4.5);
+    /// let json = report.to_json();
+    ///
+    /// assert!(json.contains("\"failing_pairs\": []"));
+    /// ```
+    #[cfg(feature = "serde")]
+    pub fn to_json(&self) -> String {
+        serde_json::to_string_pretty(self).expect("audit reports are always serializable")
+    }
+}
+
+/// Audits a palette whose colors are used as text and background pairs.
+///
+/// * Every pair of colors whose WCAG contrast ratio is lower than `target` is
+///   reported as failing, with the lower index as foreground.
+/// * Duplicated and translucent colors are reported as palette issues.
+///   Translucent colors are audited as if they were rendered over white.
+/// * For every simulated color vision deficiency, the pairs of colors that are
+///   distinguishable (a CIE76 color difference of at least `10`) with full
+///   color vision, but not once simulated, are reported as conflicts.
+///
+/// # Example
+/// ```
+/// use css_colors::{audit_palette, rgb, PaletteIssue};
+///
+/// let palette = [rgb(255, 255, 255), rgb(51, 51, 51), rgb(220, 20, 60), rgb(34, 139, 34)];
+/// let report = audit_palette(&palette, 4.5);
+///
+/// for failure in &report.failing_pairs {
+///     println!(
+///         "{} on {}: {:.2} < {}",
+///         palette[failure.foreground], palette[failure.background], failure.ratio, failure.target
+///     );
+/// }
+///
+/// assert_eq!(report.failing_pairs.len(), 4);
+/// assert!(report.palette_issues.is_empty());
+/// assert!(!report.is_clean());
+/// ```
+pub fn audit_palette<T: Color>(palette: &[T], target: f32) -> AuditReport {
+    let colors: Vec<RGBA> = palette.iter().map(|color| color.to_rgba()).collect();
+    let flattened: Vec<RGB> = colors
+        .iter()
+        .map(|&color| contrast::flatten(color))
+        .collect();
+
+    let ratios: Vec<Vec<f32>> = flattened
+        .iter()
+        .map(|&lhs| {
+            flattened
+                .iter()
+                .map(|&rhs| contrast::contrast_ratio(lhs, rhs))
+                .collect()
+        })
+        .collect();
+
+    let mut failing_pairs = Vec::new();
+
+    for (foreground, row) in ratios.iter().enumerate() {
+        for (background, &ratio) in row.iter().enumerate().skip(foreground + 1) {
+            if ratio < target {
+                failing_pairs.push(ContrastFailure {
+                    foreground,
+                    background,
+                    ratio,
+                    target,
+                });
+            }
+        }
+    }
+
+    let mut palette_issues = Vec::new();
+
+    for (index, color) in colors.iter().enumerate() {
+        if let Some(duplicate_of) = colors[..index].iter().position(|other| other == color) {
+            palette_issues.push(PaletteIssue::Duplicate {
+                index,
+                duplicate_of,
+            });
+        }
+
+        if color.a.as_u8() < 255 {
+            palette_issues.push(PaletteIssue::Translucent {
+                index,
+                alpha: color.a.as_f32(),
+            });
+        }
+    }
+
+    let color_blindness = Cvd::ALL
+        .iter()
+        .map(|&deficiency| {
+            let simulated: Vec<RGB> = flattened
+                .iter()
+                .map(|&color| cvd::simulate(color, deficiency))
+                .collect();
+
+            let mut conflicts = Vec::new();
+
+            for first in 0..flattened.len() {
+                for second in first + 1..flattened.len() {
+                    let delta_e = delta_e(simulated[first], simulated[second]);
+
+                    if delta_e < MIN_DELTA_E
+                        && self::delta_e(flattened[first], flattened[second]) >= MIN_DELTA_E
+                    {
+                        conflicts.push(CvdConflict {
+                            first,
+                            second,
+                            delta_e,
+                        });
+                    }
+                }
+            }
+
+            ColorBlindnessCheck {
+                deficiency,
+                conflicts,
+            }
+        })
+        .collect();
+
+    AuditReport {
+        contrast_matrix: ContrastMatrix {
+            colors: palette.iter().map(|color| color.to_css()).collect(),
+            ratios,
+        },
+        failing_pairs,
+        palette_issues,
+        color_blindness,
+    }
+}
+
+/// Reports every combination of colors of `palette`, used as text over a
+/// background, whose WCAG contrast ratio (see `Color::contrast_ratio`) is lower
+/// than `target`.
+///
+/// Every color is checked over every other one, in both directions, since
+/// translucent colors make the contrast depend on which one is on top: the
+/// background is composited over white, then the foreground over the result.
+/// A color is never checked against itself. Failures are ordered by the index
+/// of their foreground, then of their background.
+///
+/// # Example
+/// ```
+/// use css_colors::{audit_contrast, rgba};
+///
+/// let theme = [
+///     rgba(255, 255, 255, 1.0), // page
+///     rgba(33, 37, 41, 1.0),    // text
+///     rgba(108, 117, 125, 1.0), // muted text
+///     rgba(13, 110, 253, 1.0),  // primary
+///     rgba(33, 37, 41, 0.1),    // hover
+/// ];
+/// let failures = audit_contrast(&theme, 4.5);
+///
+/// for failure in &failures {
+///     println!(
+///         "{} on {}: {:.2} < {}",
+///         theme[failure.foreground], theme[failure.background], failure.ratio, failure.target
+///     );
+/// }
+///
+/// assert_eq!(failures.len(), 13);
+/// assert_eq!((failures[0].foreground, failures[0].background), (0, 4));
+/// assert!(audit_contrast(&theme[..2], 4.5).is_empty());
+/// ```
+pub fn audit_contrast<T: Color>(palette: &[T], target: f32) -> Vec<ContrastFailure> {
+    let colors: Vec<RGBA> = palette.iter().map(|color| color.to_rgba()).collect();
+    let mut failures = Vec::new();
+
+    for (foreground, color) in colors.iter().enumerate() {
+        for (background, &backdrop) in colors.iter().enumerate() {
+            if foreground == background {
+                continue;
+            }
+
+            let ratio = color.contrast_ratio(backdrop);
+
+            if ratio < target {
+                failures.push(ContrastFailure {
+                    foreground,
+                    background,
+                    ratio,
+                    target,
+                });
+            }
+        }
+    }
+
+    failures
+}
+
+// Computes the CIE76 color difference between two colors, i.e. their euclidean
+// distance in the CIELAB color space.
+fn delta_e(lhs: RGB, rhs: RGB) -> f32 {
+    let lhs = lhs.to_lab();
+    let rhs = rhs.to_lab();
+
+    ((lhs.l - rhs.l).powi(2) + (lhs.a - rhs.a).powi(2) + (lhs.b - rhs.b).powi(2)).sqrt()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::delta_e;
+    use {audit_contrast, audit_palette, rgb, rgba, Color, Cvd, PaletteIssue};
+
+    #[test]
+    fn can_compute_color_differences() {
+        assert_eq!(delta_e(rgb(255, 99, 71), rgb(255, 99, 71)), 0.0);
+        assert!((delta_e(rgb(0, 0, 0), rgb(255, 255, 255)) - 100.0).abs() < 0.01);
+        assert!((delta_e(rgb(255, 0, 0), rgb(0, 0, 255)) - 176.31).abs() < 0.1);
+    }
+
+    #[test]
+    fn can_build_contrast_matrices() {
+        let report = audit_palette(&[rgb(0, 0, 0), rgb(255, 255, 255), rgb(118, 118, 118)], 4.5);
+        let matrix = report.contrast_matrix;
+
+        assert_eq!(
+            matrix.colors,
+            ["rgb(0, 0, 0)", "rgb(255, 255, 255)", "rgb(118, 118, 118)"]
+        );
+        assert_eq!(matrix.ratios.len(), 3);
+
+        for i in 0..3 {
+            assert_eq!(matrix.ratios[i][i], 1.0);
+
+            for j in 0..3 {
+                assert_eq!(matrix.ratios[i][j], matrix.ratios[j][i]);
+            }
+        }
+
+        assert!(matrix.ratios[0][1] > 20.99);
+        assert!(matrix.ratios[1][2] > 4.5);
+        assert!(matrix.ratios[0][2] > 4.5);
+        assert!(report.failing_pairs.is_empty());
+    }
+
+    #[test]
+    fn can_report_failing_pairs() {
+        let report = audit_palette(&[rgb(255, 255, 255), rgb(119, 119, 119), rgb(0, 0, 0)], 4.5);
+
+        assert_eq!(report.failing_pairs.len(), 1);
+        assert_eq!(report.failing_pairs[0].foreground, 0);
+        assert_eq!(report.failing_pairs[0].background, 1);
+        assert!(report.failing_pairs[0].ratio < 4.5);
+        assert_eq!(report.failing_pairs[0].target, 4.5);
+    }
+
+    #[test]
+    fn can_report_palette_issues() {
+        let palette = [
+            rgba(255, 255, 255, 1.0),
+            rgba(0, 0, 0, 0.5),
+            rgba(255, 255, 255, 1.0),
+        ];
+        let report = audit_palette(&palette, 4.5);
+
+        assert_eq!(
+            report.palette_issues,
+            [
+                PaletteIssue::Translucent {
+                    index: 1,
+                    alpha: palette[1].a.as_f32()
+                },
+                PaletteIssue::Duplicate {
+                    index: 2,
+                    duplicate_of: 0
+                },
+            ]
+        );
+    }
+
+    #[test]
+    fn can_report_color_blindness_conflicts() {
+        let crimson = rgb(220, 20, 60);
+        let forest_green = rgb(34, 139, 34);
+        let report = audit_palette(&[crimson, forest_green], 1.0);
+
+        assert_eq!(report.color_blindness.len(), 3);
+        assert_eq!(report.color_blindness[0].deficiency, Cvd::Protanopia);
+        assert_eq!(report.color_blindness[1].deficiency, Cvd::Deuteranopia);
+        assert_eq!(report.color_blindness[2].deficiency, Cvd::Tritanopia);
+
+        // Crimson and forest green are hard to tell apart without green cones.
+        let conflicts = &report.color_blindness[1].conflicts;
+
+        assert_eq!(conflicts.len(), 1);
+        assert_eq!((conflicts[0].first, conflicts[0].second), (0, 1));
+        assert!(conflicts[0].delta_e < 10.0);
+
+        assert!(report.color_blindness[0].conflicts.is_empty());
+        assert!(report.color_blindness[2].conflicts.is_empty());
+
+        assert!(audit_palette(&[rgb(0, 0, 0), rgb(255, 255, 255)], 4.5).is_clean());
+    }
+
+    #[test]
+    fn can_audit_contrast_in_both_directions() {
+        let white = rgb(255, 255, 255);
+        let grey = rgb(119, 119, 119);
+        let black = rgb(0, 0, 0);
+
+        let failures = audit_contrast(&[white, grey, black], 4.5);
+        let pairs: Vec<(usize, usize)> = failures
+            .iter()
+            .map(|failure| (failure.foreground, failure.background))
+            .collect();
+
+        assert_eq!(pairs, [(0, 1), (1, 0)]);
+        assert_eq!(failures[0].ratio, white.contrast_ratio(grey));
+        assert_eq!(failures[0].target, 4.5);
+        assert!(audit_contrast(&[white, black], 4.5).is_empty());
+        assert!(audit_contrast::<::RGB>(&[], 4.5).is_empty());
+    }
+
+    #[test]
+    fn audit_contrast_composites_translucent_colors() {
+        let black = rgba(0, 0, 0, 1.0);
+        let veil = rgba(255, 255, 255, 0.5);
+
+        // The veil is white over the page, but mid grey over black.
+        let failures = audit_contrast(&[black, veil], 7.0);
+
+        assert_eq!(failures.len(), 1);
+        assert_eq!((failures[0].foreground, failures[0].background), (1, 0));
+        assert_eq!(failures[0].ratio, veil.contrast_ratio(black));
+        assert!(black.contrast_ratio(veil) > 20.99);
+    }
+}
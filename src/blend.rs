@@ -0,0 +1,682 @@
+use super::{ops, Ratio, RGBA};
+use std::error::Error;
+use std::fmt;
+use std::str::FromStr;
+
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "kebab-case"))]
+/// An enum of the separable blend modes of CSS, as used by `mix-blend-mode` and
+/// `background-blend-mode`.
+///
+/// The modes are named after their CSS keywords, which are used to display and
+/// parse them, so they can be stored as data and passed to `Color::blend`.
+///
+/// # Example
+/// ```
+/// use css_colors::BlendMode;
+///
+/// assert_eq!("color-dodge".parse(), Ok(BlendMode::ColorDodge));
+/// assert_eq!(BlendMode::HardLight.to_string(), "hard-light");
+/// ```
+///
+/// For more, see the [W3C compositing spec](https://www.w3.org/TR/compositing-1/#ltblendmodegt).
+pub enum BlendMode {
+    Normal,
+    Multiply,
+    Screen,
+    Overlay,
+    Darken,
+    Lighten,
+    ColorDodge,
+    ColorBurn,
+    HardLight,
+    SoftLight,
+    Difference,
+    Exclusion,
+}
+
+impl BlendMode {
+    // The CSS keyword of the blend mode.
+    fn keyword(self) -> &'static str {
+        match self {
+            BlendMode::Normal => "normal",
+            BlendMode::Multiply => "multiply",
+            BlendMode::Screen => "screen",
+            BlendMode::Overlay => "overlay",
+            BlendMode::Darken => "darken",
+            BlendMode::Lighten => "lighten",
+            BlendMode::ColorDodge => "color-dodge",
+            BlendMode::ColorBurn => "color-burn",
+            BlendMode::HardLight => "hard-light",
+            BlendMode::SoftLight => "soft-light",
+            BlendMode::Difference => "difference",
+            BlendMode::Exclusion => "exclusion",
+        }
+    }
+
+    pub(crate) fn function(self) -> BlendFn {
+        match self {
+            BlendMode::Normal => normal,
+            BlendMode::Multiply => multiply,
+            BlendMode::Screen => screen,
+            BlendMode::Overlay => overlay,
+            BlendMode::Darken => darken,
+            BlendMode::Lighten => lighten,
+            BlendMode::ColorDodge => color_dodge,
+            BlendMode::ColorBurn => color_burn,
+            BlendMode::HardLight => hard_light,
+            BlendMode::SoftLight => soft_light,
+            BlendMode::Difference => difference,
+            BlendMode::Exclusion => exclusion,
+        }
+    }
+}
+
+impl fmt::Display for BlendMode {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.keyword())
+    }
+}
+
+impl FromStr for BlendMode {
+    type Err = ParseBlendModeError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let modes = [
+            BlendMode::Normal,
+            BlendMode::Multiply,
+            BlendMode::Screen,
+            BlendMode::Overlay,
+            BlendMode::Darken,
+            BlendMode::Lighten,
+            BlendMode::ColorDodge,
+            BlendMode::ColorBurn,
+            BlendMode::HardLight,
+            BlendMode::SoftLight,
+            BlendMode::Difference,
+            BlendMode::Exclusion,
+        ];
+
+        modes
+            .iter()
+            .copied()
+            .find(|mode| mode.keyword().eq_ignore_ascii_case(s.trim()))
+            .ok_or(ParseBlendModeError)
+    }
+}
+
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Hash)]
+/// The error returned when a string isn't the CSS keyword of a `BlendMode`.
+pub struct ParseBlendModeError;
+
+impl fmt::Display for ParseBlendModeError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str("unknown blend mode")
+    }
+}
+
+impl Error for ParseBlendModeError {}
+
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Hash, Default)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "kebab-case"))]
+/// An enum of the spaces in which the channels of two colors can be blended
+/// (see `Color::blend`).
+pub enum BlendSpace {
+    // the gamma-encoded sRGB channels, as Less and browsers do
+    #[default]
+    Gamma,
+
+    // linear light, which is physically correct and doesn't darken midtones
+    Linear,
+}
+
+// A separable blend mode, which mixes a channel of the backdrop (`cb`) with the
+// same channel of the source (`cs`), both between `0.0-1.0`.
+pub(crate) type BlendFn = fn(f64, f64) -> f64;
+
+// Converts a channel between `0.0-1.0` into or out of a `BlendSpace`.
+type Transfer = fn(f64) -> f64;
+
+// Blends `source` over `backdrop` with a separable blend mode, like Less does.
+// Following the W3C compositing spec, the blended channels are weighted by the
+// alpha of both colors, and the result is composited "source over" the backdrop:
+// the alpha of the result is `as + ab × (1 - as)`. Two opaque colors give the
+// blend mode itself.
+//
+// With the `normal` blend mode, this is the "source over" compositing operator
+// of Porter and Duff, with the channels premultiplied by their alpha.
+//
+// The channels are computed in double precision and rounded once, like Less
+// does, so that the bytes match its output.
+// See https://www.w3.org/TR/compositing-1/#blending.
+pub(crate) fn blend(backdrop: RGBA, source: RGBA, mode: BlendFn) -> RGBA {
+    blend_in(backdrop, source, mode, BlendSpace::Gamma)
+}
+
+// Blends like `blend`, with the channels converted into `space` first. Alpha is
+// never gamma-encoded, so it is composited the same way in both spaces.
+pub(crate) fn blend_in(backdrop: RGBA, source: RGBA, mode: BlendFn, space: BlendSpace) -> RGBA {
+    let (decode, encode): (Transfer, Transfer) = match space {
+        BlendSpace::Gamma => (|c| c, |c| c),
+        BlendSpace::Linear => (
+            |c| f64::from(ops::linearize(c as f32)),
+            |c| f64::from(ops::delinearize(c as f32)),
+        ),
+    };
+
+    let ab = f64::from(backdrop.a.as_f32());
+    let as_ = f64::from(source.a.as_f32());
+    let ar = as_ + ab * (1.0 - as_);
+
+    let channel = |cb: Ratio, cs: Ratio| {
+        let cb = decode(f64::from(cb.as_u8()) / 255.0);
+        let cs = decode(f64::from(cs.as_u8()) / 255.0);
+        let mut cr = mode(cb, cs);
+
+        if ar != 0.0 {
+            cr = (as_ * cs + ab * (cb - as_ * (cb + cs - cr))) / ar;
+        }
+
+        Ratio::from_u8((encode(cr) * 255.0).round().clamp(0.0, 255.0) as u8)
+    };
+
+    RGBA {
+        r: channel(backdrop.r, source.r),
+        g: channel(backdrop.g, source.g),
+        b: channel(backdrop.b, source.b),
+        a: ops::quantize(ar as f32),
+    }
+}
+
+pub(crate) fn normal(_cb: f64, cs: f64) -> f64 {
+    cs
+}
+
+pub(crate) fn multiply(cb: f64, cs: f64) -> f64 {
+    cb * cs
+}
+
+pub(crate) fn screen(cb: f64, cs: f64) -> f64 {
+    cb + cs - cb * cs
+}
+
+pub(crate) fn darken(cb: f64, cs: f64) -> f64 {
+    cb.min(cs)
+}
+
+pub(crate) fn lighten(cb: f64, cs: f64) -> f64 {
+    cb.max(cs)
+}
+
+pub(crate) fn color_dodge(cb: f64, cs: f64) -> f64 {
+    if cb == 0.0 {
+        0.0
+    } else if cs == 1.0 {
+        1.0
+    } else {
+        (cb / (1.0 - cs)).min(1.0)
+    }
+}
+
+pub(crate) fn color_burn(cb: f64, cs: f64) -> f64 {
+    if cb == 1.0 {
+        1.0
+    } else if cs == 0.0 {
+        0.0
+    } else {
+        1.0 - ((1.0 - cb) / cs).min(1.0)
+    }
+}
+
+pub(crate) fn difference(cb: f64, cs: f64) -> f64 {
+    (cb - cs).abs()
+}
+
+pub(crate) fn exclusion(cb: f64, cs: f64) -> f64 {
+    cb + cs - 2.0 * cb * cs
+}
+
+// Not a blend mode of the W3C compositing spec, but one of Less.
+pub(crate) fn negation(cb: f64, cs: f64) -> f64 {
+    1.0 - (cb + cs - 1.0).abs()
+}
+
+// Not a blend mode of the W3C compositing spec, but one of Less.
+pub(crate) fn average(cb: f64, cs: f64) -> f64 {
+    (cb + cs) / 2.0
+}
+
+pub(crate) fn overlay(cb: f64, cs: f64) -> f64 {
+    hard_light(cs, cb)
+}
+
+pub(crate) fn hard_light(cb: f64, cs: f64) -> f64 {
+    if cs <= 0.5 {
+        multiply(cb, 2.0 * cs)
+    } else {
+        screen(cb, 2.0 * cs - 1.0)
+    }
+}
+
+pub(crate) fn soft_light(cb: f64, cs: f64) -> f64 {
+    if cs <= 0.5 {
+        cb - (1.0 - 2.0 * cs) * cb * (1.0 - cb)
+    } else {
+        cb + (2.0 * cs - 1.0) * (soft_light_d(cb) - cb)
+    }
+}
+
+// The `D(Cb)` function of the soft light blend mode, which follows a polynomial
+// for dark backdrops and a square root for the others, meeting at `0.25`.
+fn soft_light_d(cb: f64) -> f64 {
+    if cb <= 0.25 {
+        ((16.0 * cb - 12.0) * cb + 4.0) * cb
+    } else {
+        cb.sqrt()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use {hsl, rgb, rgba, Color, RGB};
+
+    // The colors of the examples of Less' documentation, blended with `#ff6600`.
+    const SOURCES: [(u8, u8, u8); 9] = [
+        (0x00, 0x00, 0x00),
+        (0x33, 0x33, 0x33),
+        (0x66, 0x66, 0x66),
+        (0x99, 0x99, 0x99),
+        (0xcc, 0xcc, 0xcc),
+        (0xff, 0xff, 0xff),
+        (0xff, 0x00, 0x00),
+        (0x00, 0xff, 0x00),
+        (0x00, 0x00, 0xff),
+    ];
+
+    fn check(blend: fn(RGB, RGB) -> RGB, expected: [(u8, u8, u8); 9]) {
+        let backdrop = rgb(0xff, 0x66, 0x00);
+
+        for (&(r, g, b), &(er, eg, eb)) in SOURCES.iter().zip(expected.iter()) {
+            assert_eq!(
+                blend(backdrop, rgb(r, g, b)),
+                rgb(er, eg, eb),
+                "{}",
+                rgb(r, g, b)
+            );
+        }
+    }
+
+    #[test]
+    fn can_multiply() {
+        check(
+            |backdrop, source| backdrop.multiply(source).to_rgb(),
+            [
+                (0x00, 0x00, 0x00),
+                (0x33, 0x14, 0x00),
+                (0x66, 0x29, 0x00),
+                (0x99, 0x3d, 0x00),
+                (0xcc, 0x52, 0x00),
+                (0xff, 0x66, 0x00),
+                (0xff, 0x00, 0x00),
+                (0x00, 0x66, 0x00),
+                (0x00, 0x00, 0x00),
+            ],
+        );
+    }
+
+    #[test]
+    fn can_screen() {
+        check(
+            |backdrop, source| backdrop.screen(source).to_rgb(),
+            [
+                (0xff, 0x66, 0x00),
+                (0xff, 0x85, 0x33),
+                (0xff, 0xa3, 0x66),
+                (0xff, 0xc2, 0x99),
+                (0xff, 0xe0, 0xcc),
+                (0xff, 0xff, 0xff),
+                (0xff, 0x66, 0x00),
+                (0xff, 0xff, 0x00),
+                (0xff, 0x66, 0xff),
+            ],
+        );
+    }
+
+    #[test]
+    fn can_overlay() {
+        check(
+            |backdrop, source| backdrop.overlay(source).to_rgb(),
+            [
+                (0xff, 0x00, 0x00),
+                (0xff, 0x29, 0x00),
+                (0xff, 0x52, 0x00),
+                (0xff, 0x7a, 0x00),
+                (0xff, 0xa3, 0x00),
+                (0xff, 0xcc, 0x00),
+                (0xff, 0x00, 0x00),
+                (0xff, 0xcc, 0x00),
+                (0xff, 0x00, 0x00),
+            ],
+        );
+    }
+
+    #[test]
+    fn can_hardlight() {
+        check(
+            |backdrop, source| backdrop.hardlight(source).to_rgb(),
+            [
+                (0x00, 0x00, 0x00),
+                (0x66, 0x29, 0x00),
+                (0xcc, 0x52, 0x00),
+                (0xff, 0x85, 0x33),
+                (0xff, 0xc2, 0x99),
+                (0xff, 0xff, 0xff),
+                (0xff, 0x00, 0x00),
+                (0x00, 0xff, 0x00),
+                (0x00, 0x00, 0xff),
+            ],
+        );
+    }
+
+    #[test]
+    fn can_softlight() {
+        check(
+            |backdrop, source| backdrop.softlight(source).to_rgb(),
+            [
+                (0xff, 0x29, 0x00),
+                (0xff, 0x41, 0x00),
+                (0xff, 0x5a, 0x00),
+                (0xff, 0x72, 0x00),
+                (0xff, 0x8a, 0x00),
+                (0xff, 0xa1, 0x00),
+                (0xff, 0x29, 0x00),
+                (0xff, 0xa1, 0x00),
+                (0xff, 0x29, 0x00),
+            ],
+        );
+    }
+
+    #[test]
+    fn can_difference() {
+        check(
+            |backdrop, source| backdrop.difference(source).to_rgb(),
+            [
+                (0xff, 0x66, 0x00),
+                (0xcc, 0x33, 0x33),
+                (0x99, 0x00, 0x66),
+                (0x66, 0x33, 0x99),
+                (0x33, 0x66, 0xcc),
+                (0x00, 0x99, 0xff),
+                (0x00, 0x66, 0x00),
+                (0xff, 0x99, 0x00),
+                (0xff, 0x66, 0xff),
+            ],
+        );
+    }
+
+    #[test]
+    fn can_exclusion() {
+        check(
+            |backdrop, source| backdrop.exclusion(source).to_rgb(),
+            [
+                (0xff, 0x66, 0x00),
+                (0xcc, 0x70, 0x33),
+                (0x99, 0x7a, 0x66),
+                (0x66, 0x85, 0x99),
+                (0x33, 0x8f, 0xcc),
+                (0x00, 0x99, 0xff),
+                (0x00, 0x66, 0x00),
+                (0xff, 0x99, 0x00),
+                (0xff, 0x66, 0xff),
+            ],
+        );
+    }
+
+    #[test]
+    fn can_negation() {
+        check(
+            |backdrop, source| backdrop.negation(source).to_rgb(),
+            [
+                (0xff, 0x66, 0x00),
+                (0xcc, 0x99, 0x33),
+                (0x99, 0xcc, 0x66),
+                (0x66, 0xff, 0x99),
+                (0x33, 0xcc, 0xcc),
+                (0x00, 0x99, 0xff),
+                (0x00, 0x66, 0x00),
+                (0xff, 0x99, 0x00),
+                (0xff, 0x66, 0xff),
+            ],
+        );
+    }
+
+    #[test]
+    fn can_average() {
+        check(
+            |backdrop, source| backdrop.average(source).to_rgb(),
+            [
+                (0x80, 0x33, 0x00),
+                (0x99, 0x4d, 0x1a),
+                (0xb3, 0x66, 0x33),
+                (0xcc, 0x80, 0x4d),
+                (0xe6, 0x99, 0x66),
+                (0xff, 0xb3, 0x80),
+                (0xff, 0x33, 0x00),
+                (0x80, 0xb3, 0x00),
+                (0x80, 0x33, 0x80),
+            ],
+        );
+    }
+
+    #[test]
+    fn can_blend_with_every_mode() {
+        let backdrop = rgb(255, 102, 0);
+        let source = rgb(51, 153, 204);
+
+        let expected = [
+            (BlendMode::Normal, rgb(51, 153, 204)),
+            (BlendMode::Multiply, rgb(51, 61, 0)),
+            (BlendMode::Screen, rgb(255, 194, 204)),
+            (BlendMode::Overlay, rgb(255, 122, 0)),
+            (BlendMode::Darken, rgb(51, 102, 0)),
+            (BlendMode::Lighten, rgb(255, 153, 204)),
+            (BlendMode::ColorDodge, rgb(255, 255, 0)),
+            (BlendMode::ColorBurn, rgb(255, 0, 0)),
+            (BlendMode::HardLight, rgb(102, 133, 153)),
+            (BlendMode::SoftLight, rgb(255, 114, 0)),
+            (BlendMode::Difference, rgb(204, 51, 204)),
+            (BlendMode::Exclusion, rgb(204, 133, 204)),
+        ];
+
+        for &(mode, color) in expected.iter() {
+            assert_eq!(
+                backdrop.blend(source, mode, BlendSpace::Gamma).to_rgb(),
+                color,
+                "{}",
+                mode
+            );
+        }
+    }
+
+    #[test]
+    fn blending_matches_the_less_methods() {
+        let backdrop = rgba(255, 102, 0, 0.4);
+        let source = rgba(51, 51, 51, 0.6);
+
+        assert_eq!(
+            backdrop.blend(source, BlendMode::Multiply, BlendSpace::Gamma),
+            backdrop.multiply(source)
+        );
+        assert_eq!(
+            backdrop.blend(source, BlendMode::Screen, BlendSpace::Gamma),
+            backdrop.screen(source)
+        );
+        assert_eq!(
+            backdrop.blend(source, BlendMode::Overlay, BlendSpace::Gamma),
+            backdrop.overlay(source)
+        );
+        assert_eq!(
+            backdrop.blend(source, BlendMode::HardLight, BlendSpace::Gamma),
+            backdrop.hardlight(source)
+        );
+        assert_eq!(
+            backdrop.blend(source, BlendMode::SoftLight, BlendSpace::Gamma),
+            backdrop.softlight(source)
+        );
+        assert_eq!(
+            backdrop.blend(source, BlendMode::Difference, BlendSpace::Gamma),
+            backdrop.difference(source)
+        );
+        assert_eq!(
+            backdrop.blend(source, BlendMode::Exclusion, BlendSpace::Gamma),
+            backdrop.exclusion(source)
+        );
+        assert_eq!(
+            backdrop.blend(source, BlendMode::Normal, BlendSpace::Gamma),
+            rgba(94, 62, 40, 0.76)
+        );
+    }
+
+    #[test]
+    fn can_blend_in_linear_light() {
+        let black = rgb(0, 0, 0);
+        let grey = rgb(128, 128, 128);
+        let white = rgba(255, 255, 255, 0.5);
+
+        assert_eq!(
+            black.blend(white, BlendMode::Normal, BlendSpace::Gamma),
+            rgba(128, 128, 128, 1.0)
+        );
+        assert_eq!(
+            black.blend(white, BlendMode::Normal, BlendSpace::Linear),
+            rgba(188, 188, 188, 1.0)
+        );
+
+        // Midtones are multiplied as the light they emit, which is darker than
+        // their gamma-encoded value.
+        assert_eq!(
+            grey.blend(grey, BlendMode::Multiply, BlendSpace::Gamma),
+            rgba(64, 64, 64, 1.0)
+        );
+        assert_eq!(
+            grey.blend(grey, BlendMode::Multiply, BlendSpace::Linear),
+            rgba(61, 61, 61, 1.0)
+        );
+
+        // Black and white are the same in both spaces.
+        for &mode in [
+            BlendMode::Multiply,
+            BlendMode::Screen,
+            BlendMode::Difference,
+        ]
+        .iter()
+        {
+            for &(backdrop, source) in
+                [(black, rgb(255, 255, 255)), (rgb(255, 0, 255), black)].iter()
+            {
+                assert_eq!(
+                    backdrop.blend(source, mode, BlendSpace::Linear),
+                    backdrop.blend(source, mode, BlendSpace::Gamma)
+                );
+            }
+        }
+
+        assert_eq!(BlendSpace::default(), BlendSpace::Gamma);
+    }
+
+    #[test]
+    fn can_parse_and_display_blend_modes() {
+        let keywords = [
+            "normal",
+            "multiply",
+            "screen",
+            "overlay",
+            "darken",
+            "lighten",
+            "color-dodge",
+            "color-burn",
+            "hard-light",
+            "soft-light",
+            "difference",
+            "exclusion",
+        ];
+
+        for keyword in keywords.iter() {
+            let mode: BlendMode = keyword.parse().unwrap();
+            assert_eq!(mode.to_string(), *keyword);
+        }
+
+        assert_eq!(" Soft-Light ".parse(), Ok(BlendMode::SoftLight));
+        assert_eq!("softlight".parse::<BlendMode>(), Err(ParseBlendModeError));
+        assert_eq!("".parse::<BlendMode>(), Err(ParseBlendModeError));
+    }
+
+    #[test]
+    fn follows_the_soft_light_formula_of_the_spec() {
+        // D(Cb) is continuous where its polynomial meets its square root.
+        assert_eq!(soft_light_d(0.0), 0.0);
+        assert_eq!(soft_light_d(0.25), 0.5);
+        assert_eq!(soft_light_d(1.0), 1.0);
+        assert!((soft_light_d(0.16) - 0.398_336).abs() < 1e-9);
+        assert!((soft_light_d(0.25 + 1e-9) - 0.5).abs() < 1e-8);
+
+        // A source of 50% leaves the backdrop unchanged, black and white darken
+        // and lighten it, and the sqrt branch applies to light backdrops only.
+        for &cb in [0.0, 0.1, 0.25, 0.5, 0.9, 1.0].iter() {
+            assert_eq!(soft_light(cb, 0.5), cb);
+        }
+        assert_eq!(soft_light(0.5, 0.0), 0.25);
+        assert!((soft_light(0.5, 1.0) - 0.5f64.sqrt()).abs() < 1e-12);
+        assert!((soft_light(0.2, 1.0) - 0.448).abs() < 1e-12);
+
+        // Overlay is hard light with its layers swapped.
+        assert_eq!(overlay(0.2, 0.7), hard_light(0.7, 0.2));
+    }
+
+    #[test]
+    fn composites_translucent_colors() {
+        let backdrop = rgba(255, 102, 0, 0.4);
+        let source = rgba(51, 51, 51, 0.6);
+
+        // Less: `multiply(fade(#ff6600, 40%), fade(#333333, 60%))`.
+        assert_eq!(backdrop.multiply(source), rgba(94, 52, 24, 0.76));
+        assert_eq!(backdrop.screen(source), rgba(158, 88, 40, 0.76));
+        assert_eq!(backdrop.overlay(source), rgba(158, 59, 24, 0.76));
+        assert_eq!(backdrop.hardlight(source), rgba(110, 59, 24, 0.76));
+        assert_eq!(backdrop.softlight(source), rgba(158, 66, 24, 0.76));
+        assert_eq!(backdrop.difference(source), rgba(142, 62, 40, 0.76));
+        assert_eq!(backdrop.exclusion(source), rgba(142, 81, 40, 0.76));
+        assert_eq!(backdrop.negation(source), rgba(142, 94, 40, 0.76));
+        assert_eq!(backdrop.average(source), rgba(126, 70, 32, 0.76));
+
+        // A transparent source leaves the backdrop unchanged, and vice versa.
+        assert_eq!(backdrop.multiply(rgba(0, 0, 0, 0.0)), backdrop);
+        assert_eq!(rgba(0, 0, 0, 0.0).screen(source), source);
+        assert_eq!(
+            rgb(255, 102, 0).multiply(rgba(51, 51, 51, 0.0)),
+            rgba(255, 102, 0, 1.0)
+        );
+    }
+
+    #[test]
+    fn blends_in_the_alpha_model_of_self() {
+        let orange = hsl(24, 100, 50);
+        let grey = rgb(51, 51, 51);
+
+        assert_eq!(
+            orange.difference(grey),
+            orange.to_rgba().difference(grey).to_hsla()
+        );
+        assert_eq!(
+            orange.to_hsla().negation(grey),
+            orange.to_rgba().negation(grey).to_hsla()
+        );
+        assert_eq!(
+            orange.to_rgb().exclusion(orange),
+            orange.to_rgba().exclusion(orange)
+        );
+    }
+}
@@ -0,0 +1,241 @@
+use super::{clamp_ratio, ops, percent, Angle, Color, Ratio, HSL, HSLA, RGB, RGBA};
+use std::fmt;
+
+/// Constructs a CMYK Color from numerical values, similar to the
+/// [`device-cmyk` function][css-device-cmyk] in CSS.
+///
+/// The cyan, magenta, yellow and black components are expressed in
+/// percentages. Values outside of the 0-100% range will cause a panic.
+///
+/// # Example
+/// ```
+/// use css_colors::{Color, cmyk, rgb};
+///
+/// let tomato = cmyk(0, 61, 72, 0);
+///
+/// assert_eq!(tomato.to_css(), "device-cmyk(0% 61% 72% 0%)");
+/// assert_eq!(tomato.to_rgb(), rgb(255, 99, 71));
+/// ```
+///
+/// [css-device-cmyk]: https://www.w3.org/TR/css-color-5/#device-cmyk
+pub fn cmyk(c: u8, m: u8, y: u8, k: u8) -> CMYK {
+    CMYK {
+        c: percent(c),
+        m: percent(m),
+        y: percent(y),
+        k: percent(k),
+    }
+}
+
+#[derive(Debug, Copy, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+/// A struct to represent how much cyan, magenta, yellow and black ink should be used to create a color.
+/// Each component ranges between `0-100`, where `0` is no ink, and `100` is full coverage.
+///
+/// The conversions to and from RGB are naive device conversions, which don't take
+/// any color profile into account: `k = 1 - max(r, g, b)` and `c = (1 - r - k) / (1 - k)`
+/// (respectively for magenta and yellow).
+///
+/// For more, see the [CSS Color Spec](https://www.w3.org/TR/css-color-5/#device-cmyk).
+pub struct CMYK {
+    // cyan
+    pub c: Ratio,
+
+    // magenta
+    pub m: Ratio,
+
+    // yellow
+    pub y: Ratio,
+
+    // black
+    pub k: Ratio,
+}
+
+impl fmt::Display for CMYK {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        if f.alternate() {
+            write!(
+                f,
+                "device-cmyk({:#} {:#} {:#} {:#})",
+                self.c, self.m, self.y, self.k
+            )
+        } else {
+            write!(
+                f,
+                "device-cmyk({} {} {} {})",
+                self.c, self.m, self.y, self.k
+            )
+        }
+    }
+}
+
+impl Color for CMYK {
+    type Alpha = RGBA;
+
+    fn to_css(&self) -> String {
+        self.to_string()
+    }
+
+    fn to_rgb(&self) -> RGB {
+        self.to_rgba().to_rgb()
+    }
+
+    fn to_rgba(&self) -> RGBA {
+        let k = self.k.as_f32();
+        let channel = |ink: Ratio| clamp_ratio((1.0 - ink.as_f32()) * (1.0 - k));
+
+        RGBA {
+            r: channel(self.c),
+            g: channel(self.m),
+            b: channel(self.y),
+            a: percent(100),
+        }
+    }
+
+    fn to_hsl(&self) -> HSL {
+        self.to_rgba().to_hsl()
+    }
+
+    fn to_hsla(&self) -> HSLA {
+        self.to_rgba().to_hsla()
+    }
+
+    fn to_cmyk(&self) -> CMYK {
+        *self
+    }
+
+    fn from_rgba(rgba: RGBA) -> CMYK {
+        let RGBA { r, g, b, .. } = rgba;
+        let max = r.max(g).max(b);
+
+        // Pure black can't be divided into inks, and is printed with black only.
+        if max == percent(0) {
+            return CMYK {
+                c: percent(0),
+                m: percent(0),
+                y: percent(0),
+                k: percent(100),
+            };
+        }
+
+        let k = 1.0 - max.as_f32();
+        let ink = |channel: Ratio| clamp_ratio((1.0 - channel.as_f32() - k) / (1.0 - k));
+
+        CMYK {
+            c: ink(r),
+            m: ink(g),
+            y: ink(b),
+            k: percent(100) - max,
+        }
+    }
+
+    fn saturate(self, amount: Ratio) -> Self {
+        ops::apply_to_hsl(self, |hsla| hsla.saturate(amount))
+    }
+
+    fn desaturate(self, amount: Ratio) -> Self {
+        ops::apply_to_hsl(self, |hsla| hsla.desaturate(amount))
+    }
+
+    fn lighten(self, amount: Ratio) -> Self {
+        ops::apply_to_hsl(self, |hsla| hsla.lighten(amount))
+    }
+
+    fn darken(self, amount: Ratio) -> Self {
+        ops::apply_to_hsl(self, |hsla| hsla.darken(amount))
+    }
+
+    fn fadein(self, amount: Ratio) -> RGBA {
+        self.to_rgba().fadein(amount)
+    }
+
+    fn fadeout(self, amount: Ratio) -> RGBA {
+        self.to_rgba().fadeout(amount)
+    }
+
+    fn fade(self, amount: Ratio) -> RGBA {
+        self.to_rgba().fade(amount)
+    }
+
+    fn spin(self, amount: Angle) -> Self {
+        ops::apply_to_hsl(self, |hsla| hsla.spin(amount))
+    }
+
+    fn mix<T: Color>(self, other: T, weight: Ratio) -> RGBA {
+        self.to_rgba().mix(other, weight)
+    }
+
+    fn tint(self, weight: Ratio) -> Self {
+        CMYK::from_rgba(self.to_rgba().tint(weight))
+    }
+
+    fn shade(self, weight: Ratio) -> Self {
+        CMYK::from_rgba(self.to_rgba().shade(weight))
+    }
+
+    fn greyscale(self) -> Self {
+        ops::apply_to_hsl(self, |hsla| hsla.greyscale())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use {cmyk, percent, rgb, rgba, Color, CMYK};
+
+    #[test]
+    fn can_display_cmyk_colors() {
+        assert_eq!(cmyk(0, 61, 72, 0).to_string(), "device-cmyk(0% 61% 72% 0%)");
+        assert_eq!(cmyk(0, 0, 0, 100).to_css(), "device-cmyk(0% 0% 0% 100%)");
+    }
+
+    #[test]
+    fn can_convert_rgb_to_cmyk() {
+        let table = [
+            (rgb(255, 99, 71), "device-cmyk(0% 61% 72% 0%)"),
+            (rgb(250, 128, 114), "device-cmyk(0% 49% 55% 2%)"),
+            (rgb(100, 149, 237), "device-cmyk(58% 37% 0% 7%)"),
+            (rgb(34, 139, 34), "device-cmyk(76% 0% 76% 45%)"),
+            (rgb(128, 128, 128), "device-cmyk(0% 0% 0% 50%)"),
+            (rgb(255, 0, 0), "device-cmyk(0% 100% 100% 0%)"),
+        ];
+
+        for &(color, css) in table.iter() {
+            assert_eq!(color.to_cmyk().to_css(), css);
+            assert_eq!(color.to_cmyk().to_rgb(), color);
+        }
+    }
+
+    #[test]
+    fn can_convert_cmyk_to_rgb() {
+        assert_eq!(cmyk(0, 100, 100, 0).to_rgb(), rgb(255, 0, 0));
+        assert_eq!(cmyk(100, 0, 100, 0).to_rgb(), rgb(0, 255, 0));
+        assert_eq!(cmyk(100, 100, 0, 0).to_rgb(), rgb(0, 0, 255));
+        assert_eq!(cmyk(0, 0, 0, 50).to_rgb(), rgb(127, 127, 127));
+        assert_eq!(cmyk(100, 100, 100, 0).to_rgb(), rgb(0, 0, 0));
+    }
+
+    #[test]
+    fn black_and_white_round_trip_exactly() {
+        assert_eq!(rgb(0, 0, 0).to_cmyk(), cmyk(0, 0, 0, 100));
+        assert_eq!(rgb(255, 255, 255).to_cmyk(), cmyk(0, 0, 0, 0));
+        assert_eq!(cmyk(0, 0, 0, 100).to_rgb(), rgb(0, 0, 0));
+        assert_eq!(cmyk(0, 0, 0, 0).to_rgb(), rgb(255, 255, 255));
+        assert_eq!(
+            CMYK::from_rgba(cmyk(0, 0, 0, 100).to_rgba()),
+            cmyk(0, 0, 0, 100)
+        );
+        assert_eq!(
+            CMYK::from_rgba(cmyk(0, 0, 0, 0).to_rgba()),
+            cmyk(0, 0, 0, 0)
+        );
+    }
+
+    #[test]
+    fn can_mix_cmyk_colors() {
+        let cyan = cmyk(100, 0, 0, 0);
+        let yellow = cmyk(0, 0, 100, 0);
+
+        assert_eq!(cyan.mix(yellow, percent(50)), rgba(127, 255, 128, 1.0));
+        assert_eq!(cyan.fade(percent(50)), rgba(0, 255, 255, 0.5));
+    }
+}
@@ -0,0 +1,671 @@
+use super::{ops, rgb, Color, GamutMap, OkLch, Ratio, RGB, RGBA};
+
+// Composites `color` over an opaque `backdrop` ("source over"), quantizing the
+// result into bytes, just like it would be rendered on screen.
+pub(crate) fn composite(color: RGBA, backdrop: RGB) -> RGB {
+    let alpha = color.a.as_f32();
+    let blend = |channel: f32, backdrop: f32| channel * alpha + backdrop * (1.0 - alpha);
+
+    RGB {
+        r: ops::quantize(blend(color.r.as_f32(), backdrop.r.as_f32())),
+        g: ops::quantize(blend(color.g.as_f32(), backdrop.g.as_f32())),
+        b: ops::quantize(blend(color.b.as_f32(), backdrop.b.as_f32())),
+    }
+}
+
+// Flattens a (possibly translucent) page background over the white canvas.
+pub(crate) fn flatten(color: RGBA) -> RGB {
+    composite(color, rgb(255, 255, 255))
+}
+
+// The luma below which Less' `contrast` picks the light color over the dark one.
+pub(crate) const LESS_CONTRAST_THRESHOLD: f32 = 0.43;
+
+// Computes the relative luminance of a color, as defined by WCAG 2.1.
+// See https://www.w3.org/TR/WCAG21/#dfn-relative-luminance.
+pub(crate) fn relative_luminance(color: RGB) -> f32 {
+    // WCAG linearizes the channels with the threshold of an early sRGB draft
+    // (0.03928 instead of 0.04045), which makes no difference for bytes.
+    let linearize = |channel: Ratio| {
+        let channel = channel.as_f32();
+
+        if channel <= 0.039_28 {
+            channel / 12.92
+        } else {
+            ((channel + 0.055) / 1.055).powf(2.4)
+        }
+    };
+
+    0.2126 * linearize(color.r) + 0.7152 * linearize(color.g) + 0.0722 * linearize(color.b)
+}
+
+// Computes the contrast ratio between two opaque colors, as defined by WCAG 2.1,
+// ranging from `1.0` (no contrast) to `21.0` (black on white).
+// See https://www.w3.org/TR/WCAG21/#dfn-contrast-ratio.
+pub(crate) fn contrast_ratio(lhs: RGB, rhs: RGB) -> f32 {
+    let lhs = relative_luminance(lhs);
+    let rhs = relative_luminance(rhs);
+
+    (lhs.max(rhs) + 0.05) / (lhs.min(rhs) + 0.05)
+}
+
+// The constants of APCA-W3 (version 0.0.98G-4g), as published in the
+// reference implementation: https://github.com/Myndex/apca-w3.
+const APCA_TRC: f32 = 2.4;
+const APCA_NORMAL_BACKGROUND: f32 = 0.56;
+const APCA_NORMAL_TEXT: f32 = 0.57;
+const APCA_REVERSE_TEXT: f32 = 0.62;
+const APCA_REVERSE_BACKGROUND: f32 = 0.65;
+const APCA_BLACK_THRESHOLD: f32 = 0.022;
+const APCA_BLACK_CLAMP: f32 = 1.414;
+const APCA_SCALE: f32 = 1.14;
+const APCA_LOW_OFFSET: f32 = 0.027;
+const APCA_DELTA_Y_MIN: f32 = 0.000_5;
+const APCA_LOW_CLIP: f32 = 0.1;
+
+// Computes the screen luminance of a color, as estimated by APCA: unlike the
+// relative luminance of WCAG, the channels are linearized with a simple power
+// curve, and near-black colors are soft-clamped to model flare.
+fn apca_luminance(color: RGB) -> f32 {
+    let linearize = |channel: Ratio| channel.as_f32().powf(APCA_TRC);
+    let y = 0.212_672_9 * linearize(color.r)
+        + 0.715_152_2 * linearize(color.g)
+        + 0.072_175 * linearize(color.b);
+
+    if y < APCA_BLACK_THRESHOLD {
+        y + (APCA_BLACK_THRESHOLD - y).powf(APCA_BLACK_CLAMP)
+    } else {
+        y
+    }
+}
+
+// Computes the APCA lightness contrast (Lc) of opaque `text` over an opaque
+// `background`: positive for dark text on a light background, and negative
+// for light text on a dark background.
+pub(crate) fn apca_contrast(text: RGB, background: RGB) -> f32 {
+    let text = apca_luminance(text);
+    let background = apca_luminance(background);
+
+    if (background - text).abs() < APCA_DELTA_Y_MIN {
+        return 0.0;
+    }
+
+    let contrast = if background > text {
+        let sapc =
+            (background.powf(APCA_NORMAL_BACKGROUND) - text.powf(APCA_NORMAL_TEXT)) * APCA_SCALE;
+
+        if sapc < APCA_LOW_CLIP {
+            0.0
+        } else {
+            sapc - APCA_LOW_OFFSET
+        }
+    } else {
+        let sapc =
+            (background.powf(APCA_REVERSE_BACKGROUND) - text.powf(APCA_REVERSE_TEXT)) * APCA_SCALE;
+
+        if sapc > -APCA_LOW_CLIP {
+            0.0
+        } else {
+            sapc + APCA_LOW_OFFSET
+        }
+    };
+
+    contrast * 100.0
+}
+
+// The number of halvings of the lightness range in `ensure_contrast`, which is
+// well past the precision of bytes.
+const CONTRAST_SEARCH_STEPS: u32 = 24;
+
+// Darkens or lightens `color` in OkLch (keeping its hue, and as much of its chroma
+// as the sRGB gamut allows) until its contrast ratio with `against` reaches
+// `target`, by a binary search on the lightness towards black or white.
+pub(crate) fn ensure_contrast(color: RGBA, against: RGBA, target: f32) -> RGBA {
+    if color.contrast_ratio(against) >= target {
+        return color;
+    }
+
+    let start = OkLch::from_rgb(color.to_rgb());
+    let at_lightness = |l: f32| {
+        let RGB { r, g, b } = OkLch { l, ..start }.map_to_gamut(GamutMap::ChromaReduce);
+
+        RGBA {
+            r,
+            g,
+            b,
+            a: color.a,
+        }
+    };
+
+    // Searches between the lightness of `color` (which fails) and `extreme`,
+    // which passes, keeping the passing bound so the result always passes.
+    let search = |extreme: f32| {
+        let (mut failing, mut passing) = (start.l, extreme);
+
+        for _ in 0..CONTRAST_SEARCH_STEPS {
+            let middle = (failing + passing) / 2.0;
+
+            if at_lightness(middle).contrast_ratio(against) >= target {
+                passing = middle;
+            } else {
+                failing = middle;
+            }
+        }
+
+        passing
+    };
+
+    let darkest = at_lightness(0.0);
+    let lightest = at_lightness(1.0);
+    let darkest_ratio = darkest.contrast_ratio(against);
+    let lightest_ratio = lightest.contrast_ratio(against);
+
+    // When both directions reach the target, the closest lightness wins.
+    match (darkest_ratio >= target, lightest_ratio >= target) {
+        (true, true) => {
+            let darker = search(0.0);
+            let lighter = search(1.0);
+
+            if start.l - darker <= lighter - start.l {
+                at_lightness(darker)
+            } else {
+                at_lightness(lighter)
+            }
+        }
+        (true, false) => at_lightness(search(0.0)),
+        (false, true) => at_lightness(search(1.0)),
+        (false, false) if darkest_ratio >= lightest_ratio => darkest,
+        (false, false) => lightest,
+    }
+}
+
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "snake_case"))]
+/// An enum of the text sizes that WCAG sets different contrast requirements for.
+pub enum TextSize {
+    // body text, below 18pt (or 14pt bold)
+    Normal,
+
+    // text of at least 18pt (or 14pt bold), which is legible at a lower contrast
+    Large,
+}
+
+#[derive(Debug, Copy, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+/// A struct to represent the WCAG contrast ratio between a foreground and a
+/// background color (see `Color::wcag`), with the conformance levels it passes.
+///
+/// The ratio isn't rounded before being compared to the thresholds, as WCAG
+/// requires: a ratio of `4.4999` fails a threshold of `4.5`.
+///
+/// # Example
+/// ```
+/// use css_colors::{rgb, Color, TextSize};
+///
+/// let grey_on_white = rgb(118, 118, 118).wcag(rgb(255, 255, 255));
+///
+/// assert!(grey_on_white.passes_aa(TextSize::Normal));
+/// assert!(!grey_on_white.passes_aaa(TextSize::Normal));
+/// assert!(grey_on_white.passes_aaa(TextSize::Large));
+/// ```
+pub struct ContrastResult {
+    // the contrast ratio, from `1.0` to `21.0`
+    pub ratio: f32,
+}
+
+impl ContrastResult {
+    /// Returns `true` if the contrast ratio meets WCAG level AA for text of the
+    /// given size: at least `4.5` for normal text, and `3.0` for large text.
+    /// See [Success Criterion 1.4.3](https://www.w3.org/TR/WCAG21/#contrast-minimum).
+    pub fn passes_aa(&self, text_size: TextSize) -> bool {
+        match text_size {
+            TextSize::Normal => self.ratio >= 4.5,
+            TextSize::Large => self.ratio >= 3.0,
+        }
+    }
+
+    /// Returns `true` if the contrast ratio meets WCAG level AAA for text of the
+    /// given size: at least `7.0` for normal text, and `4.5` for large text.
+    /// See [Success Criterion 1.4.6](https://www.w3.org/TR/WCAG21/#contrast-enhanced).
+    pub fn passes_aaa(&self, text_size: TextSize) -> bool {
+        match text_size {
+            TextSize::Normal => self.ratio >= 7.0,
+            TextSize::Large => self.ratio >= 4.5,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::contrast_ratio;
+    use {hsl, percent, rgb, rgba, Color, ContrastResult, TextSize, RGB, RGBA};
+
+    // Rounds a contrast ratio to two decimals, as accessibility tools report it.
+    fn rounded(ratio: f32) -> f32 {
+        (ratio * 100.0).round() / 100.0
+    }
+
+    #[test]
+    fn can_compute_contrast_ratios() {
+        assert_eq!(
+            rounded(contrast_ratio(rgb(0, 0, 0), rgb(255, 255, 255))),
+            21.0
+        );
+        assert_eq!(
+            rounded(contrast_ratio(rgb(255, 255, 255), rgb(0, 0, 0))),
+            21.0
+        );
+        assert_eq!(contrast_ratio(rgb(255, 99, 71), rgb(255, 99, 71)), 1.0);
+        assert_eq!(
+            rounded(contrast_ratio(rgb(255, 99, 71), rgb(255, 255, 255))),
+            2.95
+        );
+    }
+
+    #[test]
+    fn can_compute_composited_contrast_ratios() {
+        let page = rgb(245, 245, 220);
+        let header = rgba(25, 25, 112, 0.8);
+
+        // The header renders as #454586 over the page, and the text as #C8C8DB.
+        assert_eq!(
+            rounded(rgb(255, 255, 255).contrast_ratio_composited(header, page)),
+            8.58
+        );
+        assert_eq!(
+            rounded(rgba(255, 255, 255, 0.7).contrast_ratio_composited(header, page)),
+            5.2
+        );
+
+        // A half-transparent black header over a white page renders as #7F7F7F.
+        assert_eq!(
+            rounded(
+                rgb(255, 255, 255)
+                    .contrast_ratio_composited(rgba(0, 0, 0, 0.5), rgb(255, 255, 255))
+            ),
+            4.0
+        );
+    }
+
+    #[test]
+    fn opaque_colors_are_not_composited() {
+        let pairs = [
+            (rgb(255, 99, 71), rgb(255, 255, 255)),
+            (rgb(25, 25, 112), rgb(245, 245, 220)),
+            (rgb(0, 0, 0), rgb(128, 128, 128)),
+        ];
+
+        for &(foreground, background) in pairs.iter() {
+            assert_eq!(
+                foreground.contrast_ratio_composited(background, rgb(0, 0, 0)),
+                contrast_ratio(foreground, background)
+            );
+            assert_eq!(
+                foreground
+                    .to_rgba()
+                    .contrast_ratio_composited(background.to_rgba(), rgba(0, 0, 0, 0.5)),
+                contrast_ratio(foreground, background)
+            );
+        }
+    }
+
+    #[test]
+    fn translucent_page_backgrounds_are_flattened_over_white() {
+        let transparent = rgba(0, 0, 0, 0.0);
+
+        assert_eq!(
+            rounded(rgb(0, 0, 0).contrast_ratio_composited(transparent, transparent)),
+            21.0
+        );
+        assert_eq!(
+            rounded(rgb(0, 0, 0).contrast_ratio_composited(transparent, rgba(0, 0, 0, 0.5))),
+            rounded(contrast_ratio(rgb(0, 0, 0), rgb(127, 127, 127)))
+        );
+    }
+
+    #[test]
+    fn can_pick_a_readable_foreground() {
+        let black = rgba(0, 0, 0, 1.0);
+        let white = rgba(255, 255, 255, 1.0);
+
+        // Examples of the Less documentation.
+        assert_eq!(rgb(187, 187, 187).contrast_bw(), black);
+        assert_eq!(
+            rgb(34, 34, 34).contrast(rgba(16, 16, 16, 1.0), white, None),
+            white
+        );
+        assert_eq!(
+            rgb(34, 34, 34).contrast(rgba(16, 16, 16, 1.0), rgba(221, 221, 221, 1.0), None),
+            rgba(221, 221, 221, 1.0)
+        );
+        assert_eq!(
+            hsl(90, 100, 50).contrast(black, white, Some(percent(30))),
+            black
+        );
+        assert_eq!(
+            hsl(90, 100, 50).contrast(black, white, Some(percent(80))),
+            white
+        );
+    }
+
+    #[test]
+    fn switches_at_the_threshold() {
+        // The luma of #AFAFAF is just below 43%, and the luma of #B0B0B0 just above.
+        assert_eq!(rgb(175, 175, 175).contrast_bw(), rgba(255, 255, 255, 1.0));
+        assert_eq!(rgb(176, 176, 176).contrast_bw(), rgba(0, 0, 0, 1.0));
+        assert_eq!(rgb(128, 128, 128).contrast_bw(), rgba(255, 255, 255, 1.0));
+        assert_eq!(rgb(0, 0, 0).contrast_bw(), rgba(255, 255, 255, 1.0));
+        assert_eq!(rgb(255, 255, 255).contrast_bw(), rgba(0, 0, 0, 1.0));
+    }
+
+    #[test]
+    fn swaps_dark_and_light_colors() {
+        let navy = rgba(0, 0, 128, 1.0);
+        let ivory = rgba(255, 255, 240, 1.0);
+
+        for &background in [rgb(20, 20, 20), rgb(230, 230, 230)].iter() {
+            let picked: RGBA = background.contrast(navy, ivory, None);
+
+            assert_eq!(background.contrast(ivory, navy, None), picked);
+        }
+
+        assert_eq!(rgb(20, 20, 20).contrast(ivory, navy, None), ivory);
+    }
+
+    #[test]
+    fn can_compute_luma_and_luminance() {
+        // Examples of the Less documentation.
+        assert_eq!(rgb(100, 200, 30).luma(), percent(44));
+        assert_eq!(rgb(100, 200, 30).luminance(), percent(65));
+
+        assert_eq!(rgb(255, 255, 255).luma(), percent(100));
+        assert_eq!(rgb(0, 0, 0).luma(), percent(0));
+        assert_eq!(rgb(255, 0, 0).luma(), percent(21));
+        assert_eq!(rgb(255, 255, 255).luminance(), percent(100));
+        assert_eq!(rgb(255, 0, 0).luminance(), percent(21));
+
+        // Gamma correction darkens mid-tones, and the alpha channel is ignored.
+        assert_eq!(rgb(128, 128, 128).luma().to_string(), "22%");
+        assert_eq!(rgb(128, 128, 128).luminance(), percent(50));
+        assert_eq!(rgba(128, 128, 128, 0.1).luma(), rgb(128, 128, 128).luma());
+        assert_eq!(hsl(0, 0, 50).luminance(), percent(50));
+    }
+
+    #[test]
+    fn can_compute_perceived_brightness() {
+        let brightness = |color: RGB| color.perceived_brightness().as_u8();
+
+        assert_eq!(brightness(rgb(0, 0, 0)), 0);
+        assert_eq!(brightness(rgb(255, 255, 255)), 255);
+        assert_eq!(brightness(rgb(128, 128, 128)), 128);
+
+        // sqrt(0.299) * 255, sqrt(0.587) * 255 and sqrt(0.114) * 255
+        assert_eq!(brightness(rgb(255, 0, 0)), 139);
+        assert_eq!(brightness(rgb(0, 255, 0)), 195);
+        assert_eq!(brightness(rgb(0, 0, 255)), 86);
+
+        // pink, lavender and mint
+        assert_eq!(brightness(rgb(255, 182, 193)), 208);
+        assert_eq!(brightness(rgb(230, 230, 250)), 232);
+        assert_eq!(brightness(rgb(189, 252, 201)), 229);
+
+        assert_eq!(
+            rgba(255, 0, 0, 0.1).perceived_brightness(),
+            rgb(255, 0, 0).perceived_brightness()
+        );
+    }
+
+    #[test]
+    fn can_compute_wcag_contrast_ratios() {
+        let white = rgb(255, 255, 255);
+        let black = rgb(0, 0, 0);
+
+        assert_eq!(rounded(black.contrast_ratio(white)), 21.0);
+        assert_eq!(rounded(white.contrast_ratio(black)), 21.0);
+        assert_eq!(rounded(white.contrast_ratio(white)), 1.0);
+
+        // Reference pairs of WebAIM's contrast checker.
+        let pairs = [
+            (rgb(118, 118, 118), white, 4.54),
+            (rgb(119, 119, 119), white, 4.48),
+            (rgb(89, 89, 89), white, 7.0),
+            (rgb(255, 0, 0), white, 4.0),
+            (rgb(0, 0, 255), white, 8.59),
+            (rgb(0, 128, 0), white, 5.14),
+            (rgb(255, 255, 0), black, 19.56),
+        ];
+
+        for &(foreground, background, expected) in pairs.iter() {
+            assert!(
+                (foreground.contrast_ratio(background) - expected).abs() < 0.01,
+                "{} on {}",
+                foreground,
+                background
+            );
+            assert_eq!(
+                foreground.contrast_ratio(background),
+                background.contrast_ratio(foreground)
+            );
+        }
+    }
+
+    #[test]
+    fn composites_translucent_colors_over_white() {
+        let white = rgb(255, 255, 255);
+
+        assert_eq!(rgba(0, 0, 0, 0.0).relative_luminance(), 1.0);
+        assert_eq!(
+            rgba(0, 0, 0, 0.5).relative_luminance(),
+            rgb(127, 127, 127).relative_luminance()
+        );
+        assert_eq!(
+            rgba(0, 0, 0, 0.5).contrast_ratio(white),
+            rgb(127, 127, 127).contrast_ratio(white)
+        );
+        assert_eq!(
+            rgb(0, 0, 0).contrast_ratio(rgba(0, 0, 0, 0.5)),
+            rgb(0, 0, 0).contrast_ratio(rgb(127, 127, 127))
+        );
+    }
+
+    #[test]
+    fn uses_the_wcag_linearization_threshold() {
+        // The channels on each side of the threshold (0.03928 ≈ 10.02 / 255).
+        let dark = rgb(10, 10, 10).relative_luminance();
+        let light = rgb(11, 11, 11).relative_luminance();
+
+        assert!((dark - 10.0 / 255.0 / 12.92).abs() < 0.000_001);
+        assert!((light - ((11.0 / 255.0 + 0.055) / 1.055f32).powf(2.4)).abs() < 0.000_001);
+    }
+
+    #[test]
+    fn can_check_wcag_conformance() {
+        let result = |ratio: f32| ContrastResult { ratio };
+
+        // The thresholds are inclusive, and ratios aren't rounded.
+        assert!(result(4.5).passes_aa(TextSize::Normal));
+        assert!(!result(4.4999).passes_aa(TextSize::Normal));
+        assert!(result(3.0).passes_aa(TextSize::Large));
+        assert!(!result(2.9999).passes_aa(TextSize::Large));
+        assert!(result(7.0).passes_aaa(TextSize::Normal));
+        assert!(!result(6.9999).passes_aaa(TextSize::Normal));
+        assert!(result(4.5).passes_aaa(TextSize::Large));
+        assert!(!result(4.4999).passes_aaa(TextSize::Large));
+
+        assert!(result(21.0).passes_aaa(TextSize::Normal));
+        assert!(!result(1.0).passes_aa(TextSize::Large));
+    }
+
+    #[test]
+    fn can_check_color_pairs() {
+        let white = rgb(255, 255, 255);
+
+        // #767676 is the lightest grey that passes AA on white, and #777777 fails.
+        assert!(rgb(118, 118, 118).wcag(white).passes_aa(TextSize::Normal));
+        assert!(!rgb(119, 119, 119).wcag(white).passes_aa(TextSize::Normal));
+        assert!(rgb(119, 119, 119).wcag(white).passes_aa(TextSize::Large));
+
+        assert!(rgb(0, 0, 0).wcag(white).passes_aaa(TextSize::Normal));
+        assert!(!rgb(255, 0, 0).wcag(white).passes_aa(TextSize::Normal));
+        assert_eq!(
+            rgb(255, 0, 0).wcag(white).ratio,
+            rgb(255, 0, 0).contrast_ratio(white)
+        );
+    }
+
+    #[test]
+    fn text_color_agrees_with_contrast_ratio() {
+        let black = rgb(0, 0, 0);
+        let white = rgb(255, 255, 255);
+
+        for r in (0..=255).step_by(17) {
+            for g in (0..=255).step_by(17) {
+                for b in (0..=255).step_by(17) {
+                    for &a in [1.0, 0.5, 0.1].iter() {
+                        let background = rgba(r as u8, g as u8, b as u8, a);
+                        let text = background.text_color();
+                        let other = if text == black { white } else { black };
+
+                        assert!(text == black || text == white);
+                        assert!(
+                            text.contrast_ratio(background) >= other.contrast_ratio(background),
+                            "{}",
+                            background
+                        );
+                    }
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn can_pick_text_color_from_candidates() {
+        let navy = rgb(0, 31, 63);
+        let cream = rgb(255, 250, 235);
+        let grey = rgb(128, 128, 128);
+
+        assert_eq!(
+            rgb(255, 255, 255).text_color_from(&[cream, grey, navy]),
+            navy
+        );
+        assert_eq!(rgb(0, 0, 0).text_color_from(&[navy, grey, cream]), cream);
+        assert_eq!(rgb(128, 128, 128).text_color_from(&[grey]), grey);
+
+        // Ties go to the first candidate.
+        assert_eq!(rgb(255, 255, 255).text_color_from(&[navy, navy]), navy);
+    }
+
+    #[test]
+    #[should_panic]
+    fn cannot_pick_text_color_from_no_candidates() {
+        let candidates: [RGBA; 0] = [];
+
+        rgb(255, 255, 255).text_color_from(&candidates);
+    }
+
+    #[test]
+    fn can_ensure_contrast() {
+        let white = rgb(255, 255, 255);
+        let black = rgb(0, 0, 0);
+
+        // Mid grey only reaches 3.95 on white, so it's darkened just enough.
+        let grey = rgb(128, 128, 128).ensure_contrast(white, 4.5);
+        let ratio = grey.contrast_ratio(white);
+
+        assert!((4.5..4.6).contains(&ratio), "{}", ratio);
+        assert_eq!(grey.to_hsl().s, percent(0));
+
+        // Colors that already pass are returned as is.
+        assert_eq!(
+            rgb(0, 0, 128).ensure_contrast(white, 4.5),
+            rgba(0, 0, 128, 1.0)
+        );
+
+        // Even black doesn't reach 21.0 with rounding, but it's the best there is.
+        assert_eq!(
+            rgb(128, 128, 128).ensure_contrast(white, 21.0),
+            rgba(0, 0, 0, 1.0)
+        );
+        assert_eq!(
+            rgb(128, 128, 128).ensure_contrast(black, 25.0),
+            rgba(255, 255, 255, 1.0)
+        );
+    }
+
+    #[test]
+    fn ensure_contrast_preserves_hue() {
+        let white = rgb(255, 255, 255);
+        let black = rgb(0, 0, 0);
+        let tomato = rgb(255, 99, 71);
+
+        let darker = tomato.ensure_contrast(white, 4.5);
+        let lighter = tomato.ensure_contrast(black, 12.0);
+
+        assert!(darker.contrast_ratio(white) >= 4.5);
+        assert!(lighter.contrast_ratio(black) >= 12.0);
+        assert!((darker.to_oklch().h - tomato.to_oklch().h).abs() < 2.0);
+        assert!((lighter.to_oklch().h - tomato.to_oklch().h).abs() < 2.0);
+
+        // The alpha channel is kept, and included in the contrast.
+        let translucent = rgba(255, 99, 71, 0.8).ensure_contrast(white, 4.5);
+
+        assert_eq!(translucent.a, rgba(255, 99, 71, 0.8).a);
+        assert!(translucent.contrast_ratio(white) >= 4.5);
+    }
+
+    #[test]
+    fn ensure_contrast_picks_the_closest_direction() {
+        let grey = rgb(119, 119, 119);
+
+        // Both black and white reach 2.0 against mid grey, in opposite directions.
+        let dark = rgb(100, 100, 100).ensure_contrast(grey, 2.0);
+        let light = rgb(140, 140, 140).ensure_contrast(grey, 2.0);
+
+        assert!(dark.to_hsl().l < grey.to_hsl().l);
+        assert!(light.to_hsl().l > grey.to_hsl().l);
+        assert!(dark.contrast_ratio(grey) >= 2.0);
+        assert!(light.contrast_ratio(grey) >= 2.0);
+    }
+
+    #[test]
+    fn can_compute_apca_contrast() {
+        // The reference values of the APCA-W3 repository.
+        let vectors = [
+            (rgb(136, 136, 136), rgb(255, 255, 255), 63.056_47),
+            (rgb(255, 255, 255), rgb(136, 136, 136), -68.541_46),
+            (rgb(0, 0, 0), rgb(170, 170, 170), 58.146_26),
+            (rgb(170, 170, 170), rgb(0, 0, 0), -56.241_13),
+            (rgb(17, 34, 51), rgb(221, 238, 255), 91.666_6),
+            (rgb(221, 238, 255), rgb(17, 34, 51), -93.066_95),
+            (rgb(17, 34, 51), rgb(68, 68, 68), 8.325_4),
+            (rgb(68, 68, 68), rgb(17, 34, 51), -7.524_8),
+        ];
+
+        for &(text, background, expected) in vectors.iter() {
+            let lc = text.apca_contrast(background);
+
+            assert!(
+                (lc - expected).abs() < 0.01,
+                "{} on {}: {}",
+                text,
+                background,
+                lc
+            );
+        }
+    }
+
+    #[test]
+    fn apca_contrast_depends_on_polarity() {
+        let black = rgb(0, 0, 0);
+        let white = rgb(255, 255, 255);
+
+        assert!((black.apca_contrast(white) - 106.04).abs() < 0.01);
+        assert!((white.apca_contrast(black) + 107.88).abs() < 0.01);
+
+        // Colors too close to each other have no contrast at all.
+        assert_eq!(white.apca_contrast(white), 0.0);
+        assert_eq!(rgb(250, 250, 250).apca_contrast(white), 0.0);
+        assert_eq!(rgb(20, 20, 20).apca_contrast(black), 0.0);
+    }
+}
@@ -0,0 +1,62 @@
+use super::Ratio;
+use std::fmt;
+
+// Formats a float with up to the given number of decimals, without trailing
+// zeros (e.g. `40.9`).
+pub(crate) struct Rounded(pub f32, pub i32);
+
+impl fmt::Display for Rounded {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let scale = 10f32.powi(self.1);
+
+        // Adding zero turns a negative zero (e.g. from -0.001) into a positive one.
+        let value = (self.0 * scale).round() / scale + 0.0;
+        let formatted = format!("{:.*}", self.1 as usize, value);
+
+        if formatted.contains('.') {
+            write!(
+                f,
+                "{}",
+                formatted.trim_end_matches('0').trim_end_matches('.')
+            )
+        } else {
+            write!(f, "{}", formatted)
+        }
+    }
+}
+
+// Formats the `/ alpha` suffix of a space-separated CSS function, which is left
+// out for opaque colors. Three decimals tell every byte of alpha apart.
+pub(crate) struct Alpha(pub Ratio);
+
+impl fmt::Display for Alpha {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        if self.0.as_u8() == 255 {
+            Ok(())
+        } else {
+            write!(f, " / {}", Rounded(self.0.as_f32(), 3))
+        }
+    }
+}
+
+// Writes the `color(<space> c1 c2 c3 / alpha)` function of CSS, with as many
+// decimals per channel as the precision of the formatter (`{:.2}`), or up to
+// four.
+pub(crate) fn write_color_function(
+    f: &mut fmt::Formatter,
+    space: &str,
+    channels: [f32; 3],
+    alpha: Ratio,
+) -> fmt::Result {
+    let decimals = f.precision().unwrap_or(4) as i32;
+
+    write!(
+        f,
+        "color({} {} {} {}{})",
+        space,
+        Rounded(channels[0], decimals),
+        Rounded(channels[1], decimals),
+        Rounded(channels[2], decimals),
+        Alpha(alpha)
+    )
+}
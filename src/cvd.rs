@@ -0,0 +1,277 @@
+use super::{ops, Ratio, RGB};
+
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "snake_case"))]
+/// An enum of the color vision deficiencies (CVD) that colors can be checked against.
+///
+/// The dichromacies (`Protanopia`, `Deuteranopia` and `Tritanopia`) are simulated
+/// with the matrices of [Machado, Oliveira & Fernandes (2009)][machado], at a
+/// severity of 1.0, applied to linear RGB. Their physiological model fits
+/// published sample conversions better than the earlier approach of Brettel,
+/// Viénot & Mollon, and needs a single matrix per deficiency.
+///
+/// The anomalous trichromacies take a severity, from 0% (normal vision) to 100%
+/// (the corresponding dichromacy). They're approximated by interpolating between
+/// the identity and the matrix of the dichromacy, rather than with the matrices
+/// that Machado et al. derived for every tenth of severity.
+///
+/// [machado]: https://www.inf.ufrgs.br/~oliveira/pubs_files/CVD_Simulation/CVD_Simulation.html
+pub enum Cvd {
+    // absence of the long-wavelength (red) cones
+    Protanopia,
+
+    // absence of the medium-wavelength (green) cones
+    Deuteranopia,
+
+    // absence of the short-wavelength (blue) cones
+    Tritanopia,
+
+    // shifted sensitivity of the long-wavelength (red) cones, with a severity
+    Protanomaly(Ratio),
+
+    // shifted sensitivity of the medium-wavelength (green) cones, with a severity
+    Deuteranomaly(Ratio),
+
+    // shifted sensitivity of the short-wavelength (blue) cones, with a severity
+    Tritanomaly(Ratio),
+}
+
+impl Cvd {
+    /// Every simulated dichromacy, the most severe color vision deficiencies.
+    pub const ALL: [Cvd; 3] = [Cvd::Protanopia, Cvd::Deuteranopia, Cvd::Tritanopia];
+}
+
+// The simulation matrices of Machado, Oliveira & Fernandes (2009), at a severity
+// of 1.0, applied to linear RGB.
+// See https://www.inf.ufrgs.br/~oliveira/pubs_files/CVD_Simulation/CVD_Simulation.html.
+const PROTANOPIA: [[f32; 3]; 3] = [
+    [0.152_286, 1.052_583, -0.204_868],
+    [0.114_503, 0.786_281, 0.099_216],
+    [-0.003_882, -0.048_116, 1.051_998],
+];
+
+const DEUTERANOPIA: [[f32; 3]; 3] = [
+    [0.367_322, 0.860_646, -0.227_968],
+    [0.280_085, 0.672_501, 0.047_413],
+    [-0.011_820, 0.042_940, 0.968_881],
+];
+
+const TRITANOPIA: [[f32; 3]; 3] = [
+    [1.255_528, -0.076_749, -0.178_779],
+    [-0.078_411, 0.930_809, 0.147_602],
+    [0.004_733, 0.691_367, 0.303_900],
+];
+
+// Interpolates between the identity matrix (at a severity of 0%) and `matrix`.
+fn with_severity(matrix: &[[f32; 3]; 3], severity: Ratio) -> [[f32; 3]; 3] {
+    let severity = severity.as_f32();
+    let mut result = [[0.0; 3]; 3];
+
+    for (i, row) in matrix.iter().enumerate() {
+        for (j, value) in row.iter().enumerate() {
+            let identity = if i == j { 1.0 } else { 0.0 };
+
+            result[i][j] = identity + (value - identity) * severity;
+        }
+    }
+
+    result
+}
+
+// The matrix that simulates the given color vision deficiency in linear RGB.
+fn simulation_matrix(kind: Cvd) -> [[f32; 3]; 3] {
+    match kind {
+        Cvd::Protanopia => PROTANOPIA,
+        Cvd::Deuteranopia => DEUTERANOPIA,
+        Cvd::Tritanopia => TRITANOPIA,
+        Cvd::Protanomaly(severity) => with_severity(&PROTANOPIA, severity),
+        Cvd::Deuteranomaly(severity) => with_severity(&DEUTERANOPIA, severity),
+        Cvd::Tritanomaly(severity) => with_severity(&TRITANOPIA, severity),
+    }
+}
+
+// The matrices that redistribute the error of the simulation into the channels
+// that remain perceptible, as in the daltonization of Fidaner, Lin & Ozguven
+// (2005): the red-green error is shifted into green and blue, and the blue-yellow
+// error into red and green.
+const RED_GREEN_SHIFT: [[f32; 3]; 3] = [[0.0, 0.0, 0.0], [0.7, 1.0, 0.0], [0.7, 0.0, 1.0]];
+const BLUE_YELLOW_SHIFT: [[f32; 3]; 3] = [[1.0, 0.0, 0.7], [0.0, 1.0, 0.7], [0.0, 0.0, 0.0]];
+
+fn transform(matrix: &[[f32; 3]; 3], channels: [f32; 3]) -> [f32; 3] {
+    let row = |row: &[f32; 3]| row[0] * channels[0] + row[1] * channels[1] + row[2] * channels[2];
+
+    [row(&matrix[0]), row(&matrix[1]), row(&matrix[2])]
+}
+
+fn to_linear(color: RGB) -> [f32; 3] {
+    [
+        ops::linearize(color.r.as_f32()),
+        ops::linearize(color.g.as_f32()),
+        ops::linearize(color.b.as_f32()),
+    ]
+}
+
+fn from_linear(channels: [f32; 3]) -> RGB {
+    let channel = |value: f32| ops::quantize(ops::delinearize(value.clamp(0.0, 1.0)));
+
+    RGB {
+        r: channel(channels[0]),
+        g: channel(channels[1]),
+        b: channel(channels[2]),
+    }
+}
+
+// Simulates how `color` is perceived with the given color vision deficiency.
+pub(crate) fn simulate(color: RGB, kind: Cvd) -> RGB {
+    from_linear(transform(&simulation_matrix(kind), to_linear(color)))
+}
+
+// Adjusts `color` so that it's easier to distinguish with the given color vision
+// deficiency: the difference between `color` and its simulation (what the viewer
+// can't perceive) is shifted into the channels they can perceive.
+pub(crate) fn daltonize(color: RGB, kind: Cvd) -> RGB {
+    let shift = match kind {
+        Cvd::Tritanopia | Cvd::Tritanomaly(_) => &BLUE_YELLOW_SHIFT,
+        _ => &RED_GREEN_SHIFT,
+    };
+
+    let linear = to_linear(color);
+    let simulated = transform(&simulation_matrix(kind), linear);
+    let error = [
+        linear[0] - simulated[0].clamp(0.0, 1.0),
+        linear[1] - simulated[1].clamp(0.0, 1.0),
+        linear[2] - simulated[2].clamp(0.0, 1.0),
+    ];
+    let correction = transform(shift, error);
+
+    from_linear([
+        linear[0] + correction[0],
+        linear[1] + correction[1],
+        linear[2] + correction[2],
+    ])
+}
+
+/// Simulates how every color of `palette` is perceived with the given color
+/// vision deficiency (see `Color::simulate_cvd`).
+///
+/// # Example
+/// ```
+/// use css_colors::{rgb, simulate_cvd_palette, Cvd};
+///
+/// let palette = [rgb(255, 0, 0), rgb(0, 128, 0)];
+///
+/// assert_eq!(
+///     simulate_cvd_palette(&palette, Cvd::Protanopia),
+///     vec![rgb(109, 95, 0), rgb(131, 114, 0)]
+/// );
+/// ```
+pub fn simulate_cvd_palette(palette: &[RGB], kind: Cvd) -> Vec<RGB> {
+    palette.iter().map(|&color| simulate(color, kind)).collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{simulate, Cvd};
+    use {percent, rgb, simulate_cvd_palette, Color, RGB};
+
+    #[test]
+    fn greys_are_unaffected() {
+        for &kind in Cvd::ALL.iter() {
+            assert_eq!(simulate(rgb(0, 0, 0), kind), rgb(0, 0, 0));
+            assert_eq!(simulate(rgb(255, 255, 255), kind), rgb(255, 255, 255));
+        }
+    }
+
+    #[test]
+    fn can_simulate_color_vision_deficiencies() {
+        assert_eq!(simulate(rgb(255, 0, 0), Cvd::Protanopia), rgb(109, 95, 0));
+        assert_eq!(
+            simulate(rgb(0, 255, 0), Cvd::Deuteranopia),
+            rgb(239, 214, 58)
+        );
+        assert_eq!(simulate(rgb(0, 0, 255), Cvd::Tritanopia), rgb(0, 107, 150));
+    }
+
+    #[test]
+    fn can_simulate_anomalous_trichromacies() {
+        let colors = [
+            rgb(255, 0, 0),
+            rgb(0, 255, 0),
+            rgb(0, 0, 255),
+            rgb(255, 99, 71),
+        ];
+        let kinds = [
+            (Cvd::Protanopia, Cvd::Protanomaly as fn(_) -> _),
+            (Cvd::Deuteranopia, Cvd::Deuteranomaly),
+            (Cvd::Tritanopia, Cvd::Tritanomaly),
+        ];
+
+        for &color in colors.iter() {
+            for &(dichromacy, anomaly) in kinds.iter() {
+                assert_eq!(color.simulate_cvd(anomaly(percent(0))), color);
+                assert_eq!(
+                    color.simulate_cvd(anomaly(percent(100))),
+                    color.simulate_cvd(dichromacy)
+                );
+            }
+        }
+
+        assert_eq!(
+            rgb(255, 0, 0).simulate_cvd(Cvd::Protanomaly(percent(50))),
+            rgb(200, 68, 0)
+        );
+    }
+
+    #[test]
+    fn can_simulate_palettes() {
+        let palette = [rgb(255, 0, 0), rgb(0, 255, 0), rgb(0, 0, 255)];
+
+        for &kind in Cvd::ALL.iter() {
+            let simulated = simulate_cvd_palette(&palette, kind);
+
+            assert_eq!(simulated.len(), palette.len());
+
+            for (&color, &expected) in palette.iter().zip(simulated.iter()) {
+                assert_eq!(color.simulate_cvd(kind), expected);
+            }
+        }
+
+        assert!(simulate_cvd_palette(&[], Cvd::Tritanopia).is_empty());
+    }
+
+    #[test]
+    fn daltonizing_makes_colors_distinguishable() {
+        let distance = |lhs: RGB, rhs: RGB| lhs.to_lab().delta_e_2000(rhs.to_lab());
+        let pairs = [
+            (rgb(255, 0, 0), rgb(0, 128, 0), Cvd::Protanopia),
+            (rgb(255, 0, 0), rgb(0, 128, 0), Cvd::Deuteranopia),
+            (rgb(0, 0, 255), rgb(0, 128, 0), Cvd::Tritanopia),
+        ];
+
+        for &(lhs, rhs, kind) in pairs.iter() {
+            let before = distance(lhs.simulate_cvd(kind), rhs.simulate_cvd(kind));
+            let after = distance(
+                lhs.daltonize(kind).simulate_cvd(kind),
+                rhs.daltonize(kind).simulate_cvd(kind),
+            );
+
+            assert!(after > before, "{:?}: {} -> {}", kind, before, after);
+        }
+    }
+
+    #[test]
+    fn daltonizing_leaves_greys_unchanged() {
+        for &kind in Cvd::ALL.iter() {
+            for &value in [0, 64, 128, 255].iter() {
+                let grey = rgb(value, value, value);
+
+                assert_eq!(grey.daltonize(kind), grey);
+            }
+        }
+
+        let tomato = rgb(255, 99, 71);
+
+        assert_eq!(tomato.daltonize(Cvd::Protanomaly(percent(0))), tomato);
+    }
+}
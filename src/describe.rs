@@ -0,0 +1,201 @@
+use super::HSLA;
+
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+/// A struct that controls which parts of a color description are generated
+/// by `Color::describe_with`.
+///
+/// A description is composed of up to three words, a lightness band, a
+/// saturation band and a hue family (e.g. "light muted blue"). The bands are
+/// computed from the HSL representation of the color:
+///
+/// | Lightness   | Band         |
+/// |-------------|--------------|
+/// | `0-14%`     | `very dark`  |
+/// | `15-34%`    | `dark`       |
+/// | `35-64%`    | `medium`     |
+/// | `65-84%`    | `light`      |
+/// | `85-100%`   | `very light` |
+///
+/// | Saturation  | Band         |
+/// |-------------|--------------|
+/// | `0-24%`     | `grayish`    |
+/// | `25-59%`    | `muted`      |
+/// | `60-100%`   | `vivid`      |
+///
+/// | Hue         | Family       |
+/// |-------------|--------------|
+/// | `345-14°`   | `red`        |
+/// | `15-44°`    | `orange`     |
+/// | `45-74°`    | `yellow`     |
+/// | `75-164°`   | `green`      |
+/// | `165-194°`  | `cyan`       |
+/// | `195-254°`  | `blue`       |
+/// | `255-284°`  | `purple`     |
+/// | `285-319°`  | `magenta`    |
+/// | `320-344°`  | `pink`       |
+///
+/// Neutral colors are special cased regardless of the options: a lightness of
+/// `5%` or less is described as "black", a lightness of `95%` or more as
+/// "white", and a saturation below `8%` as a shade of "grey" (e.g. "dark grey").
+pub struct DescribeOptions {
+    // whether to include the lightness band
+    pub lightness: bool,
+
+    // whether to include the saturation band
+    pub saturation: bool,
+}
+
+impl Default for DescribeOptions {
+    fn default() -> Self {
+        DescribeOptions {
+            lightness: true,
+            saturation: true,
+        }
+    }
+}
+
+// Composes the description of a color from its HSLA representation.
+pub(crate) fn describe(color: HSLA, options: DescribeOptions) -> String {
+    let l = color.l.as_f32();
+    let s = color.s.as_f32();
+
+    if l <= 0.05 {
+        return "black".to_owned();
+    }
+
+    if l >= 0.95 {
+        return "white".to_owned();
+    }
+
+    let mut words = Vec::new();
+
+    if s < 0.08 {
+        // A "medium grey" is just a grey.
+        if options.lightness && lightness_band(l) != "medium" {
+            words.push(lightness_band(l));
+        }
+
+        words.push("grey");
+    } else {
+        if options.lightness {
+            words.push(lightness_band(l));
+        }
+
+        if options.saturation {
+            words.push(saturation_band(s));
+        }
+
+        words.push(hue_family(color.h.degrees()));
+    }
+
+    words.join(" ")
+}
+
+fn lightness_band(l: f32) -> &'static str {
+    if l < 0.15 {
+        "very dark"
+    } else if l < 0.35 {
+        "dark"
+    } else if l < 0.65 {
+        "medium"
+    } else if l < 0.85 {
+        "light"
+    } else {
+        "very light"
+    }
+}
+
+fn saturation_band(s: f32) -> &'static str {
+    if s < 0.25 {
+        "grayish"
+    } else if s < 0.6 {
+        "muted"
+    } else {
+        "vivid"
+    }
+}
+
+fn hue_family(degrees: u16) -> &'static str {
+    match degrees {
+        0..=14 | 345..=359 => "red",
+        15..=44 => "orange",
+        45..=74 => "yellow",
+        75..=164 => "green",
+        165..=194 => "cyan",
+        195..=254 => "blue",
+        255..=284 => "purple",
+        285..=319 => "magenta",
+        _ => "pink",
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use {hsl, hsla, rgb, rgba, Color, DescribeOptions};
+
+    #[test]
+    fn can_describe_colors() {
+        let table = [
+            (rgb(255, 99, 71), "medium vivid red"),
+            (rgb(255, 140, 0), "medium vivid orange"),
+            (rgb(255, 215, 0), "medium vivid yellow"),
+            (rgb(89, 161, 54), "medium muted green"),
+            (rgb(23, 98, 119), "dark vivid cyan"),
+            (rgb(100, 149, 237), "light vivid blue"),
+            (rgb(148, 189, 209), "light muted blue"),
+            (rgb(138, 43, 226), "medium vivid purple"),
+            (rgb(136, 102, 153), "medium grayish purple"),
+            (rgb(255, 20, 147), "medium vivid pink"),
+            (rgb(253, 216, 229), "very light vivid pink"),
+            (rgb(25, 25, 112), "dark vivid blue"),
+            (rgb(60, 10, 10), "very dark vivid red"),
+        ];
+
+        for &(color, description) in table.iter() {
+            assert_eq!(color.describe(), description, "{}", color);
+        }
+    }
+
+    #[test]
+    fn can_describe_neutrals() {
+        assert_eq!(rgb(0, 0, 0).describe(), "black");
+        assert_eq!(rgb(10, 12, 10).describe(), "black");
+        assert_eq!(rgb(255, 255, 255).describe(), "white");
+        assert_eq!(rgb(250, 240, 245).describe(), "white");
+        assert_eq!(rgb(128, 128, 128).describe(), "grey");
+        assert_eq!(rgb(60, 60, 60).describe(), "dark grey");
+        assert_eq!(rgb(230, 230, 230).describe(), "very light grey");
+        assert_eq!(hsl(200, 5, 25).describe(), "dark grey");
+    }
+
+    #[test]
+    fn can_describe_alpha_colors() {
+        assert_eq!(rgba(255, 99, 71, 0.5).describe(), "medium vivid red");
+        assert_eq!(hsla(220, 40, 75, 0.1).describe(), "light muted blue");
+    }
+
+    #[test]
+    fn can_omit_bands() {
+        let cornflower_blue = rgb(100, 149, 237);
+
+        let no_lightness = DescribeOptions {
+            lightness: false,
+            ..DescribeOptions::default()
+        };
+        let no_saturation = DescribeOptions {
+            saturation: false,
+            ..DescribeOptions::default()
+        };
+        let hue_only = DescribeOptions {
+            lightness: false,
+            saturation: false,
+        };
+
+        assert_eq!(cornflower_blue.describe_with(no_lightness), "vivid blue");
+        assert_eq!(cornflower_blue.describe_with(no_saturation), "light blue");
+        assert_eq!(cornflower_blue.describe_with(hue_only), "blue");
+
+        assert_eq!(rgb(60, 60, 60).describe_with(hue_only), "grey");
+        assert_eq!(rgb(0, 0, 0).describe_with(hue_only), "black");
+    }
+}
@@ -0,0 +1,146 @@
+//! Deterministic color generators, used by this crate's benchmarks.
+//!
+//! The generators are seeded and platform independent, so that benchmarks
+//! written outside of this crate can be compared against the bundled ones
+//! (see `benches/colors.rs`) using exactly the same inputs.
+//!
+//! Only available with the `bench-fixtures` feature.
+//!
+//! # Examples
+//! ```
+//! use css_colors::fixtures::{self, ColorGenerator};
+//!
+//! let mut generator = ColorGenerator::new(42);
+//!
+//! assert_eq!(generator.next_rgb(), ColorGenerator::new(42).next_rgb());
+//! assert_eq!(fixtures::rgb_colors(256).len(), 256);
+//! ```
+
+use super::{Angle, Ratio, HSL, HSLA, RGB, RGBA};
+
+/// The seed used by the `*_colors` helpers.
+pub const DEFAULT_SEED: u64 = 0x2545_f491_4f6c_dd1d;
+
+/// The collection sizes the bundled benchmarks are parameterized over.
+pub const SIZES: [usize; 3] = [16, 256, 4096];
+
+#[derive(Debug, Clone)]
+/// A seeded pseudo-random color generator (xorshift64*).
+pub struct ColorGenerator {
+    state: u64,
+}
+
+impl ColorGenerator {
+    pub fn new(seed: u64) -> Self {
+        // A zero state would only ever produce zeroes.
+        ColorGenerator {
+            state: if seed == 0 { DEFAULT_SEED } else { seed },
+        }
+    }
+
+    fn next_u64(&mut self) -> u64 {
+        self.state ^= self.state >> 12;
+        self.state ^= self.state << 25;
+        self.state ^= self.state >> 27;
+        self.state.wrapping_mul(0x2545_f491_4f6c_dd1d)
+    }
+
+    fn next_ratio(&mut self) -> Ratio {
+        Ratio::from_u8((self.next_u64() >> 56) as u8)
+    }
+
+    pub fn next_rgb(&mut self) -> RGB {
+        RGB {
+            r: self.next_ratio(),
+            g: self.next_ratio(),
+            b: self.next_ratio(),
+        }
+    }
+
+    pub fn next_rgba(&mut self) -> RGBA {
+        RGBA {
+            r: self.next_ratio(),
+            g: self.next_ratio(),
+            b: self.next_ratio(),
+            a: self.next_ratio(),
+        }
+    }
+
+    pub fn next_hsl(&mut self) -> HSL {
+        HSL {
+            h: Angle::new((self.next_u64() % 360) as u16),
+            s: self.next_ratio(),
+            l: self.next_ratio(),
+        }
+    }
+
+    pub fn next_hsla(&mut self) -> HSLA {
+        HSLA {
+            h: Angle::new((self.next_u64() % 360) as u16),
+            s: self.next_ratio(),
+            l: self.next_ratio(),
+            a: self.next_ratio(),
+        }
+    }
+}
+
+/// Generates `count` RGB colors from `DEFAULT_SEED`.
+pub fn rgb_colors(count: usize) -> Vec<RGB> {
+    let mut generator = ColorGenerator::new(DEFAULT_SEED);
+    (0..count).map(|_| generator.next_rgb()).collect()
+}
+
+/// Generates `count` RGBA colors from `DEFAULT_SEED`.
+pub fn rgba_colors(count: usize) -> Vec<RGBA> {
+    let mut generator = ColorGenerator::new(DEFAULT_SEED);
+    (0..count).map(|_| generator.next_rgba()).collect()
+}
+
+/// Generates `count` HSL colors from `DEFAULT_SEED`.
+pub fn hsl_colors(count: usize) -> Vec<HSL> {
+    let mut generator = ColorGenerator::new(DEFAULT_SEED);
+    (0..count).map(|_| generator.next_hsl()).collect()
+}
+
+/// Generates `count` HSLA colors from `DEFAULT_SEED`.
+pub fn hsla_colors(count: usize) -> Vec<HSLA> {
+    let mut generator = ColorGenerator::new(DEFAULT_SEED);
+    (0..count).map(|_| generator.next_hsla()).collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use fixtures::{self, ColorGenerator};
+    use rgb;
+
+    #[test]
+    fn generators_are_deterministic() {
+        let mut lhs = ColorGenerator::new(7);
+        let mut rhs = ColorGenerator::new(7);
+
+        for _ in 0..100 {
+            assert_eq!(lhs.next_rgba(), rhs.next_rgba());
+            assert_eq!(lhs.next_hsla(), rhs.next_hsla());
+        }
+
+        assert_eq!(fixtures::hsl_colors(64), fixtures::hsl_colors(64));
+    }
+
+    #[test]
+    fn generators_are_pinned() {
+        let mut generator = ColorGenerator::new(1);
+
+        assert_eq!(generator.next_rgb(), rgb(71, 171, 185));
+    }
+
+    #[test]
+    fn generators_vary() {
+        let colors = fixtures::rgb_colors(256);
+        let mut css: Vec<String> = colors.iter().map(|color| color.to_string()).collect();
+
+        css.sort();
+        css.dedup();
+
+        assert!(css.len() > 250);
+    }
+}
@@ -0,0 +1,182 @@
+use super::{rgb, OkLch, RGB};
+
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "snake_case"))]
+/// An enum of the strategies that bring colors from outside of the sRGB gamut into it.
+pub enum GamutMap {
+    // clamps every channel into range, which is fast but can shift the hue and lightness
+    Clip,
+
+    // reduces the chroma in OkLch until the color fits, preserving its lightness and hue
+    ChromaReduce,
+}
+
+// How far outside of the `0.0-1.0` range a sRGB channel may fall before the
+// color is considered out of gamut, to absorb floating point error.
+const GAMUT_TOLERANCE: f32 = 0.000_5;
+
+// Returns `true` if the given sRGB channels (gamma-encoded or linear, which
+// share the same range) are within the sRGB gamut.
+pub(crate) fn in_srgb_gamut(channels: [f32; 3]) -> bool {
+    channels
+        .iter()
+        .all(|channel| (-GAMUT_TOLERANCE..=1.0 + GAMUT_TOLERANCE).contains(channel))
+}
+
+// How many times the chroma range is halved when searching for the largest
+// chroma that fits, which is well below the precision of a byte.
+const CHROMA_SEARCH_STEPS: usize = 24;
+
+// Maps `color` into the sRGB gamut by binary searching the largest chroma that
+// fits with its lightness and hue. Colors that are lighter than white or darker
+// than black have no such chroma, and map to white and black.
+pub(crate) fn reduce_chroma(color: OkLch) -> RGB {
+    if color.in_gamut() {
+        return color.to_rgb();
+    }
+
+    if color.l >= 1.0 {
+        return rgb(255, 255, 255);
+    }
+
+    if color.l <= 0.0 {
+        return rgb(0, 0, 0);
+    }
+
+    let (mut low, mut high) = (0.0, color.c);
+
+    for _ in 0..CHROMA_SEARCH_STEPS {
+        let c = (low + high) / 2.0;
+        let candidate = OkLch { c, ..color };
+
+        if candidate.in_gamut() {
+            low = c;
+        } else {
+            high = c;
+        }
+    }
+
+    OkLch { c: low, ..color }.to_rgb()
+}
+
+#[cfg(test)]
+mod tests {
+    use {
+        lab, lch, linear_rgb, oklab, oklch, p3, rgb, xyz, Color, GamutMap, OkLab, OkLch, RGB, XYZ,
+    };
+
+    // Checks that reducing the chroma of `original` keeps its lightness and hue
+    // (as far as bytes allow), and only lowers its chroma.
+    fn assert_chroma_reduced(original: OkLch, reduced: RGB) {
+        let reduced = reduced.to_oklch();
+        let hue_delta = (reduced.h - original.h + 180.0).rem_euclid(360.0) - 180.0;
+
+        assert!(reduced.c < original.c, "{:?} -> {:?}", original, reduced);
+        assert!(
+            (reduced.l - original.l).abs() < 0.01,
+            "{:?} -> {:?}",
+            original,
+            reduced
+        );
+        assert!(hue_delta.abs() < 2.0, "{:?} -> {:?}", original, reduced);
+    }
+
+    #[test]
+    fn reduces_the_chroma_of_colors_out_of_gamut() {
+        for &hue in [0.0, 30.0, 90.0, 150.0, 210.0, 265.0, 330.0].iter() {
+            for &lightness in [0.3, 0.5, 0.7, 0.9].iter() {
+                let color = oklch(lightness, 0.4, hue);
+
+                assert!(!color.in_gamut());
+                assert_chroma_reduced(color, color.map_to_gamut(GamutMap::ChromaReduce));
+            }
+        }
+    }
+
+    #[test]
+    fn leaves_colors_in_gamut_unchanged() {
+        for &color in [
+            rgb(250, 128, 114),
+            rgb(0, 0, 0),
+            rgb(255, 255, 255),
+            rgb(0, 255, 0),
+        ]
+        .iter()
+        {
+            let oklch = color.to_oklch();
+
+            assert!(oklch.in_gamut());
+            assert_eq!(oklch.map_to_gamut(GamutMap::ChromaReduce), color);
+            assert_eq!(oklch.map_to_gamut(GamutMap::Clip), color);
+            assert_eq!(color.to_lab().map_to_gamut(GamutMap::ChromaReduce), color);
+            assert_eq!(color.to_xyz().map_to_gamut(GamutMap::ChromaReduce), color);
+        }
+    }
+
+    #[test]
+    fn maps_colors_beyond_white_and_black() {
+        assert_eq!(
+            oklch(1.2, 0.2, 30.0).map_to_gamut(GamutMap::ChromaReduce),
+            rgb(255, 255, 255)
+        );
+        assert_eq!(
+            oklch(-0.1, 0.2, 30.0).map_to_gamut(GamutMap::ChromaReduce),
+            rgb(0, 0, 0)
+        );
+    }
+
+    #[test]
+    fn maps_every_float_type() {
+        let vivid_lab = lab(60.0, -110.0, 80.0);
+        let vivid_lch = lch(60.0, 150.0, 140.0);
+        let vivid_oklab = oklab(0.6, 0.3, -0.3);
+        let vivid_xyz = xyz(0.2, 0.4, 0.1);
+        let vivid_linear = linear_rgb(1.2, 0.5, -0.1);
+        let vivid_p3 = p3(0.0, 1.0, 0.0);
+
+        assert!(!vivid_lab.in_gamut());
+        assert!(!vivid_lch.in_gamut());
+        assert!(!vivid_oklab.in_gamut());
+        assert!(!vivid_xyz.in_gamut());
+        assert!(!vivid_linear.in_gamut());
+        assert!(!vivid_p3.in_gamut());
+
+        assert_eq!(vivid_lab.map_to_gamut(GamutMap::Clip), vivid_lab.to_rgb());
+        assert_eq!(vivid_lch.map_to_gamut(GamutMap::Clip), vivid_lch.to_rgb());
+        assert_eq!(
+            vivid_oklab.map_to_gamut(GamutMap::Clip),
+            vivid_oklab.to_rgb()
+        );
+        assert_eq!(vivid_xyz.map_to_gamut(GamutMap::Clip), vivid_xyz.to_rgb());
+        assert_eq!(
+            vivid_linear.map_to_gamut(GamutMap::Clip),
+            vivid_linear.to_srgb()
+        );
+        assert_eq!(vivid_p3.map_to_gamut(GamutMap::Clip), vivid_p3.to_rgb());
+
+        // Every type reduces the chroma of the same color in OkLch.
+        let unclamped = |xyz: XYZ| OkLch::from_oklab(OkLab::from_linear_rgb(xyz.to_linear_rgb()));
+
+        assert_chroma_reduced(
+            unclamped(vivid_lab.to_xyz()),
+            vivid_lab.map_to_gamut(GamutMap::ChromaReduce),
+        );
+        assert_chroma_reduced(
+            unclamped(vivid_lch.to_xyz()),
+            vivid_lch.map_to_gamut(GamutMap::ChromaReduce),
+        );
+        assert_chroma_reduced(
+            unclamped(vivid_xyz),
+            vivid_xyz.map_to_gamut(GamutMap::ChromaReduce),
+        );
+        assert_chroma_reduced(
+            OkLch::from_oklab(vivid_oklab),
+            vivid_oklab.map_to_gamut(GamutMap::ChromaReduce),
+        );
+        assert_chroma_reduced(
+            OkLch::from_oklab(OkLab::from_linear_rgb([1.2, 0.5, -0.1])),
+            vivid_linear.map_to_gamut(GamutMap::ChromaReduce),
+        );
+    }
+}
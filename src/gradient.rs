@@ -0,0 +1,318 @@
+use super::interpolation::interpolate;
+use super::{Color, InterpolationSpace, RGBA};
+
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize))]
+/// A struct to represent a linear gradient, like the ones of CSS: a list of
+/// color stops, each at a position between `0.0` (the start of the gradient)
+/// and `1.0` (its end), which are interpolated between.
+///
+/// The stops are sorted by position when the gradient is built. The sort is
+/// stable, so stops that share a position keep their order: the gradient jumps
+/// from the color of the first one to the color of the last one (a hard stop),
+/// and the color at that position is the one of the last stop.
+///
+/// The stops are interpolated in sRGB, unless another space is chosen with
+/// `with_space`.
+///
+/// # Example
+/// ```
+/// use css_colors::{rgb, rgba, Gradient};
+///
+/// let gradient = Gradient::new(vec![
+///     (0.0, rgb(255, 0, 0)),
+///     (0.5, rgb(255, 255, 0)),
+///     (1.0, rgb(0, 0, 255)),
+/// ]);
+///
+/// assert_eq!(gradient.sample(0.25), rgba(255, 128, 0, 1.0));
+/// assert_eq!(gradient.sample(0.75), rgba(128, 128, 128, 1.0));
+/// ```
+pub struct Gradient {
+    // the positions and colors of the stops, sorted by position
+    stops: Vec<(f32, RGBA)>,
+
+    // the color space in which the stops are interpolated
+    space: InterpolationSpace,
+}
+
+impl Gradient {
+    /// Builds a gradient from its stops, in any order. Stops before `0.0` or
+    /// after `1.0` are kept, and shape the part of the gradient that is sampled.
+    ///
+    /// A gradient without stops, or with a position that isn't a number, will
+    /// cause a panic.
+    pub fn new<T: Color>(stops: Vec<(f32, T)>) -> Gradient {
+        assert!(!stops.is_empty(), "A gradient needs at least one stop");
+
+        let mut stops: Vec<(f32, RGBA)> = stops
+            .into_iter()
+            .map(|(position, color)| {
+                assert!(!position.is_nan(), "Invalid position for gradient stop");
+
+                (position, color.to_rgba())
+            })
+            .collect();
+
+        stops.sort_by(|lhs, rhs| lhs.0.partial_cmp(&rhs.0).unwrap());
+
+        Gradient {
+            stops,
+            space: InterpolationSpace::default(),
+        }
+    }
+
+    /// Returns a copy of `self` that interpolates its stops in `space`.
+    ///
+    /// # Example
+    /// ```
+    /// use css_colors::{rgb, rgba, Gradient, InterpolationSpace};
+    ///
+    /// let gradient = Gradient::new(vec![(0.0, rgb(0, 0, 0)), (1.0, rgb(255, 255, 255))]);
+    ///
+    /// assert_eq!(gradient.sample(0.5), rgba(128, 128, 128, 1.0));
+    /// assert_eq!(
+    ///     gradient.with_space(InterpolationSpace::Oklab).sample(0.5),
+    ///     rgba(99, 99, 99, 1.0)
+    /// );
+    /// ```
+    #[must_use]
+    pub fn with_space(self, space: InterpolationSpace) -> Self {
+        Gradient { space, ..self }
+    }
+
+    /// Returns the stops of `self`, sorted by position.
+    pub fn stops(&self) -> &[(f32, RGBA)] {
+        &self.stops
+    }
+
+    /// Samples the color at position `t`, which is clamped into `0.0-1.0`.
+    /// Between two stops, the color is interpolated like `Color::lerp_in`, and
+    /// before the first stop or after the last one, it is the color of that
+    /// stop.
+    ///
+    /// # Example
+    /// ```
+    /// use css_colors::{rgb, rgba, Gradient};
+    ///
+    /// let gradient = Gradient::new(vec![(0.2, rgb(0, 0, 0)), (0.6, rgb(255, 255, 255))]);
+    ///
+    /// assert_eq!(gradient.sample(0.0), rgba(0, 0, 0, 1.0));
+    /// assert_eq!(gradient.sample(0.3), rgba(64, 64, 64, 1.0));
+    /// assert_eq!(gradient.sample(2.0), rgba(255, 255, 255, 1.0));
+    /// ```
+    pub fn sample(&self, t: f32) -> RGBA {
+        let t = t.clamp(0.0, 1.0);
+
+        // The first stop after `t`, so that `t` is at or after the one before.
+        let next = self.stops.partition_point(|&(position, _)| position <= t);
+
+        if next == 0 {
+            return self.stops[0].1;
+        }
+
+        if next == self.stops.len() {
+            return self.stops[next - 1].1;
+        }
+
+        let (start, from) = self.stops[next - 1];
+        let (end, to) = self.stops[next];
+
+        interpolate(from, to, (t - start) / (end - start), self.space)
+    }
+
+    /// Samples `n` evenly spaced colors, from the start of the gradient (`0.0`)
+    /// to its end (`1.0`), e.g. for the legend of a heat map. A single sample is
+    /// taken at the start, and no samples give an empty `Vec`.
+    ///
+    /// # Example
+    /// ```
+    /// use css_colors::{rgb, rgba, Gradient};
+    ///
+    /// let gradient = Gradient::new(vec![(0.0, rgb(0, 0, 0)), (1.0, rgb(255, 255, 255))]);
+    ///
+    /// assert_eq!(
+    ///     gradient.steps(3),
+    ///     vec![rgba(0, 0, 0, 1.0), rgba(128, 128, 128, 1.0), rgba(255, 255, 255, 1.0)]
+    /// );
+    /// ```
+    pub fn steps(&self, n: usize) -> Vec<RGBA> {
+        if n == 1 {
+            return vec![self.sample(0.0)];
+        }
+
+        (0..n)
+            .map(|step| self.sample(step as f32 / (n - 1) as f32))
+            .collect()
+    }
+}
+
+// Gradients are validated and sorted like in `Gradient::new`.
+#[cfg(feature = "serde")]
+impl<'de> serde::Deserialize<'de> for Gradient {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        #[derive(Deserialize)]
+        struct Fields {
+            stops: Vec<(f32, RGBA)>,
+            space: InterpolationSpace,
+        }
+
+        let Fields { stops, space } = Fields::deserialize(deserializer)?;
+
+        if stops.is_empty() {
+            return Err(serde::de::Error::custom(
+                "a gradient needs at least one stop",
+            ));
+        }
+
+        if stops.iter().any(|&(position, _)| position.is_nan()) {
+            return Err(serde::de::Error::custom(
+                "invalid position for gradient stop",
+            ));
+        }
+
+        Ok(Gradient::new(stops).with_space(space))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use {hsl, rgb, rgba, Color, Gradient};
+
+    #[test]
+    fn can_sample_two_stops() {
+        let gradient = Gradient::new(vec![(0.0, rgb(250, 128, 114)), (1.0, rgb(0, 0, 128))]);
+
+        assert_eq!(gradient.sample(0.0), rgba(250, 128, 114, 1.0));
+        assert_eq!(gradient.sample(1.0), rgba(0, 0, 128, 1.0));
+        assert_eq!(gradient.sample(-1.0), rgba(250, 128, 114, 1.0));
+        assert_eq!(gradient.sample(1.5), rgba(0, 0, 128, 1.0));
+
+        for step in 0..=100 {
+            let t = step as f32 / 100.0;
+
+            assert_eq!(
+                gradient.sample(t),
+                rgb(250, 128, 114).lerp(rgb(0, 0, 128), t)
+            );
+        }
+    }
+
+    #[test]
+    fn can_sample_many_stops() {
+        let red = rgb(255, 0, 0);
+        let yellow = rgb(255, 255, 0);
+        let blue = rgb(0, 0, 255);
+
+        // The stops are sorted.
+        let gradient = Gradient::new(vec![(1.0, blue), (0.0, red), (0.25, yellow)]);
+
+        assert_eq!(gradient.stops()[1], (0.25, yellow.to_rgba()));
+        assert_eq!(gradient.sample(0.25), yellow.to_rgba());
+        assert_eq!(gradient.sample(0.125), red.lerp(yellow, 0.5));
+        assert_eq!(gradient.sample(0.625), yellow.lerp(blue, 0.5));
+
+        // Stops that don't reach the ends are extended.
+        let gradient = Gradient::new(vec![(0.25, red), (0.75, blue)]);
+
+        assert_eq!(gradient.sample(0.1), red.to_rgba());
+        assert_eq!(gradient.sample(0.5), red.lerp(blue, 0.5));
+        assert_eq!(gradient.sample(0.9), blue.to_rgba());
+
+        // A single stop is a solid color.
+        let gradient = Gradient::new(vec![(0.5, hsl(120, 100, 25))]);
+
+        assert_eq!(gradient.sample(0.0), hsl(120, 100, 25).to_rgba());
+        assert_eq!(gradient.sample(1.0), hsl(120, 100, 25).to_rgba());
+    }
+
+    #[test]
+    fn can_sample_hard_stops() {
+        let red = rgb(255, 0, 0);
+        let yellow = rgb(255, 255, 0);
+        let green = rgb(0, 128, 0);
+        let blue = rgb(0, 0, 255);
+
+        let gradient = Gradient::new(vec![(0.0, red), (0.5, yellow), (0.5, green), (1.0, blue)]);
+
+        assert_eq!(gradient.sample(0.499), red.lerp(yellow, 0.998));
+        assert_eq!(gradient.sample(0.5), green.to_rgba());
+        assert_eq!(gradient.sample(0.75), green.lerp(blue, 0.5));
+
+        // Stops at the same position keep their order, whichever the order of
+        // the others.
+        let shuffled = Gradient::new(vec![(1.0, blue), (0.5, yellow), (0.0, red), (0.5, green)]);
+
+        assert_eq!(shuffled, gradient);
+
+        // Three stops at the same position skip the one in the middle.
+        let gradient = Gradient::new(vec![(0.5, red), (0.5, yellow), (0.5, blue)]);
+
+        assert_eq!(gradient.sample(0.25), red.to_rgba());
+        assert_eq!(gradient.sample(0.5), blue.to_rgba());
+    }
+
+    #[test]
+    fn can_take_steps() {
+        let gradient = Gradient::new(vec![(0.0, rgb(0, 0, 0)), (1.0, rgb(255, 0, 0))]);
+
+        assert_eq!(gradient.steps(0), vec![]);
+        assert_eq!(gradient.steps(1), vec![rgba(0, 0, 0, 1.0)]);
+        assert_eq!(
+            gradient.steps(2),
+            vec![rgba(0, 0, 0, 1.0), rgba(255, 0, 0, 1.0)]
+        );
+        assert_eq!(
+            gradient.steps(6),
+            vec![
+                rgba(0, 0, 0, 1.0),
+                rgba(51, 0, 0, 1.0),
+                rgba(102, 0, 0, 1.0),
+                rgba(153, 0, 0, 1.0),
+                rgba(204, 0, 0, 1.0),
+                rgba(255, 0, 0, 1.0),
+            ]
+        );
+    }
+
+    #[test]
+    #[should_panic(expected = "A gradient needs at least one stop")]
+    fn cannot_build_a_gradient_without_stops() {
+        Gradient::new(Vec::<(f32, ::RGB)>::new());
+    }
+
+    #[test]
+    #[should_panic(expected = "Invalid position for gradient stop")]
+    fn cannot_build_a_gradient_with_nan_positions() {
+        Gradient::new(vec![(0.0, rgb(0, 0, 0)), (f32::NAN, rgb(255, 255, 255))]);
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn can_deserialize_gradients() {
+        use serde_json;
+        use InterpolationSpace;
+
+        let json = r#"{
+            "stops": [
+                [1.0, {"r": 255, "g": 255, "b": 255, "a": 255}],
+                [0.0, {"r": 0, "g": 0, "b": 0, "a": 255}]
+            ],
+            "space": "oklab"
+        }"#;
+        let gradient: Gradient = serde_json::from_str(json).unwrap();
+
+        assert_eq!(
+            gradient,
+            Gradient::new(vec![(0.0, rgb(0, 0, 0)), (1.0, rgb(255, 255, 255))])
+                .with_space(InterpolationSpace::Oklab)
+        );
+        assert_eq!(gradient.sample(0.0), rgba(0, 0, 0, 1.0));
+        assert_eq!(
+            serde_json::from_str::<Gradient>(&serde_json::to_string(&gradient).unwrap()).unwrap(),
+            gradient
+        );
+
+        assert!(serde_json::from_str::<Gradient>(r#"{"stops":[],"space":"srgb"}"#).is_err());
+    }
+}
@@ -0,0 +1,65 @@
+use super::{deg, ops, percent, Color, HSLA};
+
+// The lightness of the lightest and the darkest colors of a monochromatic ladder.
+const LIGHTEST: f32 = 0.95;
+const DARKEST: f32 = 0.15;
+
+// How much the saturation of the lightest and the darkest colors of a
+// monochromatic ladder is raised, as a fraction of the saturation of the base.
+const SATURATION_BOOST: f32 = 0.15;
+
+// Rotates the hue of `color` by `degrees` (wrapping around the color wheel in
+// either direction), for the color schemes built from rotations of one color.
+// Greys have no hue, so they are returned unchanged rather than given one.
+pub(crate) fn rotate_hue<T: Color>(color: T, degrees: i32) -> T {
+    if color.saturation() == percent(0) {
+        color
+    } else {
+        color.spin(deg(degrees))
+    }
+}
+
+// Builds a ladder of `steps` colors with the hue of `color`, from light to dark.
+// The lightness steps evenly from `LIGHTEST` to `DARKEST`, and the saturation of
+// `color` is raised by up to `SATURATION_BOOST` toward both ends, with the square
+// of their distance from the middle of the ladder, so that the lightest tints
+// and the darkest shades don't look washed out. `color` itself replaces the step
+// closest to its lightness (the lightest of two equally close ones).
+pub(crate) fn monochromatic<T: Color + Copy>(color: T, steps: usize) -> Vec<T> {
+    if steps < 2 {
+        return vec![color; steps];
+    }
+
+    let lightness =
+        |step: usize| LIGHTEST - (LIGHTEST - DARKEST) * step as f32 / (steps - 1) as f32;
+    let middle = (LIGHTEST + DARKEST) / 2.0;
+    let base = color.to_hsla().l.as_f32();
+
+    let closest = (0..steps)
+        .min_by(|&lhs, &rhs| {
+            let lhs = (lightness(lhs) - base).abs();
+            let rhs = (lightness(rhs) - base).abs();
+
+            lhs.partial_cmp(&rhs).unwrap()
+        })
+        .unwrap();
+
+    (0..steps)
+        .map(|step| {
+            if step == closest {
+                return color;
+            }
+
+            let l = lightness(step);
+            let distance = (l - middle) / (LIGHTEST - middle);
+            let boost = 1.0 + SATURATION_BOOST * distance * distance;
+
+            ops::apply_to_hsl(color, |HSLA { h, s, a, .. }| HSLA {
+                h,
+                s: ops::quantize(s.as_f32() * boost),
+                l: ops::quantize(l),
+                a,
+            })
+        })
+        .collect()
+}
@@ -0,0 +1,299 @@
+use super::{clamp_ratio, deg, ops, percent, Angle, Color, Ratio, HSL, HSLA, RGB, RGBA};
+use std::fmt;
+
+/// Constructs a HSI Color from numerical values.
+///
+/// The hue component is expressed in degrees. Values outside of
+/// the 0-359° range will be normalized accordingly. The saturation
+/// and intensity components are expressed in percentages. Values
+/// outside of the 0-100% range will cause a panic.
+///
+/// # Example
+/// ```
+/// use css_colors::{Color, hsi, rgb};
+///
+/// let red = hsi(0, 100, 33);
+///
+/// assert_eq!(red.to_string(), "hsi(0, 100%, 33%)");
+/// assert_eq!(red.to_rgb(), rgb(252, 0, 0));
+/// ```
+pub fn hsi(h: i32, s: u8, i: u8) -> HSI {
+    HSI {
+        h: deg(h),
+        s: percent(s),
+        i: percent(i),
+    }
+}
+
+#[derive(Debug, Copy, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+/// A struct to represent a color by its hue, saturation and intensity, a model
+/// used in image processing and computer vision.
+/// The hue is a degree on the color wheel; 0 (or 360) is red, 120 is green, 240 is blue.
+/// A valid value for `h` must range between `0-360`.
+/// The saturation and intensity range between `0-100`.
+///
+/// HSI shares its name pattern with HSL and HSV, but its components differ:
+///
+/// - the intensity is the average of the channels, `(r + g + b) / 3`, while
+///   the lightness of HSL is `(max + min) / 2`, and the value of HSV is `max`:
+///   pure red has an intensity of 33%, but a lightness of 50% and a value of 100%;
+/// - the saturation is `1 - min / i`, relative to the intensity: every color
+///   with a channel at zero is fully saturated, including dark ones;
+/// - the hue is the angle of the color in the plane perpendicular to the grey
+///   axis, which matches the hue of HSL and HSV for primaries and secondaries,
+///   but drifts by a few degrees in between.
+///
+/// CSS has no HSI notation, so `to_css` serializes the color as RGB.
+///
+/// For more, see Gonzalez & Woods, Digital Image Processing, section 6.2.
+pub struct HSI {
+    // hue
+    pub h: Angle,
+
+    // saturation
+    pub s: Ratio,
+
+    // intensity
+    pub i: Ratio,
+}
+
+impl fmt::Display for HSI {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        if f.alternate() {
+            write!(f, "hsi({}, {:#}, {:#})", self.h.degrees(), self.s, self.i)
+        } else {
+            write!(f, "hsi({}, {}, {})", self.h.degrees(), self.s, self.i)
+        }
+    }
+}
+
+// Converts a hue (in degrees), saturation and intensity into red, green and blue
+// floats, which can exceed `1.0` for intense saturated colors.
+fn hsi_to_rgb(h: f32, s: f32, i: f32) -> (f32, f32, f32) {
+    // The channel that leads the sector of the hue, relative to its start.
+    let lead = |h: f32| i * (1.0 + s * h.to_radians().cos() / (60.0 - h).to_radians().cos());
+    let min = i * (1.0 - s);
+
+    if h < 120.0 {
+        let r = lead(h);
+        (r, 3.0 * i - r - min, min)
+    } else if h < 240.0 {
+        let g = lead(h - 120.0);
+        (min, g, 3.0 * i - g - min)
+    } else {
+        let b = lead(h - 240.0);
+        (3.0 * i - b - min, min, b)
+    }
+}
+
+impl Color for HSI {
+    type Alpha = RGBA;
+
+    fn to_css(&self) -> String {
+        self.to_rgb().to_css()
+    }
+
+    fn to_rgb(&self) -> RGB {
+        self.to_rgba().to_rgb()
+    }
+
+    fn to_rgba(&self) -> RGBA {
+        let (r, g, b) = hsi_to_rgb(self.h.degrees() as f32, self.s.as_f32(), self.i.as_f32());
+
+        RGBA {
+            r: clamp_ratio(r),
+            g: clamp_ratio(g),
+            b: clamp_ratio(b),
+            a: percent(100),
+        }
+    }
+
+    fn to_hsl(&self) -> HSL {
+        self.to_rgba().to_hsl()
+    }
+
+    fn to_hsla(&self) -> HSLA {
+        self.to_rgba().to_hsla()
+    }
+
+    fn to_hsi(&self) -> HSI {
+        *self
+    }
+
+    fn from_rgba(rgba: RGBA) -> HSI {
+        let (r, g, b) = (rgba.r.as_f32(), rgba.g.as_f32(), rgba.b.as_f32());
+
+        let i = (r + g + b) / 3.0;
+        let min = r.min(g).min(b);
+
+        // Greys have no saturation, and black has no saturation to speak of.
+        if rgba.r == rgba.g && rgba.g == rgba.b {
+            return HSI {
+                h: deg(0),
+                s: percent(0),
+                i: clamp_ratio(i),
+            };
+        }
+
+        let numerator = ((r - g) + (r - b)) / 2.0;
+        let denominator = ((r - g).powi(2) + (r - b) * (g - b)).sqrt();
+        let theta = (numerator / denominator)
+            .clamp(-1.0, 1.0)
+            .acos()
+            .to_degrees();
+        let h = if b <= g { theta } else { 360.0 - theta };
+
+        HSI {
+            h: deg(h.round() as i32),
+            s: clamp_ratio(1.0 - min / i),
+            i: clamp_ratio(i),
+        }
+    }
+
+    fn saturate(self, amount: Ratio) -> Self {
+        ops::apply_to_hsl(self, |hsla| hsla.saturate(amount))
+    }
+
+    fn desaturate(self, amount: Ratio) -> Self {
+        ops::apply_to_hsl(self, |hsla| hsla.desaturate(amount))
+    }
+
+    fn lighten(self, amount: Ratio) -> Self {
+        ops::apply_to_hsl(self, |hsla| hsla.lighten(amount))
+    }
+
+    fn darken(self, amount: Ratio) -> Self {
+        ops::apply_to_hsl(self, |hsla| hsla.darken(amount))
+    }
+
+    fn fadein(self, amount: Ratio) -> RGBA {
+        self.to_rgba().fadein(amount)
+    }
+
+    fn fadeout(self, amount: Ratio) -> RGBA {
+        self.to_rgba().fadeout(amount)
+    }
+
+    fn fade(self, amount: Ratio) -> RGBA {
+        self.to_rgba().fade(amount)
+    }
+
+    fn spin(self, amount: Angle) -> Self {
+        ops::apply_to_hsl(self, |hsla| hsla.spin(amount))
+    }
+
+    fn mix<T: Color>(self, other: T, weight: Ratio) -> RGBA {
+        self.to_rgba().mix(other, weight)
+    }
+
+    fn tint(self, weight: Ratio) -> Self {
+        HSI::from_rgba(self.to_rgba().tint(weight))
+    }
+
+    fn shade(self, weight: Ratio) -> Self {
+        HSI::from_rgba(self.to_rgba().shade(weight))
+    }
+
+    fn greyscale(self) -> Self {
+        ops::apply_to_hsl(self, |hsla| hsla.greyscale())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use {hsi, percent, rgb, rgba, Color, HSI, RGB};
+
+    // The largest difference between the channels of `lhs` and `rhs`.
+    fn max_delta(lhs: RGB, rhs: RGB) -> u8 {
+        [(lhs.r, rhs.r), (lhs.g, rhs.g), (lhs.b, rhs.b)]
+            .iter()
+            .map(|&(lhs, rhs)| (lhs.as_u8() as i16 - rhs.as_u8() as i16).unsigned_abs() as u8)
+            .max()
+            .unwrap()
+    }
+
+    #[test]
+    fn can_display_hsi_colors() {
+        assert_eq!(hsi(120, 100, 33).to_string(), "hsi(120, 100%, 33%)");
+        assert_eq!(
+            format!("{:#}", rgb(255, 0, 0).to_hsi()),
+            "hsi(0, 100%, 33.3%)"
+        );
+        assert_eq!(hsi(0, 0, 100).to_css(), "rgb(255, 255, 255)");
+    }
+
+    #[test]
+    fn differs_from_hsl_and_hsv_on_primaries() {
+        let red = rgb(255, 0, 0);
+
+        assert_eq!(red.to_hsi().to_string(), "hsi(0, 100%, 33%)");
+        assert_eq!(red.to_hsl().to_string(), "hsl(0, 100%, 50%)");
+        assert_eq!(red.to_hsv().to_string(), "hsv(0, 100%, 100%)");
+
+        // Secondaries combine two channels, so they're twice as intense.
+        assert_eq!(rgb(255, 255, 0).to_hsi().to_string(), "hsi(60, 100%, 67%)");
+        assert_eq!(rgb(0, 255, 255).to_hsi().to_string(), "hsi(180, 100%, 67%)");
+        assert_eq!(rgb(255, 0, 255).to_hsi().to_string(), "hsi(300, 100%, 67%)");
+        assert_eq!(rgb(0, 255, 0).to_hsi().to_string(), "hsi(120, 100%, 33%)");
+        assert_eq!(rgb(0, 0, 255).to_hsi().to_string(), "hsi(240, 100%, 33%)");
+
+        // Dark colors with a channel at zero are fully saturated.
+        let maroon = rgb(64, 0, 0);
+
+        assert_eq!(maroon.to_hsi().s, percent(100));
+        assert_eq!(maroon.to_hsl().s, percent(100));
+        assert_eq!(maroon.to_hsv().v, percent(25));
+    }
+
+    #[test]
+    fn greys_have_no_hue_or_saturation() {
+        for &value in [0, 64, 128, 192, 255].iter() {
+            let grey = rgb(value, value, value);
+
+            assert_eq!(grey.to_hsi().h.degrees(), 0);
+            assert_eq!(grey.to_hsi().s, percent(0));
+            assert_eq!(grey.to_hsi().i, grey.to_hsl().l);
+            assert_eq!(grey.to_hsi().i, grey.to_hsv().v);
+            assert_eq!(grey.to_hsi().to_rgb(), grey);
+        }
+    }
+
+    #[test]
+    fn can_convert_hsi_to_rgb() {
+        assert_eq!(hsi(0, 100, 33).to_rgb(), rgb(252, 0, 0));
+        assert_eq!(hsi(120, 100, 33).to_rgb(), rgb(0, 252, 0));
+        assert_eq!(hsi(240, 100, 33).to_rgb(), rgb(0, 0, 252));
+        assert_eq!(hsi(60, 100, 67).to_rgb(), rgb(255, 255, 0));
+
+        // Intense saturated colors fall outside of RGB, and are clamped.
+        assert_eq!(hsi(0, 100, 100).to_rgb(), rgb(255, 0, 0));
+        assert_eq!(hsi(60, 50, 100).to_rgb(), rgb(255, 255, 127));
+    }
+
+    #[test]
+    fn can_round_trip_through_hsi() {
+        for r in (0..=255).step_by(15) {
+            for g in (0..=255).step_by(15) {
+                for b in (0..=255).step_by(15) {
+                    let color = rgb(r as u8, g as u8, b as u8);
+
+                    assert!(max_delta(color.to_hsi().to_rgb(), color) <= 4, "{}", color);
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn can_transform_hsi_colors() {
+        let red = hsi(0, 100, 33);
+
+        assert_eq!(
+            HSI::from_rgba(rgba(255, 0, 0, 0.5)),
+            rgb(255, 0, 0).to_hsi()
+        );
+        assert_eq!(red.fade(percent(50)), rgba(252, 0, 0, 0.5));
+        assert_eq!(red.greyscale().s, percent(0));
+        assert_eq!(red.spin(::deg(120)).h.degrees(), 120);
+    }
+}
@@ -1,4 +1,7 @@
-use super::{deg, percent, Angle, Color, Ratio, RGB, RGBA};
+use super::{
+    adjust, deg, ops, percent, Angle, Color, ColorAdjustment, ColorChange, Ratio, HSV, HSVA, HWB,
+    RGB, RGBA,
+};
 use std::fmt;
 
 /// Constructs a HSL Color from numerical values, similar to the
@@ -57,6 +60,7 @@ pub fn hsla(h: i32, s: u8, l: u8, a: f32) -> HSLA {
 }
 
 #[derive(Debug, Copy, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 /// A struct to represent how much hue, saturation, and luminosity should be added to create a color.
 /// The hue is a degree on the color wheel; 0 (or 360) is red, 120 is green, 240 is blue.
 /// A valid value for `h` must range between `0-360`.
@@ -77,31 +81,71 @@ pub struct HSL {
 
 impl fmt::Display for HSL {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        write!(f, "hsl({}, {}, {})", self.h.degrees(), self.s, self.l)
+        if f.alternate() {
+            write!(f, "hsl({}, {:#}, {:#})", self.h.degrees(), self.s, self.l)
+        } else {
+            write!(f, "hsl({}, {}, {})", self.h.degrees(), self.s, self.l)
+        }
+    }
+}
+
+impl HSL {
+    /// Returns a copy of `self` with its hue set to `hue`.
+    ///
+    /// # Example
+    /// ```
+    /// use css_colors::{deg, hsl, percent};
+    ///
+    /// let red = hsl(0, 100, 50);
+    ///
+    /// assert_eq!(red.with_hue(deg(120)), hsl(120, 100, 50));
+    /// assert_eq!(red.with_lightness(percent(25)), hsl(0, 100, 25));
+    /// ```
+    #[must_use]
+    pub fn with_hue(self, hue: Angle) -> Self {
+        HSL { h: hue, ..self }
+    }
+
+    /// Returns a copy of `self` with its saturation set to `saturation`.
+    #[must_use]
+    pub fn with_saturation(self, saturation: Ratio) -> Self {
+        HSL {
+            s: saturation,
+            ..self
+        }
+    }
+
+    /// Returns a copy of `self` with its lightness set to `lightness`.
+    #[must_use]
+    pub fn with_lightness(self, lightness: Ratio) -> Self {
+        HSL {
+            l: lightness,
+            ..self
+        }
     }
 }
 
 impl Color for HSL {
     type Alpha = HSLA;
 
-    fn to_css(self) -> String {
+    fn to_css(&self) -> String {
         self.to_string()
     }
 
-    fn to_rgb(self) -> RGB {
+    fn to_rgb(&self) -> RGB {
         self.to_hsla().to_rgb()
     }
 
-    fn to_rgba(self) -> RGBA {
+    fn to_rgba(&self) -> RGBA {
         self.to_hsla().to_rgba()
     }
 
-    fn to_hsl(self) -> HSL {
-        self
+    fn to_hsl(&self) -> HSL {
+        *self
     }
 
-    fn to_hsla(self) -> HSLA {
-        let HSL { h, s, l } = self;
+    fn to_hsla(&self) -> HSLA {
+        let HSL { h, s, l } = *self;
 
         HSLA {
             h,
@@ -111,6 +155,26 @@ impl Color for HSL {
         }
     }
 
+    fn to_hsv(&self) -> HSV {
+        HSV::from_hsla(self.to_hsla())
+    }
+
+    fn to_hsva(&self) -> HSVA {
+        HSVA::from_hsla(self.to_hsla())
+    }
+
+    fn to_hwb(&self) -> HWB {
+        HWB::from_hsla(self.to_hsla())
+    }
+
+    fn from_rgba(rgba: RGBA) -> HSL {
+        rgba.to_hsl()
+    }
+
+    fn from_hsla(hsla: HSLA) -> HSL {
+        hsla.to_hsl()
+    }
+
     fn saturate(self, amount: Ratio) -> Self {
         self.to_hsla().saturate(amount).to_hsl()
     }
@@ -143,6 +207,10 @@ impl Color for HSL {
         self.to_hsla().spin(amount).to_hsl()
     }
 
+    fn spin_f32(self, degrees: f32) -> Self {
+        self.to_hsla().spin_f32(degrees).to_hsl()
+    }
+
     fn mix<T: Color>(self, other: T, weight: Ratio) -> Self::Alpha {
         self.to_hsla().mix(other, weight)
     }
@@ -158,6 +226,14 @@ impl Color for HSL {
     fn greyscale(self) -> Self {
         self.to_hsla().greyscale().to_hsl()
     }
+
+    fn adjust(self, adjustment: ColorAdjustment) -> Self {
+        self.to_hsla().adjust(adjustment).to_hsl()
+    }
+
+    fn change(self, change: ColorChange) -> Self {
+        self.to_hsla().change(change).to_hsl()
+    }
 }
 
 // A function to convert an HSL value (either h, s, or l) into the equivalent, valid RGB value.
@@ -180,6 +256,7 @@ fn to_rgb_value(val: u16, temp_1: f32, temp_2: f32) -> f32 {
 }
 
 #[derive(Debug, Copy, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 /// A struct to represent how much hue, saturation, and luminosity should be added to create a color.
 /// Also handles alpha specifications.
 ///
@@ -204,30 +281,111 @@ pub struct HSLA {
 
 impl fmt::Display for HSLA {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        write!(
-            f,
-            "hsla({}, {}, {}, {:.02})",
-            self.h.degrees(),
-            self.s,
-            self.l,
-            self.a.as_f32()
-        )
+        if f.alternate() {
+            write!(
+                f,
+                "hsla({}, {:#}, {:#}, {:.03})",
+                self.h.degrees(),
+                self.s,
+                self.l,
+                self.a.as_f32()
+            )
+        } else {
+            write!(
+                f,
+                "hsla({}, {}, {}, {:.02})",
+                self.h.degrees(),
+                self.s,
+                self.l,
+                self.a.as_f32()
+            )
+        }
+    }
+}
+
+impl HSLA {
+    /// Returns a copy of `self` with its hue set to `hue`.
+    #[must_use]
+    pub fn with_hue(self, hue: Angle) -> Self {
+        HSLA { h: hue, ..self }
+    }
+
+    /// Returns a copy of `self` with its saturation set to `saturation`.
+    #[must_use]
+    pub fn with_saturation(self, saturation: Ratio) -> Self {
+        HSLA {
+            s: saturation,
+            ..self
+        }
+    }
+
+    /// Returns a copy of `self` with its lightness set to `lightness`.
+    #[must_use]
+    pub fn with_lightness(self, lightness: Ratio) -> Self {
+        HSLA {
+            l: lightness,
+            ..self
+        }
+    }
+
+    /// Returns a copy of `self` with its alpha channel set to `alpha`.
+    #[must_use]
+    pub fn with_alpha(self, alpha: Ratio) -> Self {
+        HSLA { a: alpha, ..self }
+    }
+
+    /// Composites `self` over an opaque `background` and drops the alpha channel,
+    /// like `RGBA::flatten`.
+    ///
+    /// # Example
+    /// ```
+    /// use css_colors::{hsla, rgb, Color};
+    ///
+    /// let red = hsla(0, 100, 50, 0.5);
+    /// let white = rgb(255, 255, 255);
+    ///
+    /// assert_eq!(red.flatten(white), red.to_rgba().flatten(white));
+    /// assert_eq!(red.flatten(white), rgb(255, 128, 128));
+    /// ```
+    pub fn flatten(self, background: RGB) -> RGB {
+        self.to_rgba().flatten(background)
+    }
+
+    // Mixes `self` with white (`1.0`) or black (`0.0`) like `RGBA::mix`, without
+    // quantizing the channels in between. Moving toward white or black never
+    // changes the hue, so it is kept exactly instead of being rounded through RGB.
+    fn mix_with_grey(self, grey: f32, weight: Ratio) -> Self {
+        let HSLA { h, s, l, a } = self;
+
+        let weight = weight.as_f32();
+        let rgb_weight = ops::mix_weight_f32(weight, a.as_f32(), 1.0);
+
+        let (r, g, b) = ops::hsl_to_rgb((h.degrees() as f32, s.as_f32(), l.as_f32()));
+        let blend = |channel: f32| channel * rgb_weight + grey * (1.0 - rgb_weight);
+        let (_, s, l) = ops::rgb_to_hsl(blend(r), blend(g), blend(b));
+
+        HSLA {
+            h,
+            s: ops::quantize(s),
+            l: ops::quantize(l),
+            a: ops::quantize(a.as_f32() * weight + (1.0 - weight)),
+        }
     }
 }
 
 impl Color for HSLA {
     type Alpha = Self;
 
-    fn to_css(self) -> String {
+    fn to_css(&self) -> String {
         self.to_string()
     }
 
-    fn to_rgb(self) -> RGB {
+    fn to_rgb(&self) -> RGB {
         self.to_rgba().to_rgb()
     }
 
-    fn to_rgba(self) -> RGBA {
-        let HSLA { h, s, l, a } = self;
+    fn to_rgba(&self) -> RGBA {
+        let HSLA { h, s, l, a } = *self;
 
         // If there is no saturation, the color is a shade of grey.
         // We can convert the luminosity and set r, g, and b to that value.
@@ -275,13 +433,33 @@ impl Color for HSLA {
         }
     }
 
-    fn to_hsl(self) -> HSL {
-        let HSLA { h, s, l, .. } = self;
+    fn to_hsl(&self) -> HSL {
+        let HSLA { h, s, l, .. } = *self;
         HSL { h, s, l }
     }
 
-    fn to_hsla(self) -> HSLA {
-        self
+    fn to_hsla(&self) -> HSLA {
+        *self
+    }
+
+    fn to_hsv(&self) -> HSV {
+        HSV::from_hsla(*self)
+    }
+
+    fn to_hsva(&self) -> HSVA {
+        HSVA::from_hsla(*self)
+    }
+
+    fn to_hwb(&self) -> HWB {
+        HWB::from_hsla(*self)
+    }
+
+    fn from_rgba(rgba: RGBA) -> HSLA {
+        rgba.to_hsla()
+    }
+
+    fn from_hsla(hsla: HSLA) -> HSLA {
+        hsla
     }
 
     fn saturate(self, amount: Ratio) -> Self {
@@ -329,11 +507,15 @@ impl Color for HSLA {
     }
 
     fn fadein(self, amount: Ratio) -> Self {
-        self.fade(self.a + amount)
+        self.fade(Ratio::from_u8(
+            self.a.as_u8().saturating_add(amount.as_u8()),
+        ))
     }
 
     fn fadeout(self, amount: Ratio) -> Self {
-        self.fade(self.a - amount)
+        self.fade(Ratio::from_u8(
+            self.a.as_u8().saturating_sub(amount.as_u8()),
+        ))
     }
 
     fn fade(self, amount: Ratio) -> Self::Alpha {
@@ -352,16 +534,34 @@ impl Color for HSLA {
         }
     }
 
+    // The hue is rotated in floating point and rounded once, to the whole
+    // degrees that can be represented, which keeps the saturation and lightness
+    // exact. Halves are rounded in the direction of the rotation, so that steps
+    // of half a degree move the hue either way.
+    fn spin_f32(self, degrees: f32) -> Self {
+        let hue = self.h.degrees() as f32 + degrees % 360.0;
+        let rounded = if degrees < 0.0 {
+            (hue - 0.5).ceil()
+        } else {
+            (hue + 0.5).floor()
+        };
+
+        HSLA {
+            h: deg(rounded as i32),
+            ..self
+        }
+    }
+
     fn mix<T: Color>(self, other: T, weight: Ratio) -> Self::Alpha {
         self.to_rgba().mix(other, weight).to_hsla()
     }
 
     fn tint(self, weight: Ratio) -> Self {
-        self.to_rgba().tint(weight).to_hsla()
+        self.mix_with_grey(1.0, weight)
     }
 
     fn shade(self, weight: Ratio) -> Self {
-        self.to_rgba().shade(weight).to_hsla()
+        self.mix_with_grey(0.0, weight)
     }
 
     fn greyscale(self) -> Self {
@@ -374,4 +574,22 @@ impl Color for HSLA {
             a,
         }
     }
+
+    // Unlike the default, adjusting or changing the alpha channel alone is done
+    // in HSL, which this model represents exactly.
+    fn adjust(self, adjustment: ColorAdjustment) -> Self {
+        if adjustment.adjusts_rgb() {
+            adjust::adjust_rgb(self, adjustment)
+        } else {
+            adjust::adjust_hsl(self, adjustment)
+        }
+    }
+
+    fn change(self, change: ColorChange) -> Self {
+        if change.changes_rgb() {
+            adjust::change_rgb(self, change)
+        } else {
+            adjust::change_hsl(self, change)
+        }
+    }
 }
@@ -0,0 +1,534 @@
+use super::{clamp_ratio, deg, ops, percent, Angle, Color, Ratio, HSL, HSLA, RGB, RGBA};
+use std::fmt;
+
+/// Constructs a HSV Color from numerical values.
+///
+/// HSV (also known as HSB) is the color model exposed by most color pickers.
+/// There is no `hsv()` function in CSS, so HSV colors are serialized as their
+/// closest `hsl()` equivalent by `to_css`.
+///
+/// The hue component is expressed in degrees. Values outside of
+/// the 0-359° range will be normalized accordingly. The saturation
+/// and value components are expressed in percentages. Values
+/// outside of the 0-100% range will cause a panic.
+///
+/// # Example
+/// ```
+/// use css_colors::{Color, hsv, rgb};
+///
+/// let tomato = hsv(9, 72, 100);
+///
+/// assert_eq!(tomato.to_string(), "hsv(9, 72%, 100%)");
+/// assert_eq!(tomato.to_rgb(), rgb(255, 99, 71));
+/// ```
+pub fn hsv(h: i32, s: u8, v: u8) -> HSV {
+    HSV {
+        h: deg(h),
+        s: percent(s),
+        v: percent(v),
+    }
+}
+
+/// Constructs a HSVA Color from numerical values.
+///
+/// There is no `hsva()` function in CSS, so HSVA colors are serialized as their
+/// closest `hsla()` equivalent by `to_css`.
+///
+/// The hue component is expressed in degrees. Values outside of
+/// the 0-359° range will be normalized accordingly. The saturation
+/// and value components are expressed in percentages. Values
+/// outside of the 0-100% range will cause a panic. The alpha value
+/// is expressed as a float. Values outside of the 0.0-1.0 range will
+/// cause a panic.
+///
+/// # Example
+/// ```
+/// use css_colors::{Color, hsva, rgba};
+///
+/// let tomato = hsva(9, 72, 100, 0.50);
+///
+/// assert_eq!(tomato.to_string(), "hsva(9, 72%, 100%, 0.50)");
+/// assert_eq!(tomato.to_rgba(), rgba(255, 99, 71, 0.50));
+/// ```
+pub fn hsva(h: i32, s: u8, v: u8, a: f32) -> HSVA {
+    HSVA {
+        h: deg(h),
+        s: percent(s),
+        v: percent(v),
+        a: Ratio::from_f32(a),
+    }
+}
+
+#[derive(Debug, Copy, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+/// A struct to represent how much hue, saturation, and value should be added to create a color.
+/// The hue is a degree on the color wheel; 0 (or 360) is red, 120 is green, 240 is blue.
+/// A valid value for `h` must range between `0-360`.
+/// The saturation ranges between `0-100`, where `0` is a shade of grey, and `100` is a pure hue.
+/// The value ranges between `0-100`, where `0` is black, and `100` is the brightest shade of the hue.
+pub struct HSV {
+    // hue
+    pub h: Angle,
+
+    // saturation
+    pub s: Ratio,
+
+    // value
+    pub v: Ratio,
+}
+
+impl fmt::Display for HSV {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        if f.alternate() {
+            write!(f, "hsv({}, {:#}, {:#})", self.h.degrees(), self.s, self.v)
+        } else {
+            write!(f, "hsv({}, {}, {})", self.h.degrees(), self.s, self.v)
+        }
+    }
+}
+
+impl Color for HSV {
+    type Alpha = HSVA;
+
+    /// Converts `self` into the CSS string of its closest HSL equivalent, as
+    /// there is no `hsv()` function in CSS.
+    fn to_css(&self) -> String {
+        self.to_hsl().to_css()
+    }
+
+    fn to_rgb(&self) -> RGB {
+        self.to_hsva().to_rgb()
+    }
+
+    fn to_rgba(&self) -> RGBA {
+        self.to_hsva().to_rgba()
+    }
+
+    fn to_hsl(&self) -> HSL {
+        self.to_hsva().to_hsl()
+    }
+
+    fn to_hsla(&self) -> HSLA {
+        self.to_hsva().to_hsla()
+    }
+
+    fn to_hsv(&self) -> HSV {
+        *self
+    }
+
+    fn to_hsva(&self) -> HSVA {
+        let HSV { h, s, v } = *self;
+
+        HSVA {
+            h,
+            s,
+            v,
+            a: percent(100),
+        }
+    }
+
+    fn from_rgba(rgba: RGBA) -> HSV {
+        HSVA::from_rgba(rgba).to_hsv()
+    }
+
+    fn from_hsla(hsla: HSLA) -> HSV {
+        HSVA::from_hsla(hsla).to_hsv()
+    }
+
+    fn saturate(self, amount: Ratio) -> Self {
+        self.to_hsva().saturate(amount).to_hsv()
+    }
+
+    fn desaturate(self, amount: Ratio) -> Self {
+        self.to_hsva().desaturate(amount).to_hsv()
+    }
+
+    fn lighten(self, amount: Ratio) -> Self {
+        self.to_hsva().lighten(amount).to_hsv()
+    }
+
+    fn darken(self, amount: Ratio) -> Self {
+        self.to_hsva().darken(amount).to_hsv()
+    }
+
+    fn fadein(self, amount: Ratio) -> Self::Alpha {
+        self.to_hsva().fadein(amount)
+    }
+
+    fn fadeout(self, amount: Ratio) -> Self::Alpha {
+        self.to_hsva().fadeout(amount)
+    }
+
+    fn fade(self, amount: Ratio) -> Self::Alpha {
+        self.to_hsva().fade(amount)
+    }
+
+    fn spin(self, amount: Angle) -> Self {
+        self.to_hsva().spin(amount).to_hsv()
+    }
+
+    fn mix<T: Color>(self, other: T, weight: Ratio) -> Self::Alpha {
+        self.to_hsva().mix(other, weight)
+    }
+
+    fn tint(self, weight: Ratio) -> Self {
+        self.to_hsva().tint(weight).to_hsv()
+    }
+
+    fn shade(self, weight: Ratio) -> Self {
+        self.to_hsva().shade(weight).to_hsv()
+    }
+
+    fn greyscale(self) -> Self {
+        self.to_hsva().greyscale().to_hsv()
+    }
+}
+
+#[derive(Debug, Copy, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+/// A struct to represent how much hue, saturation, and value should be added to create a color.
+/// Also handles alpha specifications.
+///
+/// A valid value for `h` must range between `0-360`.
+/// The saturation ranges between `0-100`, where `0` is a shade of grey, and `100` is a pure hue.
+/// The value ranges between `0-100`, where `0` is black, and `100` is the brightest shade of the hue.
+pub struct HSVA {
+    // hue
+    pub h: Angle,
+
+    // saturation
+    pub s: Ratio,
+
+    // value
+    pub v: Ratio,
+
+    // alpha
+    pub a: Ratio,
+}
+
+impl fmt::Display for HSVA {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        if f.alternate() {
+            write!(
+                f,
+                "hsva({}, {:#}, {:#}, {:.03})",
+                self.h.degrees(),
+                self.s,
+                self.v,
+                self.a.as_f32()
+            )
+        } else {
+            write!(
+                f,
+                "hsva({}, {}, {}, {:.02})",
+                self.h.degrees(),
+                self.s,
+                self.v,
+                self.a.as_f32()
+            )
+        }
+    }
+}
+
+impl Color for HSVA {
+    type Alpha = Self;
+
+    /// Converts `self` into the CSS string of its closest HSLA equivalent, as
+    /// there is no `hsva()` function in CSS.
+    fn to_css(&self) -> String {
+        self.to_hsla().to_css()
+    }
+
+    fn to_rgb(&self) -> RGB {
+        self.to_rgba().to_rgb()
+    }
+
+    fn to_rgba(&self) -> RGBA {
+        let HSVA { h, s, v, a } = *self;
+        let (r, g, b) = hsv_to_rgb(h.degrees() as f32, s.as_f32(), v.as_f32());
+
+        RGBA {
+            r: clamp_ratio(r),
+            g: clamp_ratio(g),
+            b: clamp_ratio(b),
+            a,
+        }
+    }
+
+    fn to_hsl(&self) -> HSL {
+        self.to_hsla().to_hsl()
+    }
+
+    fn to_hsla(&self) -> HSLA {
+        let HSVA { h, s, v, a } = *self;
+        let (s, l) = sv_to_sl(s.as_f32(), v.as_f32());
+
+        HSLA {
+            h,
+            s: clamp_ratio(s),
+            l: clamp_ratio(l),
+            a,
+        }
+    }
+
+    fn to_hsv(&self) -> HSV {
+        let HSVA { h, s, v, .. } = *self;
+
+        HSV { h, s, v }
+    }
+
+    fn to_hsva(&self) -> HSVA {
+        *self
+    }
+
+    fn from_rgba(rgba: RGBA) -> HSVA {
+        let RGBA { r, g, b, a } = rgba;
+        let (h, s, v) = rgb_to_hsv(r.as_f32(), g.as_f32(), b.as_f32());
+
+        HSVA {
+            h: deg(h.round() as i32),
+            s: clamp_ratio(s),
+            v: clamp_ratio(v),
+            a,
+        }
+    }
+
+    fn from_hsla(hsla: HSLA) -> HSVA {
+        let HSLA { h, s, l, a } = hsla;
+        let (s, v) = sl_to_sv(s.as_f32(), l.as_f32());
+
+        HSVA {
+            h,
+            s: clamp_ratio(s),
+            v: clamp_ratio(v),
+            a,
+        }
+    }
+
+    fn saturate(self, amount: Ratio) -> Self {
+        ops::apply_to_hsl(self, |hsla| hsla.saturate(amount))
+    }
+
+    fn desaturate(self, amount: Ratio) -> Self {
+        ops::apply_to_hsl(self, |hsla| hsla.desaturate(amount))
+    }
+
+    fn lighten(self, amount: Ratio) -> Self {
+        ops::apply_to_hsl(self, |hsla| hsla.lighten(amount))
+    }
+
+    fn darken(self, amount: Ratio) -> Self {
+        ops::apply_to_hsl(self, |hsla| hsla.darken(amount))
+    }
+
+    fn fadein(self, amount: Ratio) -> Self {
+        self.fade(Ratio::from_u8(
+            self.a.as_u8().saturating_add(amount.as_u8()),
+        ))
+    }
+
+    fn fadeout(self, amount: Ratio) -> Self {
+        self.fade(Ratio::from_u8(
+            self.a.as_u8().saturating_sub(amount.as_u8()),
+        ))
+    }
+
+    fn fade(self, amount: Ratio) -> Self::Alpha {
+        let HSVA { h, s, v, .. } = self;
+        HSVA { h, s, v, a: amount }
+    }
+
+    fn spin(self, amount: Angle) -> Self {
+        let HSVA { h, s, v, a } = self;
+
+        HSVA {
+            h: h + amount,
+            s,
+            v,
+            a,
+        }
+    }
+
+    fn mix<T: Color>(self, other: T, weight: Ratio) -> Self::Alpha {
+        HSVA::from_rgba(self.to_rgba().mix(other, weight))
+    }
+
+    fn tint(self, weight: Ratio) -> Self {
+        HSVA::from_rgba(self.to_rgba().tint(weight))
+    }
+
+    fn shade(self, weight: Ratio) -> Self {
+        HSVA::from_rgba(self.to_rgba().shade(weight))
+    }
+
+    fn greyscale(self) -> Self {
+        ops::apply_to_hsl(self, |hsla| hsla.greyscale())
+    }
+}
+
+// Converts RGB channels (`0.0-1.0`) into a hue (in degrees), saturation and value.
+pub(crate) fn rgb_to_hsv(r: f32, g: f32, b: f32) -> (f32, f32, f32) {
+    let max = r.max(g).max(b);
+    let min = r.min(g).min(b);
+    let chroma = max - min;
+
+    // The value is the largest of the channels, and the saturation is
+    // how far the smallest channel is from it.
+    let saturation = if max == 0.0 { 0.0 } else { chroma / max };
+
+    let hue = if chroma == 0.0 {
+        0.0
+    } else if max == r {
+        60.0 * (g - b) / chroma
+    } else if max == g {
+        120.0 + 60.0 * (b - r) / chroma
+    } else {
+        240.0 + 60.0 * (r - g) / chroma
+    };
+
+    (hue, saturation, max)
+}
+
+// Converts a hue (in degrees), saturation and value into RGB channels (`0.0-1.0`).
+pub(crate) fn hsv_to_rgb(h: f32, s: f32, v: f32) -> (f32, f32, f32) {
+    // The chroma is the distance between the largest and the smallest channel,
+    // and `x` is the middle channel, which rises and falls across each 60° sector.
+    let chroma = v * s;
+    let sector = h / 60.0;
+    let x = chroma * (1.0 - (sector % 2.0 - 1.0).abs());
+    let min = v - chroma;
+
+    let (r, g, b) = match sector as u8 {
+        0 => (chroma, x, 0.0),
+        1 => (x, chroma, 0.0),
+        2 => (0.0, chroma, x),
+        3 => (0.0, x, chroma),
+        4 => (x, 0.0, chroma),
+        _ => (chroma, 0.0, x),
+    };
+
+    (r + min, g + min, b + min)
+}
+
+// Converts the HSV saturation and value into the HSL saturation and lightness.
+pub(crate) fn sv_to_sl(s: f32, v: f32) -> (f32, f32) {
+    let l = v * (1.0 - s / 2.0);
+
+    let s = if l == 0.0 || l == 1.0 {
+        0.0
+    } else {
+        (v - l) / l.min(1.0 - l)
+    };
+
+    (s, l)
+}
+
+// Converts the HSL saturation and lightness into the HSV saturation and value.
+pub(crate) fn sl_to_sv(s: f32, l: f32) -> (f32, f32) {
+    let v = l + s * l.min(1.0 - l);
+
+    let s = if v == 0.0 { 0.0 } else { 2.0 * (1.0 - l / v) };
+
+    (s, v)
+}
+
+#[cfg(test)]
+mod tests {
+    use {deg, hsl, hsla, hsv, hsva, percent, rgb, rgba, Color, Ratio, HSLA, HSV, HSVA, RGBA};
+
+    #[test]
+    fn can_display_hsv_colors() {
+        assert_eq!(hsv(9, 72, 100).to_string(), "hsv(9, 72%, 100%)");
+        assert_eq!(hsv(9, 72, 100).to_css(), "hsl(9, 100%, 64%)");
+    }
+
+    #[test]
+    fn can_convert_between_hsv_and_hsl() {
+        assert_eq!(hsv(0, 0, 0).to_hsl(), hsl(0, 0, 0));
+        assert_eq!(hsv(0, 0, 100).to_hsl(), hsl(0, 0, 100));
+        assert_eq!(hsv(120, 100, 100).to_hsl(), hsl(120, 100, 50));
+        assert_eq!(hsv(240, 50, 100).to_hsl(), hsl(240, 100, 75));
+        assert_eq!(hsv(60, 100, 50).to_hsl(), hsl(60, 100, 25));
+
+        // Compared as strings, since 50% is not exactly representable as a `Ratio`.
+        assert_eq!(
+            HSV::from_hsla(hsl(120, 100, 50).to_hsla()).to_string(),
+            "hsv(120, 100%, 100%)"
+        );
+        assert_eq!(
+            HSV::from_hsla(hsl(240, 100, 75).to_hsla()).to_string(),
+            "hsv(240, 50%, 100%)"
+        );
+        assert_eq!(hsl(60, 100, 25).to_hsv().to_string(), "hsv(60, 100%, 50%)");
+    }
+
+    #[test]
+    fn can_convert_every_sector() {
+        assert_eq!(hsv(0, 100, 100).to_rgb(), rgb(255, 0, 0));
+        assert_eq!(hsv(60, 100, 100).to_rgb(), rgb(255, 255, 0));
+        assert_eq!(hsv(120, 100, 100).to_rgb(), rgb(0, 255, 0));
+        assert_eq!(hsv(180, 100, 100).to_rgb(), rgb(0, 255, 255));
+        assert_eq!(hsv(240, 100, 100).to_rgb(), rgb(0, 0, 255));
+        assert_eq!(hsv(300, 100, 100).to_rgb(), rgb(255, 0, 255));
+        assert_eq!(hsv(359, 100, 100).to_rgb(), rgb(255, 0, 4));
+    }
+
+    #[test]
+    fn can_transform_hsv_colors() {
+        let tomato = hsv(9, 72, 100);
+
+        assert_eq!(tomato.spin(deg(180)), hsv(189, 72, 100));
+        assert_eq!(tomato.greyscale(), hsv(9, 0, 64));
+        assert_eq!(tomato.lighten(percent(100)), hsv(9, 0, 100));
+        assert_eq!(tomato.darken(percent(100)), hsv(9, 0, 0));
+        assert_eq!(tomato.fade(percent(50)), hsva(9, 72, 100, 0.5));
+        assert_eq!(
+            tomato.to_hsl().lighten(percent(10)).to_hsv(),
+            tomato.lighten(percent(10))
+        );
+    }
+
+    #[test]
+    fn can_display_hsva_colors() {
+        assert_eq!(
+            hsva(9, 72, 100, 0.5).to_string(),
+            "hsva(9, 72%, 100%, 0.50)"
+        );
+        assert_eq!(hsva(9, 72, 100, 0.5).to_css(), "hsla(9, 100%, 64%, 0.50)");
+    }
+
+    #[test]
+    fn can_fade_hsva_colors() {
+        let tomato = hsva(9, 72, 100, 0.5);
+
+        assert_eq!(tomato.fade(percent(20)), hsva(9, 72, 100, 0.2));
+        assert_eq!(tomato.fadein(percent(20)), hsva(9, 72, 100, 0.7));
+        assert_eq!(tomato.fadeout(percent(20)), hsva(9, 72, 100, 0.3));
+        assert_eq!(tomato.fadein(percent(80)), hsva(9, 72, 100, 1.0));
+        assert_eq!(tomato.fadeout(percent(80)), hsva(9, 72, 100, 0.0));
+        assert_eq!(hsv(9, 72, 100).fadeout(percent(20)), hsva(9, 72, 100, 0.8));
+    }
+
+    #[test]
+    fn hsva_conversions_preserve_alpha() {
+        for alpha in 0..=255 {
+            let alpha = Ratio::from_u8(alpha);
+
+            let rgba = RGBA {
+                a: alpha,
+                ..rgba(255, 99, 71, 1.0)
+            };
+            let hsla = HSLA {
+                a: alpha,
+                ..hsla(9, 100, 64, 1.0)
+            };
+
+            assert_eq!(HSVA::from_rgba(rgba).a, alpha);
+            assert_eq!(HSVA::from_hsla(hsla).a, alpha);
+            assert_eq!(rgba.to_hsva().a, alpha);
+            assert_eq!(hsla.to_hsva().a, alpha);
+            assert_eq!(HSVA::from_rgba(rgba).to_rgba().a, alpha);
+            assert_eq!(HSVA::from_hsla(hsla).to_hsla().a, alpha);
+        }
+    }
+}
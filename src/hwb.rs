@@ -0,0 +1,287 @@
+use super::hsv::{hsv_to_rgb, rgb_to_hsv, sl_to_sv, sv_to_sl};
+use super::{clamp_ratio, deg, ops, percent, Angle, Color, Ratio, HSL, HSLA, RGB, RGBA};
+use std::fmt;
+
+/// Constructs a HWB Color from numerical values, similar to the
+/// [`hwb` function][css-hwb] in CSS.
+///
+/// The hue component is expressed in degrees. Values outside of
+/// the 0-359° range will be normalized accordingly. The whiteness
+/// and blackness components are expressed in percentages. Values
+/// outside of the 0-100% range will cause a panic.
+///
+/// # Example
+/// ```
+/// use css_colors::{Color, hwb};
+///
+/// let cinnabar = hwb(9, 20, 10);
+///
+/// assert_eq!(cinnabar.to_css(), "hwb(9 20% 10%)");
+/// ```
+///
+/// [css-hwb]: https://www.w3.org/TR/css-color-4/#the-hwb-notation
+pub fn hwb(h: i32, w: u8, b: u8) -> HWB {
+    HWB {
+        h: deg(h),
+        w: percent(w),
+        b: percent(b),
+    }
+}
+
+#[derive(Debug, Copy, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+/// A struct to represent a hue, and how much white and black should be mixed into it to create a color.
+/// The hue is a degree on the color wheel; 0 (or 360) is red, 120 is green, 240 is blue.
+/// A valid value for `h` must range between `0-360`.
+/// The whiteness and blackness range between `0-100`. When they add up to `100` or more,
+/// they are scaled down proportionally, and the color is a shade of grey.
+///
+/// For more, see the [CSS Color Spec](https://www.w3.org/TR/css-color-4/#the-hwb-notation).
+pub struct HWB {
+    // hue
+    pub h: Angle,
+
+    // whiteness
+    pub w: Ratio,
+
+    // blackness
+    pub b: Ratio,
+}
+
+impl HWB {
+    // Returns the whiteness and blackness as floats, scaled down so that they
+    // add up to at most `1.0`, as required by the spec.
+    fn normalized(self) -> (f32, f32) {
+        let w = self.w.as_f32();
+        let b = self.b.as_f32();
+
+        if w + b >= 1.0 {
+            (w / (w + b), b / (w + b))
+        } else {
+            (w, b)
+        }
+    }
+}
+
+impl fmt::Display for HWB {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        if f.alternate() {
+            write!(f, "hwb({} {:#} {:#})", self.h.degrees(), self.w, self.b)
+        } else {
+            write!(f, "hwb({} {} {})", self.h.degrees(), self.w, self.b)
+        }
+    }
+}
+
+impl Color for HWB {
+    type Alpha = RGBA;
+
+    fn to_css(&self) -> String {
+        self.to_string()
+    }
+
+    fn to_rgb(&self) -> RGB {
+        self.to_rgba().to_rgb()
+    }
+
+    fn to_rgba(&self) -> RGBA {
+        let (w, b) = self.normalized();
+
+        // The pure hue, diluted with white and black.
+        let (r, g, bl) = hsv_to_rgb(self.h.degrees() as f32, 1.0, 1.0);
+        let channel = |value: f32| clamp_ratio(value * (1.0 - w - b) + w);
+
+        RGBA {
+            r: channel(r),
+            g: channel(g),
+            b: channel(bl),
+            a: percent(100),
+        }
+    }
+
+    fn to_hsl(&self) -> HSL {
+        self.to_hsla().to_hsl()
+    }
+
+    fn to_hsla(&self) -> HSLA {
+        let (w, b) = self.normalized();
+
+        // HWB is a different parametrization of HSV.
+        let v = 1.0 - b;
+        let (s, l) = sv_to_sl(if v == 0.0 { 0.0 } else { 1.0 - w / v }, v);
+
+        HSLA {
+            h: self.h,
+            s: clamp_ratio(s),
+            l: clamp_ratio(l),
+            a: percent(100),
+        }
+    }
+
+    fn to_hwb(&self) -> HWB {
+        *self
+    }
+
+    fn from_rgba(rgba: RGBA) -> HWB {
+        let RGBA { r, g, b, .. } = rgba;
+        let (h, ..) = rgb_to_hsv(r.as_f32(), g.as_f32(), b.as_f32());
+
+        HWB {
+            h: deg(h.round() as i32),
+            w: r.min(g).min(b),
+            b: percent(100) - r.max(g).max(b),
+        }
+    }
+
+    fn from_hsla(hsla: HSLA) -> HWB {
+        let (s, v) = sl_to_sv(hsla.s.as_f32(), hsla.l.as_f32());
+
+        HWB {
+            h: hsla.h,
+            w: clamp_ratio((1.0 - s) * v),
+            b: clamp_ratio(1.0 - v),
+        }
+    }
+
+    fn saturate(self, amount: Ratio) -> Self {
+        ops::apply_to_hsl(self, |hsla| hsla.saturate(amount))
+    }
+
+    fn desaturate(self, amount: Ratio) -> Self {
+        ops::apply_to_hsl(self, |hsla| hsla.desaturate(amount))
+    }
+
+    fn lighten(self, amount: Ratio) -> Self {
+        ops::apply_to_hsl(self, |hsla| hsla.lighten(amount))
+    }
+
+    fn darken(self, amount: Ratio) -> Self {
+        ops::apply_to_hsl(self, |hsla| hsla.darken(amount))
+    }
+
+    fn fadein(self, amount: Ratio) -> RGBA {
+        self.to_rgba().fadein(amount)
+    }
+
+    fn fadeout(self, amount: Ratio) -> RGBA {
+        self.to_rgba().fadeout(amount)
+    }
+
+    fn fade(self, amount: Ratio) -> RGBA {
+        self.to_rgba().fade(amount)
+    }
+
+    fn spin(self, amount: Angle) -> Self {
+        let HWB { h, w, b } = self;
+
+        HWB {
+            h: h + amount,
+            w,
+            b,
+        }
+    }
+
+    fn mix<T: Color>(self, other: T, weight: Ratio) -> RGBA {
+        self.to_rgba().mix(other, weight)
+    }
+
+    fn tint(self, weight: Ratio) -> Self {
+        HWB::from_rgba(self.to_rgba().tint(weight))
+    }
+
+    fn shade(self, weight: Ratio) -> Self {
+        HWB::from_rgba(self.to_rgba().shade(weight))
+    }
+
+    fn greyscale(self) -> Self {
+        ops::apply_to_hsl(self, |hsla| hsla.greyscale())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use {deg, hsl, hwb, percent, rgb, Color, HWB, RGB};
+
+    fn assert_approximately_eq(lhs: RGB, rhs: RGB) {
+        let close = |l: u8, r: u8| (l as i16 - r as i16).abs() <= 1;
+
+        assert!(
+            close(lhs.r.as_u8(), rhs.r.as_u8())
+                && close(lhs.g.as_u8(), rhs.g.as_u8())
+                && close(lhs.b.as_u8(), rhs.b.as_u8()),
+            "lhs: {}, rhs: {}",
+            lhs,
+            rhs
+        );
+    }
+
+    #[test]
+    fn can_serialize_hwb_colors() {
+        assert_eq!(hwb(9, 20, 10).to_css(), "hwb(9 20% 10%)");
+        assert_eq!(hwb(-30, 0, 100).to_css(), "hwb(330 0% 100%)");
+        assert_eq!(hwb(360, 60, 60).to_string(), "hwb(0 60% 60%)");
+    }
+
+    #[test]
+    fn can_convert_hwb_to_rgb() {
+        // As computed by browsers, e.g. `getComputedStyle` in Firefox and Chrome.
+        let table = [
+            (hwb(0, 0, 0), rgb(255, 0, 0)),
+            (hwb(0, 0, 100), rgb(0, 0, 0)),
+            (hwb(0, 100, 0), rgb(255, 255, 255)),
+            (hwb(9, 20, 10), rgb(230, 78, 51)),
+            (hwb(120, 0, 0), rgb(0, 255, 0)),
+            (hwb(240, 50, 0), rgb(128, 128, 255)),
+            (hwb(300, 0, 50), rgb(128, 0, 128)),
+            (hwb(60, 25, 25), rgb(191, 191, 64)),
+            (hwb(210, 40, 20), rgb(102, 153, 204)),
+            (hwb(330, 10, 40), rgb(153, 26, 89)),
+            (hwb(90, 30, 5), rgb(159, 242, 77)),
+            (hwb(180, 0, 75), rgb(0, 64, 64)),
+        ];
+
+        for &(hwb, rgb) in table.iter() {
+            assert_approximately_eq(hwb.to_rgb(), rgb);
+            assert_approximately_eq(HWB::from_rgba(rgb.to_rgba()).to_rgb(), rgb);
+        }
+    }
+
+    #[test]
+    fn normalizes_whiteness_and_blackness() {
+        assert_eq!(hwb(0, 50, 50).to_rgb(), rgb(128, 128, 128));
+        assert_eq!(hwb(120, 60, 60).to_rgb(), rgb(128, 128, 128));
+        assert_eq!(hwb(45, 70, 50).to_rgb(), rgb(149, 149, 149));
+        assert_eq!(hwb(45, 100, 100).to_hsl(), hsl(45, 0, 50));
+    }
+
+    #[test]
+    fn can_convert_rgb_to_hwb() {
+        assert_eq!(rgb(255, 99, 71).to_hwb().to_css(), "hwb(9 28% 0%)");
+        assert_eq!(rgb(100, 149, 237).to_hwb().to_css(), "hwb(219 39% 7%)");
+        assert_eq!(rgb(34, 139, 34).to_hwb().to_css(), "hwb(120 13% 45%)");
+        assert_eq!(rgb(128, 128, 128).to_hwb().to_css(), "hwb(0 50% 50%)");
+    }
+
+    #[test]
+    fn can_convert_between_hwb_and_hsl() {
+        assert_eq!(hwb(120, 0, 0).to_hsl(), hsl(120, 100, 50));
+        assert_eq!(hwb(0, 0, 100).to_hsl(), hsl(0, 0, 0));
+        assert_eq!(hwb(0, 100, 0).to_hsl(), hsl(0, 0, 100));
+        assert_eq!(hsl(9, 100, 64).to_hwb().to_css(), "hwb(9 28% 0%)");
+        assert_eq!(hsl(0, 0, 50).to_hwb().to_css(), "hwb(0 50% 50%)");
+    }
+
+    #[test]
+    fn can_transform_hwb_colors() {
+        let cinnabar = hwb(9, 20, 10);
+
+        assert_eq!(cinnabar.spin(deg(111)), hwb(120, 20, 10));
+        assert_eq!(cinnabar.darken(percent(100)).to_rgb(), rgb(0, 0, 0));
+        assert_eq!(cinnabar.lighten(percent(100)).to_rgb(), rgb(255, 255, 255));
+        assert_eq!(cinnabar.greyscale().to_css(), "hwb(9 55% 45%)");
+        assert_eq!(
+            cinnabar.fade(percent(50)),
+            cinnabar.to_rgba().fade(percent(50))
+        );
+    }
+}
@@ -0,0 +1,181 @@
+use super::oklch::NEUTRAL_CHROMA;
+use super::{ops, Color, HuePath, OkLab, OkLch, RGBA};
+
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Hash, Default)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "kebab-case"))]
+/// An enum of the color spaces in which colors can be interpolated, like the
+/// `in <colorspace>` of CSS gradients (see `Color::lerp_in` and `Gradient`).
+///
+/// `Srgb` interpolates the gamma-encoded channels, which is the default of
+/// CSS. `Oklab` interpolates in a perceptually uniform space instead, so that
+/// the lightness of a ramp changes evenly. The cylindrical spaces interpolate
+/// their hue along a `HuePath`, and keep the hue of the other color when one
+/// of them is neutral.
+///
+/// The alpha channel is interpolated linearly in every space.
+pub enum InterpolationSpace {
+    // the gamma-encoded sRGB channels, like `Color::lerp`
+    #[default]
+    Srgb,
+
+    // hue, saturation and lightness, like `Color::lerp_hsl`
+    Hsl(HuePath),
+
+    // lightness and the two opponent axes of Oklab
+    Oklab,
+
+    // lightness, chroma and hue of Oklab, clamped back into sRGB
+    Oklch(HuePath),
+}
+
+// Interpolates from `from` to `to` in `space`, with `t` from `0.0` to `1.0`
+// (clamped).
+pub(crate) fn interpolate(from: RGBA, to: RGBA, t: f32, space: InterpolationSpace) -> RGBA {
+    let t = t.clamp(0.0, 1.0);
+
+    let rgb = match space {
+        InterpolationSpace::Srgb => return ops::lerp_rgba(from, to, t),
+        InterpolationSpace::Hsl(path) => return from.lerp_hsl(to, t, path).to_rgba(),
+        InterpolationSpace::Oklab => OkLab::from_rgb(from.to_rgb())
+            .lerp(OkLab::from_rgb(to.to_rgb()), t)
+            .to_rgb(),
+        InterpolationSpace::Oklch(path) => {
+            let (lhs, rhs) = (OkLch::from_rgb(from.to_rgb()), OkLch::from_rgb(to.to_rgb()));
+
+            let h = match (lhs.c < NEUTRAL_CHROMA, rhs.c < NEUTRAL_CHROMA) {
+                (true, false) => rhs.h,
+                (_, true) => lhs.h,
+                (false, false) => path.interpolate(lhs.h, rhs.h, t),
+            };
+            OkLch {
+                l: lhs.l + (rhs.l - lhs.l) * t,
+                c: lhs.c + (rhs.c - lhs.c) * t,
+                h,
+            }
+            .to_rgb()
+        }
+    };
+
+    RGBA {
+        r: rgb.r,
+        g: rgb.g,
+        b: rgb.b,
+        a: ops::quantize(from.a.as_f32() + (to.a.as_f32() - from.a.as_f32()) * t),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use {hsla, rgb, rgba, Color, Gradient, HuePath, InterpolationSpace, OkLab, OkLch};
+
+    // The Oklab lightness of the steps of a ramp from black to white.
+    fn lightness_of_ramp(space: InterpolationSpace) -> Vec<f32> {
+        rgb(0, 0, 0)
+            .steps_in(rgb(255, 255, 255), 11, space)
+            .iter()
+            .map(|color| OkLab::from_rgb(color.to_rgb()).l)
+            .collect()
+    }
+
+    #[test]
+    fn interpolates_evenly_in_oklab() {
+        let oklab = lightness_of_ramp(InterpolationSpace::Oklab);
+        let srgb = lightness_of_ramp(InterpolationSpace::Srgb);
+
+        // Each step brightens by a tenth, up to the rounding of the channels.
+        for pair in oklab.windows(2) {
+            assert!((pair[1] - pair[0] - 0.1).abs() < 0.005, "{:?}", oklab);
+        }
+
+        // In sRGB, the first steps brighten twice as much as the last ones.
+        let first = srgb[1] - srgb[0];
+        let last = srgb[10] - srgb[9];
+
+        assert!(first > 2.0 * last, "{:?}", srgb);
+    }
+
+    #[test]
+    fn interpolates_in_srgb_by_default() {
+        let salmon = rgba(250, 128, 114, 0.2);
+        let navy = rgb(0, 0, 128);
+
+        assert_eq!(InterpolationSpace::default(), InterpolationSpace::Srgb);
+
+        for step in 0..=10 {
+            let t = step as f32 / 10.0;
+
+            assert_eq!(
+                salmon.lerp_in(navy, t, InterpolationSpace::Srgb),
+                salmon.lerp(navy, t)
+            );
+            assert_eq!(
+                salmon.lerp_in(navy, t, InterpolationSpace::Hsl(HuePath::Longer)),
+                salmon.lerp_hsl(navy, t, HuePath::Longer).to_rgba()
+            );
+        }
+
+        assert_eq!(
+            salmon.steps_in(navy, 5, InterpolationSpace::default()),
+            salmon.steps(navy, 5)
+        );
+    }
+
+    #[test]
+    fn can_interpolate_in_oklch() {
+        let red = rgb(255, 0, 0);
+        let blue = rgb(0, 0, 255);
+        let hue = |color: ::RGBA| OkLch::from_rgb(color.to_rgb()).h;
+
+        // Red (29°) and blue (264°) are closer through magenta.
+        let shorter = red.lerp_in(blue, 0.5, InterpolationSpace::Oklch(HuePath::Shorter));
+        let longer = red.lerp_in(blue, 0.5, InterpolationSpace::Oklch(HuePath::Longer));
+
+        assert!(
+            hue(shorter) > 300.0 || hue(shorter) < 29.0,
+            "{}",
+            hue(shorter)
+        );
+        assert!(hue(longer) > 90.0 && hue(longer) < 200.0, "{}", hue(longer));
+
+        // The endpoints are unchanged.
+        for &path in [HuePath::Shorter, HuePath::Longer].iter() {
+            let space = InterpolationSpace::Oklch(path);
+
+            assert_eq!(red.lerp_in(blue, 0.0, space), red.to_rgba());
+            assert_eq!(red.lerp_in(blue, 1.0, space), blue.to_rgba());
+        }
+
+        // A neutral color keeps the hue of the other, rather than sweeping from 0°.
+        let white = rgb(255, 255, 255);
+
+        for step in 1..10 {
+            let t = step as f32 / 10.0;
+            let tint = white.lerp_in(blue, t, InterpolationSpace::Oklch(HuePath::Longer));
+
+            // Up to the clamping of the tints into sRGB.
+            assert!((hue(tint) - hue(blue.to_rgba())).abs() < 5.0, "{:?}", tint);
+        }
+    }
+
+    #[test]
+    fn interpolates_alpha_linearly_in_every_space() {
+        let from = hsla(0, 100, 50, 0.0);
+        let to = rgba(0, 0, 255, 1.0);
+
+        for &space in [
+            InterpolationSpace::Srgb,
+            InterpolationSpace::Hsl(HuePath::Shorter),
+            InterpolationSpace::Oklab,
+            InterpolationSpace::Oklch(HuePath::Shorter),
+        ]
+        .iter()
+        {
+            assert_eq!(from.lerp_in(to, 0.5, space).a, rgba(0, 0, 0, 0.5).a);
+
+            let gradient = Gradient::new(vec![(0.0, from.to_rgba()), (1.0, to)]).with_space(space);
+
+            assert_eq!(gradient.sample(0.3), from.lerp_in(to, 0.3, space));
+        }
+    }
+}
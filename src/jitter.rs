@@ -0,0 +1,171 @@
+use super::{deg, Angle, Ratio, HSLA};
+use rand::Rng;
+
+#[derive(Debug, Copy, Clone, PartialEq)]
+/// A struct that bounds the random variations applied by `Color::jitter`.
+///
+/// Each channel of the color is offset by a random amount within `±` its
+/// range, and clamped to its valid range. A range of zero leaves the channel
+/// untouched: by default, the alpha channel and the hue are locked.
+///
+/// # Example
+/// ```
+/// use css_colors::{deg, percent, JitterSpec};
+///
+/// let spec = JitterSpec {
+///     hue: deg(3),
+///     ..JitterSpec::new(percent(10), percent(5))
+/// };
+///
+/// assert_eq!(spec.saturation, percent(10));
+/// assert_eq!(spec.alpha, percent(0));
+/// ```
+pub struct JitterSpec {
+    // how far the saturation may drift, in either direction
+    pub saturation: Ratio,
+
+    // how far the lightness may drift, in either direction
+    pub lightness: Ratio,
+
+    // how far the alpha channel may drift, in either direction
+    pub alpha: Ratio,
+
+    // how far the hue may wobble, in either direction
+    pub hue: Angle,
+}
+
+impl JitterSpec {
+    /// Creates a spec that jitters the saturation and the lightness, keeping the
+    /// hue and the alpha channel locked.
+    pub fn new(saturation: Ratio, lightness: Ratio) -> JitterSpec {
+        JitterSpec {
+            saturation,
+            lightness,
+            alpha: Ratio::from_u8(0),
+            hue: deg(0),
+        }
+    }
+}
+
+// Jitters each channel of the color in turn. The random values are drawn in
+// a fixed order (saturation, lightness, alpha, hue), skipping locked channels,
+// so that a seeded generator always produces the same sequence of colors.
+pub(crate) fn jitter<R: Rng>(color: HSLA, rng: &mut R, spec: JitterSpec) -> HSLA {
+    let HSLA { h, s, l, a } = color;
+
+    let s = offset(rng, s, spec.saturation);
+    let l = offset(rng, l, spec.lightness);
+    let a = offset(rng, a, spec.alpha);
+
+    let h = match spec.hue.degrees() as i32 {
+        0 => h,
+        wobble => deg(h.degrees() as i32 + rng.gen_range(-wobble..=wobble)),
+    };
+
+    HSLA { h, s, l, a }
+}
+
+// Offsets `value` by a random amount within `±range`, clamped to `0-255`. The
+// offset is drawn as an integer, which is sampled identically on every platform.
+fn offset<R: Rng>(rng: &mut R, value: Ratio, range: Ratio) -> Ratio {
+    if range.as_u8() == 0 {
+        return value;
+    }
+
+    let range = range.as_u8() as i16;
+    let offset = rng.gen_range(-range..=range);
+
+    Ratio::from_u8((value.as_u8() as i16 + offset).clamp(0, 255) as u8)
+}
+
+#[cfg(test)]
+mod tests {
+    use rand::SeedableRng;
+    use rand_chacha::ChaCha8Rng;
+    use {deg, hsl, hsla, percent, rgb, Color, JitterSpec, Ratio, HSL, HSLA};
+
+    #[test]
+    fn can_jitter_reproducibly() {
+        let spec = JitterSpec::new(percent(10), percent(10));
+        let base = hsl(200, 50, 50);
+
+        let mut rng = ChaCha8Rng::seed_from_u64(42);
+        let colors: Vec<_> = (0..4).map(|_| base.jitter(&mut rng, spec)).collect();
+
+        let mut rng = ChaCha8Rng::seed_from_u64(42);
+        let again: Vec<_> = (0..4).map(|_| base.jitter(&mut rng, spec)).collect();
+
+        assert_eq!(colors, again);
+
+        // The channels are jittered in steps of 1/255, so they aren't whole percentages.
+        let expected: Vec<_> = [(113, 138), (109, 152), (142, 124), (120, 135)]
+            .iter()
+            .map(|&(s, l)| HSL {
+                h: deg(200),
+                s: Ratio::from_u8(s),
+                l: Ratio::from_u8(l),
+            })
+            .collect();
+
+        assert_eq!(colors, expected);
+    }
+
+    #[test]
+    fn preserves_the_type_of_the_color() {
+        let spec = JitterSpec::new(percent(10), percent(10));
+        let mut rng = ChaCha8Rng::seed_from_u64(7);
+
+        let jittered = rgb(255, 99, 71).jitter(&mut rng, spec);
+        assert_eq!(jittered.to_hsl().h, deg(9));
+
+        let jittered = hsla(120, 50, 50, 0.5).jitter(&mut rng, spec);
+        assert_eq!((jittered.h, jittered.a), (deg(120), percent(50)));
+    }
+
+    #[test]
+    fn stays_within_bounds() {
+        let spec = JitterSpec {
+            alpha: percent(20),
+            hue: deg(5),
+            ..JitterSpec::new(percent(20), percent(20))
+        };
+        let range = percent(20).as_u8() as i16;
+        let mut rng = ChaCha8Rng::seed_from_u64(1);
+
+        // Bases at both edges of every range, and in the middle.
+        for &(h, s, l, a) in [(0, 0, 0, 0.0), (358, 100, 100, 1.0), (180, 50, 50, 0.5)].iter() {
+            let base = hsla(h, s, l, a);
+
+            for _ in 0..500 {
+                let HSLA { h, s, l, a } = base.jitter(&mut rng, spec);
+
+                for &(value, base) in [(s, base.s), (l, base.l), (a, base.a)].iter() {
+                    assert!((value.as_u8() as i16 - base.as_u8() as i16).abs() <= range);
+                }
+
+                let wobble = (h.degrees() as i32 - base.h.degrees() as i32).rem_euclid(360);
+                assert!(wobble <= 5 || wobble >= 355, "{:?}", h);
+            }
+        }
+    }
+
+    #[test]
+    fn locked_channels_are_untouched() {
+        let mut rng = ChaCha8Rng::seed_from_u64(3);
+        let base = hsla(300, 40, 60, 0.25);
+
+        assert_eq!(
+            base.jitter(&mut rng, JitterSpec::new(percent(0), percent(0))),
+            base
+        );
+
+        for _ in 0..100 {
+            let jittered = base.jitter(&mut rng, JitterSpec::new(percent(0), percent(30)));
+
+            assert_eq!(
+                (jittered.h, jittered.s, jittered.a),
+                (base.h, base.s, base.a)
+            );
+        }
+    }
+}
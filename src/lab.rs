@@ -0,0 +1,433 @@
+use super::{GamutMap, WhitePoint, RGB, XYZ};
+
+/// Constructs a CIELAB Color from numerical values.
+///
+/// The lightness ranges between `0.0-100.0`. The `a` (green-red) and `b`
+/// (blue-yellow) axes are unbounded, but colors within the sRGB gamut fall
+/// roughly between `-128.0-128.0`.
+///
+/// # Example
+/// ```
+/// use css_colors::{lab, rgb};
+///
+/// let red = lab(53.24, 80.09, 67.2);
+///
+/// assert_eq!(red.to_rgb(), rgb(255, 0, 0));
+/// ```
+pub fn lab(l: f32, a: f32, b: f32) -> Lab {
+    Lab { l, a, b }
+}
+
+#[derive(Debug, Copy, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+/// A struct to represent a color in the CIELAB color space, which is designed to be
+/// perceptually uniform: the same distance between two colors is perceived as
+/// (roughly) the same difference, wherever the colors are in the space.
+///
+/// Unlike the other color models of this crate, the channels are floats, and
+/// CIELAB colors are not restricted to the colors that can be displayed. Colors
+/// are converted from sRGB through linear light and CIE XYZ, relative to the D65
+/// white point (see `Lab::WHITE_POINT`). Note that CSS' `lab()` function and ICC
+/// profiles use the D50 white point instead: `from_xyz_with_white_point` and
+/// `to_xyz_with_white_point` convert colors relative to another white point,
+/// with chromatic adaptation.
+///
+/// For more, see [Bruce Lindbloom's reference](http://www.brucelindbloom.com/index.html?Eqn_XYZ_to_Lab.html).
+pub struct Lab {
+    // lightness
+    pub l: f32,
+
+    // green (negative) to red (positive)
+    pub a: f32,
+
+    // blue (negative) to yellow (positive)
+    pub b: f32,
+}
+
+// The thresholds of the CIELAB transfer function, as exact fractions.
+const EPSILON: f32 = 216.0 / 24389.0;
+const KAPPA: f32 = 24389.0 / 27.0;
+
+impl Lab {
+    /// The white point that `from_rgb`, `from_xyz`, `to_rgb` and `to_xyz` are
+    /// relative to.
+    pub const WHITE_POINT: WhitePoint = WhitePoint::D65;
+
+    /// Converts an RGB color into CIELAB.
+    ///
+    /// # Example
+    /// ```
+    /// use css_colors::{rgb, Lab};
+    ///
+    /// let white = Lab::from_rgb(rgb(255, 255, 255));
+    ///
+    /// assert!((white.l - 100.0).abs() < 0.01);
+    /// ```
+    pub fn from_rgb(rgb: RGB) -> Lab {
+        Lab::from_xyz(XYZ::from_rgb(rgb))
+    }
+
+    /// Converts a CIE XYZ color into CIELAB, relative to the D65 white point.
+    ///
+    /// # Example
+    /// ```
+    /// use css_colors::{Lab, XYZ};
+    ///
+    /// assert_eq!(Lab::from_xyz(XYZ::D65).l, 100.0);
+    /// ```
+    pub fn from_xyz(xyz: XYZ) -> Lab {
+        Lab::from_xyz_with_white_point(xyz, Lab::WHITE_POINT)
+    }
+
+    /// Converts a CIE XYZ color (relative to the D65 white point, like the rest
+    /// of this crate) into CIELAB relative to the given white point, adapting
+    /// the color with the Bradford transform (see `XYZ::adapt`).
+    ///
+    /// # Example
+    /// ```
+    /// use css_colors::{rgb, Color, Lab, WhitePoint};
+    ///
+    /// let grey = Lab::from_xyz_with_white_point(rgb(128, 128, 128).to_xyz(), WhitePoint::D50);
+    ///
+    /// assert!((grey.l - 53.59).abs() < 0.01);
+    /// assert!(grey.a.abs() < 0.01 && grey.b.abs() < 0.01);
+    /// ```
+    pub fn from_xyz_with_white_point(xyz: XYZ, white_point: WhitePoint) -> Lab {
+        xyz_to_lab(
+            xyz.adapt(WhitePoint::D65, white_point),
+            white_point.to_xyz(),
+        )
+    }
+
+    /// Converts `self` into RGB. Colors outside of the sRGB gamut are clamped
+    /// channel by channel.
+    ///
+    /// # Example
+    /// ```
+    /// use css_colors::{lab, rgb};
+    ///
+    /// assert_eq!(lab(53.24, 80.09, 67.2).to_rgb(), rgb(255, 0, 0));
+    /// assert_eq!(lab(50.0, 127.0, 0.0).to_rgb(), rgb(255, 0, 125));
+    /// ```
+    pub fn to_rgb(self) -> RGB {
+        self.to_xyz().to_rgb()
+    }
+
+    /// Converts `self` into CIE XYZ, relative to the D65 white point.
+    pub fn to_xyz(self) -> XYZ {
+        self.to_xyz_with_white_point(Lab::WHITE_POINT)
+    }
+
+    /// Converts `self`, relative to the given white point, into CIE XYZ relative
+    /// to the D65 white point (see `from_xyz_with_white_point`).
+    ///
+    /// # Example
+    /// ```
+    /// use css_colors::{lab, rgb, WhitePoint};
+    ///
+    /// let print_red = lab(54.29, 80.8, 69.89).to_xyz_with_white_point(WhitePoint::D50);
+    ///
+    /// assert_eq!(print_red.to_rgb(), rgb(255, 0, 0));
+    /// ```
+    pub fn to_xyz_with_white_point(self, white_point: WhitePoint) -> XYZ {
+        lab_to_xyz(self, white_point.to_xyz()).adapt(white_point, WhitePoint::D65)
+    }
+
+    /// Returns `true` if `self` can be represented in sRGB without gamut mapping.
+    ///
+    /// # Example
+    /// ```
+    /// use css_colors::lab;
+    ///
+    /// assert!(lab(53.24, 80.09, 67.2).in_gamut());
+    /// assert!(!lab(53.24, 120.0, 67.2).in_gamut());
+    /// ```
+    pub fn in_gamut(&self) -> bool {
+        self.to_xyz().in_gamut()
+    }
+
+    /// Converts `self` into RGB, bringing colors outside of the sRGB gamut into
+    /// it with the given strategy.
+    ///
+    /// # Example
+    /// ```
+    /// use css_colors::{lab, rgb, GamutMap};
+    ///
+    /// let vivid = lab(53.24, 120.0, 67.2);
+    ///
+    /// assert_eq!(vivid.map_to_gamut(GamutMap::Clip), rgb(255, 0, 22));
+    /// assert_eq!(vivid.map_to_gamut(GamutMap::ChromaReduce), rgb(255, 38, 78));
+    /// ```
+    pub fn map_to_gamut(self, method: GamutMap) -> RGB {
+        self.to_xyz().map_to_gamut(method)
+    }
+
+    /// Computes the CIEDE2000 color difference between `self` and `other`. A
+    /// difference of about `1.0` is the smallest difference most people can notice.
+    ///
+    /// For more, see [Sharma et al.](http://www2.ece.rochester.edu/~gsharma/ciede2000/ciede2000noteCRNA.pdf)
+    ///
+    /// # Example
+    /// ```
+    /// use css_colors::lab;
+    ///
+    /// let delta_e = lab(50.0, 2.6772, -79.7751).delta_e_2000(lab(50.0, 0.0, -82.7485));
+    ///
+    /// assert!((delta_e - 2.0425).abs() < 0.001);
+    /// ```
+    pub fn delta_e_2000(self, other: Lab) -> f32 {
+        let pow_25_7 = 25f32.powi(7);
+
+        // Rescale the `a` axis, to account for the neutral colors' blue tint.
+        let c_mean = (self.a.hypot(self.b) + other.a.hypot(other.b)) / 2.0;
+        let g = 0.5 * (1.0 - (c_mean.powi(7) / (c_mean.powi(7) + pow_25_7)).sqrt());
+
+        let a_1 = (1.0 + g) * self.a;
+        let a_2 = (1.0 + g) * other.a;
+
+        let c_1 = a_1.hypot(self.b);
+        let c_2 = a_2.hypot(other.b);
+
+        let hue = |b: f32, a: f32| {
+            if a == 0.0 && b == 0.0 {
+                0.0
+            } else {
+                b.atan2(a).to_degrees().rem_euclid(360.0)
+            }
+        };
+
+        let h_1 = hue(self.b, a_1);
+        let h_2 = hue(other.b, a_2);
+
+        // The differences of lightness, chroma, and hue.
+        let delta_l = other.l - self.l;
+        let delta_c = c_2 - c_1;
+        let delta_h = if c_1 * c_2 == 0.0 {
+            0.0
+        } else if h_2 - h_1 > 180.0 {
+            h_2 - h_1 - 360.0
+        } else if h_2 - h_1 < -180.0 {
+            h_2 - h_1 + 360.0
+        } else {
+            h_2 - h_1
+        };
+        let delta_h = 2.0 * (c_1 * c_2).sqrt() * (delta_h.to_radians() / 2.0).sin();
+
+        // The means of lightness, chroma, and hue.
+        let l_mean = (self.l + other.l) / 2.0;
+        let c_mean = (c_1 + c_2) / 2.0;
+        let h_mean = if c_1 * c_2 == 0.0 {
+            h_1 + h_2
+        } else if (h_1 - h_2).abs() <= 180.0 {
+            (h_1 + h_2) / 2.0
+        } else if h_1 + h_2 < 360.0 {
+            (h_1 + h_2 + 360.0) / 2.0
+        } else {
+            (h_1 + h_2 - 360.0) / 2.0
+        };
+
+        let t = 1.0 - 0.17 * (h_mean - 30.0).to_radians().cos()
+            + 0.24 * (2.0 * h_mean).to_radians().cos()
+            + 0.32 * (3.0 * h_mean + 6.0).to_radians().cos()
+            - 0.20 * (4.0 * h_mean - 63.0).to_radians().cos();
+
+        // The weighting functions, and the rotation of the blue region.
+        let s_l = 1.0 + 0.015 * (l_mean - 50.0).powi(2) / (20.0 + (l_mean - 50.0).powi(2)).sqrt();
+        let s_c = 1.0 + 0.045 * c_mean;
+        let s_h = 1.0 + 0.015 * c_mean * t;
+
+        let delta_theta = 30.0 * (-((h_mean - 275.0) / 25.0).powi(2)).exp();
+        let r_c = 2.0 * (c_mean.powi(7) / (c_mean.powi(7) + pow_25_7)).sqrt();
+        let r_t = -(2.0 * delta_theta).to_radians().sin() * r_c;
+
+        let l = delta_l / s_l;
+        let c = delta_c / s_c;
+        let h = delta_h / s_h;
+
+        (l * l + c * c + h * h + r_t * c * h).max(0.0).sqrt()
+    }
+}
+
+// Converts CIE XYZ into CIELAB, relative to the given reference white.
+pub(crate) fn xyz_to_lab(xyz: XYZ, white: XYZ) -> Lab {
+    let f = |t: f32| {
+        if t > EPSILON {
+            t.cbrt()
+        } else {
+            (KAPPA * t + 16.0) / 116.0
+        }
+    };
+
+    let fx = f(xyz.x / white.x);
+    let fy = f(xyz.y / white.y);
+    let fz = f(xyz.z / white.z);
+
+    Lab {
+        l: 116.0 * fy - 16.0,
+        a: 500.0 * (fx - fy),
+        b: 200.0 * (fy - fz),
+    }
+}
+
+// Converts CIELAB into CIE XYZ, relative to the given reference white.
+pub(crate) fn lab_to_xyz(lab: Lab, white: XYZ) -> XYZ {
+    let fy = (lab.l + 16.0) / 116.0;
+    let fx = fy + lab.a / 500.0;
+    let fz = fy - lab.b / 200.0;
+
+    let f_inverse = |t: f32| {
+        if t.powi(3) > EPSILON {
+            t.powi(3)
+        } else {
+            (116.0 * t - 16.0) / KAPPA
+        }
+    };
+
+    let y = if lab.l > KAPPA * EPSILON {
+        fy.powi(3)
+    } else {
+        lab.l / KAPPA
+    };
+
+    XYZ {
+        x: f_inverse(fx) * white.x,
+        y: y * white.y,
+        z: f_inverse(fz) * white.z,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use {lab, rgb, Color, Lab, WhitePoint, RGB, XYZ};
+
+    fn assert_lab_eq(lhs: Lab, rhs: Lab) {
+        assert!(
+            (lhs.l - rhs.l).abs() < 0.01
+                && (lhs.a - rhs.a).abs() < 0.01
+                && (lhs.b - rhs.b).abs() < 0.01,
+            "lhs: {:?}, rhs: {:?}",
+            lhs,
+            rhs
+        );
+    }
+
+    #[test]
+    fn can_convert_rgb_to_lab() {
+        // Reference values from http://www.brucelindbloom.com (sRGB, D65).
+        assert_lab_eq(rgb(255, 0, 0).to_lab(), lab(53.2408, 80.0925, 67.2032));
+        assert_lab_eq(rgb(0, 255, 0).to_lab(), lab(87.7347, -86.1827, 83.1793));
+        assert_lab_eq(rgb(0, 0, 255).to_lab(), lab(32.2970, 79.1875, -107.8602));
+        assert_lab_eq(rgb(255, 255, 255).to_lab(), lab(100.0, 0.0, 0.0));
+        assert_lab_eq(rgb(0, 0, 0).to_lab(), lab(0.0, 0.0, 0.0));
+        assert_lab_eq(rgb(128, 128, 128).to_lab(), lab(53.5850, 0.0, 0.0));
+        assert_lab_eq(rgb(255, 99, 71).to_lab(), lab(62.2057, 57.8531, 46.4196));
+    }
+
+    #[test]
+    fn can_convert_lab_to_rgb() {
+        assert_eq!(lab(53.2408, 80.0925, 67.2032).to_rgb(), rgb(255, 0, 0));
+        assert_eq!(lab(100.0, 0.0, 0.0).to_rgb(), rgb(255, 255, 255));
+        assert_eq!(lab(0.0, 0.0, 0.0).to_rgb(), rgb(0, 0, 0));
+        assert_eq!(lab(62.2057, 57.8531, 46.4196).to_rgb(), rgb(255, 99, 71));
+    }
+
+    #[test]
+    fn can_round_trip_through_lab() {
+        for r in (0..=255).step_by(17) {
+            for g in (0..=255).step_by(17) {
+                for b in (0..=255).step_by(17) {
+                    let color = rgb(r as u8, g as u8, b as u8);
+
+                    assert_eq!(color.to_lab().to_rgb(), color);
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn can_compute_delta_e_2000() {
+        // Test data from Sharma et al. (2005), covering the edge cases of the formula.
+        let pairs = [
+            (
+                lab(50.0, 2.6772, -79.7751),
+                lab(50.0, 0.0, -82.7485),
+                2.0425,
+            ),
+            (lab(50.0, 0.0, 0.0), lab(50.0, -1.0, 2.0), 2.3669),
+            (lab(50.0, 2.49, -0.001), lab(50.0, -2.49, 0.0009), 7.1792),
+            (lab(50.0, -0.001, 2.49), lab(50.0, 0.0011, -2.49), 4.7461),
+            (lab(50.0, 2.5, 0.0), lab(73.0, 25.0, -18.0), 27.1492),
+            (
+                lab(60.2574, -34.0099, 36.2677),
+                lab(60.4626, -34.1751, 39.4387),
+                1.2644,
+            ),
+            (
+                lab(2.0776, 0.0795, -1.135),
+                lab(0.9033, -0.0636, -0.5514),
+                0.9082,
+            ),
+        ];
+
+        for &(lhs, rhs, expected) in pairs.iter() {
+            assert!(
+                (lhs.delta_e_2000(rhs) - expected).abs() < 0.001,
+                "{:?} {:?}",
+                lhs,
+                rhs
+            );
+            assert!(
+                (rhs.delta_e_2000(lhs) - expected).abs() < 0.001,
+                "{:?} {:?}",
+                rhs,
+                lhs
+            );
+        }
+
+        assert_eq!(
+            lab(50.0, 10.0, 10.0).delta_e_2000(lab(50.0, 10.0, 10.0)),
+            0.0
+        );
+    }
+
+    #[test]
+    fn clamps_out_of_gamut_colors() {
+        assert_eq!(lab(150.0, 0.0, 0.0).to_rgb(), rgb(255, 255, 255));
+        assert_eq!(lab(-10.0, 0.0, 0.0).to_rgb(), rgb(0, 0, 0));
+
+        let RGB { r, g, .. } = lab(50.0, 127.0, 0.0).to_rgb();
+
+        assert_eq!((r.as_u8(), g.as_u8()), (255, 0));
+    }
+
+    #[test]
+    fn can_convert_relative_to_d50() {
+        // Reference values of ICC profiles (and CSS' `lab()`), where greys are neutral.
+        let d50 = |color: RGB| Lab::from_xyz_with_white_point(color.to_xyz(), WhitePoint::D50);
+
+        assert_lab_eq(d50(rgb(128, 128, 128)), lab(53.585, 0.0, 0.0));
+        assert_lab_eq(d50(rgb(255, 255, 255)), lab(100.0, 0.0, 0.0));
+        assert_lab_eq(d50(rgb(255, 0, 0)), lab(54.291_7, 80.812_5, 69.885_1));
+
+        // Without adaptation, the D65 grey would look blue relative to D50.
+        let unadapted = super::xyz_to_lab(rgb(128, 128, 128).to_xyz(), XYZ::D50);
+        assert!(unadapted.b < -5.0);
+
+        for &color in [rgb(128, 128, 128), rgb(255, 0, 0), rgb(12, 200, 90)].iter() {
+            let lab = d50(color);
+
+            assert_eq!(lab.to_xyz_with_white_point(WhitePoint::D50).to_rgb(), color);
+        }
+    }
+
+    #[test]
+    fn defaults_to_d65() {
+        let salmon = rgb(250, 128, 114).to_xyz();
+
+        assert_eq!(Lab::WHITE_POINT, WhitePoint::D65);
+        assert_eq!(
+            Lab::from_xyz(salmon),
+            Lab::from_xyz_with_white_point(salmon, WhitePoint::D65)
+        );
+    }
+}
@@ -0,0 +1,267 @@
+use super::css::Rounded;
+use super::{GamutMap, Lab, WhitePoint, RGB, XYZ};
+use std::fmt;
+
+/// Constructs a LCH Color from numerical values, similar to the
+/// [`lch` function][css-lch] in CSS.
+///
+/// The lightness ranges between `0.0-100.0`. The chroma has no fixed upper
+/// bound, but colors within the sRGB gamut stay below about `135.0`. The hue
+/// is expressed in degrees, and normalized into the `0-360°` range.
+///
+/// # Example
+/// ```
+/// use css_colors::lch;
+///
+/// let tomato = lch(62.5, 66.9, 40.9);
+///
+/// assert_eq!(tomato.to_css(), "lch(62.5% 66.9 40.9)");
+/// ```
+///
+/// [css-lch]: https://www.w3.org/TR/css-color-4/#specifying-lab-lch
+pub fn lch(l: f32, c: f32, h: f32) -> LCH {
+    LCH {
+        l,
+        c,
+        h: h.rem_euclid(360.0),
+    }
+}
+
+#[derive(Debug, Copy, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+/// A struct to represent a color in the cylindrical form of CIELAB: the chroma
+/// is the distance from the neutral axis, and the hue is the angle around it.
+///
+/// To match CSS, the underlying CIELAB values are relative to the D50 white
+/// point (unlike `Lab`, which uses D65), so colors are chromatically adapted with
+/// the Bradford transform when converted from or into sRGB and `Lab`.
+///
+/// For more, see the [CSS Color Spec](https://www.w3.org/TR/css-color-4/#lab-colors).
+pub struct LCH {
+    // lightness
+    pub l: f32,
+
+    // chroma
+    pub c: f32,
+
+    // hue, in degrees
+    pub h: f32,
+}
+
+impl fmt::Display for LCH {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            f,
+            "lch({}% {} {})",
+            Rounded(self.l, 2),
+            Rounded(self.c, 2),
+            Rounded(self.h, 2)
+        )
+    }
+}
+
+// Below this chroma, a color is considered neutral and its hue is undefined.
+const NEUTRAL_CHROMA: f32 = 0.000_1;
+
+impl LCH {
+    /// Converts an RGB color into LCH.
+    ///
+    /// # Example
+    /// ```
+    /// use css_colors::{rgb, LCH};
+    ///
+    /// assert_eq!(LCH::from_rgb(rgb(255, 99, 71)).to_css(), "lch(62.99% 76.27 38.88)");
+    /// ```
+    pub fn from_rgb(rgb: RGB) -> LCH {
+        LCH::from_d50_lab(Lab::from_xyz_with_white_point(
+            XYZ::from_rgb(rgb),
+            WhitePoint::D50,
+        ))
+    }
+
+    /// Converts a (D65) `Lab` color into LCH.
+    pub fn from_lab(lab: Lab) -> LCH {
+        LCH::from_d50_lab(Lab::from_xyz_with_white_point(
+            lab.to_xyz(),
+            WhitePoint::D50,
+        ))
+    }
+
+    /// Converts `self` into RGB. Colors outside of the sRGB gamut are clamped
+    /// channel by channel (see `in_gamut`).
+    ///
+    /// # Example
+    /// ```
+    /// use css_colors::{lch, rgb};
+    ///
+    /// assert_eq!(lch(54.29, 106.84, 40.85).to_rgb(), rgb(255, 0, 0));
+    /// assert_eq!(lch(54.29, 150.0, 40.85).to_rgb(), rgb(255, 0, 0));
+    /// ```
+    pub fn to_rgb(self) -> RGB {
+        self.to_xyz().to_rgb()
+    }
+
+    /// Converts `self` into a (D65) `Lab` color.
+    pub fn to_lab(self) -> Lab {
+        Lab::from_xyz(self.to_xyz())
+    }
+
+    /// Converts `self` into CIE XYZ, relative to the D65 white point.
+    pub fn to_xyz(self) -> XYZ {
+        self.to_d50_lab().to_xyz_with_white_point(WhitePoint::D50)
+    }
+
+    /// Returns `true` if `self` can be represented in sRGB without clamping.
+    ///
+    /// # Example
+    /// ```
+    /// use css_colors::lch;
+    ///
+    /// assert!(lch(54.29, 106.84, 40.85).in_gamut());
+    /// assert!(!lch(54.29, 150.0, 40.85).in_gamut());
+    /// ```
+    pub fn in_gamut(&self) -> bool {
+        self.to_xyz().in_gamut()
+    }
+
+    /// Returns `true` if `self` can be represented in sRGB (see `in_gamut`).
+    #[deprecated(note = "renamed to `in_gamut`")]
+    pub fn is_in_gamut(self) -> bool {
+        self.in_gamut()
+    }
+
+    /// Converts `self` into RGB, bringing colors outside of the sRGB gamut into
+    /// it with the given strategy.
+    ///
+    /// # Example
+    /// ```
+    /// use css_colors::{lch, rgb, GamutMap};
+    ///
+    /// let vivid = lch(60.0, 150.0, 140.0);
+    ///
+    /// assert_eq!(vivid.map_to_gamut(GamutMap::Clip), rgb(0, 182, 0));
+    /// assert_eq!(vivid.map_to_gamut(GamutMap::ChromaReduce), rgb(0, 161, 76));
+    /// ```
+    pub fn map_to_gamut(self, method: GamutMap) -> RGB {
+        self.to_xyz().map_to_gamut(method)
+    }
+
+    /// Converts `self` into the `lch()` notation of CSS, with up to two decimals
+    /// per component.
+    pub fn to_css(self) -> String {
+        self.to_string()
+    }
+
+    fn from_d50_lab(lab: Lab) -> LCH {
+        let c = lab.a.hypot(lab.b);
+
+        // The hue of a neutral color is undefined (and its angle meaningless).
+        let h = if c < NEUTRAL_CHROMA {
+            0.0
+        } else {
+            lab.b.atan2(lab.a).to_degrees().rem_euclid(360.0)
+        };
+
+        LCH { l: lab.l, c, h }
+    }
+
+    fn to_d50_lab(self) -> Lab {
+        let h = self.h.to_radians();
+
+        Lab {
+            l: self.l,
+            a: self.c * h.cos(),
+            b: self.c * h.sin(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use {lab, lch, rgb, Color, LCH};
+
+    fn assert_lch_eq(lhs: LCH, rhs: LCH) {
+        assert!(
+            (lhs.l - rhs.l).abs() < 0.05
+                && (lhs.c - rhs.c).abs() < 0.05
+                && (lhs.h - rhs.h).abs() < 0.05,
+            "lhs: {:?}, rhs: {:?}",
+            lhs,
+            rhs
+        );
+    }
+
+    #[test]
+    fn can_convert_rgb_to_lch() {
+        // Conversions of the CSS Color 4 spec (computed with its sample code).
+        assert_lch_eq(rgb(255, 0, 0).to_lch(), lch(54.29, 106.84, 40.85));
+        assert_lch_eq(rgb(0, 128, 0).to_lch(), lch(46.28, 67.98, 134.38));
+        assert_lch_eq(rgb(0, 0, 255).to_lch(), lch(29.57, 131.21, 301.36));
+        assert_lch_eq(rgb(255, 255, 0).to_lch(), lch(97.61, 94.71, 99.57));
+        assert_lch_eq(rgb(128, 128, 128).to_lch(), lch(53.59, 0.0, 0.0));
+    }
+
+    #[test]
+    fn can_convert_lch_to_rgb() {
+        assert_eq!(lch(54.29, 106.84, 40.85).to_rgb(), rgb(255, 0, 0));
+        assert_eq!(lch(46.28, 67.98, 134.38).to_rgb(), rgb(0, 128, 0));
+        assert_eq!(lch(29.57, 131.21, 301.36).to_rgb(), rgb(0, 0, 255));
+
+        for r in (0..=255).step_by(51) {
+            for g in (0..=255).step_by(51) {
+                for b in (0..=255).step_by(51) {
+                    let color = rgb(r as u8, g as u8, b as u8);
+
+                    assert_eq!(color.to_lch().to_rgb(), color);
+                    assert!(color.to_lch().in_gamut());
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn can_convert_between_lab_and_lch() {
+        let tomato = rgb(255, 99, 71);
+
+        assert_lch_eq(LCH::from_lab(tomato.to_lab()), tomato.to_lch());
+        assert_eq!(tomato.to_lch().to_lab().to_rgb(), tomato);
+    }
+
+    #[test]
+    fn handles_neutral_colors() {
+        for &grey in [rgb(0, 0, 0), rgb(128, 128, 128), rgb(255, 255, 255)].iter() {
+            let LCH { c, h, .. } = grey.to_lch();
+
+            assert!(c < 0.01);
+            assert_eq!(h, 0.0);
+        }
+
+        assert_eq!(LCH::from_lab(lab(50.0, 0.0, 0.0)).h, 0.0);
+        assert_eq!(rgb(255, 255, 255).to_lch().to_css(), "lch(100% 0 0)");
+        assert_eq!(lch(0.0, 0.0, 0.0).to_rgb(), rgb(0, 0, 0));
+    }
+
+    #[test]
+    fn clamps_out_of_gamut_colors() {
+        let vivid_green = lch(90.0, 140.0, 140.0);
+
+        assert!(!vivid_green.in_gamut());
+
+        let clamped = vivid_green.to_rgb();
+        assert_eq!((clamped.r.as_u8(), clamped.g.as_u8()), (0, 255));
+
+        assert!(!lch(120.0, 0.0, 0.0).in_gamut());
+        assert_eq!(lch(120.0, 0.0, 0.0).to_rgb(), rgb(255, 255, 255));
+    }
+
+    #[test]
+    fn can_serialize_to_css() {
+        assert_eq!(lch(52.0, 58.1, 22.7).to_css(), "lch(52% 58.1 22.7)");
+        assert_eq!(
+            lch(54.294, 106.8385, 40.853).to_css(),
+            "lch(54.29% 106.84 40.85)"
+        );
+        assert_eq!(lch(50.0, 10.0, -30.0).to_css(), "lch(50% 10 330)");
+        assert_eq!(format!("{}", lch(0.0, 0.0, 360.0)), "lch(0% 0 0)");
+    }
+}
@@ -1,15 +1,125 @@
+#[cfg(feature = "serde")]
+#[macro_use]
+extern crate serde;
+#[cfg(feature = "serde")]
+extern crate serde_json;
+
+#[cfg(feature = "rand")]
+extern crate rand;
+#[cfg(all(test, feature = "rand"))]
+extern crate rand_chacha;
+
+mod adjust;
 mod angle;
+mod ansi;
+mod audit;
+mod blend;
+mod cmyk;
+mod contrast;
+mod css;
+mod cvd;
+mod describe;
+mod gamut;
+mod gradient;
+mod harmony;
+mod hsi;
 mod hsl;
+mod hsv;
+mod hwb;
+mod interpolation;
+#[cfg(feature = "rand")]
+mod jitter;
+mod lab;
+mod lch;
+mod linear;
+mod oklab;
+mod oklch;
+mod op;
+mod p3;
+mod parse;
+mod premultiplied;
 mod ratio;
+mod representability;
 mod rgb;
+mod rgb16;
+mod rgb_float;
+mod spectrum;
+mod swatch;
+mod temperature;
+mod xyz;
+mod ycbcr;
+
+pub mod accuracy;
+pub mod ops;
+
+#[cfg(feature = "bench-fixtures")]
+pub mod fixtures;
 
+pub use adjust::*;
 pub use angle::*;
+pub use audit::*;
+pub use blend::{BlendMode, BlendSpace, ParseBlendModeError};
+pub use cmyk::*;
+pub use contrast::*;
+pub use cvd::*;
+pub use describe::*;
+pub use gamut::*;
+pub use gradient::*;
+pub use hsi::*;
 pub use hsl::*;
+pub use hsv::*;
+pub use hwb::*;
+pub use interpolation::InterpolationSpace;
+#[cfg(feature = "rand")]
+pub use jitter::*;
+pub use lab::*;
+pub use lch::*;
+pub use linear::*;
+pub use oklab::*;
+pub use oklch::*;
+pub use op::*;
+pub use p3::*;
+pub use parse::*;
+pub use premultiplied::*;
 pub use ratio::*;
+pub use representability::*;
 pub use rgb::*;
+pub use rgb16::*;
+pub use rgb_float::*;
+pub use swatch::*;
+pub use xyz::*;
+pub use ycbcr::*;
 
 /// A trait that can be used for converting between different color models
 /// and performing various transformations on them.
+///
+/// Conversion and serialization methods borrow `self`, so colors can be used
+/// through references and trait objects, while transformations consume `self`
+/// and return a new color.
+///
+/// # Examples
+/// ```
+/// use css_colors::{Color, rgb, rgba, RGBA};
+///
+/// let colors: Vec<Box<dyn Color<Alpha = RGBA>>> = vec![
+///     Box::new(rgb(250, 128, 114)),
+///     Box::new(rgba(250, 128, 114, 0.50)),
+/// ];
+///
+/// let css: Vec<String> = colors.iter().map(|color| color.to_css()).collect();
+///
+/// assert_eq!(css, ["rgb(250, 128, 114)", "rgba(250, 128, 114, 0.50)"]);
+/// ```
+///
+/// # Weights
+///
+/// Most weights and amounts are `Ratio`s, which cannot exceed `100%`: building
+/// one from a larger percentage (`percent(150)`) panics where it is built.
+/// Weights given as plain numbers (`mix_f32`, `invert_weighted`) are clamped
+/// into their range instead, after a `debug_assert!`, so that an out of range
+/// weight panics in debug builds and saturates in release builds. The `t` of
+/// `lerp` is the exception: it is clamped silently, as easing curves commonly
+/// overshoot `0.0-1.0`.
 pub trait Color {
     type Alpha: Color;
 
@@ -25,7 +135,7 @@ pub trait Color {
     /// assert_eq!(salmon.to_css(), "rgb(250, 128, 114)");
     /// assert_eq!(opaque_salmon.to_css(), "rgba(250, 128, 114, 0.50)");
     /// ```
-    fn to_css(self) -> String;
+    fn to_css(&self) -> String;
 
     /// Converts `self` into its RGB representation.
     /// When converting from a color model that supports an alpha channel
@@ -39,7 +149,7 @@ pub trait Color {
     ///
     /// assert_eq!(opaque_tomato.to_rgb(), rgb(255, 99, 71));
     /// ```
-    fn to_rgb(self) -> RGB;
+    fn to_rgb(&self) -> RGB;
 
     /// Converts `self` into its RGBA representation.
     /// When converting from a color model that does not supports an alpha channel
@@ -53,7 +163,36 @@ pub trait Color {
     ///
     /// assert_eq!(tomato.to_rgba(), rgba(255, 99, 71, 1.0));
     /// ```
-    fn to_rgba(self) -> RGBA;
+    fn to_rgba(&self) -> RGBA;
+
+    /// Converts `self` into its RGBA representation with 16 bits per channel.
+    /// Color models with 8-bit channels are scaled by `257`, so that `255` maps to `65535`.
+    ///
+    /// # Examples
+    /// ```
+    /// use css_colors::{Color, rgb, rgba16};
+    ///
+    /// let tomato = rgb(255, 99, 71);
+    ///
+    /// assert_eq!(tomato.to_rgba16(), rgba16(65535, 25443, 18247, 65535));
+    /// ```
+    fn to_rgba16(&self) -> RGBA16 {
+        RGBA16::from_rgba(self.to_rgba())
+    }
+
+    /// Converts `self` into its RGBA representation with floating point channels
+    /// (see `RGBFloat`). When converting from a color model that does not support
+    /// an alpha channel (e.g. RGB), it will be treated as fully opaque.
+    ///
+    /// # Examples
+    /// ```
+    /// use css_colors::{Color, rgb, rgb_float};
+    ///
+    /// assert_eq!(rgb(255, 0, 51).to_rgb_float(), rgb_float(1.0, 0.0, 0.2, 1.0));
+    /// ```
+    fn to_rgb_float(&self) -> RGBFloat {
+        RGBFloat::from(self.to_rgba())
+    }
 
     /// Converts `self` into its HSL representation.
     /// When converting from a color model that supports an alpha channel
@@ -69,7 +208,7 @@ pub trait Color {
     /// assert_eq!(tomato.to_hsl(), hsl(9, 100, 64));
     /// assert_eq!(opaque_tomato.to_hsl(), hsl(9, 100, 64));
     /// ```
-    fn to_hsl(self) -> HSL;
+    fn to_hsl(&self) -> HSL;
 
     /// Converts `self` into its HSLA representation.
     /// When converting from a color model that does not supports an alpha channel
@@ -85,636 +224,4337 @@ pub trait Color {
     /// assert_eq!(tomato.to_hsla(), hsla(9, 100, 64, 1.0));
     /// assert_eq!(opaque_tomato.to_hsla(), hsla(9, 100, 64, 0.5));
     /// ```
-    fn to_hsla(self) -> HSLA;
+    fn to_hsla(&self) -> HSLA;
 
-    /// Increases the saturation of `self` by an absolute amount.
-    /// Operates on the color within its HSL representation and preserves any existing alpha channel.
-    /// For more, see Less' [Color Operations](http://lesscss.org/functions/#color-operations-saturate).
+    /// Converts `self` into its HSV representation.
+    /// When converting from a color model that supports an alpha channel
+    /// (e.g. RGBA), the alpha value will not be preserved.
     ///
     /// # Examples
     /// ```
-    /// use css_colors::{Color, rgb, hsla, percent};
+    /// use css_colors::{Color, rgb, rgba, hsv};
     ///
-    /// let salmon = hsla(6, 93, 71, 1.0);
-    /// let cornflower_blue = rgb(100, 149, 237);
+    /// let tomato = rgb(255, 99, 71);
+    /// let opaque_tomato = rgba(255, 99, 71, 0.5);
     ///
-    /// assert_eq!(salmon.saturate(percent(7)), hsla(6, 100, 71, 1.0));
-    /// assert_eq!(cornflower_blue.saturate(percent(10)), rgb(92, 146, 246));
+    /// assert_eq!(tomato.to_hsv(), hsv(9, 72, 100));
+    /// assert_eq!(opaque_tomato.to_hsv(), hsv(9, 72, 100));
     /// ```
-    fn saturate(self, amount: Ratio) -> Self;
+    fn to_hsv(&self) -> HSV {
+        HSV::from_rgba(self.to_rgba())
+    }
 
-    /// Decreases the saturation of `self` by an absolute amount.
-    /// Operates on the color within its HSL representation and preserves any existing alpha channel.
-    /// For more, see Less' [Color Operations](http://lesscss.org/functions/#color-operations-desaturate).
+    /// Converts `self` into its HSVA representation.
+    /// When converting from a color model that does not supports an alpha channel
+    /// (e.g. RGB), it will be treated as fully opaque.
     ///
     /// # Examples
     /// ```
-    /// use css_colors::{Color, rgb, rgba, percent};
+    /// use css_colors::{Color, rgb, rgba, hsva};
     ///
-    /// let tomato = rgba(255, 99, 71, 1.0);
-    /// let cornflower_blue = rgb(100, 149, 237);
+    /// let tomato = rgb(255, 99, 71);
+    /// let opaque_tomato = rgba(255, 99, 71, 0.5);
     ///
-    /// assert_eq!(tomato.desaturate(percent(10)), rgba(246, 105, 80, 1.0));
-    /// assert_eq!(cornflower_blue.desaturate(percent(33)), rgb(129, 157, 209));
+    /// assert_eq!(tomato.to_hsva(), hsva(9, 72, 100, 1.0));
+    /// assert_eq!(opaque_tomato.to_hsva(), hsva(9, 72, 100, 0.5));
     /// ```
-    fn desaturate(self, amount: Ratio) -> Self;
+    fn to_hsva(&self) -> HSVA {
+        HSVA::from_rgba(self.to_rgba())
+    }
 
-    /// Increases the lightness of `self` by an absolute amount.
-    /// Operates on the color within its HSL representation and preserves any existing alpha channel.
-    /// For more, see Less' [Color Operations](http://lesscss.org/functions/#color-operations-lighten).
+    /// Converts `self` into its HSI representation, where the intensity is the
+    /// average of the red, green and blue channels (see `HSI`).
+    /// When converting from a color model that supports an alpha channel
+    /// (e.g. RGBA), the alpha value will not be preserved.
     ///
     /// # Examples
     /// ```
-    /// use css_colors::{Color, rgb, rgba, percent};
+    /// use css_colors::{Color, rgb, rgba, hsi};
     ///
-    /// let tomato = rgba(255, 99, 71, 1.0);
-    /// let cornflower_blue = rgb(100, 149, 237);
+    /// let tomato = rgb(255, 99, 71);
+    /// let opaque_tomato = rgba(255, 99, 71, 0.5);
     ///
-    /// assert_eq!(tomato.lighten(percent(20)), rgba(255, 185, 173, 1.0));
-    /// assert_eq!(cornflower_blue.lighten(percent(33)), rgb(251, 253, 255));
+    /// assert_eq!(tomato.to_hsi().to_string(), "hsi(8, 50%, 56%)");
+    /// assert_eq!(opaque_tomato.to_hsi(), tomato.to_hsi());
     /// ```
-    fn lighten(self, amount: Ratio) -> Self;
+    fn to_hsi(&self) -> HSI {
+        HSI::from_rgba(self.to_rgba())
+    }
 
-    /// Decreases the lightness of `self` by an absolute amount.
-    /// Operates on the color within its HSL representation and preserves any existing alpha channel.
-    /// For more, see Less' [Color Operations](http://lesscss.org/functions/#color-operations-darken).
+    /// Converts `self` into its HWB representation.
+    /// When converting from a color model that supports an alpha channel
+    /// (e.g. RGBA), the alpha value will not be preserved.
     ///
     /// # Examples
     /// ```
-    /// use css_colors::{Color, rgb, rgba, percent};
+    /// use css_colors::{Color, rgb, rgba, hwb};
     ///
-    /// let tomato = rgba(255, 99, 71, 1.0);
-    /// let cornflower_blue = rgb(100, 149, 237);
+    /// let tomato = rgb(255, 99, 71);
+    /// let opaque_tomato = rgba(255, 99, 71, 0.5);
     ///
-    /// assert_eq!(tomato.darken(percent(20)), rgba(224, 34, 0, 1.0));
-    /// assert_eq!(cornflower_blue.darken(percent(33)), rgb(18, 65, 152));
+    /// assert_eq!(tomato.to_hwb().to_css(), "hwb(9 28% 0%)");
+    /// assert_eq!(opaque_tomato.to_hwb().to_css(), "hwb(9 28% 0%)");
     /// ```
-    fn darken(self, amount: Ratio) -> Self;
+    fn to_hwb(&self) -> HWB {
+        HWB::from_rgba(self.to_rgba())
+    }
 
-    /// Decreases the transparency (or increase the opacity) of `self`, making it more opaque.
-    /// For opqaue colors, converts into the alpha equivalent of `self`, and then increases the opacity.
-    /// For more, see Less' [Color Operations](http://lesscss.org/functions/#color-operations-fadein).
+    /// Converts `self` into its CMYK representation, using a naive device conversion.
+    /// When converting from a color model that supports an alpha channel
+    /// (e.g. RGBA), the alpha value will not be preserved.
     ///
     /// # Examples
     /// ```
-    /// use css_colors::{Color, rgb, rgba, percent};
+    /// use css_colors::{Color, rgb, rgba, cmyk};
     ///
-    /// let tomato = rgba(255, 99, 71, 0.25);
-    /// let cornflower_blue = rgb(100, 149, 237);
+    /// let tomato = rgb(255, 99, 71);
+    /// let opaque_tomato = rgba(255, 99, 71, 0.5);
     ///
-    /// assert_eq!(tomato.fadein(percent(25)), rgba(255, 99, 71, 0.5));
-    /// assert_eq!(cornflower_blue.fadein(percent(75)), rgba(100, 149, 237, 1.0));
+    /// assert_eq!(tomato.to_cmyk().to_css(), "device-cmyk(0% 61% 72% 0%)");
+    /// assert_eq!(opaque_tomato.to_cmyk().to_css(), "device-cmyk(0% 61% 72% 0%)");
     /// ```
-    fn fadein(self, amount: Ratio) -> Self::Alpha;
+    fn to_cmyk(&self) -> CMYK {
+        CMYK::from_rgba(self.to_rgba())
+    }
 
-    /// Increases the transparency (or decrease the opacity) of `self`, making it less opaque.
-    /// For opqaue colors, converts into the alpha equivalent of `self`, and then decreases the opacity.
-    /// For more, see Less' [Color Operations](http://lesscss.org/functions/#color-operations-fadeout).
+    /// Converts `self` into linear-light sRGB, in which colors can be blended
+    /// physically correctly (see `LinearRGB`).
+    /// When converting from a color model that supports an alpha channel
+    /// (e.g. RGBA), the alpha value will not be preserved.
     ///
     /// # Examples
     /// ```
-    /// use css_colors::{Color, rgb, rgba, percent};
+    /// use css_colors::{Color, rgb, linear_rgb};
     ///
-    /// let tomato = rgba(255, 99, 71, 0.5);
-    /// let cornflower_blue = rgb(100, 149, 237);
+    /// assert_eq!(rgb(255, 0, 255).to_linear(), linear_rgb(1.0, 0.0, 1.0));
+    /// ```
+    fn to_linear(&self) -> LinearRGB {
+        LinearRGB::from_rgb(self.to_rgb())
+    }
+
+    /// Converts `self` into YCbCr, encoded with the given `YCbCrStandard` and `YCbCrRange`.
+    /// When converting from a color model that supports an alpha channel
+    /// (e.g. RGBA), the alpha value will not be preserved.
     ///
-    /// assert_eq!(tomato.fadeout(percent(25)), rgba(255, 99, 71, 0.25));
-    /// assert_eq!(cornflower_blue.fadeout(percent(75)), rgba(100, 149, 237, 0.25));
+    /// # Examples
+    /// ```
+    /// use css_colors::{Color, rgb, ycbcr, YCbCrRange, YCbCrStandard};
+    ///
+    /// let white = rgb(255, 255, 255);
+    ///
+    /// assert_eq!(white.to_ycbcr(YCbCrStandard::Bt601, YCbCrRange::Full), ycbcr(255, 128, 128));
+    /// assert_eq!(white.to_ycbcr(YCbCrStandard::Bt601, YCbCrRange::Studio), ycbcr(235, 128, 128));
     /// ```
-    fn fadeout(self, amount: Ratio) -> Self::Alpha;
+    fn to_ycbcr(&self, standard: YCbCrStandard, range: YCbCrRange) -> YCbCr {
+        YCbCr::from_rgb(self.to_rgb(), standard, range)
+    }
 
-    /// Sets the absolute opacity of `self`, and returns the alpha equivalent.
-    /// Can be applied to colors whether they already have an opacity value or not.
-    /// For more, see Less' [Color Operations](http://lesscss.org/functions/#color-operations-fade).
+    /// Converts `self` into the Display P3 color space.
+    /// When converting from a color model that supports an alpha channel
+    /// (e.g. RGBA), the alpha value will not be preserved.
     ///
     /// # Examples
     /// ```
-    /// use css_colors::{Color, rgb, rgba, percent};
+    /// use css_colors::{Color, rgb};
     ///
-    /// let tomato = rgba(255, 99, 71, 0.5);
-    /// let cornflower_blue = rgb(100, 149, 237);
+    /// let green = rgb(0, 255, 0).to_p3();
     ///
-    /// assert_eq!(tomato.fade(percent(25)), rgba(255, 99, 71, 0.25));
-    /// assert_eq!(cornflower_blue.fade(percent(50)), rgba(100, 149, 237, 0.5));
+    /// assert_eq!(green.to_css(), "color(display-p3 0.4584 0.9853 0.2983)");
     /// ```
-    fn fade(self, amount: Ratio) -> Self::Alpha;
+    fn to_p3(&self) -> P3 {
+        P3::from_rgb(self.to_rgb())
+    }
 
-    /// Rotate the hue angle of `self` in either direction.
-    /// Returns the appropriate `RGB` representation of the color once it has been spun.
-    /// For more, see Less' [Color Operations](http://lesscss.org/functions/#color-operations-spin).
+    /// Converts `self` into the CIE XYZ color space (relative to the D65 white point).
+    /// When converting from a color model that supports an alpha channel
+    /// (e.g. RGBA), the alpha value will not be preserved.
     ///
     /// # Examples
     /// ```
-    /// use css_colors::{Color, rgb, hsl, deg};
+    /// use css_colors::{Color, rgb, XYZ};
     ///
-    /// let red = hsl(10, 90, 50);
-    /// let pink = rgb(243, 13, 90);
+    /// let white = rgb(255, 255, 255).to_xyz();
     ///
-    /// assert_eq!(red.spin(deg(30)), hsl(40, 90, 50));
-    /// assert_eq!(pink.spin(deg(-30)), rgb(243, 13, 205));
+    /// assert!((white.x - XYZ::D65.x).abs() < 0.0001);
     /// ```
-    fn spin(self, amount: Angle) -> Self;
+    fn to_xyz(&self) -> XYZ {
+        XYZ::from_rgb(self.to_rgb())
+    }
 
-    /// Mixes two colors (`self` and any other `Color`) together in variable proportion.
-    /// Takes opacity into account in the calculations.
-    /// For more, see Less' [Color Operations](http://lesscss.org/functions/#color-operations-mix).
+    /// Converts `self` into its CIELAB representation (relative to the D65 white point).
+    /// When converting from a color model that supports an alpha channel
+    /// (e.g. RGBA), the alpha value will not be preserved.
     ///
     /// # Examples
     /// ```
-    /// use css_colors::{Color, rgb, rgba, hsl, hsla, percent};
+    /// use css_colors::{Color, rgb};
     ///
-    /// let red = hsl(10, 90, 50);
-    /// let golden = rgb(243, 166, 13);
-    /// let navy = rgba(0, 0, 80, 1.0);
+    /// let red = rgb(255, 0, 0).to_lab();
     ///
-    /// assert_eq!(red.mix(navy, percent(50)).to_string(), "hsla(347, 65%, 29%, 1.00)");
-    /// assert_eq!(golden.mix(navy, percent(25)), rgba(61, 42, 63, 1.0));
+    /// assert_eq!(red.l.round(), 53.0);
+    /// assert_eq!(red.a.round(), 80.0);
+    /// assert_eq!(red.b.round(), 67.0);
     /// ```
-    fn mix<T: Color>(self, other: T, weight: Ratio) -> Self::Alpha;
+    fn to_lab(&self) -> Lab {
+        Lab::from_rgb(self.to_rgb())
+    }
 
-    /// Mixes `self` with white in variable proportion.
-    /// Equivalent to calling `mix()` with `white` (`rgb(255, 255, 255)`).
-    /// For more, see Less' [Color Operations](http://lesscss.org/functions/#color-operations-tint).
+    /// Converts `self` into its LCH representation, as used by CSS' `lch()`.
+    /// When converting from a color model that supports an alpha channel
+    /// (e.g. RGBA), the alpha value will not be preserved.
     ///
     /// # Examples
     /// ```
-    /// use css_colors::{Color, rgb, rgba, hsl, percent};
+    /// use css_colors::{Color, rgb};
     ///
-    /// let red = hsl(10, 90, 50);
-    /// let golden = rgb(243, 166, 13);
+    /// assert_eq!(rgb(255, 0, 0).to_lch().to_css(), "lch(54.29% 106.84 40.85)");
+    /// ```
+    fn to_lch(&self) -> LCH {
+        LCH::from_rgb(self.to_rgb())
+    }
+
+    /// Converts `self` into its Oklab representation. When converting from a color
+    /// model that supports an alpha channel (e.g. RGBA), the alpha value will not
+    /// be preserved.
     ///
-    /// assert_eq!(red.tint(percent(10)), hsl(10, 92, 95));
-    /// assert_eq!(golden.tint(percent(25)), rgb(252, 233, 194));
+    /// # Examples
+    /// ```
+    /// use css_colors::{Color, rgb};
+    ///
+    /// let red = rgb(255, 0, 0).to_oklab();
+    ///
+    /// assert!((red.l - 0.628).abs() < 0.001);
     /// ```
-    fn tint(self, weight: Ratio) -> Self;
+    fn to_oklab(&self) -> OkLab {
+        OkLab::from_rgb(self.to_rgb())
+    }
 
-    /// Mixes `self` with white in variable proportion.
-    /// Equivalent to calling `mix()` with `black` (`rgb(0, 0, 0)`).
-    /// For more, see Less' [Color Operations](http://lesscss.org/functions/#color-operations-shade).
+    /// Converts `self` into its OkLch representation, as used by CSS' `oklch()`.
+    /// When converting from a color model that supports an alpha channel
+    /// (e.g. RGBA), the alpha value will not be preserved.
     ///
     /// # Examples
     /// ```
-    /// use css_colors::{Color, rgb, rgba, hsl, percent};
+    /// use css_colors::{Color, rgb};
     ///
-    /// let red = hsl(10, 90, 50);
-    /// let golden = rgb(243, 166, 13);
+    /// assert_eq!(rgb(255, 0, 0).to_oklch().to_css(), "oklch(62.8% 0.2577 29.23)");
+    /// ```
+    fn to_oklch(&self) -> OkLch {
+        OkLch::from_rgb(self.to_rgb())
+    }
+
+    /// Estimates the correlated color temperature of `self` (in kelvin), or
+    /// `None` if `self` is too far from the colors of a black body for it to
+    /// be meaningful (see `XYZ::cct`).
     ///
-    /// assert_eq!(red.shade(percent(10)), hsl(10, 92, 5));
-    /// assert_eq!(golden.shade(percent(25)), rgb(61, 42, 3));
+    /// # Examples
+    /// ```
+    /// use css_colors::{Color, rgb, RGB};
+    ///
+    /// let daylight = RGB::from_kelvin(5500.0).cct().unwrap();
+    ///
+    /// assert!((daylight - 5500.0).abs() < 100.0);
+    /// assert_eq!(rgb(0, 255, 0).cct(), None);
     /// ```
-    fn shade(self, weight: Ratio) -> Self;
+    fn cct(&self) -> Option<f32> {
+        self.to_xyz().cct()
+    }
 
-    /// Remove all saturation from `self` in the HSL color space.
-    /// Equivalent to calling `desaturate(0)` on a color.
-    /// For more, see Less' [Color Operations](http://lesscss.org/functions/#color-operations-greyscale).
+    /// Converts an RGBA color into the color model of `Self`.
+    /// When converting into a color model that does not support an alpha channel
+    /// (e.g. RGB), the alpha value will not be preserved.
     ///
     /// # Examples
     /// ```
-    /// use css_colors::{Color, rgb, rgba};
+    /// use css_colors::{Color, rgba, hsl, HSL, RGB};
+    ///
+    /// let opaque_tomato = rgba(255, 99, 71, 0.5);
+    ///
+    /// assert_eq!(RGB::from_rgba(opaque_tomato).to_css(), "rgb(255, 99, 71)");
+    /// assert_eq!(HSL::from_rgba(opaque_tomato), hsl(9, 100, 64));
+    /// ```
+    fn from_rgba(rgba: RGBA) -> Self
+    where
+        Self: Sized;
+
+    /// Converts an HSLA color into the color model of `Self`.
+    /// When converting into a color model that does not support an alpha channel
+    /// (e.g. HSL), the alpha value will not be preserved.
+    ///
+    /// # Examples
+    /// ```
+    /// use css_colors::{Color, hsla, rgb, HSL, RGB};
+    ///
+    /// let opaque_tomato = hsla(9, 100, 64, 0.5);
+    ///
+    /// assert_eq!(RGB::from_hsla(opaque_tomato), rgb(255, 99, 71));
+    /// assert_eq!(HSL::from_hsla(opaque_tomato).to_css(), "hsl(9, 100%, 64%)");
+    /// ```
+    fn from_hsla(hsla: HSLA) -> Self
+    where
+        Self: Sized,
+    {
+        Self::from_rgba(hsla.to_rgba())
+    }
+
+    /// Increases the saturation of `self` by an absolute amount.
+    /// Operates on the color within its HSL representation and preserves any existing alpha channel.
+    /// For more, see Less' [Color Operations](http://lesscss.org/functions/#color-operations-saturate).
+    ///
+    /// # Examples
+    /// ```
+    /// use css_colors::{Color, rgb, hsla, percent};
+    ///
+    /// let salmon = hsla(6, 93, 71, 1.0);
+    /// let cornflower_blue = rgb(100, 149, 237);
+    ///
+    /// assert_eq!(salmon.saturate(percent(7)), hsla(6, 100, 71, 1.0));
+    /// assert_eq!(cornflower_blue.saturate(percent(10)), rgb(92, 146, 246));
+    /// ```
+    fn saturate(self, amount: Ratio) -> Self
+    where
+        Self: Sized;
+
+    /// Decreases the saturation of `self` by an absolute amount.
+    /// Operates on the color within its HSL representation and preserves any existing alpha channel.
+    /// For more, see Less' [Color Operations](http://lesscss.org/functions/#color-operations-desaturate).
+    ///
+    /// # Examples
+    /// ```
+    /// use css_colors::{Color, rgb, rgba, percent};
     ///
     /// let tomato = rgba(255, 99, 71, 1.0);
     /// let cornflower_blue = rgb(100, 149, 237);
     ///
-    /// assert_eq!(tomato.greyscale(), rgba(163, 163, 163, 1.0));
-    /// assert_eq!(cornflower_blue.greyscale(), rgb(169, 169, 169));
+    /// assert_eq!(tomato.desaturate(percent(10)), rgba(246, 105, 80, 1.0));
+    /// assert_eq!(cornflower_blue.desaturate(percent(33)), rgb(129, 157, 209));
     /// ```
-    fn greyscale(self) -> Self;
-}
+    fn desaturate(self, amount: Ratio) -> Self
+    where
+        Self: Sized;
 
-#[cfg(test)]
-mod css_color_tests {
-    use angle::*;
-    use ratio::*;
-    use {hsl, hsla, rgb, rgba, Angle, Color, Ratio, HSL, HSLA, RGB, RGBA};
+    /// Increases the saturation of `self` by a relative amount: `amount` is the
+    /// fraction of the distance to 100% saturation that is covered, so vivid
+    /// colors are saturated less than with `saturate`. Operates on the color within
+    /// its HSL representation and preserves any existing alpha channel.
+    /// For more, see Sass' [`scale-color`](https://sass-lang.com/documentation/modules/color#scale).
+    ///
+    /// Unlike Sass, greys (with a saturation of exactly 0%) are left unchanged:
+    /// their hue is meaningless (0°, i.e. red), so saturating them would tint them.
+    ///
+    /// # Examples
+    /// ```
+    /// use css_colors::{Color, hsl, percent};
+    ///
+    /// // 50% + 20% × (100% - 50%)
+    /// assert_eq!(hsl(0, 50, 50).saturate_rel(percent(20)).to_string(), "hsl(0, 60%, 50%)");
+    /// assert_eq!(hsl(0, 0, 50).saturate_rel(percent(20)).to_string(), "hsl(0, 0%, 50%)");
+    /// ```
+    fn saturate_rel(self, amount: Ratio) -> Self
+    where
+        Self: Sized,
+    {
+        ops::apply_to_hsl(self, |hsla| {
+            if hsla.s == percent(0) {
+                return hsla;
+            }
 
-    pub trait ApproximatelyEq {
-        fn approximately_eq(self, other: Self) -> bool;
-    }
+            let s = hsla.s.as_f32();
 
-    impl ApproximatelyEq for u8 {
-        fn approximately_eq(self, other: Self) -> bool {
-            self == other || self + 1 == other || self - 1 == other
-        }
+            HSLA {
+                s: ops::quantize(s + (1.0 - s) * amount.as_f32()),
+                ..hsla
+            }
+        })
     }
 
-    impl ApproximatelyEq for u16 {
-        fn approximately_eq(self, other: Self) -> bool {
-            self == other || self + 1 == other || self - 1 == other
-        }
+    /// Decreases the saturation of `self` by a relative amount: `amount` is the
+    /// fraction of the distance to 0% saturation that is covered, so muted colors
+    /// are desaturated less than with `desaturate`. Operates on the color within
+    /// its HSL representation and preserves any existing alpha channel.
+    /// For more, see Sass' [`scale-color`](https://sass-lang.com/documentation/modules/color#scale).
+    ///
+    /// # Examples
+    /// ```
+    /// use css_colors::{Color, hsl, percent};
+    ///
+    /// // 50% - 20% × 50%
+    /// assert_eq!(hsl(0, 50, 50).desaturate_rel(percent(20)).to_string(), "hsl(0, 40%, 50%)");
+    /// ```
+    fn desaturate_rel(self, amount: Ratio) -> Self
+    where
+        Self: Sized,
+    {
+        ops::apply_to_hsl(self, |hsla| HSLA {
+            s: ops::quantize(hsla.s.as_f32() * (1.0 - amount.as_f32())),
+            ..hsla
+        })
     }
 
-    impl ApproximatelyEq for Angle {
-        fn approximately_eq(self, other: Self) -> bool {
-            self.degrees().approximately_eq(other.degrees())
+    /// Increases the saturation of `self` perceptually: `amount` is added to its
+    /// chroma in OkLch, relative to the `0.4` that CSS maps to `100%` in `oklch()`,
+    /// while its Oklab lightness and hue are kept. Colors that end up outside of the
+    /// sRGB gamut are brought back by reducing the chroma (see `GamutMap`). Greys
+    /// are left unchanged, and any existing alpha channel is preserved.
+    ///
+    /// Unlike `saturate`, which keeps the HSL lightness, the perceived lightness
+    /// doesn't shift, e.g. for yellows and blues.
+    ///
+    /// # Examples
+    /// ```
+    /// use css_colors::{Color, rgb, percent};
+    ///
+    /// let dusty_blue = rgb(100, 120, 160);
+    /// let vivid_blue = dusty_blue.saturate_perceptual(percent(20));
+    ///
+    /// assert!(vivid_blue.to_oklch().c > dusty_blue.to_oklch().c + 0.07);
+    /// assert!((vivid_blue.to_oklch().l - dusty_blue.to_oklch().l).abs() < 0.005);
+    /// assert_eq!(rgb(128, 128, 128).saturate_perceptual(percent(20)), rgb(128, 128, 128));
+    /// ```
+    fn saturate_perceptual(self, amount: Ratio) -> Self
+    where
+        Self: Sized,
+    {
+        // Avoid the round trip through RGB, which is lossy for other color models.
+        if amount == percent(0) {
+            return self;
         }
+
+        let delta = amount.as_f32() * oklch::CHROMA_REFERENCE;
+
+        Self::from_rgba(oklch::adjust_chroma(self.to_rgba(), delta))
     }
 
-    impl ApproximatelyEq for Ratio {
+    /// Decreases the saturation of `self` perceptually, by subtracting `amount`
+    /// from its chroma in OkLch (see `saturate_perceptual`). The chroma stops at
+    /// zero, and the Oklab lightness and hue are kept.
+    ///
+    /// Unlike `desaturate`, which keeps the HSL lightness, this keeps the perceived
+    /// lightness: yellow stays much lighter than blue as both turn grey.
+    ///
+    /// # Examples
+    /// ```
+    /// use css_colors::{Color, rgb, percent};
+    ///
+    /// let yellow = rgb(255, 255, 0);
+    ///
+    /// assert_eq!(yellow.desaturate(percent(100)), rgb(128, 128, 128));
+    /// assert_eq!(yellow.desaturate_perceptual(percent(100)), rgb(244, 244, 244));
+    /// ```
+    fn desaturate_perceptual(self, amount: Ratio) -> Self
+    where
+        Self: Sized,
+    {
+        // Avoid the round trip through RGB, which is lossy for other color models.
+        if amount == percent(0) {
+            return self;
+        }
+
+        let delta = amount.as_f32() * oklch::CHROMA_REFERENCE;
+
+        Self::from_rgba(oklch::adjust_chroma(self.to_rgba(), -delta))
+    }
+
+    /// Adjusts the vibrance of `self`: like `saturate`, but muted colors are
+    /// saturated more than vivid ones, which avoids a neon look. `amount` is a
+    /// percentage between `-100` and `100`, and the HSV saturation `s` becomes
+    ///
+    /// `s + amount / 100 × (1 - s)`
+    ///
+    /// clamped to `0-100%`, while the hue and HSV value are kept. A fully saturated
+    /// color is left unchanged by positive amounts, and negative amounts desaturate
+    /// muted colors the most. Greys are left unchanged, and any existing alpha
+    /// channel is preserved. Amounts outside of `-100-100` are clamped (see
+    /// [Weights](#weights)).
+    ///
+    /// # Examples
+    /// ```
+    /// use css_colors::{Color, rgb};
+    ///
+    /// let slate_blue = rgb(100, 112, 140);
+    /// let red = rgb(230, 20, 20);
+    ///
+    /// assert_eq!(slate_blue.vibrance(50), rgb(50, 77, 140));
+    /// assert_eq!(red.vibrance(50), rgb(230, 10, 10));
+    /// assert_eq!(rgb(128, 128, 128).vibrance(50), rgb(128, 128, 128));
+    /// ```
+    fn vibrance(self, amount: i8) -> Self
+    where
+        Self: Sized,
+    {
+        debug_assert!(
+            (-100..=100).contains(&amount),
+            "Invalid amount for vibrance, must be between -100 and 100"
+        );
+
+        let amount = f32::from(amount.clamp(-100, 100)) / 100.0;
+        let RGBA { r, g, b, a } = self.to_rgba();
+        let (r, g, b) = (r.as_f32(), g.as_f32(), b.as_f32());
+
+        let max = r.max(g).max(b);
+        let min = r.min(g).min(b);
+
+        if max == min {
+            return self;
+        }
+
+        // Scaling the distance of every channel to the brightest one scales the
+        // HSV saturation, without changing the hue or the value.
+        let s = (max - min) / max;
+        let scale = (s + amount * (1.0 - s)).clamp(0.0, 1.0) / s;
+        let channel = |c: f32| ops::quantize(max - (max - c) * scale);
+
+        Self::from_rgba(RGBA {
+            r: channel(r),
+            g: channel(g),
+            b: channel(b),
+            a,
+        })
+    }
+
+    /// Increases the lightness of `self` by an absolute amount.
+    /// Operates on the color within its HSL representation and preserves any existing alpha channel.
+    /// For more, see Less' [Color Operations](http://lesscss.org/functions/#color-operations-lighten).
+    ///
+    /// # Examples
+    /// ```
+    /// use css_colors::{Color, rgb, rgba, percent};
+    ///
+    /// let tomato = rgba(255, 99, 71, 1.0);
+    /// let cornflower_blue = rgb(100, 149, 237);
+    ///
+    /// assert_eq!(tomato.lighten(percent(20)), rgba(255, 185, 173, 1.0));
+    /// assert_eq!(cornflower_blue.lighten(percent(33)), rgb(251, 253, 255));
+    /// ```
+    fn lighten(self, amount: Ratio) -> Self
+    where
+        Self: Sized;
+
+    /// Decreases the lightness of `self` by an absolute amount.
+    /// Operates on the color within its HSL representation and preserves any existing alpha channel.
+    /// For more, see Less' [Color Operations](http://lesscss.org/functions/#color-operations-darken).
+    ///
+    /// # Examples
+    /// ```
+    /// use css_colors::{Color, rgb, rgba, percent};
+    ///
+    /// let tomato = rgba(255, 99, 71, 1.0);
+    /// let cornflower_blue = rgb(100, 149, 237);
+    ///
+    /// assert_eq!(tomato.darken(percent(20)), rgba(224, 34, 0, 1.0));
+    /// assert_eq!(cornflower_blue.darken(percent(33)), rgb(18, 65, 152));
+    /// ```
+    fn darken(self, amount: Ratio) -> Self
+    where
+        Self: Sized;
+
+    /// Increases the lightness of `self` by a relative amount: `amount` is the
+    /// fraction of the distance to 100% lightness that is covered, so light colors
+    /// are lightened less than with `lighten`, and never blown out. Operates on the
+    /// color within its HSL representation and preserves any existing alpha channel.
+    /// For more, see Sass' [`scale-color`](https://sass-lang.com/documentation/modules/color#scale).
+    ///
+    /// # Examples
+    /// ```
+    /// use css_colors::{Color, hsl, percent};
+    ///
+    /// // 70% + 20% × (100% - 70%)
+    /// assert_eq!(hsl(0, 50, 70).lighten_rel(percent(20)).to_string(), "hsl(0, 50%, 76%)");
+    /// assert_eq!(hsl(0, 50, 70).lighten(percent(20)).to_string(), "hsl(0, 50%, 90%)");
+    /// ```
+    fn lighten_rel(self, amount: Ratio) -> Self
+    where
+        Self: Sized,
+    {
+        ops::apply_to_hsl(self, |hsla| {
+            let l = hsla.l.as_f32();
+
+            HSLA {
+                l: ops::quantize(l + (1.0 - l) * amount.as_f32()),
+                ..hsla
+            }
+        })
+    }
+
+    /// Decreases the lightness of `self` by a relative amount: `amount` is the
+    /// fraction of the distance to 0% lightness that is covered, so dark colors
+    /// are darkened less than with `darken`. Operates on the color within its HSL
+    /// representation and preserves any existing alpha channel.
+    /// For more, see Sass' [`scale-color`](https://sass-lang.com/documentation/modules/color#scale).
+    ///
+    /// # Examples
+    /// ```
+    /// use css_colors::{Color, hsl, percent};
+    ///
+    /// // 70% - 20% × 70%
+    /// assert_eq!(hsl(0, 50, 70).darken_rel(percent(20)).to_string(), "hsl(0, 50%, 56%)");
+    /// assert_eq!(hsl(0, 50, 0).darken_rel(percent(20)).to_string(), "hsl(0, 50%, 0%)");
+    /// ```
+    fn darken_rel(self, amount: Ratio) -> Self
+    where
+        Self: Sized,
+    {
+        ops::apply_to_hsl(self, |hsla| HSLA {
+            l: ops::quantize(hsla.l.as_f32() * (1.0 - amount.as_f32())),
+            ..hsla
+        })
+    }
+
+    /// Decreases the transparency (or increase the opacity) of `self`, making it more opaque.
+    /// The `amount` is a percentage of opacity, not an alpha byte (see `fade`), and
+    /// the opacity saturates at `100%` instead of overflowing.
+    /// For opaque colors, converts into the alpha equivalent of `self` (at `100%` opacity), and then increases the opacity.
+    /// For more, see Less' [Color Operations](http://lesscss.org/functions/#color-operations-fadein).
+    ///
+    /// # Examples
+    /// ```
+    /// use css_colors::{Color, rgb, rgba, percent};
+    ///
+    /// let tomato = rgba(255, 99, 71, 0.25);
+    /// let cornflower_blue = rgb(100, 149, 237);
+    ///
+    /// assert_eq!(tomato.fadein(percent(25)), rgba(255, 99, 71, 0.5));
+    /// assert_eq!(cornflower_blue.fadein(percent(75)), rgba(100, 149, 237, 1.0));
+    /// ```
+    fn fadein(self, amount: Ratio) -> Self::Alpha
+    where
+        Self: Sized;
+
+    /// Increases the transparency (or decrease the opacity) of `self`, making it less opaque.
+    /// The `amount` is a percentage of opacity, not an alpha byte (see `fade`), and
+    /// the opacity saturates at `0%` instead of underflowing.
+    /// For opaque colors, converts into the alpha equivalent of `self` (at `100%` opacity), and then decreases the opacity.
+    /// For more, see Less' [Color Operations](http://lesscss.org/functions/#color-operations-fadeout).
+    ///
+    /// # Examples
+    /// ```
+    /// use css_colors::{Color, rgb, rgba, percent};
+    ///
+    /// let tomato = rgba(255, 99, 71, 0.5);
+    /// let cornflower_blue = rgb(100, 149, 237);
+    ///
+    /// assert_eq!(tomato.fadeout(percent(25)), rgba(255, 99, 71, 0.25));
+    /// assert_eq!(cornflower_blue.fadeout(percent(75)), rgba(100, 149, 237, 0.25));
+    /// ```
+    fn fadeout(self, amount: Ratio) -> Self::Alpha
+    where
+        Self: Sized;
+
+    /// Sets the absolute opacity of `self`, and returns the alpha equivalent.
+    /// Can be applied to colors whether they already have an opacity value or not.
+    /// For more, see Less' [Color Operations](http://lesscss.org/functions/#color-operations-fade).
+    ///
+    /// Like the amounts of `fadein`, `fadeout` and `lighten`, the opacity is a
+    /// `Ratio`, so it reads as in Less: `fade(percent(50))` is `fade(@color, 50%)`.
+    /// A raw alpha byte can be given with `Ratio::from_u8` instead (`128` for `50%`).
+    ///
+    /// # Examples
+    /// ```
+    /// use css_colors::{Color, rgb, rgba, percent, Ratio};
+    ///
+    /// let tomato = rgba(255, 99, 71, 0.5);
+    /// let cornflower_blue = rgb(100, 149, 237);
+    ///
+    /// assert_eq!(tomato.fade(percent(25)), rgba(255, 99, 71, 0.25));
+    /// assert_eq!(cornflower_blue.fade(percent(50)), rgba(100, 149, 237, 0.5));
+    /// assert_eq!(cornflower_blue.fade(percent(50)).a, Ratio::from_u8(128));
+    /// ```
+    fn fade(self, amount: Ratio) -> Self::Alpha
+    where
+        Self: Sized;
+
+    /// Rotate the hue angle of `self` in either direction.
+    /// Returns the color in the same model as `self` (with the same alpha) once it has
+    /// been spun. RGB colors are spun through HSL, so only HSL colors spin losslessly.
+    /// For more, see Less' [Color Operations](http://lesscss.org/functions/#color-operations-spin).
+    ///
+    /// # Examples
+    /// ```
+    /// use css_colors::{Color, rgb, hsl, deg};
+    ///
+    /// let red = hsl(10, 90, 50);
+    /// let pink = rgb(243, 13, 90);
+    ///
+    /// assert_eq!(red.spin(deg(30)), hsl(40, 90, 50));
+    /// assert_eq!(pink.spin(deg(-30)), rgb(243, 13, 205));
+    /// ```
+    fn spin(self, amount: Angle) -> Self
+    where
+        Self: Sized;
+
+    /// Rotates the hue angle of `self` by a fractional amount of degrees, in either
+    /// direction. Returns the color in the same model as `self`.
+    ///
+    /// The color models that store their channels as bytes can only hold whole
+    /// degrees, so each call rounds its result, and repeated small rotations (e.g.
+    /// `0.5°` per frame of an animation) stall. `RGBFloat` keeps fractional hues,
+    /// so its rotations accumulate across calls.
+    ///
+    /// # Examples
+    /// ```
+    /// use css_colors::{Color, hsl, rgba, RGBFloat};
+    ///
+    /// assert_eq!(hsl(10, 90, 50).spin_f32(29.6), hsl(40, 90, 50));
+    ///
+    /// let mut color = RGBFloat::from(rgba(250, 128, 114, 1.0));
+    ///
+    /// for _ in 0..60 {
+    ///     color = color.spin_f32(0.5);
+    /// }
+    ///
+    /// assert_eq!(color.to_rgba(), rgba(250, 128, 114, 1.0).spin_f32(30.0));
+    /// ```
+    fn spin_f32(self, degrees: f32) -> Self
+    where
+        Self: Sized,
+    {
+        Self::from_rgba(RGBFloat::from(self.to_rgba()).spin_f32(degrees).quantize())
+    }
+
+    /// Offsets several channels of `self` at once (see `ColorAdjustment`), like
+    /// Sass' [`adjust-color`](https://sass-lang.com/documentation/modules/color#adjust),
+    /// and returns the color in the same model as `self`.
+    ///
+    /// The HSL channels are adjusted within a single HSL conversion, which avoids
+    /// the rounding errors of chaining `spin`, `saturate` and `lighten`. Adjusting
+    /// both RGB and HSL channels will cause a panic.
+    ///
+    /// # Examples
+    /// ```
+    /// use css_colors::{Color, ColorAdjustment, hsla};
+    ///
+    /// let adjustment = ColorAdjustment {
+    ///     hue: Some(10),
+    ///     saturation: Some(-5),
+    ///     alpha: Some(-0.1),
+    ///     ..Default::default()
+    /// };
+    ///
+    /// assert_eq!(hsla(120, 80, 50, 1.0).adjust(adjustment), hsla(130, 75, 50, 0.9));
+    /// ```
+    fn adjust(self, adjustment: ColorAdjustment) -> Self
+    where
+        Self: Sized,
+    {
+        if adjustment.adjusts_hsl() {
+            adjust::adjust_hsl(self, adjustment)
+        } else {
+            adjust::adjust_rgb(self, adjustment)
+        }
+    }
+
+    /// Assigns several channels of `self` at once (see `ColorChange`), like Sass'
+    /// [`change-color`](https://sass-lang.com/documentation/modules/color#change),
+    /// and returns the color in the same model as `self`. Unlike `fade`, changing
+    /// the alpha channel doesn't change the color model; colors without an alpha
+    /// channel stay opaque. Changing both RGB and HSL channels will cause a panic.
+    ///
+    /// # Examples
+    /// ```
+    /// use css_colors::{Color, ColorChange, rgba};
+    ///
+    /// let change = ColorChange {
+    ///     blue: Some(255),
+    ///     alpha: Some(0.5),
+    ///     ..Default::default()
+    /// };
+    ///
+    /// assert_eq!(rgba(250, 128, 114, 1.0).change(change), rgba(250, 128, 255, 0.5));
+    /// ```
+    fn change(self, change: ColorChange) -> Self
+    where
+        Self: Sized,
+    {
+        if change.changes_hsl() {
+            adjust::change_hsl(self, change)
+        } else {
+            adjust::change_rgb(self, change)
+        }
+    }
+
+    /// Returns the complement of `self`: its hue rotated by half a turn, in the
+    /// same color model (and with the same alpha). Greys have no hue, so they are
+    /// returned unchanged. For more, see Sass' [`complement`](https://sass-lang.com/documentation/modules/color#complement).
+    ///
+    /// # Examples
+    /// ```
+    /// use css_colors::{Color, hsl, hsla, rgb};
+    ///
+    /// assert_eq!(hsl(30, 100, 50).complement(), hsl(210, 100, 50));
+    /// assert_eq!(hsla(30, 100, 50, 0.5).complement(), hsla(210, 100, 50, 0.5));
+    /// assert_eq!(hsl(30, 0, 50).complement(), hsl(30, 0, 50));
+    /// assert_eq!(rgb(128, 128, 128).complement(), rgb(128, 128, 128));
+    /// ```
+    fn complement(self) -> Self
+    where
+        Self: Sized,
+    {
+        if self.saturation() == percent(0) {
+            self
+        } else {
+            self.spin(deg(180))
+        }
+    }
+
+    /// Returns the triadic color scheme of `self`: `self`, then its hue rotated by
+    /// a third and two thirds of a turn, in the same color model (and with the
+    /// same saturation, lightness and alpha). Like in `complement`, greys have no
+    /// hue, so they are returned three times.
+    ///
+    /// # Examples
+    /// ```
+    /// use css_colors::{Color, hsl, hsla, rgb};
+    ///
+    /// assert_eq!(hsl(30, 100, 50).triadic(), [hsl(30, 100, 50), hsl(150, 100, 50), hsl(270, 100, 50)]);
+    /// assert_eq!(hsla(300, 50, 40, 0.5).triadic()[1], hsla(60, 50, 40, 0.5));
+    /// assert_eq!(rgb(128, 128, 128).triadic(), [rgb(128, 128, 128); 3]);
+    /// ```
+    fn triadic(self) -> [Self; 3]
+    where
+        Self: Sized + Copy,
+    {
+        [
+            self,
+            harmony::rotate_hue(self, 120),
+            harmony::rotate_hue(self, 240),
+        ]
+    }
+
+    /// Returns the split-complementary color scheme of `self`: `self`, then the two
+    /// hues `spread` away from its complement on either side (`30°` is the usual
+    /// spread), in the same color model. A spread of `0°` gives the complement
+    /// twice, see `complement` for the two-color scheme. Like in `complement`,
+    /// greys have no hue, so they are returned three times.
+    ///
+    /// # Examples
+    /// ```
+    /// use css_colors::{Color, hsl, deg};
+    ///
+    /// let red = hsl(350, 100, 50);
+    ///
+    /// assert_eq!(red.split_complementary(deg(30)), [red, hsl(140, 100, 50), hsl(200, 100, 50)]);
+    /// assert_eq!(red.split_complementary(deg(0))[1], red.complement());
+    /// ```
+    fn split_complementary(self, spread: Angle) -> [Self; 3]
+    where
+        Self: Sized + Copy,
+    {
+        let spread = spread.degrees() as i32;
+
+        [
+            self,
+            harmony::rotate_hue(self, 180 - spread),
+            harmony::rotate_hue(self, 180 + spread),
+        ]
+    }
+
+    /// Returns `count` analogous colors of `self`: hues `spread` apart (`30°` is
+    /// the usual spread) and centered on the hue of `self`, in the same color
+    /// model (and with the same saturation, lightness and alpha), from the lowest
+    /// hue to the highest.
+    ///
+    /// With an odd `count`, `self` is the middle color. With an even `count`,
+    /// `self` falls between the two middle colors, which are half a `spread` away
+    /// from it (rounded to whole degrees). A `count` of `1` gives `self`, and `0`
+    /// gives an empty `Vec`. Greys have no hue, so they are repeated.
+    ///
+    /// # Examples
+    /// ```
+    /// use css_colors::{Color, hsl, deg};
+    ///
+    /// let red = hsl(0, 100, 50);
+    ///
+    /// assert_eq!(red.analogous(3, deg(30)), vec![hsl(330, 100, 50), red, hsl(30, 100, 50)]);
+    /// assert_eq!(red.analogous(2, deg(30)), vec![hsl(345, 100, 50), hsl(15, 100, 50)]);
+    /// ```
+    fn analogous(self, count: usize, spread: Angle) -> Vec<Self>
+    where
+        Self: Sized + Copy,
+    {
+        let spread = spread.degrees() as f32;
+        let middle = (count as f32 - 1.0) / 2.0;
+
+        (0..count)
+            .map(|i| {
+                let offset = (i as f32 - middle) * spread;
+
+                harmony::rotate_hue(self, offset.round() as i32)
+            })
+            .collect()
+    }
+
+    /// Returns the tetradic (or rectangle) color scheme of `self`: `self`, its hue
+    /// rotated by `offset`, its complement, and the complement rotated by `offset`,
+    /// in the same color model (and with the same alpha). An offset of `90°` gives
+    /// the square scheme, with four hues evenly spaced. Like in `complement`,
+    /// greys have no hue, so they are returned four times.
+    ///
+    /// # Examples
+    /// ```
+    /// use css_colors::{Color, hsl, deg};
+    ///
+    /// let red = hsl(0, 100, 50);
+    ///
+    /// assert_eq!(
+    ///     red.tetradic(deg(60)),
+    ///     [red, hsl(60, 100, 50), hsl(180, 100, 50), hsl(240, 100, 50)]
+    /// );
+    /// assert_eq!(red.tetradic(deg(90))[3], hsl(270, 100, 50));
+    /// ```
+    fn tetradic(self, offset: Angle) -> [Self; 4]
+    where
+        Self: Sized + Copy,
+    {
+        let offset = offset.degrees() as i32;
+
+        [
+            self,
+            harmony::rotate_hue(self, offset),
+            harmony::rotate_hue(self, 180),
+            harmony::rotate_hue(self, 180 + offset),
+        ]
+    }
+
+    /// Returns a monochromatic ladder of `steps` colors with the hue of `self`, from
+    /// a near-white tint to a near-black shade, in the same color model (and with
+    /// the same alpha), like the `50` to `900` shades of a design system.
+    ///
+    /// The curve is fixed, so that palettes stay stable across versions:
+    ///
+    /// * the lightness steps evenly from `95%` down to `15%`;
+    /// * the saturation of `self` is raised toward both ends, by up to `15%` of
+    ///   itself, with the square of the distance from the middle of the ladder,
+    ///   so that the ends don't look washed out;
+    /// * `self` replaces the step closest to its own lightness, rather than being
+    ///   forced into the middle.
+    ///
+    /// A single step gives `self`, and no steps give an empty `Vec`.
+    ///
+    /// # Examples
+    /// ```
+    /// use css_colors::{Color, hsl};
+    ///
+    /// let blue = hsl(210, 80, 40);
+    /// let ladder = blue.monochromatic(5);
+    ///
+    /// assert_eq!(ladder[0], hsl(210, 92, 95));
+    /// assert_eq!(ladder[2], hsl(210, 80, 55));
+    /// assert_eq!(ladder[3], blue);
+    /// assert_eq!(ladder[4], hsl(210, 92, 15));
+    /// ```
+    fn monochromatic(self, steps: usize) -> Vec<Self>
+    where
+        Self: Sized + Copy,
+    {
+        harmony::monochromatic(self, steps)
+    }
+
+    /// Returns the inverse (or negative) of `self`: every red, green and blue
+    /// channel `c` becomes `255 - c`, in the same color model. The alpha channel
+    /// is preserved. For more, see Sass' [`invert`](https://sass-lang.com/documentation/modules/color#invert).
+    ///
+    /// # Examples
+    /// ```
+    /// use css_colors::{Color, hsl, rgb, rgba};
+    ///
+    /// assert_eq!(rgb(255, 255, 255).invert(), rgb(0, 0, 0));
+    /// assert_eq!(rgba(250, 128, 114, 0.5).invert(), rgba(5, 127, 141, 0.5));
+    /// assert_eq!(hsl(0, 100, 50).invert().to_string(), "hsl(180, 100%, 50%)");
+    /// ```
+    fn invert(self) -> Self
+    where
+        Self: Sized,
+    {
+        ops::apply_to_rgb_channels(self, |channel| 1.0 - channel)
+    }
+
+    /// Mixes the inverse of `self` (see `invert`) with `self`, where `weight` is
+    /// the percentage of the inverse: `100` fully inverts `self`, and `0` leaves it
+    /// unchanged. Values above `100` are clamped (see [Weights](#weights)). For more, see Sass'
+    /// [`invert`](https://sass-lang.com/documentation/modules/color#invert).
+    ///
+    /// # Examples
+    /// ```
+    /// use css_colors::{Color, rgb, rgba};
+    ///
+    /// let salmon = rgb(250, 128, 114);
+    ///
+    /// assert_eq!(salmon.invert_weighted(100), rgba(5, 127, 141, 1.0));
+    /// assert_eq!(salmon.invert_weighted(0), rgba(250, 128, 114, 1.0));
+    /// assert_eq!(rgb(255, 255, 255).invert_weighted(50), rgba(127, 127, 127, 1.0));
+    /// ```
+    fn invert_weighted(self, weight: u8) -> Self::Alpha
+    where
+        Self: Sized,
+    {
+        let original = self.to_rgba();
+
+        let weight = ops::clamp_weight(weight as f32 / 100.0);
+
+        self.invert().mix(original, Ratio::from_f32(weight))
+    }
+
+    /// Multiplies the red, green and blue channels of `self` by `factor`, like the
+    /// [`brightness()`](https://www.w3.org/TR/filter-effects-1/#brightnessEquivalent)
+    /// filter of CSS: `0.0` turns the color black, `1.0` leaves it unchanged, and
+    /// larger factors brighten it, clamping the channels at `255`. As browsers do
+    /// for filter functions, the gamma-encoded channels are multiplied. The alpha
+    /// channel is preserved.
+    ///
+    /// Unlike `lighten`, which adds to the HSL lightness, this is multiplicative:
+    /// chaining it reproduces a `filter: brightness(...)` chain.
+    ///
+    /// Negative factors will cause a panic.
+    ///
+    /// # Examples
+    /// ```
+    /// use css_colors::{Color, rgb, rgba};
+    ///
+    /// let sienna = rgb(160, 82, 46);
+    ///
+    /// assert_eq!(sienna.brightness(0.5), rgb(80, 41, 23));
+    /// assert_eq!(sienna.brightness(2.0), rgb(255, 164, 92));
+    /// assert_eq!(rgba(160, 82, 46, 0.5).brightness(0.0), rgba(0, 0, 0, 0.5));
+    /// ```
+    fn brightness(self, factor: f32) -> Self
+    where
+        Self: Sized,
+    {
+        assert!(factor >= 0.0, "Invalid factor for brightness");
+
+        ops::apply_to_rgb_channels(self, |channel| channel * factor)
+    }
+
+    /// Scales the red, green and blue channels of `self` around `50%` by `factor`,
+    /// like the [`contrast()`](https://www.w3.org/TR/filter-effects-1/#contrastEquivalent)
+    /// filter of CSS: each channel `c` becomes `(c - 0.5) × factor + 0.5`. `0.0`
+    /// turns the color into a mid grey, `1.0` leaves it unchanged, and larger
+    /// factors push the channels apart, clamping them. As browsers do for filter
+    /// functions, the gamma-encoded channels are scaled. The alpha channel is
+    /// preserved.
+    ///
+    /// It's named after the CSS function, but `contrast` is the Less function that
+    /// picks a readable color (see `contrast`).
+    ///
+    /// Negative factors will cause a panic.
+    ///
+    /// # Examples
+    /// ```
+    /// use css_colors::{Color, rgb};
+    ///
+    /// let sienna = rgb(160, 82, 46);
+    ///
+    /// assert_eq!(sienna.contrast_adjust(0.0), rgb(128, 128, 128));
+    /// assert_eq!(sienna.contrast_adjust(0.5), rgb(144, 105, 87));
+    /// assert_eq!(sienna.contrast_adjust(1.5), rgb(176, 59, 5));
+    /// ```
+    fn contrast_adjust(self, factor: f32) -> Self
+    where
+        Self: Sized,
+    {
+        assert!(factor >= 0.0, "Invalid factor for contrast");
+
+        ops::apply_to_rgb_channels(self, |channel| (channel - 0.5) * factor + 0.5)
+    }
+
+    /// Rotates the hue of `self` like the [`hue-rotate()`](https://www.w3.org/TR/filter-effects-1/#huerotateEquivalent)
+    /// filter of CSS, which applies the `hueRotate` matrix of SVG's feColorMatrix to
+    /// the red, green and blue channels, clamping the result. As browsers do for
+    /// filter functions, the gamma-encoded channels are transformed. The alpha
+    /// channel is preserved.
+    ///
+    /// Unlike `spin`, which rotates the HSL hue and keeps the HSL saturation and
+    /// lightness, the matrix roughly preserves the luma: rotating pure red by
+    /// `180°` gives a dark teal rather than a bright cyan.
+    ///
+    /// # Examples
+    /// ```
+    /// use css_colors::{Color, deg, hsl, rgb};
+    ///
+    /// let red = rgb(255, 0, 0);
+    ///
+    /// // `spin` keeps the HSL lightness, so red turns into a bright cyan...
+    /// assert_eq!(red.spin(deg(180)).to_hsl(), hsl(180, 100, 50));
+    /// // ...while `hue_rotate` keeps its luma.
+    /// assert_eq!(red.hue_rotate(deg(180)), rgb(0, 109, 109));
+    /// assert_eq!(red.hue_rotate(deg(90)), rgb(0, 91, 0));
+    /// ```
+    fn hue_rotate(self, angle: Angle) -> Self
+    where
+        Self: Sized,
+    {
+        ops::apply_color_matrix(self, &ops::hue_rotate_matrix(angle.degrees() as f32))
+    }
+
+    /// Tones `self` in sepia like the [`sepia()`](https://www.w3.org/TR/filter-effects-1/#sepiaEquivalent)
+    /// filter of CSS: its matrix is interpolated with the identity by `amount`, from
+    /// `0.0` (unchanged) to `1.0` (fully sepia), and applied to the gamma-encoded
+    /// red, green and blue channels, clamping the result. As in CSS, amounts above
+    /// `1.0` are clamped to it. The alpha channel is preserved.
+    ///
+    /// Negative amounts will cause a panic.
+    ///
+    /// # Examples
+    /// ```
+    /// use css_colors::{Color, rgb, rgba};
+    ///
+    /// assert_eq!(rgb(255, 255, 255).sepia(1.0), rgb(255, 255, 239));
+    /// assert_eq!(rgb(100, 149, 237).sepia(1.0), rgb(199, 177, 138));
+    /// assert_eq!(rgba(100, 149, 237, 0.5).sepia(0.0), rgba(100, 149, 237, 0.5));
+    /// ```
+    fn sepia(self, amount: f32) -> Self
+    where
+        Self: Sized,
+    {
+        assert!(amount >= 0.0, "Invalid amount for sepia");
+
+        ops::apply_color_matrix(self, &ops::sepia_matrix(amount.min(1.0)))
+    }
+
+    /// Snaps each red, green and blue channel of `self` to the nearest of `levels`
+    /// evenly spaced values, from `0` to `255`: with `2` levels, every channel is
+    /// either `0` or `255`, and with `4`, one of `0`, `85`, `170` or `255`. The alpha
+    /// channel is preserved, and other color models go through RGB.
+    ///
+    /// Fewer than `2` levels will cause a panic.
+    ///
+    /// # Examples
+    /// ```
+    /// use css_colors::{Color, rgb, rgba};
+    ///
+    /// let salmon = rgb(250, 128, 114);
+    ///
+    /// assert_eq!(salmon.posterize(2), rgb(255, 255, 0));
+    /// assert_eq!(salmon.posterize(4), rgb(255, 170, 85));
+    /// assert_eq!(rgba(250, 128, 114, 0.5).posterize(6), rgba(255, 153, 102, 0.5));
+    /// ```
+    fn posterize(self, levels: u8) -> Self
+    where
+        Self: Sized,
+    {
+        assert!(levels >= 2, "Invalid number of levels, must be at least 2");
+
+        let steps = f32::from(levels - 1);
+
+        ops::apply_to_rgb_channels(self, |channel| (channel * steps).round() / steps)
+    }
+
+    /// Mixes two colors (`self` and any other `Color`) together in variable proportion.
+    /// Takes opacity into account in the calculations.
+    /// For more, see Less' [Color Operations](http://lesscss.org/functions/#color-operations-mix).
+    ///
+    /// # Examples
+    /// ```
+    /// use css_colors::{Color, rgb, rgba, hsl, hsla, percent};
+    ///
+    /// let red = hsl(10, 90, 50);
+    /// let golden = rgb(243, 166, 13);
+    /// let navy = rgba(0, 0, 80, 1.0);
+    ///
+    /// assert_eq!(red.mix(navy, percent(50)).to_string(), "hsla(347, 65%, 29%, 1.00)");
+    /// assert_eq!(golden.mix(navy, percent(25)), rgba(61, 42, 63, 1.0));
+    /// ```
+    fn mix<T: Color>(self, other: T, weight: Ratio) -> Self::Alpha
+    where
+        Self: Sized;
+
+    /// Mixes two colors like `mix`, with a `weight` between `0.0` and `1.0` that is
+    /// finer than the 256 steps of a `Ratio`, e.g. to generate long gradients.
+    /// `mix` forwards to this method, so both agree on the weights they share.
+    ///
+    /// Weights outside of `0.0-1.0` are clamped (see [Weights](#weights)).
+    ///
+    /// # Examples
+    /// ```
+    /// use css_colors::{Color, rgb, rgba, percent};
+    ///
+    /// let golden = rgb(243, 166, 13);
+    /// let navy = rgba(0, 0, 80, 1.0);
+    ///
+    /// assert_eq!(golden.mix_f32(navy, 0.25), golden.mix(navy, percent(25)));
+    /// assert_eq!(golden.mix_f32(navy, 0.2525), rgba(61, 42, 63, 1.0));
+    /// assert_eq!(golden.mix_f32(navy, 0.2575), rgba(63, 43, 63, 1.0));
+    /// ```
+    fn mix_f32<T: Color>(self, other: T, weight: f32) -> Self::Alpha
+    where
+        Self: Sized,
+    {
+        Self::Alpha::from_rgba(ops::mix_rgba(self.to_rgba(), other.to_rgba(), weight))
+    }
+
+    /// Mixes two colors like `mix`, in linear light rather than on the
+    /// gamma-encoded channels. This is how light actually adds up, so midpoints
+    /// aren't darkened: half black and half white gives a grey that looks halfway
+    /// between them. `mix` stays the default, as it matches Less.
+    ///
+    /// The alpha channel isn't gamma-encoded, so it is mixed like in `mix`.
+    ///
+    /// # Examples
+    /// ```
+    /// use css_colors::{Color, rgb, rgba, percent};
+    ///
+    /// let black = rgb(0, 0, 0);
+    /// let white = rgb(255, 255, 255);
+    ///
+    /// assert_eq!(black.mix(white, percent(50)), rgba(127, 127, 127, 1.0));
+    /// assert_eq!(black.mix_linear(white, percent(50)), rgba(187, 187, 187, 1.0));
+    /// ```
+    fn mix_linear<T: Color>(self, other: T, weight: Ratio) -> Self::Alpha
+    where
+        Self: Sized,
+    {
+        Self::Alpha::from_rgba(ops::mix_rgba_linear(
+            self.to_rgba(),
+            other.to_rgba(),
+            weight.as_f32(),
+        ))
+    }
+
+    /// Interpolates linearly from `self` to `other`, e.g. to animate a color:
+    /// `t` goes from `0.0` (which gives `self`) to `1.0` (which gives `other`),
+    /// and is clamped into that range.
+    ///
+    /// **Note:** this is the opposite of the weight of `mix`, which is the
+    /// proportion of `self`. Unlike `mix`, the alpha channel doesn't weight the
+    /// other channels: all four are interpolated independently, and rounded once.
+    ///
+    /// # Examples
+    /// ```
+    /// use css_colors::{Color, rgb, rgba, percent};
+    ///
+    /// let golden = rgb(243, 166, 13);
+    /// let navy = rgb(0, 0, 80);
+    ///
+    /// assert_eq!(golden.lerp(navy, 0.0), golden.to_rgba());
+    /// assert_eq!(golden.lerp(navy, 1.0), navy.to_rgba());
+    /// assert_eq!(golden.lerp(navy, 0.75), golden.mix(navy, percent(25)));
+    /// assert_eq!(golden.lerp(rgba(0, 0, 80, 0.0), 0.5), rgba(122, 83, 47, 0.5));
+    /// ```
+    fn lerp<T: Color>(self, other: T, t: f32) -> RGBA
+    where
+        Self: Sized,
+    {
+        self.lerp_in(other, t, InterpolationSpace::Srgb)
+    }
+
+    /// Interpolates from `self` to `other` like `lerp`, in the given color space.
+    /// In `Oklab`, the perceived lightness changes evenly with `t`, which avoids
+    /// the dull or uneven midpoints of sRGB.
+    ///
+    /// # Examples
+    /// ```
+    /// use css_colors::{Color, HuePath, InterpolationSpace, rgb, rgba};
+    ///
+    /// let red = rgb(255, 0, 0);
+    /// let blue = rgb(0, 0, 255);
+    ///
+    /// assert_eq!(red.lerp_in(blue, 0.5, InterpolationSpace::Srgb), red.lerp(blue, 0.5));
+    /// assert_eq!(red.lerp_in(blue, 0.5, InterpolationSpace::Oklab), rgba(140, 83, 162, 1.0));
+    ///
+    /// let magenta = red.lerp_in(blue, 0.5, InterpolationSpace::Hsl(HuePath::Shorter));
+    ///
+    /// assert_eq!(magenta.to_hsl().h.degrees(), 300);
+    /// ```
+    fn lerp_in<T: Color>(self, other: T, t: f32, space: InterpolationSpace) -> RGBA
+    where
+        Self: Sized,
+    {
+        interpolation::interpolate(self.to_rgba(), other.to_rgba(), t, space)
+    }
+
+    /// Returns `n` colors evenly spaced from `self` to `other`, both included,
+    /// interpolated like `lerp` (e.g. for the series of a chart). With a single
+    /// color, `self` is returned, and no colors give an empty `Vec`, like
+    /// `Gradient::steps`.
+    ///
+    /// # Examples
+    /// ```
+    /// use css_colors::{Color, rgb, rgba};
+    ///
+    /// let black = rgb(0, 0, 0);
+    /// let red = rgb(255, 0, 0);
+    ///
+    /// assert_eq!(black.steps(red, 2), vec![rgba(0, 0, 0, 1.0), rgba(255, 0, 0, 1.0)]);
+    /// assert_eq!(black.steps(red, 4)[1], rgba(85, 0, 0, 1.0));
+    /// assert_eq!(black.steps(red, 1), vec![rgba(0, 0, 0, 1.0)]);
+    /// assert!(black.steps(red, 0).is_empty());
+    /// ```
+    fn steps<T: Color>(self, other: T, n: usize) -> Vec<RGBA>
+    where
+        Self: Sized,
+    {
+        self.steps_in(other, n, InterpolationSpace::Srgb)
+    }
+
+    /// Returns `n` colors evenly spaced from `self` to `other` like `steps`,
+    /// interpolated in the given color space (see `lerp_in`).
+    ///
+    /// # Examples
+    /// ```
+    /// use css_colors::{Color, InterpolationSpace, rgb, rgba};
+    ///
+    /// let black = rgb(0, 0, 0);
+    /// let white = rgb(255, 255, 255);
+    ///
+    /// assert_eq!(black.steps_in(white, 3, InterpolationSpace::Srgb)[1], rgba(128, 128, 128, 1.0));
+    /// assert_eq!(black.steps_in(white, 3, InterpolationSpace::Oklab)[1], rgba(99, 99, 99, 1.0));
+    /// ```
+    fn steps_in<T: Color>(self, other: T, n: usize, space: InterpolationSpace) -> Vec<RGBA>
+    where
+        Self: Sized,
+    {
+        let (from, to) = (self.to_rgba(), other.to_rgba());
+
+        if n == 1 {
+            return vec![from];
+        }
+
+        (0..n)
+            .map(|step| interpolation::interpolate(from, to, step as f32 / (n - 1) as f32, space))
+            .collect()
+    }
+
+    /// Interpolates from `self` to `other` in HSL, which goes around the color
+    /// wheel instead of through the muddy greys between two hues in RGB. `t` goes
+    /// from `0.0` (`self`) to `1.0` (`other`) like in `lerp`, and `path` chooses
+    /// which way around the wheel the hue goes, like the hue interpolation methods
+    /// of CSS.
+    ///
+    /// The saturation, lightness and alpha are interpolated independently. A grey
+    /// (with no saturation) has no hue, so the hue of the other color is kept all
+    /// along, even with `HuePath::Longer`.
+    ///
+    /// # Examples
+    /// ```
+    /// use css_colors::{Color, HuePath, hsl, hsla, rgb};
+    ///
+    /// let red = hsl(350, 100, 50);
+    /// let orange = hsl(10, 100, 50);
+    ///
+    /// assert_eq!(red.lerp_hsl(orange, 0.5, HuePath::Shorter), hsla(0, 100, 50, 1.0));
+    /// assert_eq!(red.lerp_hsl(orange, 0.5, HuePath::Longer), hsla(180, 100, 50, 1.0));
+    /// assert_eq!(rgb(255, 0, 0).lerp_hsl(rgb(0, 0, 255), 0.5, HuePath::Decreasing), hsla(300, 100, 50, 1.0));
+    /// assert_eq!(hsl(0, 0, 0).lerp_hsl(orange, 0.5, HuePath::Shorter), hsla(10, 50, 25, 1.0));
+    /// ```
+    fn lerp_hsl<T: Color>(self, other: T, t: f32, path: HuePath) -> HSLA
+    where
+        Self: Sized,
+    {
+        let (from, to) = (self.to_hsla(), other.to_hsla());
+        let t = t.clamp(0.0, 1.0);
+
+        // Greys have no hue to interpolate from or to, so the hue of the other
+        // color is kept, whichever the path (and between two greys, the hue of
+        // `self`).
+        let hue = match (from.s.as_u8() == 0, to.s.as_u8() == 0) {
+            (true, false) => to.h.degrees() as f32,
+            (_, true) => from.h.degrees() as f32,
+            (false, false) => path.interpolate(from.h.degrees() as f32, to.h.degrees() as f32, t),
+        };
+
+        let lerp = |from: Ratio, to: Ratio| {
+            ops::quantize(from.as_f32() + (to.as_f32() - from.as_f32()) * t)
+        };
+
+        HSLA {
+            h: deg(hue.round() as i32),
+            s: lerp(from.s, to.s),
+            l: lerp(from.l, to.l),
+            a: lerp(from.a, to.a),
+        }
+    }
+
+    /// Mixes `self` with white in variable proportion.
+    /// Equivalent to calling `mix()` with `white` (`rgb(255, 255, 255)`).
+    /// For more, see Less' [Color Operations](http://lesscss.org/functions/#color-operations-tint).
+    ///
+    /// # Examples
+    /// ```
+    /// use css_colors::{Color, rgb, rgba, hsl, percent};
+    ///
+    /// let red = hsl(10, 90, 50);
+    /// let golden = rgb(243, 166, 13);
+    ///
+    /// assert_eq!(red.tint(percent(10)), hsl(10, 90, 95));
+    /// assert_eq!(golden.tint(percent(25)), rgb(252, 233, 194));
+    /// ```
+    fn tint(self, weight: Ratio) -> Self
+    where
+        Self: Sized;
+
+    /// Mixes `self` with white in variable proportion.
+    /// Equivalent to calling `mix()` with `black` (`rgb(0, 0, 0)`).
+    /// For more, see Less' [Color Operations](http://lesscss.org/functions/#color-operations-shade).
+    ///
+    /// # Examples
+    /// ```
+    /// use css_colors::{Color, rgb, rgba, hsl, percent};
+    ///
+    /// let red = hsl(10, 90, 50);
+    /// let golden = rgb(243, 166, 13);
+    ///
+    /// assert_eq!(red.shade(percent(10)).to_string(), "hsl(10, 89%, 5%)");
+    /// assert_eq!(golden.shade(percent(25)), rgb(61, 42, 3));
+    /// ```
+    fn shade(self, weight: Ratio) -> Self
+    where
+        Self: Sized;
+
+    /// Returns `n` tints of `self`, mixed toward white in even increments, from the
+    /// closest to `self` to the closest to white. Neither `self` nor white are
+    /// included: the tints are `tint`s of `self` with weights of `n / (n + 1)`
+    /// down to `1 / (n + 1)` (each rounded to a `Ratio`), e.g. `80%`, `60%`, `40%`
+    /// and `20%` for four tints.
+    ///
+    /// # Examples
+    /// ```
+    /// use css_colors::{Color, rgb, rgba, percent};
+    ///
+    /// let golden = rgb(243, 166, 13);
+    /// let tints = golden.tints(4);
+    ///
+    /// assert_eq!(tints.len(), 4);
+    /// assert_eq!(tints[1], golden.tint(percent(60)).to_rgba());
+    /// assert_eq!(tints[3], rgba(253, 237, 207, 1.0));
+    /// ```
+    fn tints(self, n: usize) -> Vec<RGBA>
+    where
+        Self: Sized + Copy,
+    {
+        (1..=n)
+            .map(|step| {
+                let weight = Ratio::from_f32(1.0 - step as f32 / (n + 1) as f32);
+
+                self.tint(weight).to_rgba()
+            })
+            .collect()
+    }
+
+    /// Returns `n` shades of `self`, mixed toward black in even increments, like
+    /// `tints`: the shades are `shade`s of `self` with weights of `n / (n + 1)`
+    /// down to `1 / (n + 1)`, so neither `self` nor black are included.
+    ///
+    /// # Examples
+    /// ```
+    /// use css_colors::{Color, rgb, percent};
+    ///
+    /// let golden = rgb(243, 166, 13);
+    /// let shades = golden.shades(4);
+    ///
+    /// assert_eq!(shades.len(), 4);
+    /// assert_eq!(shades[1], golden.shade(percent(60)).to_rgba());
+    /// ```
+    fn shades(self, n: usize) -> Vec<RGBA>
+    where
+        Self: Sized + Copy,
+    {
+        (1..=n)
+            .map(|step| {
+                let weight = Ratio::from_f32(1.0 - step as f32 / (n + 1) as f32);
+
+                self.shade(weight).to_rgba()
+            })
+            .collect()
+    }
+
+    /// Remove all saturation from `self` in the HSL color space.
+    /// Equivalent to calling `desaturate(0)` on a color.
+    /// For more, see Less' [Color Operations](http://lesscss.org/functions/#color-operations-greyscale).
+    ///
+    /// **The HSL lightness is not the perceived brightness**: every fully saturated
+    /// color, from yellow to blue, turns into the same `50%` grey. To keep the
+    /// brightness of the color, see `greyscale_luma`.
+    ///
+    /// # Examples
+    /// ```
+    /// use css_colors::{Color, rgb, rgba};
+    ///
+    /// let tomato = rgba(255, 99, 71, 1.0);
+    /// let cornflower_blue = rgb(100, 149, 237);
+    ///
+    /// assert_eq!(tomato.greyscale(), rgba(163, 163, 163, 1.0));
+    /// assert_eq!(cornflower_blue.greyscale(), rgb(169, 169, 169));
+    /// ```
+    fn greyscale(self) -> Self
+    where
+        Self: Sized;
+
+    /// Turns `self` into the grey with the same Rec. 709 luma: the red, green and
+    /// blue channels are weighted (`0.2126`, `0.7152` and `0.0722`) in linear light,
+    /// and the result is encoded back into sRGB. The alpha channel is preserved.
+    ///
+    /// Unlike `greyscale`, which keeps the HSL lightness, bright colors stay light
+    /// and dark ones stay dark.
+    ///
+    /// # Examples
+    /// ```
+    /// use css_colors::{Color, rgb, rgba};
+    ///
+    /// let yellow = rgb(255, 255, 0);
+    /// let blue = rgb(0, 0, 255);
+    ///
+    /// assert_eq!(yellow.greyscale(), blue.greyscale());
+    /// assert_eq!(yellow.greyscale_luma(), rgb(247, 247, 247));
+    /// assert_eq!(blue.greyscale_luma(), rgb(76, 76, 76));
+    /// assert_eq!(rgba(255, 0, 0, 0.5).greyscale_luma(), rgba(127, 127, 127, 0.5));
+    /// ```
+    fn greyscale_luma(self) -> Self
+    where
+        Self: Sized,
+    {
+        let RGBA { r, g, b, a } = self.to_rgba();
+
+        let luma = 0.2126 * ops::linearize(r.as_f32())
+            + 0.7152 * ops::linearize(g.as_f32())
+            + 0.0722 * ops::linearize(b.as_f32());
+        let grey = ops::quantize(ops::delinearize(luma));
+
+        Self::from_rgba(RGBA {
+            r: grey,
+            g: grey,
+            b: grey,
+            a,
+        })
+    }
+
+    /// Turns `self` into a pastel version of itself: much lighter, somewhat
+    /// desaturated, with the same hue. Operates on the color within its HSL
+    /// representation and preserves any existing alpha channel.
+    ///
+    /// The `strength` controls how far the color moves toward the pastel band:
+    /// * the lightness moves linearly toward `85%`, reaching it at a strength of `100%`;
+    /// * the saturation `s` is multiplied by `1 - strength * s / 2`, so vivid colors
+    ///   lose more saturation than muted ones, and end up at `50%` at most.
+    ///
+    /// A strength of `0%` leaves the color unchanged.
+    ///
+    /// # Examples
+    /// ```
+    /// use css_colors::{Color, hsl, hsla, percent};
+    ///
+    /// let red = hsl(0, 100, 50);
+    /// let muted_teal = hsla(180, 40, 30, 0.5);
+    ///
+    /// assert_eq!(red.pastelize(percent(0)), red);
+    /// assert_eq!(red.pastelize(percent(100)), hsl(0, 50, 85));
+    /// assert_eq!(muted_teal.pastelize(percent(100)), hsla(180, 32, 85, 0.5));
+    /// ```
+    fn pastelize(self, strength: Ratio) -> Self
+    where
+        Self: Sized,
+    {
+        // Avoid the round trip through HSL, which is lossy for other color models.
+        if strength == percent(0) {
+            return self;
+        }
+
+        let strength = strength.as_f32();
+
+        ops::apply_to_hsl(self, |hsla| {
+            let HSLA { h, s, l, a } = hsla;
+            let s = s.as_f32();
+            let l = l.as_f32();
+
+            HSLA {
+                h,
+                s: ops::quantize(s * (1.0 - strength * s / 2.0)),
+                l: ops::quantize(l + (0.85 - l) * strength),
+                a,
+            }
+        })
+    }
+
+    /// Mixes two colors (`self` and any other `Color`) together in the Oklab color
+    /// space, which avoids the muddy midpoints of `mix` (e.g. between blue and
+    /// yellow). The `weight` specifies the amount of `self` in the result, and the
+    /// alpha channels are interpolated with the same weight.
+    ///
+    /// # Examples
+    /// ```
+    /// use css_colors::{Color, rgb, rgba, percent};
+    ///
+    /// let blue = rgb(0, 0, 255);
+    /// let yellow = rgb(255, 255, 0);
+    ///
+    /// assert_eq!(blue.mix(yellow, percent(50)), rgba(127, 127, 128, 1.0));
+    /// assert_eq!(blue.mix_oklab(yellow, percent(50)), rgba(107, 171, 199, 1.0));
+    /// ```
+    fn mix_oklab<T: Color>(self, other: T, weight: Ratio) -> Self::Alpha
+    where
+        Self: Sized,
+    {
+        let lhs = self.to_rgba();
+        let rhs = other.to_rgba();
+
+        let RGB { r, g, b } = lhs
+            .to_oklab()
+            .lerp(rhs.to_oklab(), 1.0 - weight.as_f32())
+            .to_rgb();
+        let a = (lhs.a * weight) + (rhs.a * (percent(100) - weight));
+
+        Self::Alpha::from_rgba(RGBA { r, g, b, a })
+    }
+
+    /// Multiplies the channels of `self` (the backdrop) and `other` (the source),
+    /// like the [`multiply` function][less-multiply] of Less: each channel `c1`
+    /// becomes `c1 × c2 / 255`, so the result is darker than both colors. Blending
+    /// with black gives black, and with white leaves `self` unchanged.
+    ///
+    /// Like Less, translucent colors are handled as in the W3C compositing spec:
+    /// the blended channels are weighted by both alpha values, and `other` is
+    /// composited over `self`, so the alpha of the result is `a2 + a1 × (1 - a2)`.
+    ///
+    /// # Examples
+    /// ```
+    /// use css_colors::{Color, rgb, rgba};
+    ///
+    /// let orange = rgb(255, 102, 0);
+    ///
+    /// assert_eq!(orange.multiply(rgb(51, 51, 51)), rgba(51, 20, 0, 1.0));
+    /// assert_eq!(orange.multiply(rgb(255, 255, 255)), orange.to_rgba());
+    /// ```
+    ///
+    /// [less-multiply]: http://lesscss.org/functions/#color-blending-multiply
+    fn multiply<T: Color>(self, other: T) -> Self::Alpha
+    where
+        Self: Sized,
+    {
+        Self::Alpha::from_rgba(blend::blend(
+            self.to_rgba(),
+            other.to_rgba(),
+            blend::multiply,
+        ))
+    }
+
+    /// Screens the channels of `self` (the backdrop) and `other` (the source),
+    /// like the [`screen` function][less-screen] of Less: the opposite of
+    /// `multiply`, each channel `c1` becomes `255 - (255 - c1) × (255 - c2) / 255`,
+    /// so the result is lighter than both colors. Translucent colors are handled
+    /// like in `multiply`.
+    ///
+    /// # Examples
+    /// ```
+    /// use css_colors::{Color, rgb, rgba};
+    ///
+    /// let orange = rgb(255, 102, 0);
+    ///
+    /// assert_eq!(orange.screen(rgb(51, 51, 51)), rgba(255, 133, 51, 1.0));
+    /// assert_eq!(orange.screen(rgb(0, 0, 0)), orange.to_rgba());
+    /// ```
+    ///
+    /// [less-screen]: http://lesscss.org/functions/#color-blending-screen
+    fn screen<T: Color>(self, other: T) -> Self::Alpha
+    where
+        Self: Sized,
+    {
+        Self::Alpha::from_rgba(blend::blend(self.to_rgba(), other.to_rgba(), blend::screen))
+    }
+
+    /// Blends `self` (the backdrop) and `other` (the source) like the
+    /// [`overlay` function][less-overlay] of Less: where `self` is dark, the
+    /// channels are multiplied, and where it's light, they're screened (see
+    /// `multiply` and `screen`), which increases the contrast of `self`.
+    /// Translucent colors are handled like in `multiply`.
+    ///
+    /// # Examples
+    /// ```
+    /// use css_colors::{Color, rgb, rgba};
+    ///
+    /// let orange = rgb(255, 102, 0);
+    ///
+    /// assert_eq!(orange.overlay(rgb(51, 51, 51)), rgba(255, 41, 0, 1.0));
+    /// ```
+    ///
+    /// [less-overlay]: http://lesscss.org/functions/#color-blending-overlay
+    fn overlay<T: Color>(self, other: T) -> Self::Alpha
+    where
+        Self: Sized,
+    {
+        Self::Alpha::from_rgba(blend::blend(
+            self.to_rgba(),
+            other.to_rgba(),
+            blend::overlay,
+        ))
+    }
+
+    /// Blends `self` (the backdrop) and `other` (the source) like the
+    /// [`hardlight` function][less-hardlight] of Less: the same as `overlay`, with
+    /// the roles of the colors swapped, so it's `other` that decides whether the
+    /// channels are multiplied or screened. Translucent colors are handled like in
+    /// `multiply`.
+    ///
+    /// # Examples
+    /// ```
+    /// use css_colors::{Color, rgb, rgba};
+    ///
+    /// let orange = rgb(255, 102, 0);
+    ///
+    /// assert_eq!(orange.hardlight(rgb(51, 51, 51)), rgba(102, 41, 0, 1.0));
+    /// ```
+    ///
+    /// [less-hardlight]: http://lesscss.org/functions/#color-blending-hardlight
+    fn hardlight<T: Color>(self, other: T) -> Self::Alpha
+    where
+        Self: Sized,
+    {
+        Self::Alpha::from_rgba(blend::blend(
+            self.to_rgba(),
+            other.to_rgba(),
+            blend::hard_light,
+        ))
+    }
+
+    /// Blends `self` (the backdrop) and `other` (the source) like the
+    /// [`softlight` function][less-softlight] of Less: a softer `overlay`, which
+    /// darkens `self` where `other` is darker than `50%`, and lightens it where it's
+    /// lighter, following the [soft light formula](https://www.w3.org/TR/compositing-1/#blendingsoftlight)
+    /// of the W3C compositing spec. Translucent colors are handled like in `multiply`.
+    ///
+    /// # Examples
+    /// ```
+    /// use css_colors::{Color, rgb, rgba};
+    ///
+    /// let orange = rgb(255, 102, 0);
+    ///
+    /// assert_eq!(orange.softlight(rgb(51, 51, 51)), rgba(255, 65, 0, 1.0));
+    /// ```
+    ///
+    /// [less-softlight]: http://lesscss.org/functions/#color-blending-softlight
+    fn softlight<T: Color>(self, other: T) -> Self::Alpha
+    where
+        Self: Sized,
+    {
+        Self::Alpha::from_rgba(blend::blend(
+            self.to_rgba(),
+            other.to_rgba(),
+            blend::soft_light,
+        ))
+    }
+
+    /// Subtracts the channels of `other` (the source) from those of `self` (the
+    /// backdrop), or the other way around, like the [`difference` function][less-difference]
+    /// of Less: each channel becomes `|c1 - c2|`. Blending with black leaves `self`
+    /// unchanged, and with white inverts it. Translucent colors are handled like in
+    /// `multiply`.
+    ///
+    /// # Examples
+    /// ```
+    /// use css_colors::{Color, rgb, rgba};
+    ///
+    /// let orange = rgb(255, 102, 0);
+    ///
+    /// assert_eq!(orange.difference(rgb(51, 51, 51)), rgba(204, 51, 51, 1.0));
+    /// assert_eq!(orange.difference(rgb(255, 255, 255)), orange.invert().to_rgba());
+    /// ```
+    ///
+    /// [less-difference]: http://lesscss.org/functions/#color-blending-difference
+    fn difference<T: Color>(self, other: T) -> Self::Alpha
+    where
+        Self: Sized,
+    {
+        Self::Alpha::from_rgba(blend::blend(
+            self.to_rgba(),
+            other.to_rgba(),
+            blend::difference,
+        ))
+    }
+
+    /// Blends `self` (the backdrop) and `other` (the source) like the
+    /// [`exclusion` function][less-exclusion] of Less: similar to `difference`, with
+    /// less contrast, each channel becomes `c1 + c2 - 2 × c1 × c2` (in `0.0-1.0`).
+    /// Translucent colors are handled like in `multiply`.
+    ///
+    /// # Examples
+    /// ```
+    /// use css_colors::{Color, rgb, rgba};
+    ///
+    /// let orange = rgb(255, 102, 0);
+    ///
+    /// assert_eq!(orange.exclusion(rgb(51, 51, 51)), rgba(204, 112, 51, 1.0));
+    /// ```
+    ///
+    /// [less-exclusion]: http://lesscss.org/functions/#color-blending-exclusion
+    fn exclusion<T: Color>(self, other: T) -> Self::Alpha
+    where
+        Self: Sized,
+    {
+        Self::Alpha::from_rgba(blend::blend(
+            self.to_rgba(),
+            other.to_rgba(),
+            blend::exclusion,
+        ))
+    }
+
+    /// Blends `self` (the backdrop) and `other` (the source) like the
+    /// [`negation` function][less-negation] of Less, the reverse of `difference`:
+    /// each channel becomes `1 - |c1 + c2 - 1|` (in `0.0-1.0`). Translucent colors
+    /// are handled like in `multiply`.
+    ///
+    /// # Examples
+    /// ```
+    /// use css_colors::{Color, rgb, rgba};
+    ///
+    /// let orange = rgb(255, 102, 0);
+    ///
+    /// assert_eq!(orange.negation(rgb(51, 51, 51)), rgba(204, 153, 51, 1.0));
+    /// ```
+    ///
+    /// [less-negation]: http://lesscss.org/functions/#color-blending-negation
+    fn negation<T: Color>(self, other: T) -> Self::Alpha
+    where
+        Self: Sized,
+    {
+        Self::Alpha::from_rgba(blend::blend(
+            self.to_rgba(),
+            other.to_rgba(),
+            blend::negation,
+        ))
+    }
+
+    /// Averages the channels of `self` (the backdrop) and `other` (the source),
+    /// like the [`average` function][less-average] of Less. Translucent colors are
+    /// handled like in `multiply`, which differs from `mix` at `50%`. To average
+    /// more than two colors, see `RGBA::average_of`.
+    ///
+    /// # Examples
+    /// ```
+    /// use css_colors::{Color, rgb, rgba};
+    ///
+    /// let orange = rgb(255, 102, 0);
+    ///
+    /// assert_eq!(orange.average(rgb(51, 51, 51)), rgba(153, 77, 26, 1.0));
+    /// ```
+    ///
+    /// [less-average]: http://lesscss.org/functions/#color-blending-average
+    fn average<T: Color>(self, other: T) -> Self::Alpha
+    where
+        Self: Sized,
+    {
+        Self::Alpha::from_rgba(blend::blend(
+            self.to_rgba(),
+            other.to_rgba(),
+            blend::average,
+        ))
+    }
+
+    /// Blends `self` (the backdrop) and `other` (the source) with one of the
+    /// separable blend modes of CSS, given as data. This is what a browser paints
+    /// for an element with `mix-blend-mode` over its backdrop, and the modes that
+    /// Less also has give the same result as their own methods (such as
+    /// `multiply`). Translucent colors are handled like in `multiply`.
+    ///
+    /// Like Less and browsers, `BlendSpace::Gamma` blends the gamma-encoded
+    /// channels, which darkens midtones. `BlendSpace::Linear` blends them in linear
+    /// light instead, which is physically correct (see `mix_linear`).
+    ///
+    /// # Examples
+    /// ```
+    /// use css_colors::{Color, BlendMode, BlendSpace, rgb, rgba};
+    ///
+    /// let orange = rgb(255, 102, 0);
+    /// let blue = rgb(51, 153, 204);
+    /// let gamma = BlendSpace::Gamma;
+    ///
+    /// assert_eq!(orange.blend(blue, BlendMode::Darken, gamma), rgba(51, 102, 0, 1.0));
+    /// assert_eq!(orange.blend(blue, "color-dodge".parse().unwrap(), gamma), rgba(255, 255, 0, 1.0));
+    /// assert_eq!(orange.blend(blue, BlendMode::Multiply, gamma), orange.multiply(blue));
+    ///
+    /// let white = rgba(255, 255, 255, 0.5);
+    /// let black = rgb(0, 0, 0);
+    ///
+    /// assert_eq!(black.blend(white, BlendMode::Normal, gamma), rgba(128, 128, 128, 1.0));
+    /// assert_eq!(black.blend(white, BlendMode::Normal, BlendSpace::Linear), rgba(188, 188, 188, 1.0));
+    /// ```
+    fn blend<T: Color>(self, other: T, mode: BlendMode, space: BlendSpace) -> Self::Alpha
+    where
+        Self: Sized,
+    {
+        Self::Alpha::from_rgba(blend::blend_in(
+            self.to_rgba(),
+            other.to_rgba(),
+            mode.function(),
+            space,
+        ))
+    }
+
+    /// Composites `self` over `backdrop`, following the "source over" operator of
+    /// Porter and Duff: this is what a translucent color looks like once painted
+    /// on top of another. Unlike `mix`, which weights both colors by a percentage,
+    /// `self` covers the backdrop by its alpha.
+    ///
+    /// The alpha of the result is `as + ab × (1 - as)`, and the channels are
+    /// premultiplied by their alpha during the blend. A fully transparent `self`
+    /// gives the backdrop, and a fully opaque one gives `self`.
+    ///
+    /// # Examples
+    /// ```
+    /// use css_colors::{Color, rgb, rgba};
+    ///
+    /// let red = rgba(255, 0, 0, 0.5);
+    ///
+    /// assert_eq!(red.over(rgb(255, 255, 255)), rgba(255, 127, 127, 1.0));
+    /// assert_eq!(red.over(rgba(0, 0, 0, 0.0)), red);
+    /// ```
+    ///
+    /// For more, see the [W3C compositing spec](https://www.w3.org/TR/compositing-1/#porterduffcompositingoperators_srcover).
+    fn over<T: Color>(self, backdrop: T) -> RGBA
+    where
+        Self: Sized,
+    {
+        let source = self.to_rgba();
+
+        if source.a.as_u8() == 0 {
+            return backdrop.to_rgba();
+        }
+
+        blend::blend(backdrop.to_rgba(), source, blend::normal)
+    }
+
+    /// Converts `self` into the `#AARRGGBB` hex format used by legacy Internet Explorer
+    /// filters. Colors without an alpha channel are treated as fully opaque.
+    /// For more, see Less' [Misc Functions](http://lesscss.org/functions/#color-definition-argb).
+    ///
+    /// # Examples
+    /// ```
+    /// use css_colors::{Color, rgb, rgba, hsla};
+    ///
+    /// let tomato = rgb(255, 99, 71);
+    /// let opaque_tomato = rgba(255, 99, 71, 0.5);
+    /// let opaque_black = hsla(0, 0, 0, 0.5);
+    ///
+    /// assert_eq!(tomato.ie_hex_str(), "#FFFF6347");
+    /// assert_eq!(opaque_tomato.ie_hex_str(), "#80FF6347");
+    /// assert_eq!(opaque_black.ie_hex_str(), "#80000000");
+    /// ```
+    fn ie_hex_str(&self) -> String {
+        let RGBA { r, g, b, a } = self.to_rgba();
+
+        format!(
+            "#{:02X}{:02X}{:02X}{:02X}",
+            a.as_u8(),
+            r.as_u8(),
+            g.as_u8(),
+            b.as_u8()
+        )
+    }
+
+    /// Randomly varies the saturation and lightness of `self` within the bounds of
+    /// `spec`, optionally along with the alpha channel and a small hue wobble. Every
+    /// channel is clamped to its valid range. Operates on the color within its HSL
+    /// representation, and returns a color of the same type.
+    ///
+    /// The output only depends on the values drawn from `rng`, so a generator that
+    /// is reproducible across platforms (e.g. `ChaCha8Rng` from `rand_chacha`, but not
+    /// `thread_rng`) gives the same sequence of colors for the same seed.
+    ///
+    /// Only available with the `rand` feature.
+    ///
+    /// # Examples
+    /// ```
+    /// # extern crate css_colors;
+    /// # extern crate rand;
+    /// # fn main() {
+    /// use css_colors::{Color, JitterSpec, hsl, percent};
+    ///
+    /// let spec = JitterSpec::new(percent(10), percent(5));
+    /// let jittered = hsl(200, 50, 50).jitter(&mut rand::rngs::mock::StepRng::new(0, 1), spec);
+    ///
+    /// assert_eq!(jittered.h, hsl(200, 50, 50).h);
+    /// # }
+    /// ```
+    #[cfg(feature = "rand")]
+    fn jitter<R: rand::Rng>(self, rng: &mut R, spec: JitterSpec) -> Self
+    where
+        Self: Sized,
+    {
+        ops::apply_to_hsl(self, |hsla| jitter::jitter(hsla, rng, spec))
+    }
+
+    /// Finds the nearest entry of the xterm-256 color palette, for terminals that
+    /// don't support truecolor. Colors are matched against the 6×6×6 color cube
+    /// (indices `16-231`) and the grayscale ramp (indices `232-255`) by squared
+    /// distance in RGB. The alpha channel is ignored.
+    ///
+    /// # Examples
+    /// ```
+    /// use css_colors::{Color, rgb};
+    ///
+    /// assert_eq!(rgb(255, 0, 0).to_ansi256(), 196);
+    /// assert_eq!(rgb(128, 128, 128).to_ansi256(), 244);
+    /// ```
+    fn to_ansi256(&self) -> u8 {
+        ansi::nearest_ansi256(self.to_rgb())
+    }
+
+    /// Converts `self` into the escape sequence that sets the foreground color of
+    /// a 256-color terminal to the nearest palette entry. See `to_ansi256`.
+    ///
+    /// # Examples
+    /// ```
+    /// use css_colors::{Color, rgb};
+    ///
+    /// assert_eq!(rgb(255, 0, 0).to_ansi256_fg(), "\u{1b}[38;5;196m");
+    /// ```
+    fn to_ansi256_fg(&self) -> String {
+        format!("\u{1b}[38;5;{}m", self.to_ansi256())
+    }
+
+    /// Returns the relative luminance of `self`, as defined by
+    /// [WCAG 2.1](https://www.w3.org/TR/WCAG21/#dfn-relative-luminance), between
+    /// `0.0` (black) and `1.0` (white).
+    ///
+    /// A translucent color has no luminance of its own, so it is composited over
+    /// white first (like a page with no background). To composite it over another
+    /// background, see `contrast_ratio_composited`.
+    ///
+    /// # Examples
+    /// ```
+    /// use css_colors::{Color, rgb, rgba};
+    ///
+    /// assert_eq!(rgb(255, 255, 255).relative_luminance(), 1.0);
+    /// assert!((rgb(255, 0, 0).relative_luminance() - 0.2126).abs() < 0.0001);
+    /// assert_eq!(rgba(0, 0, 0, 0.0).relative_luminance(), 1.0);
+    /// ```
+    fn relative_luminance(&self) -> f32 {
+        contrast::relative_luminance(contrast::flatten(self.to_rgba()))
+    }
+
+    /// Computes the WCAG contrast ratio between `self` (as the foreground) and
+    /// `other` (as the background): `(L1 + 0.05) / (L2 + 0.05)`, where `L1` is
+    /// the relative luminance of the lighter color. The ratio ranges from `1.0`
+    /// (no contrast) to `21.0` (black on white), in either order. For more, see
+    /// [WCAG 2.1](https://www.w3.org/TR/WCAG21/#dfn-contrast-ratio).
+    ///
+    /// Alpha isn't ignored: a translucent `other` is composited over white, and a
+    /// translucent `self` over the result (see `contrast_ratio_composited` for
+    /// another page background).
+    ///
+    /// # Examples
+    /// ```
+    /// use css_colors::{Color, rgb, rgba};
+    ///
+    /// let white = rgb(255, 255, 255);
+    ///
+    /// assert!((rgb(0, 0, 0).contrast_ratio(white) - 21.0).abs() < 0.01);
+    /// assert!((white.contrast_ratio(rgb(0, 0, 0)) - 21.0).abs() < 0.01);
+    /// assert!((rgb(118, 118, 118).contrast_ratio(white) - 4.54).abs() < 0.01);
+    /// assert!((rgba(0, 0, 0, 0.5).contrast_ratio(white) - 4.0).abs() < 0.01);
+    /// ```
+    fn contrast_ratio<T: Color>(&self, other: T) -> f32
+    where
+        Self: Sized,
+    {
+        self.contrast_ratio_composited(other, rgb(255, 255, 255))
+    }
+
+    /// Computes the WCAG contrast ratio between `self` (as the foreground) and
+    /// `other` (as the background), to check it against the conformance levels
+    /// of WCAG (see `ContrastResult` and `contrast_ratio`).
+    ///
+    /// # Examples
+    /// ```
+    /// use css_colors::{Color, rgb, TextSize};
+    ///
+    /// let result = rgb(255, 99, 71).wcag(rgb(255, 255, 255));
+    ///
+    /// assert!((result.ratio - 2.95).abs() < 0.01);
+    /// assert!(!result.passes_aa(TextSize::Large));
+    /// ```
+    fn wcag<T: Color>(&self, other: T) -> ContrastResult
+    where
+        Self: Sized,
+    {
+        ContrastResult {
+            ratio: self.contrast_ratio(other),
+        }
+    }
+
+    /// Simulates how `self` is perceived with the given color vision deficiency,
+    /// e.g. to check that a palette stays distinguishable (see `Cvd` for the
+    /// algorithm, and `simulate_cvd_palette`). The alpha channel is ignored.
+    ///
+    /// # Examples
+    /// ```
+    /// use css_colors::{Color, Cvd, percent, rgb};
+    ///
+    /// assert_eq!(rgb(255, 0, 0).simulate_cvd(Cvd::Protanopia), rgb(109, 95, 0));
+    /// assert_eq!(rgb(0, 255, 0).simulate_cvd(Cvd::Deuteranopia), rgb(239, 214, 58));
+    /// assert_eq!(rgb(0, 0, 255).simulate_cvd(Cvd::Tritanopia), rgb(0, 107, 150));
+    /// assert_eq!(rgb(0, 0, 255).simulate_cvd(Cvd::Tritanomaly(percent(0))), rgb(0, 0, 255));
+    /// ```
+    fn simulate_cvd(self, kind: Cvd) -> RGB
+    where
+        Self: Sized,
+    {
+        cvd::simulate(self.to_rgb(), kind)
+    }
+
+    /// Adjusts `self` to be easier to tell apart from other colors with the given
+    /// color vision deficiency, with the daltonization of Fidaner, Lin & Ozguven:
+    /// the difference between `self` and its simulation (see `simulate_cvd`) is
+    /// shifted into the channels that remain perceptible, in linear RGB. Greys are
+    /// unchanged, and the alpha channel is ignored.
+    ///
+    /// # Examples
+    /// ```
+    /// use css_colors::{Color, Cvd, rgb};
+    ///
+    /// let red = rgb(255, 0, 0);
+    ///
+    /// assert_eq!(red.simulate_cvd(Cvd::Protanopia), rgb(109, 95, 0));
+    /// // Red is shifted towards pink, which appears as a light blue instead of a dark yellow.
+    /// assert_eq!(red.daltonize(Cvd::Protanopia), rgb(255, 184, 202));
+    /// assert_eq!(red.daltonize(Cvd::Protanopia).simulate_cvd(Cvd::Protanopia), rgb(193, 196, 203));
+    /// assert_eq!(rgb(128, 128, 128).daltonize(Cvd::Protanopia), rgb(128, 128, 128));
+    /// ```
+    fn daltonize(self, kind: Cvd) -> RGB
+    where
+        Self: Sized,
+    {
+        cvd::daltonize(self.to_rgb(), kind)
+    }
+
+    /// Computes the APCA lightness contrast (Lc) of `self` as text over
+    /// `background`, with the [APCA-W3](https://github.com/Myndex/apca-w3)
+    /// algorithm (version 0.0.98G-4g) of the draft WCAG 3.
+    ///
+    /// Unlike the contrast ratio of WCAG 2, APCA is polarity-aware, so the order
+    /// of the colors matters: dark text on a light background has a positive Lc
+    /// (up to about `106`), and light text on a dark background has a negative
+    /// one (down to about `-108`). Pairs with too little contrast have an Lc of
+    /// `0.0`. A translucent background is composited over white, and a
+    /// translucent `self` over the result.
+    ///
+    /// This is experimental: APCA is still a draft, and its constants and the
+    /// thresholds to check Lc values against may change.
+    ///
+    /// # Examples
+    /// ```
+    /// use css_colors::{Color, rgb};
+    ///
+    /// let grey = rgb(136, 136, 136);
+    /// let white = rgb(255, 255, 255);
+    ///
+    /// assert!((grey.apca_contrast(white) - 63.06).abs() < 0.01);
+    /// assert!((white.apca_contrast(grey) + 68.54).abs() < 0.01);
+    /// ```
+    fn apca_contrast<T: Color>(&self, background: T) -> f32
+    where
+        Self: Sized,
+    {
+        let background = contrast::flatten(background.to_rgba());
+        let text = contrast::composite(self.to_rgba(), background);
+
+        contrast::apca_contrast(text, background)
+    }
+
+    /// Returns the closest variant of `self` whose WCAG contrast ratio (see
+    /// `contrast_ratio`) with `against` reaches `target`, e.g. to make a brand
+    /// color accessible. `self` is darkened or lightened in OkLch, keeping its hue
+    /// and as much of its chroma as the sRGB gamut allows, with a binary search on
+    /// the lightness. When both directions reach the target, the one that changes
+    /// the lightness least is picked.
+    ///
+    /// `self` is returned unchanged if it already passes, and black or white
+    /// (whichever has the higher contrast) if neither reaches the target. The
+    /// alpha channel is kept, and accounted for in the contrast.
+    ///
+    /// # Examples
+    /// ```
+    /// use css_colors::{Color, rgb, rgba};
+    ///
+    /// let white = rgb(255, 255, 255);
+    /// let grey = rgb(128, 128, 128).ensure_contrast(white, 4.5);
+    ///
+    /// assert!(grey.contrast_ratio(white) >= 4.5);
+    /// assert_eq!(rgb(128, 128, 128).ensure_contrast(white, 21.0), rgba(0, 0, 0, 1.0));
+    /// ```
+    fn ensure_contrast<T: Color>(self, against: T, target: f32) -> RGBA
+    where
+        Self: Sized,
+    {
+        contrast::ensure_contrast(self.to_rgba(), against.to_rgba(), target)
+    }
+
+    /// Returns black or white, whichever has the higher WCAG contrast ratio (see
+    /// `contrast_ratio`) as text over `self`. A translucent `self` is composited
+    /// over white first. When both are tied, black is returned.
+    ///
+    /// Unlike `contrast_bw`, which compares the luma of `self` to a threshold,
+    /// this always picks the most readable of the two.
+    ///
+    /// # Examples
+    /// ```
+    /// use css_colors::{Color, rgb, rgba};
+    ///
+    /// let black = rgb(0, 0, 0);
+    /// let white = rgb(255, 255, 255);
+    ///
+    /// assert_eq!(rgb(255, 255, 0).text_color(), black);
+    /// assert_eq!(rgb(0, 0, 128).text_color(), white);
+    /// assert_eq!(rgba(0, 0, 128, 0.1).text_color(), black);
+    /// ```
+    fn text_color(&self) -> RGB
+    where
+        Self: Sized,
+    {
+        self.text_color_from(&[rgb(0, 0, 0), rgb(255, 255, 255)])
+    }
+
+    /// Returns the candidate with the highest WCAG contrast ratio (see
+    /// `contrast_ratio`) as text over `self`, e.g. to pick a label color from a
+    /// brand palette. A translucent `self` is composited over white first, and a
+    /// translucent candidate over `self`. When candidates are tied, the first
+    /// one is returned.
+    ///
+    /// Panics if `candidates` is empty.
+    ///
+    /// # Examples
+    /// ```
+    /// use css_colors::{Color, rgb};
+    ///
+    /// let navy = rgb(0, 31, 63);
+    /// let cream = rgb(255, 250, 235);
+    ///
+    /// assert_eq!(rgb(255, 99, 71).text_color_from(&[navy, cream]), navy);
+    /// assert_eq!(rgb(0, 116, 217).text_color_from(&[navy, cream]), cream);
+    /// ```
+    fn text_color_from<T: Color + Copy>(&self, candidates: &[T]) -> T
+    where
+        Self: Sized,
+    {
+        assert!(!candidates.is_empty(), "no candidate text colors given");
+
+        let background = self.to_rgba();
+        let mut best = candidates[0];
+        let mut best_ratio = best.contrast_ratio(background);
+
+        for &candidate in &candidates[1..] {
+            let ratio = candidate.contrast_ratio(background);
+
+            if ratio > best_ratio {
+                best = candidate;
+                best_ratio = ratio;
+            }
+        }
+
+        best
+    }
+
+    /// Computes the WCAG contrast ratio between `self` and `backdrop`, as they are
+    /// rendered over `page_background`. The (possibly translucent) backdrop is
+    /// first composited over the page background, then `self` is composited over
+    /// the result, and the contrast ratio between the two is computed.
+    ///
+    /// Each layer is quantized into bytes as it is composited, just like it would
+    /// be rendered on screen. A translucent page background is composited over
+    /// white. When both `self` and `backdrop` are fully opaque, the page
+    /// background has no effect.
+    ///
+    /// The ratio ranges from `1.0` (no contrast) to `21.0` (black on white).
+    /// For more, see [WCAG 2.1](https://www.w3.org/TR/WCAG21/#dfn-contrast-ratio).
+    ///
+    /// # Examples
+    /// ```
+    /// use css_colors::{Color, rgb, rgba};
+    ///
+    /// let page = rgb(255, 255, 255);
+    /// let header = rgba(0, 0, 0, 0.5);
+    /// let text = rgb(255, 255, 255);
+    ///
+    /// assert!(rgb(0, 0, 0).contrast_ratio_composited(page, page) > 20.99);
+    /// assert!(text.contrast_ratio_composited(header, page) < 4.5);
+    /// ```
+    fn contrast_ratio_composited<B: Color, P: Color>(&self, backdrop: B, page_background: P) -> f32
+    where
+        Self: Sized,
+    {
+        let page_background = contrast::flatten(page_background.to_rgba());
+        let backdrop = contrast::composite(backdrop.to_rgba(), page_background);
+        let foreground = contrast::composite(self.to_rgba(), backdrop);
+
+        contrast::contrast_ratio(foreground, backdrop)
+    }
+
+    /// Returns the red channel of `self`, like the [`red` function][less-red] of Less.
+    ///
+    /// # Examples
+    /// ```
+    /// use css_colors::{Color, hsl, rgb};
+    ///
+    /// assert_eq!(rgb(10, 20, 30).red().as_u8(), 10);
+    /// assert_eq!(hsl(0, 100, 50).red().as_u8(), 255);
+    /// ```
+    ///
+    /// [less-red]: http://lesscss.org/functions/#color-channel-red
+    fn red(&self) -> Ratio {
+        self.to_rgb().r
+    }
+
+    /// Returns the green channel of `self`, like the [`green` function][less-green] of Less.
+    ///
+    /// # Examples
+    /// ```
+    /// use css_colors::{Color, rgb};
+    ///
+    /// assert_eq!(rgb(10, 20, 30).green().as_u8(), 20);
+    /// ```
+    ///
+    /// [less-green]: http://lesscss.org/functions/#color-channel-green
+    fn green(&self) -> Ratio {
+        self.to_rgb().g
+    }
+
+    /// Returns the blue channel of `self`, like the [`blue` function][less-blue] of Less.
+    ///
+    /// # Examples
+    /// ```
+    /// use css_colors::{Color, rgb};
+    ///
+    /// assert_eq!(rgb(10, 20, 30).blue().as_u8(), 30);
+    /// ```
+    ///
+    /// [less-blue]: http://lesscss.org/functions/#color-channel-blue
+    fn blue(&self) -> Ratio {
+        self.to_rgb().b
+    }
+
+    /// Returns the alpha channel of `self`, like the [`alpha` function][less-alpha]
+    /// of Less. Colors without an alpha channel are fully opaque.
+    ///
+    /// # Examples
+    /// ```
+    /// use css_colors::{Color, percent, rgb, rgba};
+    ///
+    /// assert_eq!(rgba(2, 3, 4, 0.5).alpha().to_string(), "50%");
+    /// assert_eq!(rgb(2, 3, 4).alpha(), percent(100));
+    /// ```
+    ///
+    /// [less-alpha]: http://lesscss.org/functions/#color-channel-alpha
+    fn alpha(&self) -> Ratio {
+        self.to_rgba().a
+    }
+
+    /// Returns the hue of `self` in HSL, like the [`hue` function][less-hue] of Less.
+    ///
+    /// # Examples
+    /// ```
+    /// use css_colors::{Color, hsl, rgb};
+    ///
+    /// assert_eq!(hsl(90, 100, 50).hue().degrees(), 90);
+    /// assert_eq!(rgb(0, 0, 255).hue().degrees(), 240);
+    /// ```
+    ///
+    /// [less-hue]: http://lesscss.org/functions/#color-channel-hue
+    fn hue(&self) -> Angle {
+        self.to_hsl().h
+    }
+
+    /// Returns the saturation of `self` in HSL, like the
+    /// [`saturation` function][less-saturation] of Less.
+    ///
+    /// # Examples
+    /// ```
+    /// use css_colors::{Color, hsl, percent};
+    ///
+    /// assert_eq!(hsl(90, 100, 50).saturation(), percent(100));
+    /// ```
+    ///
+    /// [less-saturation]: http://lesscss.org/functions/#color-channel-saturation
+    fn saturation(&self) -> Ratio {
+        self.to_hsl().s
+    }
+
+    /// Returns the lightness of `self` in HSL, like the
+    /// [`lightness` function][less-lightness] of Less.
+    ///
+    /// # Examples
+    /// ```
+    /// use css_colors::{Color, hsl, percent};
+    ///
+    /// assert_eq!(hsl(90, 100, 50).lightness(), percent(50));
+    /// ```
+    ///
+    /// [less-lightness]: http://lesscss.org/functions/#color-channel-lightness
+    fn lightness(&self) -> Ratio {
+        self.to_hsl().l
+    }
+
+    /// Returns the saturation of `self` in HSV, like the
+    /// [`hsvsaturation` function][less-hsvsaturation] of Less. It differs from the
+    /// saturation in HSL (see `saturation`) for most colors. The hue is the same
+    /// in both models (see `hue`).
+    ///
+    /// # Examples
+    /// ```
+    /// use css_colors::{Color, hsl, percent};
+    ///
+    /// let pink = hsl(0, 100, 75);
+    ///
+    /// assert_eq!(pink.saturation(), percent(100));
+    /// assert_eq!(pink.hsv_saturation().to_string(), "50%");
+    /// ```
+    ///
+    /// [less-hsvsaturation]: http://lesscss.org/functions/#color-channel-hsvsaturation
+    fn hsv_saturation(&self) -> Ratio {
+        self.to_hsv().s
+    }
+
+    /// Returns the value of `self` in HSV, like the [`hsvvalue` function][less-hsvvalue]
+    /// of Less: the value of its brightest channel. Unlike the lightness in HSL (see
+    /// `lightness`), it's 100% for every fully saturated color.
+    ///
+    /// # Examples
+    /// ```
+    /// use css_colors::{Color, hsl, percent};
+    ///
+    /// let green = hsl(120, 100, 50);
+    ///
+    /// assert_eq!(green.lightness(), percent(50));
+    /// assert_eq!(green.hsv_value(), percent(100));
+    /// ```
+    ///
+    /// [less-hsvvalue]: http://lesscss.org/functions/#color-channel-hsvvalue
+    fn hsv_value(&self) -> Ratio {
+        self.to_hsv().v
+    }
+
+    /// Returns the luma of `self`: its gamma-corrected relative luminance, like
+    /// the [`luma` function][less-luma] of Less. The red, green and blue channels
+    /// are linearized, then weighted with the coefficients of ITU-R BT.709
+    /// (`0.2126`, `0.7152` and `0.0722`). The alpha channel is ignored.
+    ///
+    /// # Examples
+    /// ```
+    /// use css_colors::{Color, percent, rgb};
+    ///
+    /// assert_eq!(rgb(255, 255, 255).luma(), percent(100));
+    /// assert_eq!(rgb(255, 0, 0).luma(), percent(21));
+    /// assert_eq!(rgb(100, 200, 30).luma(), percent(44));
+    /// ```
+    ///
+    /// [less-luma]: http://lesscss.org/functions/#color-channel-luma
+    fn luma(&self) -> Ratio {
+        ops::quantize(contrast::relative_luminance(self.to_rgb()))
+    }
+
+    /// Returns the luminance of `self` without gamma correction, like the
+    /// [`luminance` function][less-luminance] of Less: the red, green and blue
+    /// channels are weighted as they are (see `luma`). The alpha channel is ignored.
+    ///
+    /// # Examples
+    /// ```
+    /// use css_colors::{Color, percent, rgb};
+    ///
+    /// assert_eq!(rgb(0, 0, 0).luminance(), percent(0));
+    /// assert_eq!(rgb(100, 200, 30).luminance(), percent(65));
+    /// ```
+    ///
+    /// [less-luminance]: http://lesscss.org/functions/#color-channel-luminance
+    fn luminance(&self) -> Ratio {
+        let RGB { r, g, b } = self.to_rgb();
+
+        ops::quantize(0.2126 * r.as_f32() + 0.7152 * g.as_f32() + 0.0722 * b.as_f32())
+    }
+
+    /// Returns the perceived brightness of `self`, with the HSP formula of Darel
+    /// Rex Finley: `sqrt(0.299 R² + 0.587 G² + 0.114 B²)`. The alpha channel is
+    /// ignored.
+    ///
+    /// It's a cheap estimate (a single square root) meant for sorting or
+    /// thumbnails, which matches how bright saturated colors appear better than
+    /// `luminance`. Unlike `luma` and `relative_luminance`, it's not a physical
+    /// measure of light, so it shouldn't be used for contrast checks.
+    ///
+    /// # Examples
+    /// ```
+    /// use css_colors::{Color, percent, rgb};
+    ///
+    /// assert_eq!(rgb(255, 255, 255).perceived_brightness(), percent(100));
+    /// assert_eq!(rgb(0, 0, 255).perceived_brightness().to_string(), "34%");
+    /// assert_eq!(rgb(0, 0, 255).luma().to_string(), "7%");
+    /// ```
+    fn perceived_brightness(&self) -> Ratio {
+        let RGB { r, g, b } = self.to_rgb();
+        let (r, g, b) = (r.as_f32(), g.as_f32(), b.as_f32());
+
+        ops::quantize((0.299 * r * r + 0.587 * g * g + 0.114 * b * b).sqrt())
+    }
+
+    /// Returns whichever of `dark` and `light` is more readable over `self`,
+    /// like the [`contrast` function][less-contrast] of Less: `light` if the luma
+    /// of `self` (see `luma`, which is compared before being quantized) is below
+    /// `threshold`, and `dark` otherwise. The threshold defaults to 43%. If `dark`
+    /// is lighter than `light`, they are swapped.
+    ///
+    /// # Examples
+    /// ```
+    /// use css_colors::{Color, percent, rgb, rgba, hsl};
+    ///
+    /// let black = rgba(0, 0, 0, 1.0);
+    /// let white = rgba(255, 255, 255, 1.0);
+    ///
+    /// assert_eq!(rgb(187, 187, 187).contrast(black, white, None), black);
+    /// assert_eq!(rgb(34, 34, 34).contrast(black, white, None), white);
+    /// assert_eq!(hsl(90, 100, 50).contrast(black, white, Some(percent(80))), white);
+    /// ```
+    ///
+    /// [less-contrast]: http://lesscss.org/functions/#color-operations-contrast
+    fn contrast(self, dark: RGBA, light: RGBA, threshold: Option<Ratio>) -> RGBA
+    where
+        Self: Sized,
+    {
+        let luma = |color: RGBA| contrast::relative_luminance(color.to_rgb());
+        let (dark, light) = if luma(dark) > luma(light) {
+            (light, dark)
+        } else {
+            (dark, light)
+        };
+
+        let threshold = threshold.map_or(contrast::LESS_CONTRAST_THRESHOLD, Ratio::as_f32);
+
+        if luma(self.to_rgba()) < threshold {
+            light
+        } else {
+            dark
+        }
+    }
+
+    /// Returns black or white, whichever is more readable over `self` (see
+    /// `contrast`, with its default threshold).
+    ///
+    /// # Examples
+    /// ```
+    /// use css_colors::{Color, rgb, rgba};
+    ///
+    /// assert_eq!(rgb(187, 187, 187).contrast_bw(), rgba(0, 0, 0, 1.0));
+    /// assert_eq!(rgb(25, 25, 112).contrast_bw(), rgba(255, 255, 255, 1.0));
+    /// ```
+    fn contrast_bw(self) -> RGBA
+    where
+        Self: Sized,
+    {
+        self.contrast(rgba(0, 0, 0, 1.0), rgba(255, 255, 255, 1.0), None)
+    }
+
+    /// Describes `self` in plain words, composed from its lightness, saturation
+    /// and hue (e.g. "light muted blue"). Useful for accessibility labels.
+    /// See `DescribeOptions` for the thresholds used for each band.
+    ///
+    /// # Examples
+    /// ```
+    /// use css_colors::{Color, rgb, hsl};
+    ///
+    /// let cornflower_blue = rgb(100, 149, 237);
+    /// let charcoal = hsl(0, 0, 25);
+    ///
+    /// assert_eq!(cornflower_blue.describe(), "light vivid blue");
+    /// assert_eq!(charcoal.describe(), "dark grey");
+    /// ```
+    fn describe(&self) -> String {
+        self.describe_with(DescribeOptions::default())
+    }
+
+    /// Describes `self` in plain words, omitting the bands disabled in `options`.
+    ///
+    /// # Examples
+    /// ```
+    /// use css_colors::{Color, DescribeOptions, rgb};
+    ///
+    /// let cornflower_blue = rgb(100, 149, 237);
+    /// let options = DescribeOptions { saturation: false, ..DescribeOptions::default() };
+    ///
+    /// assert_eq!(cornflower_blue.describe_with(options), "light blue");
+    /// ```
+    fn describe_with(&self, options: DescribeOptions) -> String {
+        describe::describe(self.to_hsla(), options)
+    }
+}
+
+#[cfg(test)]
+mod css_color_tests {
+    use angle::*;
+    use ratio::*;
+    use {
+        hsl, hsla, rgb, rgba, Angle, Color, Gradient, HuePath, Ratio, HSL, HSLA, HSV, HSVA, RGB,
+        RGBA,
+    };
+
+    pub trait ApproximatelyEq {
+        fn approximately_eq(self, other: Self) -> bool;
+    }
+
+    impl ApproximatelyEq for u8 {
+        fn approximately_eq(self, other: Self) -> bool {
+            self == other || self + 1 == other || self - 1 == other
+        }
+    }
+
+    impl ApproximatelyEq for u16 {
+        fn approximately_eq(self, other: Self) -> bool {
+            self == other || self + 1 == other || self - 1 == other
+        }
+    }
+
+    impl ApproximatelyEq for Angle {
+        fn approximately_eq(self, other: Self) -> bool {
+            self.degrees().approximately_eq(other.degrees())
+        }
+    }
+
+    impl ApproximatelyEq for Ratio {
+        fn approximately_eq(self, other: Self) -> bool {
+            self.as_u8().approximately_eq(other.as_u8())
+        }
+    }
+
+    impl ApproximatelyEq for RGB {
         fn approximately_eq(self, other: Self) -> bool {
-            self.as_u8().approximately_eq(other.as_u8())
+            self.to_css() == other.to_css()
+                || self.r.approximately_eq(other.r)
+                    && self.g.approximately_eq(other.g)
+                    && self.b.approximately_eq(other.b)
+        }
+    }
+
+    impl ApproximatelyEq for RGBA {
+        fn approximately_eq(self, other: Self) -> bool {
+            self.to_css() == other.to_css()
+                || self.r.approximately_eq(other.r)
+                    && self.g.approximately_eq(other.g)
+                    && self.b.approximately_eq(other.b)
+                    && self.a == other.a
+        }
+    }
+
+    impl ApproximatelyEq for HSL {
+        fn approximately_eq(self, other: Self) -> bool {
+            self.to_css() == other.to_css()
+                || self.h.approximately_eq(other.h)
+                    && self
+                        .s
+                        .as_percentage()
+                        .approximately_eq(other.s.as_percentage())
+                    && self
+                        .l
+                        .as_percentage()
+                        .approximately_eq(other.l.as_percentage())
+        }
+    }
+
+    impl ApproximatelyEq for HSLA {
+        fn approximately_eq(self, other: Self) -> bool {
+            self.to_css() == other.to_css()
+                || self.h.approximately_eq(other.h)
+                    && self
+                        .s
+                        .as_percentage()
+                        .approximately_eq(other.s.as_percentage())
+                    && self
+                        .l
+                        .as_percentage()
+                        .approximately_eq(other.l.as_percentage())
+                    && self.a == other.a
+        }
+    }
+
+    impl ApproximatelyEq for HSV {
+        fn approximately_eq(self, other: Self) -> bool {
+            self.to_string() == other.to_string()
+                || self.h.approximately_eq(other.h)
+                    && self
+                        .s
+                        .as_percentage()
+                        .approximately_eq(other.s.as_percentage())
+                    && self
+                        .v
+                        .as_percentage()
+                        .approximately_eq(other.v.as_percentage())
+        }
+    }
+
+    impl ApproximatelyEq for HSVA {
+        fn approximately_eq(self, other: Self) -> bool {
+            self.to_string() == other.to_string()
+                || self.h.approximately_eq(other.h)
+                    && self
+                        .s
+                        .as_percentage()
+                        .approximately_eq(other.s.as_percentage())
+                    && self
+                        .v
+                        .as_percentage()
+                        .approximately_eq(other.v.as_percentage())
+                    && self.a == other.a
+        }
+    }
+
+    #[macro_export]
+    macro_rules! assert_approximately_eq {
+        ($lhs:expr, $rhs:expr) => {
+            let lhs = $lhs;
+            let rhs = $rhs;
+
+            assert!(lhs.approximately_eq(rhs), "lhs: {}, rhs: {}", lhs, rhs);
+        };
+    }
+
+    #[test]
+    fn can_create_color_structs() {
+        assert_eq!(
+            rgb(5, 10, 15),
+            RGB {
+                r: Ratio::from_u8(5),
+                g: Ratio::from_u8(10),
+                b: Ratio::from_u8(15),
+            }
+        );
+        assert_eq!(
+            rgba(5, 10, 15, 1.0),
+            RGBA {
+                r: Ratio::from_u8(5),
+                g: Ratio::from_u8(10),
+                b: Ratio::from_u8(15),
+                a: Ratio::from_u8(255),
+            }
+        );
+        assert_eq!(
+            hsl(6, 93, 71),
+            HSL {
+                h: Angle::new(6),
+                s: Ratio::from_percentage(93),
+                l: Ratio::from_percentage(71)
+            }
+        );
+        assert_eq!(
+            hsla(6, 93, 71, 1.0),
+            HSLA {
+                h: Angle::new(6),
+                s: Ratio::from_percentage(93),
+                l: Ratio::from_percentage(71),
+                a: Ratio::from_u8(255),
+            }
+        );
+    }
+
+    #[macro_use]
+    mod conversions {
+        macro_rules! conversion_test {
+            (
+                $color_name:ident,
+                rgb($r:expr, $g:expr, $b:expr),
+                hsl($h:expr, $s:expr, $l:expr)
+            ) => {
+                mod $color_name {
+                    use super::super::ApproximatelyEq;
+                    use $crate::{hsl, hsla, rgb, rgba, Color};
+
+                    #[test]
+                    fn rgb_to_rgb() {
+                        assert_eq!(rgb($r, $g, $b).to_rgb(), rgb($r, $g, $b));
+                    }
+
+                    #[test]
+                    fn rgb_to_rgba() {
+                        assert_eq!(rgb($r, $g, $b).to_rgba(), rgba($r, $g, $b, 1.0));
+                    }
+
+                    #[test]
+                    fn rgba_to_rgb() {
+                        assert_eq!(rgba($r, $g, $b, 1.0).to_rgb(), rgb($r, $g, $b));
+                        assert_eq!(rgba($r, $g, $b, 0.78).to_rgb(), rgb($r, $g, $b));
+                        assert_eq!(rgba($r, $g, $b, 0.0).to_rgb(), rgb($r, $g, $b));
+                    }
+
+                    #[test]
+                    fn rgba_to_rgba() {
+                        assert_eq!(rgba($r, $g, $b, 1.0).to_rgba(), rgba($r, $g, $b, 1.0));
+
+                        assert_eq!(rgba($r, $g, $b, 0.78).to_rgba(), rgba($r, $g, $b, 0.78));
+
+                        assert_eq!(rgba($r, $g, $b, 0.0).to_rgba(), rgba($r, $g, $b, 0.0));
+                    }
+
+                    #[test]
+                    fn rgb_to_hsl() {
+                        assert_approximately_eq!(rgb($r, $g, $b).to_hsl(), hsl($h, $s, $l));
+                    }
+
+                    #[test]
+                    fn rgb_to_hsla() {
+                        assert_approximately_eq!(rgb($r, $g, $b).to_hsla(), hsla($h, $s, $l, 1.0));
+                    }
+
+                    #[test]
+                    fn rgba_to_hsl() {
+                        assert_approximately_eq!(rgba($r, $g, $b, 1.0).to_hsl(), hsl($h, $s, $l));
+
+                        assert_approximately_eq!(rgba($r, $g, $b, 0.78).to_hsl(), hsl($h, $s, $l));
+
+                        assert_approximately_eq!(rgba($r, $g, $b, 0.0).to_hsl(), hsl($h, $s, $l));
+                    }
+
+                    #[test]
+                    fn rgba_to_hsla() {
+                        assert_approximately_eq!(
+                            rgba($r, $g, $b, 1.0).to_hsla(),
+                            hsla($h, $s, $l, 1.0)
+                        );
+
+                        assert_approximately_eq!(
+                            rgba($r, $g, $b, 0.78).to_hsla(),
+                            hsla($h, $s, $l, 0.78)
+                        );
+
+                        assert_approximately_eq!(
+                            rgba($r, $g, $b, 0.0).to_hsla(),
+                            hsla($h, $s, $l, 0.0)
+                        );
+                    }
+
+                    #[test]
+                    fn hsl_to_hsl() {
+                        assert_eq!(hsl($h, $s, $l).to_hsl(), hsl($h, $s, $l));
+                    }
+
+                    #[test]
+                    fn hsl_to_hsla() {
+                        assert_eq!(hsl($h, $s, $l).to_hsla(), hsla($h, $s, $l, 1.0));
+                    }
+
+                    #[test]
+                    fn hsla_to_hsl() {
+                        assert_eq!(hsla($h, $s, $l, 1.0).to_hsl(), hsl($h, $s, $l));
+
+                        assert_eq!(hsla($h, $s, $l, 0.78).to_hsl(), hsl($h, $s, $l));
+
+                        assert_eq!(hsla($h, $s, $l, 0.0).to_hsl(), hsl($h, $s, $l));
+                    }
+
+                    #[test]
+                    fn hsla_to_hsla() {
+                        assert_eq!(hsla($h, $s, $l, 1.0).to_hsla(), hsla($h, $s, $l, 1.0));
+
+                        assert_eq!(hsla($h, $s, $l, 0.78).to_hsla(), hsla($h, $s, $l, 0.78));
+
+                        assert_eq!(hsla($h, $s, $l, 0.0).to_hsla(), hsla($h, $s, $l, 0.0));
+                    }
+
+                    #[test]
+                    fn hsl_to_rgb() {
+                        assert_approximately_eq!(hsl($h, $s, $l).to_rgb(), rgb($r, $g, $b));
+                    }
+
+                    #[test]
+                    fn hsl_to_rgba() {
+                        assert_approximately_eq!(hsl($h, $s, $l).to_rgba(), rgba($r, $g, $b, 1.0));
+                    }
+
+                    #[test]
+                    fn hsla_to_rgb() {
+                        assert_approximately_eq!(hsla($h, $s, $l, 1.0).to_rgb(), rgb($r, $g, $b));
+
+                        assert_approximately_eq!(hsla($h, $s, $l, 0.78).to_rgb(), rgb($r, $g, $b));
+
+                        assert_approximately_eq!(hsla($h, $s, $l, 0.0).to_rgb(), rgb($r, $g, $b));
+                    }
+
+                    #[test]
+                    fn hsla_to_rgba() {
+                        assert_approximately_eq!(
+                            hsla($h, $s, $l, 1.0).to_rgba(),
+                            rgba($r, $g, $b, 1.0)
+                        );
+
+                        assert_approximately_eq!(
+                            hsla($h, $s, $l, 0.78).to_rgba(),
+                            rgba($r, $g, $b, 0.78)
+                        );
+
+                        assert_approximately_eq!(
+                            hsla($h, $s, $l, 0.0).to_rgba(),
+                            rgba($r, $g, $b, 0.0)
+                        );
+                    }
+                }
+            };
+        }
+
+        conversion_test!(black, rgb(0, 0, 0), hsl(0, 0, 0));
+        conversion_test!(grey, rgb(230, 230, 230), hsl(0, 0, 90));
+        conversion_test!(white, rgb(255, 255, 255), hsl(0, 0, 100));
+        conversion_test!(pink, rgb(253, 216, 229), hsl(339, 90, 92));
+        conversion_test!(brown, rgb(172, 96, 83), hsl(9, 35, 50));
+        conversion_test!(teal, rgb(23, 98, 119), hsl(193, 68, 28));
+        conversion_test!(green, rgb(89, 161, 54), hsl(100, 50, 42));
+        conversion_test!(pale_blue, rgb(148, 189, 209), hsl(200, 40, 70));
+        conversion_test!(mauve, rgb(136, 102, 153), hsl(280, 20, 50));
+        conversion_test!(cherry, rgb(230, 25, 60), hsl(350, 80, 50));
+        conversion_test!(tomato, rgb(255, 99, 71), hsl(9, 100, 64));
+        conversion_test!(light_salmon, rgb(255, 160, 122), hsl(17, 100, 74));
+        conversion_test!(blue_violet, rgb(138, 43, 226), hsl(271, 76, 53));
+        conversion_test!(dark_orange, rgb(255, 140, 0), hsl(33, 100, 50));
+        conversion_test!(deep_pink, rgb(255, 20, 147), hsl(328, 100, 54));
+        conversion_test!(chartreuse, rgb(127, 255, 0), hsl(90, 100, 50));
+    }
+
+    #[macro_use]
+    mod hsv_conversions {
+        macro_rules! hsv_conversion_test {
+            (
+                $color_name:ident,
+                rgb($r:expr, $g:expr, $b:expr),
+                hsv($h:expr, $s:expr, $v:expr)
+            ) => {
+                mod $color_name {
+                    use super::super::ApproximatelyEq;
+                    use $crate::{hsv, hsva, rgb, rgba, Color};
+
+                    #[test]
+                    fn hsv_to_hsv() {
+                        assert_eq!(hsv($h, $s, $v).to_hsv(), hsv($h, $s, $v));
+                    }
+
+                    #[test]
+                    fn rgb_to_hsv() {
+                        assert_approximately_eq!(rgb($r, $g, $b).to_hsv(), hsv($h, $s, $v));
+                    }
+
+                    #[test]
+                    fn rgba_to_hsv() {
+                        assert_approximately_eq!(rgba($r, $g, $b, 1.0).to_hsv(), hsv($h, $s, $v));
+
+                        assert_approximately_eq!(rgba($r, $g, $b, 0.78).to_hsv(), hsv($h, $s, $v));
+
+                        assert_approximately_eq!(rgba($r, $g, $b, 0.0).to_hsv(), hsv($h, $s, $v));
+                    }
+
+                    #[test]
+                    fn hsv_to_rgb() {
+                        assert_approximately_eq!(hsv($h, $s, $v).to_rgb(), rgb($r, $g, $b));
+                    }
+
+                    #[test]
+                    fn hsv_to_rgba() {
+                        assert_approximately_eq!(hsv($h, $s, $v).to_rgba(), rgba($r, $g, $b, 1.0));
+                    }
+
+                    #[test]
+                    fn hsv_to_hsl_and_back() {
+                        assert_approximately_eq!(
+                            hsv($h, $s, $v).to_hsl().to_hsv(),
+                            hsv($h, $s, $v)
+                        );
+                    }
+
+                    #[test]
+                    fn hsva_to_hsva() {
+                        assert_eq!(hsva($h, $s, $v, 1.0).to_hsva(), hsva($h, $s, $v, 1.0));
+
+                        assert_eq!(hsva($h, $s, $v, 0.78).to_hsva(), hsva($h, $s, $v, 0.78));
+
+                        assert_eq!(hsva($h, $s, $v, 0.0).to_hsva(), hsva($h, $s, $v, 0.0));
+                    }
+
+                    #[test]
+                    fn hsv_to_hsva() {
+                        assert_eq!(hsv($h, $s, $v).to_hsva(), hsva($h, $s, $v, 1.0));
+                    }
+
+                    #[test]
+                    fn hsva_to_hsv() {
+                        assert_eq!(hsva($h, $s, $v, 1.0).to_hsv(), hsv($h, $s, $v));
+
+                        assert_eq!(hsva($h, $s, $v, 0.78).to_hsv(), hsv($h, $s, $v));
+
+                        assert_eq!(hsva($h, $s, $v, 0.0).to_hsv(), hsv($h, $s, $v));
+                    }
+
+                    #[test]
+                    fn rgb_to_hsva() {
+                        assert_approximately_eq!(rgb($r, $g, $b).to_hsva(), hsva($h, $s, $v, 1.0));
+                    }
+
+                    #[test]
+                    fn rgba_to_hsva() {
+                        assert_approximately_eq!(
+                            rgba($r, $g, $b, 1.0).to_hsva(),
+                            hsva($h, $s, $v, 1.0)
+                        );
+
+                        assert_approximately_eq!(
+                            rgba($r, $g, $b, 0.78).to_hsva(),
+                            hsva($h, $s, $v, 0.78)
+                        );
+
+                        assert_approximately_eq!(
+                            rgba($r, $g, $b, 0.0).to_hsva(),
+                            hsva($h, $s, $v, 0.0)
+                        );
+                    }
+
+                    #[test]
+                    fn hsva_to_rgb() {
+                        assert_approximately_eq!(hsva($h, $s, $v, 1.0).to_rgb(), rgb($r, $g, $b));
+
+                        assert_approximately_eq!(hsva($h, $s, $v, 0.78).to_rgb(), rgb($r, $g, $b));
+
+                        assert_approximately_eq!(hsva($h, $s, $v, 0.0).to_rgb(), rgb($r, $g, $b));
+                    }
+
+                    #[test]
+                    fn hsva_to_rgba() {
+                        assert_approximately_eq!(
+                            hsva($h, $s, $v, 1.0).to_rgba(),
+                            rgba($r, $g, $b, 1.0)
+                        );
+
+                        assert_approximately_eq!(
+                            hsva($h, $s, $v, 0.78).to_rgba(),
+                            rgba($r, $g, $b, 0.78)
+                        );
+
+                        assert_approximately_eq!(
+                            hsva($h, $s, $v, 0.0).to_rgba(),
+                            rgba($r, $g, $b, 0.0)
+                        );
+                    }
+
+                    #[test]
+                    fn hsva_to_hsla_and_back() {
+                        assert_approximately_eq!(
+                            hsva($h, $s, $v, 1.0).to_hsla().to_hsva(),
+                            hsva($h, $s, $v, 1.0)
+                        );
+
+                        assert_approximately_eq!(
+                            hsva($h, $s, $v, 0.78).to_hsla().to_hsva(),
+                            hsva($h, $s, $v, 0.78)
+                        );
+
+                        assert_approximately_eq!(
+                            hsva($h, $s, $v, 0.0).to_hsla().to_hsva(),
+                            hsva($h, $s, $v, 0.0)
+                        );
+                    }
+                }
+            };
+        }
+
+        hsv_conversion_test!(black, rgb(0, 0, 0), hsv(0, 0, 0));
+        hsv_conversion_test!(grey, rgb(230, 230, 230), hsv(0, 0, 90));
+        hsv_conversion_test!(white, rgb(255, 255, 255), hsv(0, 0, 100));
+        hsv_conversion_test!(brown, rgb(172, 96, 83), hsv(9, 52, 67));
+        hsv_conversion_test!(teal, rgb(23, 98, 119), hsv(193, 81, 47));
+        hsv_conversion_test!(green, rgb(89, 161, 54), hsv(100, 66, 63));
+        hsv_conversion_test!(pale_blue, rgb(148, 189, 209), hsv(200, 29, 82));
+        hsv_conversion_test!(mauve, rgb(136, 102, 153), hsv(280, 33, 60));
+        hsv_conversion_test!(cherry, rgb(230, 25, 60), hsv(350, 89, 90));
+        hsv_conversion_test!(tomato, rgb(255, 99, 71), hsv(9, 72, 100));
+        hsv_conversion_test!(light_salmon, rgb(255, 160, 122), hsv(17, 52, 100));
+        hsv_conversion_test!(blue_violet, rgb(138, 43, 226), hsv(271, 81, 89));
+        hsv_conversion_test!(dark_orange, rgb(255, 140, 0), hsv(33, 100, 100));
+        hsv_conversion_test!(chartreuse, rgb(127, 255, 0), hsv(90, 100, 100));
+    }
+
+    #[test]
+    fn can_saturate() {
+        assert_approximately_eq!(hsl(9, 35, 50).saturate(percent(20)), hsl(9, 55, 50));
+        assert_approximately_eq!(
+            hsla(9, 35, 50, 1.0).saturate(percent(20)),
+            hsla(9, 55, 50, 1.0)
+        );
+
+        assert_approximately_eq!(rgb(172, 96, 83).saturate(percent(20)), rgb(197, 78, 57));
+        assert_approximately_eq!(
+            rgba(172, 96, 83, 1.0).saturate(percent(20)),
+            rgba(197, 78, 57, 1.0)
+        );
+    }
+
+    #[test]
+    fn can_desaturate() {
+        assert_approximately_eq!(hsl(9, 55, 50).desaturate(percent(20)), hsl(9, 35, 50));
+        assert_approximately_eq!(
+            hsla(9, 55, 50, 1.0).desaturate(percent(20)),
+            hsla(9, 35, 50, 1.0)
+        );
+        assert_approximately_eq!(rgb(197, 78, 57).desaturate(percent(20)), rgb(172, 96, 83));
+        assert_approximately_eq!(
+            rgba(197, 78, 57, 1.0).desaturate(percent(20)),
+            rgba(172, 96, 83, 1.0)
+        );
+    }
+
+    #[test]
+    fn can_lighten() {
+        assert_approximately_eq!(hsl(9, 35, 50).lighten(percent(20)), hsl(9, 35, 70));
+        assert_approximately_eq!(
+            hsla(9, 35, 50, 1.0).lighten(percent(20)),
+            hsla(9, 35, 70, 1.0)
+        );
+        assert_approximately_eq!(rgb(172, 96, 83).lighten(percent(20)), rgb(205, 160, 152));
+        assert_approximately_eq!(
+            rgba(172, 96, 83, 1.0).lighten(percent(20)),
+            rgba(205, 160, 152, 1.0)
+        );
+    }
+
+    #[test]
+    fn can_darken() {
+        assert_approximately_eq!(hsl(9, 35, 70).darken(percent(20)), hsl(9, 35, 50));
+        assert_approximately_eq!(
+            hsla(9, 35, 70, 1.0).darken(percent(20)),
+            hsla(9, 35, 50, 1.0)
+        );
+        assert_approximately_eq!(rgb(205, 160, 152).darken(percent(20)), rgb(172, 96, 83));
+        assert_approximately_eq!(
+            rgba(205, 160, 152, 1.0).darken(percent(20)),
+            rgba(172, 96, 83, 1.0)
+        );
+    }
+
+    #[test]
+    fn can_fadein() {
+        assert_approximately_eq!(hsl(9, 35, 50).fadein(percent(25)), hsla(9, 35, 50, 1.0));
+        assert_approximately_eq!(
+            hsla(9, 35, 50, 0.5).fadein(percent(25)),
+            hsla(9, 35, 50, 0.75)
+        );
+        assert_approximately_eq!(rgb(172, 96, 83).fadein(percent(25)), rgba(172, 96, 83, 1.0));
+        assert_approximately_eq!(
+            rgba(172, 96, 83, 0.50).fadein(percent(25)),
+            rgba(172, 96, 83, 0.75)
+        );
+    }
+
+    #[test]
+    fn can_fadeout() {
+        assert_approximately_eq!(hsl(9, 35, 50).fadeout(percent(25)), hsla(9, 35, 50, 0.75));
+        assert_approximately_eq!(
+            rgb(172, 96, 83).fadeout(percent(25)),
+            rgba(172, 96, 83, 0.75)
+        );
+        assert_approximately_eq!(
+            hsla(9, 35, 50, 0.60).fadeout(percent(25)),
+            hsla(9, 35, 50, 0.35)
+        );
+        assert_approximately_eq!(
+            rgba(172, 96, 83, 0.60).fadeout(percent(25)),
+            rgba(172, 96, 83, 0.35)
+        );
+    }
+
+    #[test]
+    fn fadein_and_fadeout_saturate() {
+        let amount = Ratio::from_u8(20);
+
+        for &(alpha, faded_in, faded_out) in [(0, 20, 0), (245, 255, 225), (255, 255, 235)].iter() {
+            let color = RGBA {
+                a: Ratio::from_u8(alpha),
+                ..rgba(172, 96, 83, 1.0)
+            };
+
+            assert_eq!(color.fadein(amount).a, Ratio::from_u8(faded_in));
+            assert_eq!(color.fadeout(amount).a, Ratio::from_u8(faded_out));
+
+            // HSLA and HSVA store the same alpha byte, and fade it identically.
+            assert_eq!(color.to_hsla().fadein(amount).a, Ratio::from_u8(faded_in));
+            assert_eq!(color.to_hsla().fadeout(amount).a, Ratio::from_u8(faded_out));
+            assert_eq!(color.to_hsva().fadein(amount).a, Ratio::from_u8(faded_in));
+            assert_eq!(color.to_hsva().fadeout(amount).a, Ratio::from_u8(faded_out));
+        }
+
+        assert_eq!(rgb(172, 96, 83).fadein(percent(100)).a, percent(100));
+        assert_eq!(hsl(9, 35, 50).fadeout(percent(100)).a, percent(0));
+    }
+
+    #[test]
+    fn fading_opaque_colors_starts_from_full_opacity() {
+        let amount = Ratio::from_u8(64);
+        let faded_out = Ratio::from_u8(191);
+
+        assert_eq!(rgb(172, 96, 83).fadeout(amount).a, faded_out);
+        assert_eq!(rgba(172, 96, 83, 1.0).fadeout(amount).a, faded_out);
+        assert_eq!(hsl(9, 35, 50).fadeout(amount).a, faded_out);
+        assert_eq!(hsla(9, 35, 50, 1.0).fadeout(amount).a, faded_out);
+
+        assert_eq!(rgb(172, 96, 83).fadein(amount), rgba(172, 96, 83, 1.0));
+        assert_eq!(hsl(9, 35, 50).fadein(amount), hsla(9, 35, 50, 1.0));
+    }
+
+    #[test]
+    fn can_fade() {
+        let faded_color = rgba(23, 98, 119, 0.5);
+
+        // Amounts are percentages of opacity, as in Less, not alpha bytes.
+        assert_eq!(
+            rgb(23, 98, 119).fade(percent(50)).to_string(),
+            "rgba(23, 98, 119, 0.50)"
+        );
+        assert_eq!(rgb(23, 98, 119).fade(percent(20)).a.as_u8(), 51);
+        assert_eq!(rgb(23, 98, 119).fade(Ratio::from_u8(51)).a, percent(20));
+
+        assert_approximately_eq!(rgb(23, 98, 119).fade(percent(50)), faded_color);
+        assert_approximately_eq!(rgba(23, 98, 119, 1.0).fade(percent(50)), faded_color);
+        assert_approximately_eq!(hsl(193, 67, 28).fade(percent(50)), faded_color.to_hsla());
+        assert_approximately_eq!(
+            hsla(193, 67, 28, 1.0).fade(percent(50)),
+            faded_color.to_hsla()
+        );
+    }
+
+    #[test]
+    fn perceptual_desaturation_keeps_the_luminance() {
+        let yellow = rgb(255, 255, 0);
+        let blue = rgb(0, 0, 255);
+
+        for &color in [yellow, blue, rgb(250, 128, 114), rgb(46, 139, 87)].iter() {
+            for &amount in [10, 50, 100].iter() {
+                let perceptual = color.desaturate_perceptual(percent(amount));
+
+                assert!(perceptual.to_oklch().c <= color.to_oklch().c);
+                assert!(
+                    (perceptual.relative_luminance() - color.relative_luminance()).abs() < 0.03,
+                    "{} {}",
+                    color,
+                    amount
+                );
+            }
+        }
+
+        // HSL keeps the lightness of yellow, which is far from its luminance.
+        let hsl_grey = yellow.desaturate(percent(100));
+
+        assert!((hsl_grey.relative_luminance() - yellow.relative_luminance()).abs() > 0.5);
+        assert!(
+            yellow
+                .desaturate_perceptual(percent(100))
+                .relative_luminance()
+                > blue
+                    .desaturate_perceptual(percent(100))
+                    .relative_luminance()
+                    + 0.5
+        );
+    }
+
+    #[test]
+    fn perceptual_saturation_stays_in_gamut() {
+        let red = rgba(255, 0, 0, 0.5);
+        let saturated = red.saturate_perceptual(percent(50));
+
+        assert_eq!(saturated.a, red.a);
+        assert!((saturated.to_oklch().l - red.to_oklch().l).abs() < 0.01);
+        assert!((saturated.to_oklch().h - red.to_oklch().h).abs() < 1.0);
+        assert_eq!(
+            hsl(0, 0, 40).saturate_perceptual(percent(50)),
+            hsl(0, 0, 40)
+        );
+        assert_eq!(
+            hsl(9, 35, 50).saturate_perceptual(percent(0)),
+            hsl(9, 35, 50)
+        );
+    }
+
+    #[test]
+    fn vibrance_boosts_muted_colors_the_most() {
+        let muted_blue = rgb(100, 112, 140);
+        let vivid_red = rgb(230, 20, 20);
+
+        for &amount in [10, 50, 100].iter() {
+            let muted_boost = muted_blue.vibrance(amount).hsv_saturation().as_f32()
+                - muted_blue.hsv_saturation().as_f32();
+            let vivid_boost = vivid_red.vibrance(amount).hsv_saturation().as_f32()
+                - vivid_red.hsv_saturation().as_f32();
+
+            assert!(muted_boost > vivid_boost * 5.0, "{}", amount);
         }
+
+        // The hue and value are kept, and negative amounts desaturate.
+        let boosted = muted_blue.vibrance(40);
+
+        assert_eq!(boosted.to_hsv().h, muted_blue.to_hsv().h);
+        assert_eq!(boosted.hsv_value(), muted_blue.hsv_value());
+        assert!(muted_blue.vibrance(-50).hsv_saturation() < muted_blue.hsv_saturation());
+        assert_eq!(muted_blue.vibrance(-100), rgb(140, 140, 140));
+        assert_eq!(muted_blue.vibrance(0), muted_blue);
+        assert_eq!(rgb(255, 0, 0).vibrance(100), rgb(255, 0, 0));
+        assert_eq!(
+            rgba(100, 112, 140, 0.3).vibrance(40).a,
+            rgba(100, 112, 140, 0.3).a
+        );
     }
 
-    impl ApproximatelyEq for RGB {
-        fn approximately_eq(self, other: Self) -> bool {
-            self.to_css() == other.to_css()
-                || self.r.approximately_eq(other.r)
-                    && self.g.approximately_eq(other.g)
-                    && self.b.approximately_eq(other.b)
+    #[test]
+    fn can_saturate_and_desaturate_relatively() {
+        // Saturation of `hsl(200, s, 50%)` scaled by 20% in both directions. The
+        // channels are stored as bytes, so 94.4% and 74.4% round to 94.5% and 74.5%.
+        let levels = [
+            (0, "0%", "0%"),
+            (50, "60%", "40%"),
+            (93, "95%", "75%"),
+            (100, "100%", "80%"),
+        ];
+
+        for &(s, saturated, desaturated) in levels.iter() {
+            let color = hsl(200, s, 50);
+
+            assert_eq!(color.saturate_rel(percent(20)).s.to_string(), saturated);
+            assert_eq!(color.desaturate_rel(percent(20)).s.to_string(), desaturated);
         }
+
+        // Greys stay grey, whatever the amount.
+        assert_eq!(hsl(0, 0, 50).saturate_rel(percent(100)), hsl(0, 0, 50));
+        assert_eq!(
+            rgb(128, 128, 128).saturate_rel(percent(50)),
+            rgb(128, 128, 128)
+        );
+
+        assert_eq!(hsl(200, 1, 50).saturate_rel(percent(100)).s, percent(100));
+        assert_eq!(hsl(200, 93, 50).desaturate_rel(percent(100)).s, percent(0));
+        assert_eq!(
+            hsla(200, 50, 50, 0.5).saturate_rel(percent(20)).a,
+            hsla(200, 50, 50, 0.5).a
+        );
     }
 
-    impl ApproximatelyEq for RGBA {
-        fn approximately_eq(self, other: Self) -> bool {
-            self.to_css() == other.to_css()
-                || self.r.approximately_eq(other.r)
-                    && self.g.approximately_eq(other.g)
-                    && self.b.approximately_eq(other.b)
-                    && self.a == other.a
+    #[test]
+    fn can_lighten_and_darken_relatively() {
+        // Lightness of `hsl(0, 100%, l)` scaled by 20%, as computed by Sass'
+        // `scale-color($lightness: 20%)` and `scale-color($lightness: -20%)`.
+        let levels = [
+            (0, "20%", "0%"),
+            (25, "40%", "20%"),
+            (50, "60%", "40%"),
+            (70, "76%", "56%"),
+            (90, "92%", "72%"),
+            (100, "100%", "80%"),
+        ];
+
+        for &(l, lighter, darker) in levels.iter() {
+            let color = hsl(0, 100, l);
+
+            assert_eq!(color.lighten_rel(percent(20)).l.to_string(), lighter);
+            assert_eq!(color.darken_rel(percent(20)).l.to_string(), darker);
         }
+
+        // Scaling never goes past the extremes.
+        assert_eq!(hsl(0, 100, 100).lighten_rel(percent(100)), hsl(0, 100, 100));
+        assert_eq!(hsl(0, 100, 0).darken_rel(percent(100)), hsl(0, 100, 0));
+        assert_eq!(hsl(0, 100, 40).lighten_rel(percent(100)).l, percent(100));
+        assert_eq!(hsl(0, 100, 40).darken_rel(percent(100)).l, percent(0));
+        assert_eq!(hsl(0, 100, 40).lighten_rel(percent(0)), hsl(0, 100, 40));
+
+        // Other color models are scaled through HSL, keeping their alpha.
+        assert_eq!(rgb(0, 0, 0).darken_rel(percent(50)), rgb(0, 0, 0));
+        assert_eq!(
+            rgb(255, 255, 255).lighten_rel(percent(50)),
+            rgb(255, 255, 255)
+        );
+        assert_approximately_eq!(
+            rgba(255, 99, 71, 0.5).lighten_rel(percent(50)),
+            hsla(9, 100, 82, 0.5).to_rgba()
+        );
     }
 
-    impl ApproximatelyEq for HSL {
-        fn approximately_eq(self, other: Self) -> bool {
-            self.to_css() == other.to_css()
-                || self.h.approximately_eq(other.h)
-                    && self
-                        .s
-                        .as_percentage()
-                        .approximately_eq(other.s.as_percentage())
-                    && self
-                        .l
-                        .as_percentage()
-                        .approximately_eq(other.l.as_percentage())
+    #[test]
+    fn can_spin_forward() {
+        assert_approximately_eq!(rgb(75, 207, 23).spin(deg(100)), rgb(23, 136, 207));
+        assert_approximately_eq!(
+            rgba(75, 207, 23, 1.0).spin(deg(100)),
+            rgba(23, 136, 207, 1.0)
+        );
+        assert_approximately_eq!(hsl(10, 90, 50).spin(deg(30)), hsl(40, 90, 50));
+        assert_approximately_eq!(hsla(10, 90, 50, 1.0).spin(deg(30)), hsla(40, 90, 50, 1.0));
+    }
+
+    #[test]
+    fn can_spin_backwards() {
+        assert_approximately_eq!(rgb(75, 207, 23).spin(deg(-100)), rgb(207, 32, 23));
+        assert_approximately_eq!(
+            rgba(75, 207, 23, 1.0).spin(deg(-100)),
+            rgba(207, 32, 23, 1.0)
+        );
+        assert_approximately_eq!(hsl(10, 90, 50).spin(deg(-30)), hsl(340, 90, 50));
+        assert_approximately_eq!(hsla(10, 90, 50, 1.0).spin(deg(-30)), hsla(340, 90, 50, 1.0));
+    }
+
+    #[test]
+    fn can_set_channels() {
+        let salmon = rgb(250, 128, 114);
+        let translucent_salmon = rgba(250, 128, 114, 0.5);
+
+        assert_eq!(salmon.with_red(0), rgb(0, 128, 114));
+        assert_eq!(salmon.with_green(0), rgb(250, 0, 114));
+        assert_eq!(salmon.with_blue(0), rgb(250, 128, 0));
+
+        assert_eq!(translucent_salmon.with_red(0), rgba(0, 128, 114, 0.5));
+        assert_eq!(translucent_salmon.with_green(0), rgba(250, 0, 114, 0.5));
+        assert_eq!(translucent_salmon.with_blue(0), rgba(250, 128, 0, 0.5));
+        assert_eq!(
+            translucent_salmon.with_alpha(percent(100)),
+            rgba(250, 128, 114, 1.0)
+        );
+
+        let red = hsl(0, 100, 50);
+        let translucent_red = hsla(0, 100, 50, 0.5);
+
+        assert_eq!(red.with_hue(deg(120)), hsl(120, 100, 50));
+        assert_eq!(red.with_saturation(percent(20)), hsl(0, 20, 50));
+        assert_eq!(red.with_lightness(percent(20)), hsl(0, 100, 20));
+
+        assert_eq!(translucent_red.with_hue(deg(-120)), hsla(240, 100, 50, 0.5));
+        assert_eq!(
+            translucent_red.with_saturation(percent(20)),
+            hsla(0, 20, 50, 0.5)
+        );
+        assert_eq!(
+            translucent_red.with_lightness(percent(20)),
+            hsla(0, 100, 20, 0.5)
+        );
+        assert_eq!(
+            translucent_red.with_alpha(percent(0)),
+            hsla(0, 100, 50, 0.0)
+        );
+
+        // Setters compose in iterator chains.
+        let reds: Vec<RGB> = (0..3).map(|i| salmon.with_red(i * 100)).collect();
+
+        assert_eq!(
+            reds,
+            [rgb(0, 128, 114), rgb(100, 128, 114), rgb(200, 128, 114)]
+        );
+    }
+
+    #[test]
+    fn can_spin_back_and_forth() {
+        for h in (0..360).step_by(7) {
+            for &amount in [1, 30, 90, 179, 180, 250, 359].iter() {
+                let hsl = hsl(h, 90, 50);
+                let hsla = hsla(h, 40, 80, 0.3);
+
+                assert_eq!(hsl.spin(deg(amount)).spin(deg(-amount)), hsl);
+                assert_eq!(hsla.spin(deg(amount)).spin(deg(-amount)), hsla);
+                assert_eq!(hsla.spin(deg(-amount)).spin(deg(amount)), hsla);
+            }
         }
+
+        // Spinning keeps the color model, and the alpha channel.
+        let spun: HSLA = hsla(10, 90, 50, 0.5).spin(deg(30));
+        assert_eq!(spun, hsla(40, 90, 50, 0.5));
+
+        let spun: RGBA = rgba(243, 13, 90, 0.5).spin(deg(-30));
+        assert_eq!(spun.a, rgba(243, 13, 90, 0.5).a);
     }
 
-    impl ApproximatelyEq for HSLA {
-        fn approximately_eq(self, other: Self) -> bool {
-            self.to_css() == other.to_css()
-                || self.h.approximately_eq(other.h)
-                    && self
-                        .s
-                        .as_percentage()
-                        .approximately_eq(other.s.as_percentage())
-                    && self
-                        .l
-                        .as_percentage()
-                        .approximately_eq(other.l.as_percentage())
-                    && self.a == other.a
+    #[test]
+    fn can_spin_by_any_amount() {
+        let amounts = (-100_000..100_000).step_by(997).chain(vec![
+            -360,
+            360,
+            720,
+            i16::MIN as i32,
+            i16::MAX as i32,
+            i32::MIN,
+            i32::MAX,
+        ]);
+
+        for amount in amounts {
+            for h in (0..360).step_by(45) {
+                let color = hsla(h, 90, 50, 0.5);
+
+                assert_eq!(color.spin(deg(amount)), color.spin(deg(amount % 360)));
+            }
         }
+
+        assert_eq!(hsl(10, 90, 50).spin(deg(360)), hsl(10, 90, 50));
+        assert_eq!(
+            hsl(10, 90, 50).spin(deg(-450)),
+            hsl(10, 90, 50).spin(deg(270))
+        );
     }
 
-    #[macro_export]
-    macro_rules! assert_approximately_eq {
-        ($lhs:expr, $rhs:expr) => {
-            let lhs = $lhs;
-            let rhs = $rhs;
+    #[test]
+    fn can_spin_hsl_by_fractional_degrees() {
+        let salmon = hsla(6, 93, 71, 0.5);
+        let mut forward = salmon;
+        let mut backward = salmon;
 
-            assert!(lhs.approximately_eq(rhs), "lhs: {}, rhs: {}", lhs, rhs);
-        };
+        // Hues are whole degrees, so each half-degree step is rounded to a whole
+        // degree in the direction of the rotation.
+        for _ in 0..720 {
+            forward = forward.spin_f32(0.5);
+            backward = backward.spin_f32(-0.5);
+        }
+
+        assert_eq!(forward, salmon);
+        assert_eq!(backward, salmon);
+        assert_eq!(salmon.spin_f32(0.5), hsla(7, 93, 71, 0.5));
+        assert_eq!(salmon.spin_f32(-0.5), hsla(5, 93, 71, 0.5));
+        assert_eq!(hsl(6, 93, 71).spin_f32(-6.5), hsl(359, 93, 71));
+
+        // Smaller steps stall, since the rotation is rounded on every call.
+        assert_eq!(salmon.spin_f32(0.4), salmon);
+        assert_eq!(salmon.spin_f32(29.6), salmon.spin(deg(30)));
+        assert_eq!(salmon.spin_f32(-1e9), salmon.spin(deg(-1_000_000_000)));
+    }
+
+    #[test]
+    fn can_complement() {
+        assert_eq!(hsl(30, 100, 50).complement(), hsl(210, 100, 50));
+        assert_eq!(hsl(210, 100, 50).complement(), hsl(30, 100, 50));
+        assert_eq!(hsla(300, 40, 20, 0.3).complement(), hsla(120, 40, 20, 0.3));
+        assert_approximately_eq!(rgba(255, 0, 0, 0.5).complement(), rgba(0, 255, 255, 0.5));
+        assert_approximately_eq!(rgb(250, 128, 114).complement(), rgb(114, 236, 250));
+
+        for h in (0..360).step_by(15) {
+            let color = hsla(h, 80, 40, 0.5);
+
+            assert_eq!(color.complement().complement(), color);
+        }
+
+        // Greys have no hue to rotate.
+        assert_eq!(hsl(30, 0, 50).complement(), hsl(30, 0, 50));
+        assert_eq!(rgb(128, 128, 128).complement(), rgb(128, 128, 128));
+    }
+
+    #[test]
+    fn can_invert() {
+        assert_eq!(rgb(255, 255, 255).invert(), rgb(0, 0, 0));
+        assert_eq!(rgb(0, 0, 0).invert(), rgb(255, 255, 255));
+        assert_eq!(rgb(250, 128, 114).invert(), rgb(5, 127, 141));
+        assert_eq!(rgba(250, 128, 114, 0.3).invert(), rgba(5, 127, 141, 0.3));
+        assert_eq!(rgb(250, 128, 114).invert().invert(), rgb(250, 128, 114));
+
+        // HSL colors are inverted through RGB.
+        assert_eq!(hsl(0, 0, 100).invert(), hsl(0, 0, 0));
+        assert_approximately_eq!(
+            hsl(6, 93, 71).invert(),
+            rgb(250, 128, 114).invert().to_hsl()
+        );
+        assert_eq!(
+            hsla(120, 100, 25, 0.5).invert().a,
+            hsla(120, 100, 25, 0.5).a
+        );
+    }
+
+    #[test]
+    fn can_invert_weighted() {
+        let salmon = rgb(250, 128, 114);
+
+        assert_eq!(salmon.invert_weighted(0), salmon.to_rgba());
+        assert_eq!(salmon.invert_weighted(100), salmon.invert().to_rgba());
+        assert_eq!(salmon.invert_weighted(50), rgba(127, 127, 128, 1.0));
+        assert_eq!(
+            rgba(250, 128, 114, 0.5).invert_weighted(100),
+            rgba(5, 127, 141, 0.5)
+        );
+        assert_approximately_eq!(
+            hsla(6, 93, 71, 0.5).invert_weighted(0),
+            hsla(6, 93, 71, 0.5)
+        );
+    }
+
+    #[test]
+    fn can_mix() {
+        let brown_rgba = rgba(50, 50, 0, 1.0);
+        let brown_hsla = hsla(60, 100, 10, 1.0);
+
+        assert_approximately_eq!(
+            rgba(100, 0, 0, 1.0).mix(rgba(0, 100, 0, 1.0), percent(50)),
+            brown_rgba
+        );
+        assert_approximately_eq!(rgb(100, 0, 0).mix(rgb(0, 100, 0), percent(50)), brown_rgba);
+        assert_approximately_eq!(
+            hsl(0, 100, 20).mix(hsl(120, 100, 20), percent(50)),
+            brown_hsla
+        );
+        assert_approximately_eq!(
+            hsla(0, 100, 20, 1.0).mix(hsla(120, 100, 20, 1.0), percent(50)),
+            brown_hsla
+        );
+    }
+
+    #[test]
+    fn can_apply_css_brightness_and_contrast_filters() {
+        // What `filter: brightness(f)` and `contrast(f)` give `rgb(160, 82, 46)`,
+        // per the feComponentTransfer equivalents of the Filter Effects spec.
+        let sienna = rgb(160, 82, 46);
+        let levels = [
+            (0.0, rgb(0, 0, 0), rgb(128, 128, 128)),
+            (0.5, rgb(80, 41, 23), rgb(144, 105, 87)),
+            (1.0, sienna, sienna),
+            (1.5, rgb(240, 123, 69), rgb(176, 59, 5)),
+            (2.0, rgb(255, 164, 92), rgb(193, 37, 0)),
+        ];
+
+        for &(factor, brightness, contrast) in levels.iter() {
+            assert_eq!(sienna.brightness(factor), brightness, "{}", factor);
+            assert_approximately_eq!(sienna.contrast_adjust(factor), contrast);
+        }
+
+        let translucent = hsla(19, 55, 40, 0.25);
+
+        assert_eq!(translucent.brightness(1.2).a, translucent.a);
+        assert_eq!(translucent.contrast_adjust(0.8).a, translucent.a);
+        assert_eq!(rgb(255, 255, 255).brightness(3.0), rgb(255, 255, 255));
+    }
+
+    #[test]
+    #[should_panic(expected = "Invalid factor for brightness")]
+    fn cannot_apply_negative_brightness() {
+        rgb(160, 82, 46).brightness(-1.0);
+    }
+
+    #[test]
+    fn can_apply_css_hue_rotate_filter() {
+        // What `filter: hue-rotate(a)` gives solid colors, per the feColorMatrix
+        // equivalent of the Filter Effects spec.
+        let samples = [
+            (rgb(0, 255, 0), 90, rgb(0, 218, 255)),
+            (rgb(0, 255, 0), 180, rgb(255, 110, 255)),
+            (rgb(0, 0, 255), 180, rgb(37, 37, 0)),
+            (rgb(250, 128, 114), 90, rgb(114, 174, 56)),
+            (rgb(250, 128, 114), 270, rgb(192, 132, 250)),
+        ];
+
+        for &(color, degrees, rotated) in samples.iter() {
+            assert_eq!(
+                color.hue_rotate(deg(degrees)),
+                rotated,
+                "{} {}",
+                color,
+                degrees
+            );
+        }
+
+        // Greys have no hue to rotate, and a full turn is the identity.
+        for &color in [rgb(0, 0, 0), rgb(128, 128, 128), rgb(250, 128, 114)].iter() {
+            assert_approximately_eq!(color.hue_rotate(deg(0)), color);
+            assert_approximately_eq!(color.hue_rotate(deg(360)), color);
+        }
+        assert_eq!(rgb(128, 128, 128).hue_rotate(deg(123)), rgb(128, 128, 128));
+
+        let translucent = hsla(200, 50, 50, 0.3);
+
+        assert_eq!(translucent.hue_rotate(deg(45)).a, translucent.a);
+    }
+
+    #[test]
+    fn can_apply_css_sepia_filter() {
+        let cornflower_blue = rgb(100, 149, 237);
+        let sepia = cornflower_blue.sepia(1.0);
+
+        assert_eq!(rgb(255, 255, 255).sepia(1.0), rgb(255, 255, 239));
+        assert_eq!(rgb(0, 0, 0).sepia(1.0), rgb(0, 0, 0));
+        assert_eq!(cornflower_blue.sepia(0.0), cornflower_blue);
+        assert_eq!(cornflower_blue.sepia(2.0), sepia);
+
+        // The matrix is interpolated linearly, so each channel moves linearly.
+        for step in 0..=10 {
+            let amount = step as f32 / 10.0;
+            let toned = cornflower_blue.sepia(amount);
+            let expected = |from: Ratio, to: Ratio| {
+                from.as_u8() as f32 + (to.as_u8() as f32 - from.as_u8() as f32) * amount
+            };
+
+            assert!((toned.r.as_u8() as f32 - expected(cornflower_blue.r, sepia.r)).abs() <= 1.0);
+            assert!((toned.g.as_u8() as f32 - expected(cornflower_blue.g, sepia.g)).abs() <= 1.0);
+            assert!((toned.b.as_u8() as f32 - expected(cornflower_blue.b, sepia.b)).abs() <= 1.0);
+        }
+
+        assert_eq!(
+            hsla(219, 79, 66, 0.4).sepia(0.7).a,
+            hsla(219, 79, 66, 0.4).a
+        );
+    }
+
+    #[test]
+    fn can_posterize() {
+        for &levels in [2u8, 4, 6].iter() {
+            let step = 255.0 / f32::from(levels - 1);
+            let allowed: Vec<u8> = (0..levels)
+                .map(|i| (f32::from(i) * step).round() as u8)
+                .collect();
+
+            for value in 0..=255u8 {
+                let snapped = rgb(value, value, value).posterize(levels).r.as_u8();
+                let distance = |level: u8| (i16::from(level) - i16::from(value)).abs();
+
+                assert!(allowed.contains(&snapped), "{} {}", levels, value);
+                assert!(allowed
+                    .iter()
+                    .all(|&level| distance(snapped) <= distance(level)));
+            }
+
+            assert_eq!(rgb(0, 255, 0).posterize(levels), rgb(0, 255, 0));
+        }
+
+        assert_eq!(rgb(127, 128, 200).posterize(2), rgb(0, 255, 255));
+        assert_approximately_eq!(
+            hsla(6, 93, 71, 0.4).posterize(4).to_rgba(),
+            rgba(255, 85, 85, 0.4)
+        );
+    }
+
+    #[test]
+    #[should_panic(expected = "Invalid number of levels")]
+    fn cannot_posterize_to_one_level() {
+        rgb(250, 128, 114).posterize(1);
+    }
+
+    #[test]
+    fn can_mix_with_fine_weights() {
+        let black = rgb(0, 0, 0);
+        let white = rgb(255, 255, 255);
+
+        // Every step of a 256-step gradient is distinct.
+        let gradient: Vec<RGBA> = (0..256)
+            .map(|step| white.mix_f32(black, step as f32 / 255.0))
+            .collect();
+
+        for pair in gradient.windows(2) {
+            assert!(pair[0].r < pair[1].r, "{:?}", pair);
+            assert!(pair[0].g < pair[1].g, "{:?}", pair);
+            assert!(pair[0].b < pair[1].b, "{:?}", pair);
+        }
+
+        // Weights between two `Ratio`s are not rounded to either of them.
+        assert_eq!(
+            white.mix_f32(black, 127.5 / 255.0),
+            rgba(128, 128, 128, 1.0)
+        );
+        assert_eq!(
+            white.mix(black, Ratio::from_u8(127)),
+            rgba(127, 127, 127, 1.0)
+        );
+
+        assert_eq!(
+            rgba(0, 0, 0, 0.0).mix_f32(white, 0.5).a,
+            Ratio::from_f32(0.5)
+        );
+        assert_eq!(hsl(0, 100, 50).mix_f32(white, 1.0), hsla(0, 100, 50, 1.0));
+    }
+
+    #[test]
+    #[cfg(debug_assertions)]
+    #[should_panic(expected = "Invalid weight for mix")]
+    fn cannot_mix_with_weights_above_one() {
+        rgb(0, 0, 0).mix_f32(rgb(255, 255, 255), 1.5);
+    }
+
+    #[test]
+    #[cfg(debug_assertions)]
+    #[should_panic(expected = "Invalid weight for mix")]
+    fn cannot_invert_with_weights_above_100() {
+        rgb(0, 0, 0).invert_weighted(150);
+    }
+
+    #[test]
+    #[cfg(not(debug_assertions))]
+    fn clamps_weights_out_of_range() {
+        let black = rgb(0, 0, 0);
+        let white = rgb(255, 255, 255);
+
+        assert_eq!(black.mix_f32(white, 1.5), black.mix_f32(white, 1.0));
+        assert_eq!(black.mix_f32(white, -0.5), black.mix_f32(white, 0.0));
+        assert_eq!(black.invert_weighted(150), black.invert_weighted(100));
+        assert_eq!(
+            ::RGBFloat::from_rgba(black.to_rgba()).mix_f32(white, 2.0),
+            ::RGBFloat::from_rgba(black.to_rgba())
+        );
+    }
+
+    #[test]
+    fn can_average_colors() {
+        let red = rgb(255, 0, 0);
+        let green = hsl(120, 100, 50);
+        let blue = rgba(0, 0, 255, 0.5);
+
+        assert_eq!(
+            RGBA::average_of(vec![red.to_rgba(), green.to_rgba(), blue]),
+            Some(rgba(85, 85, 85, 0.835))
+        );
+        assert_eq!(
+            RGBA::average_of(vec![blue, green.to_rgba(), red.to_rgba()]),
+            RGBA::average_of(vec![red.to_rgba(), green.to_rgba(), blue])
+        );
+
+        assert_eq!(RGBA::average_of(Some(green)), Some(green.to_rgba()));
+        assert_eq!(RGBA::average_of(vec![blue; 7]), Some(blue));
+        assert_eq!(RGBA::average_of(Vec::<HSL>::new()), None);
+    }
+
+    #[test]
+    fn can_blend_weighted_colors() {
+        let red = rgb(255, 0, 0).to_rgba();
+        let clear_blue = rgba(0, 0, 255, 0.0);
+
+        assert_eq!(RGBA::blend_weighted(&[(red, 2.5)]), Some(red));
+        assert_eq!(RGBA::blend_weighted(&[]), None);
+        assert_eq!(RGBA::blend_weighted(&[(red, 0.0), (clear_blue, 0.0)]), None);
+
+        // Alpha is weighted like the other channels, and doesn't weigh them.
+        assert_eq!(
+            RGBA::blend_weighted(&[(red, 1.0), (clear_blue, 1.0)]),
+            Some(rgba(128, 0, 128, 0.5))
+        );
+        assert_eq!(
+            RGBA::blend_weighted(&[(red, 1.0), (clear_blue, 1.0), (red, 0.0)]),
+            RGBA::average_of(vec![red, clear_blue])
+        );
     }
 
     #[test]
-    fn can_create_color_structs() {
+    fn can_mix_in_linear_light() {
+        let black = rgb(0, 0, 0);
+        let white = rgb(255, 255, 255);
+
+        assert_eq!(black.mix(white, percent(50)), rgba(127, 127, 127, 1.0));
+        assert_approximately_eq!(
+            black.mix_linear(white, percent(50)),
+            rgba(188, 188, 188, 1.0)
+        );
+        assert_eq!(black.mix_linear(white, percent(0)), white.to_rgba());
+        assert_eq!(black.mix_linear(white, percent(100)), black.to_rgba());
+
+        // Opaque colors mix like `LinearRGB::lerp`.
+        let red = rgb(255, 0, 0);
+        let green = rgb(0, 255, 0);
+
+        for weight in (0..=255).step_by(5) {
+            let weight = Ratio::from_u8(weight as u8);
+            let lerp = green
+                .to_linear()
+                .lerp(red.to_linear(), weight.as_f32())
+                .to_srgb();
+
+            assert_approximately_eq!(red.mix_linear(green, weight), lerp.to_rgba());
+        }
+
+        // Alpha isn't gamma-encoded, so it is mixed like in `mix`.
+        let translucent = rgba(255, 0, 0, 0.2);
+
         assert_eq!(
-            rgb(5, 10, 15),
-            RGB {
-                r: Ratio::from_u8(5),
-                g: Ratio::from_u8(10),
-                b: Ratio::from_u8(15),
-            }
+            translucent.mix_linear(white, percent(50)).a,
+            translucent.mix(white, percent(50)).a
         );
         assert_eq!(
-            rgba(5, 10, 15, 1.0),
-            RGBA {
-                r: Ratio::from_u8(5),
-                g: Ratio::from_u8(10),
-                b: Ratio::from_u8(15),
-                a: Ratio::from_u8(255),
-            }
+            hsl(0, 0, 0).mix_linear(white, percent(50)).to_rgba(),
+            black.mix_linear(white, percent(50))
         );
+    }
+
+    #[test]
+    fn can_lerp() {
+        let golden = rgb(243, 166, 13);
+        let navy = rgba(0, 0, 80, 0.5);
+
+        assert_eq!(golden.lerp(navy, 0.0), golden.to_rgba());
+        assert_eq!(golden.lerp(navy, 1.0), navy);
         assert_eq!(
-            hsl(6, 93, 71),
-            HSL {
-                h: Angle::new(6),
-                s: Ratio::from_percentage(93),
-                l: Ratio::from_percentage(71)
-            }
+            hsl(0, 0, 0).lerp(rgb(255, 255, 255), 0.5),
+            rgba(128, 128, 128, 1.0)
         );
+
+        // The alpha channel is interpolated independently of the others.
         assert_eq!(
-            hsla(6, 93, 71, 1.0),
-            HSLA {
-                h: Angle::new(6),
-                s: Ratio::from_percentage(93),
-                l: Ratio::from_percentage(71),
-                a: Ratio::from_u8(255),
-            }
+            rgba(255, 0, 0, 0.0).lerp(rgba(0, 0, 255, 1.0), 0.5),
+            rgba(128, 0, 128, 0.5)
         );
+
+        // Easing curves can overshoot, which is clamped.
+        assert_eq!(golden.lerp(navy, -0.2), golden.to_rgba());
+        assert_eq!(golden.lerp(navy, 1.2), navy);
     }
 
-    #[macro_use]
-    mod conversions {
-        macro_rules! conversion_test {
-            (
-                $color_name:ident,
-                rgb($r:expr, $g:expr, $b:expr),
-                hsl($h:expr, $s:expr, $l:expr)
-            ) => {
-                mod $color_name {
-                    use super::super::ApproximatelyEq;
-                    use $crate::{hsl, hsla, rgb, rgba, Color};
+    #[test]
+    fn lerp_agrees_with_mix() {
+        let colors = [
+            rgb(250, 128, 114),
+            rgb(0, 0, 128),
+            rgb(255, 215, 0),
+            rgb(46, 139, 87),
+        ];
 
-                    #[test]
-                    fn rgb_to_rgb() {
-                        assert_eq!(rgb($r, $g, $b).to_rgb(), rgb($r, $g, $b));
-                    }
+        for &lhs in colors.iter() {
+            for &rhs in colors.iter() {
+                for weight in 0..=100 {
+                    let t = (100 - weight) as f32 / 100.0;
 
-                    #[test]
-                    fn rgb_to_rgba() {
-                        assert_eq!(rgb($r, $g, $b).to_rgba(), rgba($r, $g, $b, 1.0));
-                    }
+                    assert_approximately_eq!(
+                        lhs.lerp(rhs, t),
+                        lhs.mix_f32(rhs, weight as f32 / 100.0)
+                    );
+                }
+            }
+        }
+    }
 
-                    #[test]
-                    fn rgba_to_rgb() {
-                        assert_eq!(rgba($r, $g, $b, 1.0).to_rgb(), rgb($r, $g, $b));
-                        assert_eq!(rgba($r, $g, $b, 0.78).to_rgb(), rgb($r, $g, $b));
-                        assert_eq!(rgba($r, $g, $b, 0.0).to_rgb(), rgb($r, $g, $b));
-                    }
+    #[test]
+    fn lerp_is_monotonic() {
+        let from = rgba(250, 128, 114, 0.2);
+        let to = rgba(46, 139, 87, 0.9);
+        let mut previous = from;
 
-                    #[test]
-                    fn rgba_to_rgba() {
-                        assert_eq!(rgba($r, $g, $b, 1.0).to_rgba(), rgba($r, $g, $b, 1.0));
+        for step in 1..=1000 {
+            let current = from.lerp(to, step as f32 / 1000.0);
 
-                        assert_eq!(rgba($r, $g, $b, 0.78).to_rgba(), rgba($r, $g, $b, 0.78));
+            assert!(current.r <= previous.r);
+            assert!(current.g >= previous.g);
+            assert!(current.b <= previous.b);
+            assert!(current.a >= previous.a);
 
-                        assert_eq!(rgba($r, $g, $b, 0.0).to_rgba(), rgba($r, $g, $b, 0.0));
-                    }
+            previous = current;
+        }
 
-                    #[test]
-                    fn rgb_to_hsl() {
-                        assert_approximately_eq!(rgb($r, $g, $b).to_hsl(), hsl($h, $s, $l));
-                    }
+        assert_eq!(previous, to);
+    }
 
-                    #[test]
-                    fn rgb_to_hsla() {
-                        assert_approximately_eq!(rgb($r, $g, $b).to_hsla(), hsla($h, $s, $l, 1.0));
-                    }
+    #[test]
+    fn can_take_steps() {
+        let salmon = rgba(250, 128, 114, 0.2);
+        let sea_green = hsla(146, 50, 36, 0.9);
 
-                    #[test]
-                    fn rgba_to_hsl() {
-                        assert_approximately_eq!(rgba($r, $g, $b, 1.0).to_hsl(), hsl($h, $s, $l));
+        assert_eq!(salmon.steps(sea_green, 0), vec![]);
+        assert_eq!(salmon.steps(sea_green, 1), vec![salmon]);
+        assert_eq!(
+            salmon.steps(sea_green, 2),
+            vec![salmon, sea_green.to_rgba()]
+        );
 
-                        assert_approximately_eq!(rgba($r, $g, $b, 0.78).to_hsl(), hsl($h, $s, $l));
+        let steps = salmon.steps(sea_green, 16);
 
-                        assert_approximately_eq!(rgba($r, $g, $b, 0.0).to_hsl(), hsl($h, $s, $l));
-                    }
+        assert_eq!(steps.len(), 16);
+        assert_eq!(steps[0], salmon);
+        assert_eq!(steps[15], sea_green.to_rgba());
 
-                    #[test]
-                    fn rgba_to_hsla() {
-                        assert_approximately_eq!(
-                            rgba($r, $g, $b, 1.0).to_hsla(),
-                            hsla($h, $s, $l, 1.0)
-                        );
+        for pair in steps.windows(2) {
+            assert!(pair[1].r < pair[0].r);
+            assert!(pair[1].g >= pair[0].g);
+            assert!(pair[1].b < pair[0].b);
+            assert!(pair[1].a > pair[0].a);
+        }
 
-                        assert_approximately_eq!(
-                            rgba($r, $g, $b, 0.78).to_hsla(),
-                            hsla($h, $s, $l, 0.78)
-                        );
+        assert_eq!(
+            steps,
+            Gradient::new(vec![(0.0, salmon), (1.0, sea_green.to_rgba())]).steps(16)
+        );
+    }
 
-                        assert_approximately_eq!(
-                            rgba($r, $g, $b, 0.0).to_hsla(),
-                            hsla($h, $s, $l, 0.0)
-                        );
-                    }
+    #[test]
+    fn can_lerp_in_hsl() {
+        let crimson = hsla(350, 80, 40, 0.2);
+        let orange = hsla(10, 100, 60, 1.0);
 
-                    #[test]
-                    fn hsl_to_hsl() {
-                        assert_eq!(hsl($h, $s, $l).to_hsl(), hsl($h, $s, $l));
-                    }
+        let hues = |path: HuePath| -> Vec<u16> {
+            [0.0, 0.25, 0.5, 0.75, 1.0]
+                .iter()
+                .map(|&t| crimson.lerp_hsl(orange, t, path).h.degrees())
+                .collect()
+        };
 
-                    #[test]
-                    fn hsl_to_hsla() {
-                        assert_eq!(hsl($h, $s, $l).to_hsla(), hsla($h, $s, $l, 1.0));
-                    }
+        assert_eq!(hues(HuePath::Shorter), [350, 355, 0, 5, 10]);
+        assert_eq!(hues(HuePath::Longer), [350, 265, 180, 95, 10]);
+        assert_eq!(hues(HuePath::Increasing), [350, 355, 0, 5, 10]);
+        assert_eq!(hues(HuePath::Decreasing), [350, 265, 180, 95, 10]);
 
-                    #[test]
-                    fn hsla_to_hsl() {
-                        assert_eq!(hsla($h, $s, $l, 1.0).to_hsl(), hsl($h, $s, $l));
+        // The other channels don't depend on the path.
+        for &path in [HuePath::Shorter, HuePath::Longer].iter() {
+            let middle = crimson.lerp_hsl(orange, 0.5, path);
 
-                        assert_eq!(hsla($h, $s, $l, 0.78).to_hsl(), hsl($h, $s, $l));
+            assert_eq!(middle.s, Ratio::from_f32(0.9));
+            assert_eq!(middle.l, Ratio::from_f32(0.5));
+            assert_eq!(middle.a, Ratio::from_f32(0.6));
+        }
 
-                        assert_eq!(hsla($h, $s, $l, 0.0).to_hsl(), hsl($h, $s, $l));
-                    }
+        assert_eq!(crimson.lerp_hsl(orange, 0.0, HuePath::Longer), crimson);
+        assert_eq!(crimson.lerp_hsl(orange, 1.0, HuePath::Longer), orange);
+        assert_eq!(crimson.lerp_hsl(orange, 2.0, HuePath::Longer), orange);
+    }
 
-                    #[test]
-                    fn hsla_to_hsla() {
-                        assert_eq!(hsla($h, $s, $l, 1.0).to_hsla(), hsla($h, $s, $l, 1.0));
+    #[test]
+    fn lerp_hsl_keeps_the_hue_of_colors_against_greys() {
+        let white = rgb(255, 255, 255);
+        let blue = hsl(240, 100, 50);
 
-                        assert_eq!(hsla($h, $s, $l, 0.78).to_hsla(), hsla($h, $s, $l, 0.78));
+        for &path in [
+            HuePath::Shorter,
+            HuePath::Longer,
+            HuePath::Increasing,
+            HuePath::Decreasing,
+        ]
+        .iter()
+        {
+            for step in 0..=10 {
+                let t = step as f32 / 10.0;
 
-                        assert_eq!(hsla($h, $s, $l, 0.0).to_hsla(), hsla($h, $s, $l, 0.0));
-                    }
+                assert_eq!(white.lerp_hsl(blue, t, path).h, deg(240));
+                assert_eq!(blue.lerp_hsl(white, t, path).h, deg(240));
+            }
 
-                    #[test]
-                    fn hsl_to_rgb() {
-                        assert_approximately_eq!(hsl($h, $s, $l).to_rgb(), rgb($r, $g, $b));
-                    }
+            // Between two greys, there is no hue to interpolate.
+            assert_eq!(white.lerp_hsl(rgb(0, 0, 0), 0.5, path), hsla(0, 0, 50, 1.0));
+        }
 
-                    #[test]
-                    fn hsl_to_rgba() {
-                        assert_approximately_eq!(hsl($h, $s, $l).to_rgba(), rgba($r, $g, $b, 1.0));
-                    }
+        // Saturation fades in from the grey, rather than the hue sweeping.
+        assert_eq!(
+            white.lerp_hsl(blue, 0.5, HuePath::Shorter).to_string(),
+            "hsla(240, 50%, 75%, 1.00)"
+        );
+    }
 
-                    #[test]
-                    fn hsla_to_rgb() {
-                        assert_approximately_eq!(hsla($h, $s, $l, 1.0).to_rgb(), rgb($r, $g, $b));
+    #[test]
+    fn can_generate_triadic_schemes() {
+        for hue in (0..360).step_by(7) {
+            let [base, second, third] = hsla(hue, 80, 40, 0.5).triadic();
 
-                        assert_approximately_eq!(hsla($h, $s, $l, 0.78).to_rgb(), rgb($r, $g, $b));
+            assert_eq!(base, hsla(hue, 80, 40, 0.5));
+            assert_eq!(second.h, deg(hue + 120));
+            assert_eq!(third.h, deg(hue + 240));
+            assert_eq!((second.s, second.l, second.a), (base.s, base.l, base.a));
+            assert_eq!((third.s, third.l, third.a), (base.s, base.l, base.a));
+        }
 
-                        assert_approximately_eq!(hsla($h, $s, $l, 0.0).to_rgb(), rgb($r, $g, $b));
-                    }
+        // In RGB, each member is the rotation of the base through HSL.
+        let salmon = rgb(250, 128, 114);
+        let scheme = salmon.triadic();
 
-                    #[test]
-                    fn hsla_to_rgba() {
-                        assert_approximately_eq!(
-                            hsla($h, $s, $l, 1.0).to_rgba(),
-                            rgba($r, $g, $b, 1.0)
-                        );
+        assert_eq!(scheme[0], salmon);
 
-                        assert_approximately_eq!(
-                            hsla($h, $s, $l, 0.78).to_rgba(),
-                            rgba($r, $g, $b, 0.78)
-                        );
+        for (member, &turn) in scheme.iter().zip([0, 120, 240].iter()) {
+            let expected = salmon.to_hsl().spin(deg(turn)).to_rgb();
 
-                        assert_approximately_eq!(
-                            hsla($h, $s, $l, 0.0).to_rgba(),
-                            rgba($r, $g, $b, 0.0)
-                        );
-                    }
-                }
-            };
+            assert_approximately_eq!(*member, expected);
         }
 
-        conversion_test!(black, rgb(0, 0, 0), hsl(0, 0, 0));
-        conversion_test!(grey, rgb(230, 230, 230), hsl(0, 0, 90));
-        conversion_test!(white, rgb(255, 255, 255), hsl(0, 0, 100));
-        conversion_test!(pink, rgb(253, 216, 229), hsl(339, 90, 92));
-        conversion_test!(brown, rgb(172, 96, 83), hsl(9, 35, 50));
-        conversion_test!(teal, rgb(23, 98, 119), hsl(193, 68, 28));
-        conversion_test!(green, rgb(89, 161, 54), hsl(100, 50, 42));
-        conversion_test!(pale_blue, rgb(148, 189, 209), hsl(200, 40, 70));
-        conversion_test!(mauve, rgb(136, 102, 153), hsl(280, 20, 50));
-        conversion_test!(cherry, rgb(230, 25, 60), hsl(350, 80, 50));
-        conversion_test!(tomato, rgb(255, 99, 71), hsl(9, 100, 64));
-        conversion_test!(light_salmon, rgb(255, 160, 122), hsl(17, 100, 74));
-        conversion_test!(blue_violet, rgb(138, 43, 226), hsl(271, 76, 53));
-        conversion_test!(dark_orange, rgb(255, 140, 0), hsl(33, 100, 50));
-        conversion_test!(deep_pink, rgb(255, 20, 147), hsl(328, 100, 54));
-        conversion_test!(chartreuse, rgb(127, 255, 0), hsl(90, 100, 50));
+        // Greys have no hue to rotate.
+        assert_eq!(hsl(200, 0, 30).triadic(), [hsl(200, 0, 30); 3]);
+        assert_eq!(rgba(80, 80, 80, 0.5).triadic(), [rgba(80, 80, 80, 0.5); 3]);
     }
 
     #[test]
-    fn can_saturate() {
-        assert_approximately_eq!(hsl(9, 35, 50).saturate(percent(20)), hsl(9, 55, 50));
-        assert_approximately_eq!(
-            hsla(9, 35, 50, 1.0).saturate(percent(20)),
-            hsla(9, 55, 50, 1.0)
+    fn can_generate_split_complementary_schemes() {
+        // Around the wrap of the color wheel, from either side.
+        let [base, lhs, rhs] = hsla(350, 80, 40, 0.5).split_complementary(deg(30));
+
+        assert_eq!(base, hsla(350, 80, 40, 0.5));
+        assert_eq!(lhs, hsla(140, 80, 40, 0.5));
+        assert_eq!(rhs, hsla(200, 80, 40, 0.5));
+
+        let scheme = hsl(10, 80, 40).split_complementary(deg(30));
+
+        assert_eq!(
+            scheme,
+            [hsl(10, 80, 40), hsl(160, 80, 40), hsl(220, 80, 40)]
         );
 
-        assert_approximately_eq!(rgb(172, 96, 83).saturate(percent(20)), rgb(197, 78, 57));
-        assert_approximately_eq!(
-            rgba(172, 96, 83, 1.0).saturate(percent(20)),
-            rgba(197, 78, 57, 1.0)
+        // Spreads that reach past the base wrap as well.
+        let scheme = hsl(170, 80, 40).split_complementary(deg(200));
+
+        assert_eq!(
+            scheme,
+            [hsl(170, 80, 40), hsl(150, 80, 40), hsl(190, 80, 40)]
+        );
+
+        for hue in (0..360).step_by(7) {
+            let base = hsl(hue, 80, 40);
+            let [_, lhs, rhs] = base.split_complementary(deg(45));
+
+            assert_eq!(lhs, base.complement().spin(deg(-45)));
+            assert_eq!(rhs, base.complement().spin(deg(45)));
+        }
+
+        assert_eq!(
+            rgb(128, 128, 128).split_complementary(deg(30)),
+            [rgb(128, 128, 128); 3]
         );
     }
 
     #[test]
-    fn can_desaturate() {
-        assert_approximately_eq!(hsl(9, 55, 50).desaturate(percent(20)), hsl(9, 35, 50));
-        assert_approximately_eq!(
-            hsla(9, 55, 50, 1.0).desaturate(percent(20)),
-            hsla(9, 35, 50, 1.0)
-        );
-        assert_approximately_eq!(rgb(197, 78, 57).desaturate(percent(20)), rgb(172, 96, 83));
-        assert_approximately_eq!(
-            rgba(197, 78, 57, 1.0).desaturate(percent(20)),
-            rgba(172, 96, 83, 1.0)
+    fn can_generate_analogous_palettes() {
+        let base = hsla(350, 80, 40, 0.5);
+        let hues =
+            |colors: Vec<HSLA>| -> Vec<u16> { colors.iter().map(|c| c.h.degrees()).collect() };
+
+        // Odd counts are centered on the base.
+        assert_eq!(hues(base.analogous(3, deg(30))), [320, 350, 20]);
+        assert_eq!(hues(base.analogous(5, deg(20))), [310, 330, 350, 10, 30]);
+        assert_eq!(base.analogous(5, deg(20))[2], base);
+
+        // Even counts place the base between the two middle colors.
+        assert_eq!(hues(base.analogous(2, deg(30))), [335, 5]);
+        assert_eq!(hues(base.analogous(4, deg(30))), [305, 335, 5, 35]);
+        assert_eq!(hues(base.analogous(4, deg(25))), [312, 337, 3, 28]);
+
+        for color in base.analogous(6, deg(40)) {
+            assert_eq!((color.s, color.l, color.a), (base.s, base.l, base.a));
+        }
+
+        assert_eq!(base.analogous(0, deg(30)), vec![]);
+        assert_eq!(base.analogous(1, deg(30)), vec![base]);
+        assert_eq!(
+            rgb(128, 128, 128).analogous(3, deg(30)),
+            vec![rgb(128, 128, 128); 3]
         );
     }
 
     #[test]
-    fn can_lighten() {
-        assert_approximately_eq!(hsl(9, 35, 50).lighten(percent(20)), hsl(9, 35, 70));
-        assert_approximately_eq!(
-            hsla(9, 35, 50, 1.0).lighten(percent(20)),
-            hsla(9, 35, 70, 1.0)
-        );
-        assert_approximately_eq!(rgb(172, 96, 83).lighten(percent(20)), rgb(205, 160, 152));
-        assert_approximately_eq!(
-            rgba(172, 96, 83, 1.0).lighten(percent(20)),
-            rgba(205, 160, 152, 1.0)
-        );
+    fn can_generate_tetradic_schemes() {
+        let distance = |lhs: HSLA, rhs: HSLA| (rhs.h - lhs.h).degrees();
+
+        for hue in (0..360).step_by(7) {
+            let base = hsla(hue, 80, 40, 0.5);
+
+            // The square scheme.
+            let [first, second, third, fourth] = base.tetradic(deg(90));
+
+            assert_eq!(first, base);
+            assert_eq!(distance(first, second), 90);
+            assert_eq!(distance(second, third), 90);
+            assert_eq!(distance(third, fourth), 90);
+            assert_eq!(distance(fourth, first), 90);
+
+            // A rectangle scheme.
+            let [first, second, third, fourth] = base.tetradic(deg(40));
+
+            assert_eq!(distance(first, second), 40);
+            assert_eq!(distance(first, third), 180);
+            assert_eq!(distance(second, fourth), 180);
+            assert_eq!(distance(third, fourth), 40);
+
+            for color in [second, third, fourth].iter() {
+                assert_eq!((color.s, color.l, color.a), (base.s, base.l, base.a));
+            }
+        }
+
+        let scheme = rgba(250, 128, 114, 0.5).tetradic(deg(90));
+
+        assert_eq!(scheme[0], rgba(250, 128, 114, 0.5));
+        assert_eq!(scheme[2], rgba(250, 128, 114, 0.5).complement());
+        assert!(scheme.iter().all(|color| color.a == scheme[0].a));
+
+        assert_eq!(hsl(200, 0, 30).tetradic(deg(90)), [hsl(200, 0, 30); 4]);
     }
 
     #[test]
-    fn can_darken() {
-        assert_approximately_eq!(hsl(9, 35, 70).darken(percent(20)), hsl(9, 35, 50));
-        assert_approximately_eq!(
-            hsla(9, 35, 70, 1.0).darken(percent(20)),
-            hsla(9, 35, 50, 1.0)
-        );
-        assert_approximately_eq!(rgb(205, 160, 152).darken(percent(20)), rgb(172, 96, 83));
-        assert_approximately_eq!(
-            rgba(205, 160, 152, 1.0).darken(percent(20)),
-            rgba(172, 96, 83, 1.0)
+    fn can_generate_monochromatic_ladders() {
+        // The blue of Material Design, at its natural position in a ladder of ten.
+        let blue = rgb(33, 150, 243);
+
+        assert_eq!(
+            blue.monochromatic(10),
+            vec![
+                rgb(229, 243, 255),
+                rgb(186, 223, 254),
+                rgb(142, 202, 252),
+                rgb(100, 181, 248),
+                rgb(60, 161, 244),
+                blue,
+                rgb(9, 116, 203),
+                rgb(5, 92, 163),
+                rgb(1, 67, 121),
+                rgb(0, 42, 76),
+            ]
         );
+
+        // The hue and alpha are kept, and the lightness goes down the ladder.
+        let ladder = hsla(340, 60, 90, 0.5).monochromatic(7);
+
+        assert_eq!(ladder[0], hsla(340, 60, 90, 0.5));
+        assert!(ladder
+            .iter()
+            .all(|color| color.h == deg(340) && color.a == ladder[0].a));
+        assert!(ladder.windows(2).all(|pair| pair[1].l < pair[0].l));
+
+        // The saturation is raised toward the ends.
+        assert_eq!(ladder[3].s, percent(60));
+        assert!(ladder[5].s > ladder[4].s && ladder[6].s > ladder[5].s);
+
+        assert_eq!(blue.monochromatic(0), vec![]);
+        assert_eq!(blue.monochromatic(1), vec![blue]);
     }
 
     #[test]
-    fn can_fadein() {
-        assert_approximately_eq!(hsl(9, 35, 50).fadein(percent(25)), hsla(9, 35, 50, 1.0));
-        assert_approximately_eq!(
-            hsla(9, 35, 50, 0.5).fadein(percent(25)),
-            hsla(9, 35, 50, 0.75)
-        );
-        assert_approximately_eq!(rgb(172, 96, 83).fadein(percent(25)), rgba(172, 96, 83, 1.0));
-        assert_approximately_eq!(
-            rgba(172, 96, 83, 0.50).fadein(percent(25)),
-            rgba(172, 96, 83, 0.75)
-        );
+    fn can_generate_tints_and_shades() {
+        let golden = rgb(243, 166, 13);
+        let red = hsla(10, 90, 50, 0.5);
+
+        for n in 0..10 {
+            assert_eq!(golden.tints(n).len(), n);
+            assert_eq!(red.shades(n).len(), n);
+        }
+
+        let weights = [percent(80), percent(60), percent(40), percent(20)];
+
+        for (i, &weight) in weights.iter().enumerate() {
+            assert_eq!(golden.tints(4)[i], golden.tint(weight).to_rgba());
+            assert_eq!(golden.shades(4)[i], golden.shade(weight).to_rgba());
+            assert_eq!(red.tints(4)[i], red.tint(weight).to_rgba());
+            assert_eq!(red.shades(4)[i], red.shade(weight).to_rgba());
+        }
+
+        // The endpoints are excluded, and the tints get lighter.
+        let tints = golden.tints(9);
+
+        assert_ne!(tints[0], golden.to_rgba());
+        assert_ne!(tints[8], rgba(255, 255, 255, 1.0));
+        assert!(tints.windows(2).all(|pair| pair[1].b > pair[0].b));
+
+        let shades = golden.shades(9);
+
+        assert!(shades.windows(2).all(|pair| pair[1].r < pair[0].r));
+        assert_ne!(shades[8], rgba(0, 0, 0, 1.0));
     }
 
     #[test]
-    fn can_fadeout() {
-        assert_approximately_eq!(hsl(9, 35, 50).fadeout(percent(25)), hsla(9, 35, 50, 0.75));
-        assert_approximately_eq!(
-            rgb(172, 96, 83).fadeout(percent(25)),
-            rgba(172, 96, 83, 0.75)
+    fn can_composite_over() {
+        let backdrops = [
+            rgba(0, 0, 0, 0.0),
+            rgba(250, 128, 114, 0.5),
+            rgba(46, 139, 87, 0.2),
+            rgba(0, 0, 128, 1.0),
+        ];
+
+        for &backdrop in backdrops.iter() {
+            // A fully transparent source leaves the backdrop as it is.
+            assert_eq!(rgba(255, 215, 0, 0.0).over(backdrop), backdrop);
+
+            // A fully opaque source covers the backdrop.
+            assert_eq!(rgb(255, 215, 0).over(backdrop), rgba(255, 215, 0, 1.0));
+        }
+
+        // A transparent backdrop leaves the source as it is.
+        let transparent = rgba(0, 0, 128, 0.0);
+        assert_eq!(
+            rgba(250, 128, 114, 0.5).over(transparent),
+            rgba(250, 128, 114, 0.5)
         );
-        assert_approximately_eq!(
-            hsla(9, 35, 50, 0.60).fadeout(percent(25)),
-            hsla(9, 35, 50, 0.35)
+        assert_eq!(
+            rgba(46, 139, 87, 0.2).over(transparent),
+            rgba(46, 139, 87, 0.2)
         );
-        assert_approximately_eq!(
-            rgba(172, 96, 83, 0.60).fadeout(percent(25)),
-            rgba(172, 96, 83, 0.35)
+
+        // The alphas are `128 / 255` each, so the result has an alpha of
+        // `0.502 + 0.502 × 0.498 = 0.752`, and the red and blue channels are
+        // weighted by `0.502 / 0.752` and `0.250 / 0.752`.
+        assert_eq!(
+            rgba(255, 0, 0, 0.5).over(rgba(0, 0, 255, 0.5)),
+            RGBA {
+                r: Ratio::from_u8(170),
+                g: Ratio::from_u8(0),
+                b: Ratio::from_u8(85),
+                a: Ratio::from_u8(192),
+            }
         );
     }
 
     #[test]
-    fn can_fade() {
-        let faded_color = rgba(23, 98, 119, 0.5);
+    fn can_flatten() {
+        let white = rgb(255, 255, 255);
+        let grey = rgb(128, 128, 128);
 
-        assert_approximately_eq!(rgb(23, 98, 119).fade(percent(50)), faded_color);
-        assert_approximately_eq!(rgba(23, 98, 119, 1.0).fade(percent(50)), faded_color);
-        assert_approximately_eq!(hsl(193, 67, 28).fade(percent(50)), faded_color.to_hsla());
-        assert_approximately_eq!(
-            hsla(193, 67, 28, 1.0).fade(percent(50)),
-            faded_color.to_hsla()
-        );
-    }
+        assert_approximately_eq!(rgba(255, 0, 0, 0.5).flatten(white), rgb(255, 127, 127));
+        assert_approximately_eq!(hsla(0, 100, 50, 0.5).flatten(white), rgb(255, 127, 127));
 
-    #[test]
-    fn can_spin_forward() {
-        assert_approximately_eq!(rgb(75, 207, 23).spin(deg(100)), rgb(23, 136, 207));
-        assert_approximately_eq!(
-            rgba(75, 207, 23, 1.0).spin(deg(100)),
-            rgba(23, 136, 207, 1.0)
+        assert_eq!(rgba(255, 0, 0, 0.0).flatten(grey), grey);
+        assert_eq!(rgba(0, 0, 0, 0.0).flatten(grey), grey);
+        assert_eq!(hsla(120, 50, 25, 0.0).flatten(grey), grey);
+
+        assert_eq!(rgba(46, 139, 87, 1.0).flatten(grey), rgb(46, 139, 87));
+        assert_eq!(
+            rgba(46, 139, 87, 0.2).flatten(grey),
+            rgba(46, 139, 87, 0.2).over(grey).to_rgb()
         );
-        assert_approximately_eq!(hsl(10, 90, 50).spin(deg(30)), hsl(40, 90, 50));
-        assert_approximately_eq!(hsla(10, 90, 50, 1.0).spin(deg(30)), hsla(40, 90, 50, 1.0));
     }
 
     #[test]
-    fn can_spin_backwards() {
-        assert_approximately_eq!(rgb(75, 207, 23).spin(deg(-100)), rgb(207, 32, 23));
-        assert_approximately_eq!(
-            rgba(75, 207, 23, 1.0).spin(deg(-100)),
-            rgba(207, 32, 23, 1.0)
+    fn can_composite_stacks() {
+        let surface = rgb(18, 18, 18);
+        let white = rgba(255, 255, 255, 0.08);
+        let primary = rgba(187, 134, 252, 0.04);
+
+        assert_eq!(surface.composite_stack(&[]), surface);
+        assert_eq!(surface.composite_stack(&[white]), white.flatten(surface));
+        assert_eq!(
+            surface.composite_stack(&[white, primary]),
+            primary.flatten(white.flatten(surface))
         );
-        assert_approximately_eq!(hsl(10, 90, 50).spin(deg(-30)), hsl(340, 90, 50));
-        assert_approximately_eq!(hsla(10, 90, 50, 1.0).spin(deg(-30)), hsla(340, 90, 50, 1.0));
-    }
 
-    #[test]
-    fn can_mix() {
-        let brown_rgba = rgba(50, 50, 0, 1.0);
-        let brown_hsla = hsla(60, 100, 10, 1.0);
+        // The order of the layers matters.
+        let (white, primary) = (
+            white.with_alpha(percent(50)),
+            primary.with_alpha(percent(50)),
+        );
 
-        assert_approximately_eq!(
-            rgba(100, 0, 0, 1.0).mix(rgba(0, 100, 0, 1.0), percent(50)),
-            brown_rgba
+        assert_eq!(
+            surface.composite_stack(&[white, primary]),
+            rgb(162, 135, 195)
         );
-        assert_approximately_eq!(rgb(100, 0, 0).mix(rgb(0, 100, 0), percent(50)), brown_rgba);
-        assert_approximately_eq!(
-            hsl(0, 100, 20).mix(hsl(120, 100, 20), percent(50)),
-            brown_hsla
+        assert_eq!(
+            surface.composite_stack(&[primary, white]),
+            rgb(179, 166, 195)
         );
-        assert_approximately_eq!(
-            hsla(0, 100, 20, 1.0).mix(hsla(120, 100, 20, 1.0), percent(50)),
-            brown_hsla
+
+        // Stacks can be split anywhere, on a translucent base as well.
+        let base = rgba(0, 0, 255, 0.3);
+        let layers = [
+            rgba(255, 0, 0, 0.5),
+            rgba(0, 255, 0, 0.25),
+            rgba(250, 128, 114, 0.6),
+        ];
+
+        for split in 0..=layers.len() {
+            let (below, above) = layers.split_at(split);
+
+            assert_eq!(
+                base.composite_stack(&layers),
+                base.composite_stack(below).composite_stack(above)
+            );
+            assert_eq!(
+                surface.composite_stack(&layers),
+                surface.composite_stack(below).composite_stack(above)
+            );
+        }
+
+        assert_eq!(
+            base.composite_stack(&layers[..2]),
+            layers[1].over(layers[0].over(base))
         );
+
+        // The layers let `0.7 × 0.5 × 0.75 × 0.4` of the light through.
+        assert_eq!(base.composite_stack(&layers).a, Ratio::from_f32(0.895));
+    }
+
+    #[test]
+    fn blend_weighted_agrees_with_mix() {
+        let colors = [
+            rgb(250, 128, 114),
+            rgb(0, 0, 128),
+            rgb(255, 215, 0),
+            rgb(46, 139, 87),
+        ];
+
+        for &lhs in colors.iter() {
+            for &rhs in colors.iter() {
+                for step in 0..=100 {
+                    let weight = step as f32 / 100.0;
+                    let pairs = [(lhs.to_rgba(), weight), (rhs.to_rgba(), 1.0 - weight)];
+
+                    // Both round the same weighted sum, computed in a different order.
+                    assert_approximately_eq!(
+                        RGBA::blend_weighted(&pairs).unwrap(),
+                        lhs.mix_f32(rhs, weight)
+                    );
+                }
+            }
+        }
+    }
+
+    #[test]
+    #[should_panic(expected = "Invalid weight for blend")]
+    fn cannot_blend_negative_weights() {
+        RGBA::blend_weighted(&[(rgba(0, 0, 0, 1.0), 2.0), (rgba(0, 0, 0, 1.0), -1.0)]);
     }
 
     #[test]
@@ -762,7 +4602,34 @@ mod css_color_tests {
         assert_approximately_eq!(hsl(6, 93, 71).tint(percent(50)), hsl(6, 92, 85));
         assert_approximately_eq!(
             hsla(6, 93, 71, 0.5).tint(percent(50)),
-            hsla(6, 95, 93, 0.75)
+            hsla(6, 93, 93, 0.75)
+        );
+    }
+
+    #[test]
+    fn tint_and_shade_keep_the_hue_of_hsl_colors() {
+        for &(s, l) in [(100, 50), (40, 30), (80, 90), (5, 50)].iter() {
+            let blue = hsla(240, s, l, 0.8);
+
+            for weight in 90..=100 {
+                assert_eq!(blue.tint(percent(weight)).h.degrees(), 240);
+                assert_eq!(blue.shade(percent(weight)).h.degrees(), 240);
+                assert_eq!(blue.to_hsl().tint(percent(weight)).h.degrees(), 240);
+            }
+        }
+
+        // Tinting a light color keeps its saturation, which rounding through RGB doesn't.
+        assert_eq!(
+            hsl(240, 60, 70).tint(percent(50)).to_string(),
+            "hsl(240, 60%, 85%)"
+        );
+        assert_eq!(
+            hsl(240, 60, 30).shade(percent(50)).to_string(),
+            "hsl(240, 60%, 15%)"
+        );
+        assert_eq!(
+            hsla(240, 60, 70, 1.0).tint(percent(100)),
+            hsla(240, 60, 70, 1.0)
         );
     }
 
@@ -791,6 +4658,98 @@ mod css_color_tests {
         assert_approximately_eq!(hsla(90, 90, 50, 1.0).greyscale(), hsla(90, 0, 50, 1.0));
     }
 
+    #[test]
+    fn can_greyscale_by_luma() {
+        let yellow = rgb(255, 255, 0);
+        let blue = rgb(0, 0, 255);
+
+        assert_eq!(yellow.greyscale(), blue.greyscale());
+        assert!(yellow.greyscale_luma().r.as_u8() > blue.greyscale_luma().r.as_u8() + 150);
+
+        for &color in [rgb(250, 128, 114), rgb(0, 0, 0), rgb(255, 255, 255)].iter() {
+            let grey = color.greyscale_luma();
+
+            assert!(grey.r == grey.g && grey.g == grey.b, "{}", grey);
+            assert!((grey.relative_luminance() - color.relative_luminance()).abs() < 0.005);
+        }
+
+        assert_eq!(hsla(60, 100, 50, 0.5).greyscale_luma(), hsla(0, 0, 97, 0.5));
+    }
+
+    #[test]
+    fn can_pastelize() {
+        let colors = [
+            rgb(255, 0, 0),
+            rgb(0, 0, 255),
+            rgb(255, 215, 0),
+            rgb(34, 139, 34),
+            rgb(128, 0, 128),
+            rgb(0, 0, 0),
+            rgb(255, 255, 255),
+        ];
+
+        for &color in colors.iter() {
+            let pastel = color.pastelize(percent(100)).to_hsl();
+
+            assert_eq!(pastel.l, percent(85), "{}", color);
+            assert!(pastel.s <= percent(50), "{}", color);
+            assert_eq!(color.pastelize(percent(0)), color);
+        }
+
+        // The hue and alpha are untouched.
+        assert_eq!(hsl(210, 100, 40).pastelize(percent(100)).h, deg(210));
+        assert_eq!(
+            hsla(210, 100, 40, 0.3).pastelize(percent(60)).a,
+            Ratio::from_f32(0.3)
+        );
+
+        // Vivid colors lose more saturation than muted ones.
+        assert_approximately_eq!(hsl(0, 100, 50).pastelize(percent(50)), hsl(0, 75, 68));
+        assert_approximately_eq!(hsl(0, 20, 50).pastelize(percent(50)), hsl(0, 19, 68));
+    }
+
+    #[test]
+    fn can_convert_fully_saturated_colors_to_hsl() {
+        assert_eq!(rgb(220, 27, 255).to_hsl().s, percent(100));
+        assert_eq!(rgb(161, 255, 23).to_hsl().s, percent(100));
+        assert_eq!(rgb(107, 255, 57).to_hsla().s, percent(100));
+    }
+
+    #[test]
+    fn can_convert_to_ie_hex_str() {
+        // Expected values produced by Less' `ie-hex-str()`.
+        assert_eq!(rgb(92, 135, 178).ie_hex_str(), "#FF5C87B2");
+        assert_eq!(rgba(92, 135, 178, 0.5).ie_hex_str(), "#805C87B2");
+        assert_eq!(rgba(0, 0, 0, 0.0).ie_hex_str(), "#00000000");
+        assert_eq!(rgba(255, 255, 255, 0.2).ie_hex_str(), "#33FFFFFF");
+        assert_eq!(hsl(210, 36, 53).ie_hex_str(), "#FF5C87B2");
+        assert_eq!(hsla(210, 36, 53, 0.75).ie_hex_str(), "#BF5C87B2");
+    }
+
+    #[test]
+    fn can_convert_by_reference() {
+        let salmon = rgb(250, 128, 114);
+        let colors = [rgb(5, 10, 255), salmon];
+        let salmon_ref = &salmon;
+
+        assert_eq!(
+            colors.iter().map(Color::to_css).collect::<Vec<_>>(),
+            ["rgb(5, 10, 255)", "rgb(250, 128, 114)"]
+        );
+        assert_eq!(salmon_ref.to_hsl(), salmon.to_hsl());
+        assert_eq!(salmon.to_rgba(), rgba(250, 128, 114, 1.0));
+    }
+
+    #[test]
+    fn can_be_used_as_trait_objects() {
+        let opaque: Vec<Box<dyn Color<Alpha = HSLA>>> =
+            vec![Box::new(hsl(6, 93, 71)), Box::new(hsla(6, 93, 71, 0.5))];
+
+        assert_eq!(opaque[0].to_css(), "hsl(6, 93%, 71%)");
+        assert_eq!(opaque[1].to_css(), "hsla(6, 93%, 71%, 0.50)");
+        assert_eq!(opaque[1].to_hsl(), hsl(6, 93, 71));
+    }
+
     #[test]
     fn can_clone() {
         let rgb_color = rgb(5, 10, 15);
@@ -883,6 +4842,63 @@ mod css_color_tests {
         assert_eq!("hsla(6, 93%, 71%, 1.00)".to_owned(), format!("{}", hsla));
     }
 
+    #[test]
+    fn can_be_displayed_precisely() {
+        let rough_hsl = HSL {
+            h: Angle::new(6),
+            s: Ratio::from_u8(236),
+            l: Ratio::from_percentage(71),
+        };
+        let rough_rgba = RGBA {
+            a: Ratio::from_u8(1),
+            ..rgba(5, 10, 255, 0.0)
+        };
+
+        assert_eq!(format!("{:#}", hsl(6, 93, 71)), "hsl(6, 93%, 71%)");
+        assert_eq!(format!("{:#}", rough_hsl), "hsl(6, 92.5%, 71%)");
+        assert_eq!(format!("{}", rough_hsl), "hsl(6, 93%, 71%)");
+        assert_eq!(
+            format!("{:#}", hsla(6, 93, 71, 0.5)),
+            "hsla(6, 93%, 71%, 0.502)"
+        );
+        assert_eq!(
+            format!("{:#}", rgba(5, 10, 255, 0.0)),
+            "rgba(5, 10, 255, 0.000)"
+        );
+        assert_eq!(format!("{:#}", rough_rgba), "rgba(5, 10, 255, 0.004)");
+    }
+
+    #[test]
+    fn unequal_colors_are_displayed_differently() {
+        let mut hsl_strings = Vec::new();
+        let mut alpha_strings = Vec::new();
+
+        for s in 0..=255 {
+            for l in 0..=255 {
+                let color = HSL {
+                    h: Angle::new(6),
+                    s: Ratio::from_u8(s),
+                    l: Ratio::from_u8(l),
+                };
+
+                hsl_strings.push(format!("{:#}", color));
+            }
+
+            alpha_strings.push(format!(
+                "{:#}",
+                hsla(6, 93, 71, 1.0).fade(Ratio::from_u8(s))
+            ));
+        }
+
+        hsl_strings.sort();
+        hsl_strings.dedup();
+        alpha_strings.sort();
+        alpha_strings.dedup();
+
+        assert_eq!(hsl_strings.len(), 256 * 256);
+        assert_eq!(alpha_strings.len(), 256);
+    }
+
     #[test]
     fn can_be_stringified() {
         let rgb = rgb(5, 10, 255);
@@ -895,4 +4911,253 @@ mod css_color_tests {
         assert_eq!(String::from("hsl(6, 93%, 71%)"), hsl.to_string());
         assert_eq!(String::from("hsla(6, 93%, 71%, 0.50)"), hsla.to_string());
     }
+
+    mod provided_conversions {
+        use accuracy::*;
+        use {cmyk, hsl, hsla, hsv, hwb, rgb, rgba, Color, OkLch, YCbCrRange, YCbCrStandard, RGB};
+
+        // Converts `color` into another model with `round_trip`, and back into RGB,
+        // checking that it lands within `bound` of where it started.
+        fn assert_round_trips<C: Color, F: Fn(&C) -> RGB>(color: C, round_trip: F, bound: u8) {
+            let (expected, actual) = (color.to_rgb(), round_trip(&color));
+
+            let channels = [
+                (expected.r, actual.r),
+                (expected.g, actual.g),
+                (expected.b, actual.b),
+            ];
+
+            assert!(
+                channels.iter().all(
+                    |&(lhs, rhs)| (lhs.as_u8() as i16 - rhs.as_u8() as i16).abs() <= bound as i16
+                ),
+                "expected: {}, actual: {}",
+                expected,
+                actual
+            );
+        }
+
+        // Only relies on the `Color` trait, so it works with every implementor.
+        fn badge_color<C: Color>(color: C) -> OkLch {
+            color.to_oklch()
+        }
+
+        #[test]
+        fn can_be_used_generically() {
+            assert_eq!(
+                badge_color(rgb(250, 128, 114)),
+                rgb(250, 128, 114).to_oklch()
+            );
+            assert_eq!(
+                badge_color(hsl(6, 93, 71)),
+                hsl(6, 93, 71).to_rgb().to_oklch()
+            );
+            assert_eq!(
+                badge_color(cmyk(0, 49, 54, 2)),
+                cmyk(0, 49, 54, 2).to_rgb().to_oklch()
+            );
+        }
+
+        #[test]
+        fn can_round_trip_through_hsv() {
+            let bound = MAX_HSV_ROUNDTRIP_DELTA;
+
+            assert_round_trips(rgb(250, 128, 114), |c| c.to_hsv().to_rgb(), bound);
+            assert_round_trips(rgba(250, 128, 114, 0.5), |c| c.to_hsv().to_rgb(), bound);
+            assert_round_trips(cmyk(0, 49, 54, 2), |c| c.to_hsv().to_rgb(), bound);
+        }
+
+        #[test]
+        fn can_round_trip_through_hsva() {
+            let bound = MAX_HSV_ROUNDTRIP_DELTA;
+
+            assert_round_trips(rgb(250, 128, 114), |c| c.to_hsva().to_rgb(), bound);
+            assert_round_trips(hwb(6, 45, 2), |c| c.to_hsva().to_rgb(), bound);
+
+            assert_round_trips(rgba(250, 128, 114, 0.5), |c| c.to_hsva().to_rgb(), bound);
+
+            // Unlike the other conversions, the alpha channel is preserved.
+            assert_eq!(
+                rgba(250, 128, 114, 0.5).to_hsva().a,
+                rgba(250, 128, 114, 0.5).a
+            );
+        }
+
+        #[test]
+        fn can_round_trip_through_hwb() {
+            let bound = MAX_HWB_ROUNDTRIP_DELTA;
+
+            assert_round_trips(rgb(250, 128, 114), |c| c.to_hwb().to_rgb(), bound);
+            assert_round_trips(rgba(250, 128, 114, 0.5), |c| c.to_hwb().to_rgb(), bound);
+            assert_round_trips(hsv(6, 54, 98), |c| c.to_hwb().to_rgb(), bound);
+        }
+
+        #[test]
+        fn can_round_trip_through_cmyk() {
+            let bound = MAX_CMYK_ROUNDTRIP_DELTA;
+
+            assert_round_trips(rgb(250, 128, 114), |c| c.to_cmyk().to_rgb(), bound);
+            assert_round_trips(hsla(6, 93, 71, 0.5), |c| c.to_cmyk().to_rgb(), bound);
+            assert_round_trips(hwb(6, 45, 2), |c| c.to_cmyk().to_rgb(), bound);
+        }
+
+        #[test]
+        fn can_round_trip_through_linear() {
+            assert_round_trips(rgb(250, 128, 114), |c| c.to_linear().to_srgb(), 0);
+            assert_round_trips(hsla(6, 93, 71, 0.5), |c| c.to_linear().to_srgb(), 0);
+            assert_round_trips(cmyk(0, 49, 54, 2), |c| c.to_linear().to_srgb(), 0);
+        }
+
+        #[test]
+        fn can_round_trip_through_ycbcr() {
+            for &standard in [YCbCrStandard::Bt601, YCbCrStandard::Bt709].iter() {
+                for &range in [YCbCrRange::Full, YCbCrRange::Studio].iter() {
+                    assert_round_trips(
+                        rgb(250, 128, 114),
+                        |c| c.to_ycbcr(standard, range).to_rgb(standard, range),
+                        1,
+                    );
+                    assert_round_trips(
+                        hsv(6, 54, 98),
+                        |c| c.to_ycbcr(standard, range).to_rgb(standard, range),
+                        1,
+                    );
+                }
+            }
+        }
+
+        #[test]
+        fn can_round_trip_through_p3() {
+            assert_round_trips(rgb(250, 128, 114), |c| c.to_p3().to_rgb(), 0);
+            assert_round_trips(hsl(6, 93, 71), |c| c.to_p3().to_rgb(), 0);
+            assert_round_trips(hwb(6, 45, 2), |c| c.to_p3().to_rgb(), 0);
+        }
+
+        #[test]
+        fn can_round_trip_through_xyz() {
+            assert_round_trips(rgb(250, 128, 114), |c| c.to_xyz().to_rgb(), 0);
+            assert_round_trips(hsla(6, 93, 71, 0.5), |c| c.to_xyz().to_rgb(), 0);
+            assert_round_trips(cmyk(0, 49, 54, 2), |c| c.to_xyz().to_rgb(), 0);
+        }
+
+        #[test]
+        fn can_round_trip_through_lab() {
+            let bound = MAX_LAB_ROUNDTRIP_DELTA;
+
+            assert_round_trips(rgb(250, 128, 114), |c| c.to_lab().to_rgb(), bound);
+            assert_round_trips(hsv(6, 54, 98), |c| c.to_lab().to_rgb(), bound);
+            assert_round_trips(cmyk(0, 49, 54, 2), |c| c.to_lab().to_rgb(), bound);
+        }
+
+        #[test]
+        fn can_round_trip_through_lch() {
+            let bound = MAX_LAB_ROUNDTRIP_DELTA;
+
+            assert_round_trips(rgb(250, 128, 114), |c| c.to_lch().to_rgb(), bound);
+            assert_round_trips(hsla(6, 93, 71, 0.5), |c| c.to_lch().to_rgb(), bound);
+            assert_round_trips(hwb(6, 45, 2), |c| c.to_lch().to_rgb(), bound);
+        }
+
+        #[test]
+        fn can_round_trip_through_oklab() {
+            let bound = MAX_OKLAB_ROUNDTRIP_DELTA;
+
+            assert_round_trips(rgb(250, 128, 114), |c| c.to_oklab().to_rgb(), bound);
+            assert_round_trips(hsl(6, 93, 71), |c| c.to_oklab().to_rgb(), bound);
+            assert_round_trips(cmyk(0, 49, 54, 2), |c| c.to_oklab().to_rgb(), bound);
+        }
+
+        #[test]
+        fn can_round_trip_through_oklch() {
+            let bound = MAX_OKLAB_ROUNDTRIP_DELTA;
+
+            assert_round_trips(rgb(250, 128, 114), |c| c.to_oklch().to_rgb(), bound);
+            assert_round_trips(rgba(250, 128, 114, 0.5), |c| c.to_oklch().to_rgb(), bound);
+            assert_round_trips(hsv(6, 54, 98), |c| c.to_oklch().to_rgb(), bound);
+        }
+    }
+
+    mod channel_accessors {
+        use {cmyk, hsl, hsla, percent, rgb, rgba, Color};
+
+        #[test]
+        fn can_access_rgb_channels() {
+            // Examples of the Less documentation.
+            assert_eq!(rgb(10, 20, 30).red().as_u8(), 10);
+            assert_eq!(rgb(10, 20, 30).green().as_u8(), 20);
+            assert_eq!(rgb(10, 20, 30).blue().as_u8(), 30);
+
+            let tomato = hsl(9, 100, 64);
+
+            assert_eq!(
+                (tomato.red(), tomato.green(), tomato.blue()),
+                (tomato.to_rgb().r, tomato.to_rgb().g, tomato.to_rgb().b)
+            );
+            assert_eq!(cmyk(0, 0, 100, 0).blue().as_u8(), 0);
+        }
+
+        #[test]
+        fn can_access_hsl_channels() {
+            // Examples of the Less documentation.
+            assert_eq!(hsl(90, 100, 50).hue().degrees(), 90);
+            assert_eq!(hsl(90, 100, 50).saturation(), percent(100));
+            assert_eq!(hsl(90, 100, 50).lightness(), percent(50));
+
+            assert_eq!(rgb(255, 99, 71).hue(), rgb(255, 99, 71).to_hsl().h);
+            assert_eq!(rgb(255, 99, 71).saturation(), rgb(255, 99, 71).to_hsl().s);
+            assert_eq!(rgb(255, 99, 71).lightness(), rgb(255, 99, 71).to_hsl().l);
+            assert_eq!(rgb(128, 128, 128).saturation(), percent(0));
+        }
+
+        #[test]
+        fn can_access_the_alpha_channel() {
+            // Example of the Less documentation.
+            assert_eq!(rgba(2, 3, 4, 0.5).alpha(), rgba(2, 3, 4, 0.5).a);
+
+            assert_eq!(hsla(90, 100, 50, 0.25).alpha(), hsla(90, 100, 50, 0.25).a);
+            assert_eq!(rgb(2, 3, 4).alpha().as_u8(), 255);
+            assert_eq!(hsl(90, 100, 50).alpha().as_u8(), 255);
+            assert_eq!(cmyk(0, 0, 0, 0).alpha().as_u8(), 255);
+        }
+
+        #[test]
+        fn can_access_hsv_channels() {
+            // Channels of the same color in HSL and HSV, as `(s, l)` and `(s, v)`.
+            let channels = |color: ::HSL| {
+                (
+                    format!("{}, {}", color.saturation(), color.lightness()),
+                    format!("{}, {}", color.hsv_saturation(), color.hsv_value()),
+                )
+            };
+
+            // Fully saturated colors only agree on the saturation.
+            assert_eq!(
+                channels(hsl(120, 100, 50)),
+                ("100%, 50%".to_owned(), "100%, 100%".to_owned())
+            );
+            assert_eq!(
+                channels(hsl(120, 100, 25)),
+                ("100%, 25%".to_owned(), "100%, 50%".to_owned())
+            );
+
+            // Pastels are less saturated in HSV.
+            assert_eq!(
+                channels(hsl(0, 100, 75)),
+                ("100%, 75%".to_owned(), "50%, 100%".to_owned())
+            );
+
+            // Greys have the same channels in both models.
+            assert_eq!(
+                channels(hsl(0, 0, 100)),
+                ("0%, 100%".to_owned(), "0%, 100%".to_owned())
+            );
+            assert_eq!(
+                channels(hsl(0, 0, 50)),
+                ("0%, 50%".to_owned(), "0%, 50%".to_owned())
+            );
+
+            assert_eq!(rgb(255, 99, 71).hsv_value(), rgb(255, 99, 71).to_hsv().v);
+            assert_eq!(rgb(255, 99, 71).to_hsv().h, rgb(255, 99, 71).hue());
+        }
+    }
 }
@@ -0,0 +1,233 @@
+use super::css::write_color_function;
+use super::gamut::{in_srgb_gamut, reduce_chroma};
+use super::xyz::clamp_rgb;
+use super::{ops, GamutMap, OkLab, OkLch, Ratio, RGB};
+use std::fmt;
+
+/// Constructs a linear-light sRGB Color from numerical values.
+///
+/// The channels range between `0.0-1.0`, and are proportional to the amount
+/// of light emitted (unlike the channels of `RGB`, which are gamma-encoded).
+///
+/// # Example
+/// ```
+/// use css_colors::{linear_rgb, rgb};
+///
+/// assert_eq!(linear_rgb(1.0, 0.216, 0.0).to_srgb(), rgb(255, 128, 0));
+/// ```
+pub fn linear_rgb(r: f32, g: f32, b: f32) -> LinearRGB {
+    LinearRGB { r, g, b }
+}
+
+#[derive(Debug, Copy, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+/// A struct to represent a color in linear-light sRGB: the primaries and white
+/// point of sRGB, without its transfer function (see `ops::linearize` and
+/// `ops::delinearize`).
+///
+/// Light adds up linearly, so blending and averaging colors in this space is
+/// physically correct, while doing so with the gamma-encoded channels of `RGB`
+/// (as `mix` does, for compatibility with Less) makes the results too dark.
+///
+/// # Example
+/// ```
+/// use css_colors::{percent, rgb, rgba, Color};
+///
+/// let red = rgb(255, 0, 0);
+/// let green = rgb(0, 255, 0);
+///
+/// let linear = red.to_linear().lerp(green.to_linear(), 0.5).to_srgb();
+///
+/// assert_eq!(linear, rgb(188, 188, 0));
+/// assert_eq!(red.mix(green, percent(50)), rgba(128, 127, 0, 1.0));
+/// ```
+pub struct LinearRGB {
+    pub r: f32,
+    pub g: f32,
+    pub b: f32,
+}
+
+impl fmt::Display for LinearRGB {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write_color_function(
+            f,
+            "srgb-linear",
+            [self.r, self.g, self.b],
+            Ratio::from_u8(255),
+        )
+    }
+}
+
+impl LinearRGB {
+    /// Converts a (gamma-encoded) RGB color into linear light.
+    ///
+    /// # Example
+    /// ```
+    /// use css_colors::{rgb, LinearRGB};
+    ///
+    /// let grey = LinearRGB::from_rgb(rgb(128, 128, 128));
+    ///
+    /// assert!((grey.r - 0.2159).abs() < 0.0001);
+    /// ```
+    pub fn from_rgb(rgb: RGB) -> LinearRGB {
+        LinearRGB {
+            r: ops::linearize(rgb.r.as_f32()),
+            g: ops::linearize(rgb.g.as_f32()),
+            b: ops::linearize(rgb.b.as_f32()),
+        }
+    }
+
+    /// Converts `self` back into (gamma-encoded) RGB. Channels outside of the
+    /// `0.0-1.0` range are clamped.
+    ///
+    /// # Example
+    /// ```
+    /// use css_colors::{linear_rgb, rgb};
+    ///
+    /// assert_eq!(linear_rgb(0.5, 0.5, 0.5).to_srgb(), rgb(188, 188, 188));
+    /// assert_eq!(linear_rgb(1.5, -0.5, 0.0).to_srgb(), rgb(255, 0, 0));
+    /// ```
+    pub fn to_srgb(self) -> RGB {
+        clamp_rgb([
+            ops::delinearize(self.r),
+            ops::delinearize(self.g),
+            ops::delinearize(self.b),
+        ])
+    }
+
+    /// Returns `true` if `self` can be represented in sRGB without gamut mapping.
+    ///
+    /// # Example
+    /// ```
+    /// use css_colors::linear_rgb;
+    ///
+    /// assert!(linear_rgb(1.0, 0.5, 0.0).in_gamut());
+    /// assert!(!linear_rgb(1.2, 0.5, -0.1).in_gamut());
+    /// ```
+    pub fn in_gamut(&self) -> bool {
+        in_srgb_gamut([self.r, self.g, self.b])
+    }
+
+    /// Converts `self` into RGB, bringing colors outside of the sRGB gamut into
+    /// it with the given strategy.
+    ///
+    /// # Example
+    /// ```
+    /// use css_colors::{linear_rgb, rgb, GamutMap};
+    ///
+    /// let vivid = linear_rgb(1.2, 0.5, -0.1);
+    ///
+    /// assert_eq!(vivid.map_to_gamut(GamutMap::Clip), rgb(255, 188, 0));
+    /// assert_eq!(vivid.map_to_gamut(GamutMap::ChromaReduce), rgb(255, 196, 72));
+    /// ```
+    pub fn map_to_gamut(self, method: GamutMap) -> RGB {
+        match method {
+            GamutMap::Clip => self.to_srgb(),
+            GamutMap::ChromaReduce => reduce_chroma(OkLch::from_oklab(OkLab::from_linear_rgb([
+                self.r, self.g, self.b,
+            ]))),
+        }
+    }
+
+    /// Converts `self` into the `color(srgb-linear r g b)` notation of CSS, with
+    /// up to four decimals per channel. Formatting `self` with a precision
+    /// (e.g. `{:.2}`) sets the number of decimals instead.
+    ///
+    /// # Example
+    /// ```
+    /// use css_colors::{rgb, LinearRGB};
+    ///
+    /// let grey = LinearRGB::from_rgb(rgb(128, 128, 128));
+    ///
+    /// assert_eq!(grey.to_css(), "color(srgb-linear 0.2159 0.2159 0.2159)");
+    /// assert_eq!(format!("{:.1}", grey), "color(srgb-linear 0.2 0.2 0.2)");
+    /// ```
+    pub fn to_css(self) -> String {
+        self.to_string()
+    }
+
+    /// Interpolates between `self` (at a `t` of `0.0`) and `other` (at a `t` of
+    /// `1.0`), channel by channel.
+    pub fn lerp(self, other: LinearRGB, t: f32) -> LinearRGB {
+        LinearRGB {
+            r: self.r + (other.r - self.r) * t,
+            g: self.g + (other.g - self.g) * t,
+            b: self.b + (other.b - self.b) * t,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use {linear_rgb, percent, rgb, rgba, Color, LinearRGB};
+
+    #[test]
+    fn can_convert_rgb_to_linear() {
+        assert_eq!(rgb(0, 0, 0).to_linear(), linear_rgb(0.0, 0.0, 0.0));
+        assert_eq!(rgb(255, 255, 255).to_linear(), linear_rgb(1.0, 1.0, 1.0));
+        assert_eq!(rgba(255, 0, 0, 0.5).to_linear(), linear_rgb(1.0, 0.0, 0.0));
+
+        // The linear segment of the transfer function, near black.
+        assert!((rgb(10, 10, 10).to_linear().r - 10.0 / 255.0 / 12.92).abs() < 0.000_001);
+        assert!((rgb(188, 188, 188).to_linear().g - 0.5).abs() < 0.005);
+    }
+
+    #[test]
+    fn can_round_trip_through_linear() {
+        for r in (0..=255).step_by(5) {
+            for g in (0..=255).step_by(5) {
+                for b in (0..=255).step_by(5) {
+                    let color = rgb(r as u8, g as u8, b as u8);
+
+                    assert_eq!(color.to_linear().to_srgb(), color);
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn can_lerp_in_linear_light() {
+        let red = rgb(255, 0, 0).to_linear();
+        let green = rgb(0, 255, 0).to_linear();
+
+        assert_eq!(red.lerp(green, 0.0), red);
+        assert_eq!(red.lerp(green, 1.0), green);
+        assert_eq!(red.lerp(green, 0.5), linear_rgb(0.5, 0.5, 0.0));
+        assert_eq!(red.lerp(green, 0.25).to_srgb(), rgb(225, 137, 0));
+    }
+
+    #[test]
+    fn mixes_brighter_than_mix() {
+        let red = rgb(255, 0, 0);
+        let green = rgb(0, 255, 0);
+
+        let gamma = red.mix(green, percent(50));
+        let linear = red.to_linear().lerp(green.to_linear(), 0.5).to_srgb();
+
+        assert_eq!(gamma, rgba(128, 127, 0, 1.0));
+        assert_eq!(linear, rgb(188, 188, 0));
+
+        // Black and white meet at a perceived mid-grey, not a dark one.
+        let grey = LinearRGB::from_rgb(rgb(0, 0, 0))
+            .lerp(LinearRGB::from_rgb(rgb(255, 255, 255)), 0.5)
+            .to_srgb();
+
+        assert_eq!(grey, rgb(188, 188, 188));
+    }
+
+    #[test]
+    fn can_serialize_to_css() {
+        assert_eq!(
+            linear_rgb(1.0, 0.5, 0.0).to_css(),
+            "color(srgb-linear 1 0.5 0)"
+        );
+        assert_eq!(
+            rgb(255, 128, 0).to_linear().to_string(),
+            "color(srgb-linear 1 0.2159 0)"
+        );
+        assert_eq!(
+            format!("{:.2}", rgb(255, 128, 0).to_linear()),
+            "color(srgb-linear 1 0.22 0)"
+        );
+    }
+}
@@ -0,0 +1,224 @@
+use super::xyz::clamp_rgb;
+use super::{ops, GamutMap, OkLch, RGB};
+
+/// Constructs an Oklab Color from numerical values.
+///
+/// The lightness ranges between `0.0-1.0`. The `a` (green-red) and `b`
+/// (blue-yellow) axes are unbounded, but colors within the sRGB gamut fall
+/// roughly between `-0.4-0.4`.
+///
+/// # Example
+/// ```
+/// use css_colors::{oklab, rgb};
+///
+/// let red = oklab(0.628, 0.2249, 0.1258);
+///
+/// assert_eq!(red.to_rgb(), rgb(255, 0, 0));
+/// ```
+pub fn oklab(l: f32, a: f32, b: f32) -> OkLab {
+    OkLab { l, a, b }
+}
+
+#[derive(Debug, Copy, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+/// A struct to represent a color in the Oklab color space, a perceptually uniform
+/// color space that predicts lightness, chroma and hue better than CIELAB. In
+/// particular, interpolating between two colors in Oklab doesn't pass through
+/// muddy, desaturated colors.
+///
+/// For more, see [Björn Ottosson's introduction](https://bottosson.github.io/posts/oklab/).
+pub struct OkLab {
+    // lightness
+    pub l: f32,
+
+    // green (negative) to red (positive)
+    pub a: f32,
+
+    // blue (negative) to yellow (positive)
+    pub b: f32,
+}
+
+impl OkLab {
+    /// Converts an RGB color into Oklab.
+    ///
+    /// # Example
+    /// ```
+    /// use css_colors::{rgb, OkLab};
+    ///
+    /// let white = OkLab::from_rgb(rgb(255, 255, 255));
+    ///
+    /// assert!((white.l - 1.0).abs() < 0.0001);
+    /// ```
+    pub fn from_rgb(rgb: RGB) -> OkLab {
+        OkLab::from_linear_rgb([
+            ops::linearize(rgb.r.as_f32()),
+            ops::linearize(rgb.g.as_f32()),
+            ops::linearize(rgb.b.as_f32()),
+        ])
+    }
+
+    // Converts linear sRGB channels into Oklab. The channels may fall outside of
+    // the `0.0-1.0` range, for colors outside of the sRGB gamut.
+    pub(crate) fn from_linear_rgb(linear: [f32; 3]) -> OkLab {
+        let [r, g, b] = linear;
+
+        // Linear sRGB to (the cube root of) the LMS cone responses.
+        let l = (0.412_221_46 * r + 0.536_332_55 * g + 0.051_445_995 * b).cbrt();
+        let m = (0.211_903_5 * r + 0.680_699_5 * g + 0.107_396_96 * b).cbrt();
+        let s = (0.088_302_46 * r + 0.281_718_85 * g + 0.629_978_7 * b).cbrt();
+
+        OkLab {
+            l: 0.210_454_26 * l + 0.793_617_8 * m - 0.004_072_047 * s,
+            a: 1.977_998_5 * l - 2.428_592_2 * m + 0.450_593_7 * s,
+            b: 0.025_904_037 * l + 0.782_771_77 * m - 0.808_675_77 * s,
+        }
+    }
+
+    /// Converts `self` into RGB. Colors outside of the sRGB gamut are clamped
+    /// channel by channel.
+    ///
+    /// # Example
+    /// ```
+    /// use css_colors::{oklab, rgb};
+    ///
+    /// assert_eq!(oklab(0.452, -0.0325, -0.3115).to_rgb(), rgb(0, 0, 255));
+    /// assert_eq!(oklab(0.5, 0.0, -0.5).to_rgb(), rgb(87, 0, 255));
+    /// ```
+    pub fn to_rgb(self) -> RGB {
+        clamp_rgb(self.to_unclamped_rgb())
+    }
+
+    /// Returns `true` if `self` can be represented in sRGB without gamut mapping.
+    ///
+    /// # Example
+    /// ```
+    /// use css_colors::oklab;
+    ///
+    /// assert!(oklab(0.45, -0.03, -0.3).in_gamut());
+    /// assert!(!oklab(0.5, 0.0, -0.5).in_gamut());
+    /// ```
+    pub fn in_gamut(&self) -> bool {
+        OkLch::from_oklab(*self).in_gamut()
+    }
+
+    /// Converts `self` into RGB, bringing colors outside of the sRGB gamut into
+    /// it with the given strategy (see `OkLch::map_to_gamut`).
+    ///
+    /// # Example
+    /// ```
+    /// use css_colors::{oklab, rgb, GamutMap};
+    ///
+    /// let vivid = oklab(0.5, 0.0, -0.5);
+    ///
+    /// assert_eq!(vivid.map_to_gamut(GamutMap::Clip), rgb(87, 0, 255));
+    /// assert_eq!(vivid.map_to_gamut(GamutMap::ChromaReduce), rgb(52, 53, 255));
+    /// ```
+    pub fn map_to_gamut(self, method: GamutMap) -> RGB {
+        OkLch::from_oklab(self).map_to_gamut(method)
+    }
+
+    /// Interpolates from `self` (at a `t` of `0.0`) to `other` (at a `t` of
+    /// `1.0`), component by component.
+    pub fn lerp(self, other: OkLab, t: f32) -> OkLab {
+        OkLab {
+            l: self.l + (other.l - self.l) * t,
+            a: self.a + (other.a - self.a) * t,
+            b: self.b + (other.b - self.b) * t,
+        }
+    }
+
+    // Converts `self` into gamma-encoded sRGB channels. The channels of colors
+    // outside of the sRGB gamut fall outside of the `0.0-1.0` range.
+    pub(crate) fn to_unclamped_rgb(self) -> [f32; 3] {
+        let OkLab { l, a, b } = self;
+
+        let l_ = (l + 0.396_337_78 * a + 0.215_803_76 * b).powi(3);
+        let m_ = (l - 0.105_561_346 * a - 0.063_854_17 * b).powi(3);
+        let s_ = (l - 0.089_484_18 * a - 1.291_485_5 * b).powi(3);
+
+        [
+            ops::delinearize(4.076_741_7 * l_ - 3.307_711_6 * m_ + 0.230_969_94 * s_),
+            ops::delinearize(-1.268_438 * l_ + 2.609_757_4 * m_ - 0.341_319_38 * s_),
+            ops::delinearize(-0.004_196_086_3 * l_ - 0.703_418_6 * m_ + 1.707_614_7 * s_),
+        ]
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use {oklab, percent, rgb, rgba, Color, OkLab};
+
+    fn assert_oklab_eq(lhs: OkLab, rhs: OkLab) {
+        assert!(
+            (lhs.l - rhs.l).abs() < 0.0005
+                && (lhs.a - rhs.a).abs() < 0.0005
+                && (lhs.b - rhs.b).abs() < 0.0005,
+            "lhs: {:?}, rhs: {:?}",
+            lhs,
+            rhs
+        );
+    }
+
+    #[test]
+    fn can_convert_rgb_to_oklab() {
+        // Reference values computed with Björn Ottosson's reference implementation.
+        assert_oklab_eq(rgb(255, 255, 255).to_oklab(), oklab(1.0, 0.0, 0.0));
+        assert_oklab_eq(rgb(0, 0, 0).to_oklab(), oklab(0.0, 0.0, 0.0));
+        assert_oklab_eq(rgb(255, 0, 0).to_oklab(), oklab(0.62796, 0.22486, 0.12585));
+        assert_oklab_eq(rgb(0, 255, 0).to_oklab(), oklab(0.86644, -0.23389, 0.1795));
+        assert_oklab_eq(
+            rgb(0, 0, 255).to_oklab(),
+            oklab(0.45201, -0.03246, -0.31153),
+        );
+    }
+
+    #[test]
+    fn can_round_trip_through_oklab() {
+        for r in (0..=255).step_by(17) {
+            for g in (0..=255).step_by(17) {
+                for b in (0..=255).step_by(17) {
+                    let color = rgb(r as u8, g as u8, b as u8);
+
+                    assert_eq!(color.to_oklab().to_rgb(), color);
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn clamps_out_of_gamut_colors() {
+        assert_eq!(oklab(1.5, 0.0, 0.0).to_rgb(), rgb(255, 255, 255));
+        assert_eq!(oklab(-0.5, 0.0, 0.0).to_rgb(), rgb(0, 0, 0));
+        assert_eq!(oklab(0.6, 0.4, 0.0).to_rgb().r.as_u8(), 255);
+    }
+
+    #[test]
+    fn can_mix_in_oklab() {
+        let blue = rgb(0, 0, 255);
+        let yellow = rgb(255, 255, 0);
+
+        // Mixing in sRGB goes through a muddy grey, while Oklab keeps some chroma.
+        assert_eq!(blue.mix(yellow, percent(50)), rgba(127, 127, 128, 1.0));
+        assert_eq!(
+            blue.mix_oklab(yellow, percent(50)),
+            rgba(107, 171, 199, 1.0)
+        );
+
+        assert_eq!(blue.mix_oklab(yellow, percent(100)), rgba(0, 0, 255, 1.0));
+        assert_eq!(blue.mix_oklab(yellow, percent(0)), rgba(255, 255, 0, 1.0));
+        assert_eq!(
+            rgba(0, 0, 255, 1.0).mix_oklab(rgba(255, 255, 0, 0.0), percent(50)),
+            rgba(107, 171, 199, 0.5)
+        );
+    }
+
+    #[test]
+    fn can_lerp_from_self_to_other() {
+        let black = oklab(0.0, 0.0, 0.0);
+        let red = oklab(0.6, 0.2, 0.1);
+
+        assert_eq!(black.lerp(red, 0.0), black);
+        assert_eq!(black.lerp(red, 1.0), red);
+        assert_oklab_eq(black.lerp(red, 0.25), oklab(0.15, 0.05, 0.025));
+    }
+}
@@ -0,0 +1,277 @@
+use super::css::Rounded;
+use super::gamut::{in_srgb_gamut, reduce_chroma};
+use super::{Color, GamutMap, OkLab, RGB, RGBA};
+use std::fmt;
+
+/// Constructs an OkLch Color from numerical values, similar to the
+/// [`oklch` function][css-oklch] in CSS.
+///
+/// The lightness ranges between `0.0-1.0`. The chroma has no fixed upper
+/// bound, but colors within the sRGB gamut stay below about `0.33`. The hue
+/// is expressed in degrees, and normalized into the `0-360°` range.
+///
+/// # Example
+/// ```
+/// use css_colors::oklch;
+///
+/// let red = oklch(0.628, 0.258, 29.2);
+///
+/// assert_eq!(red.to_css(), "oklch(62.8% 0.258 29.2)");
+/// ```
+///
+/// [css-oklch]: https://www.w3.org/TR/css-color-4/#specifying-oklab-oklch
+pub fn oklch(l: f32, c: f32, h: f32) -> OkLch {
+    OkLch {
+        l,
+        c,
+        h: h.rem_euclid(360.0),
+    }
+}
+
+#[derive(Debug, Copy, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+/// A struct to represent a color in the cylindrical form of Oklab: the chroma
+/// is the distance from the neutral axis, and the hue is the angle around it.
+///
+/// For more, see the [CSS Color Spec](https://www.w3.org/TR/css-color-4/#ok-lab).
+pub struct OkLch {
+    // lightness
+    pub l: f32,
+
+    // chroma
+    pub c: f32,
+
+    // hue, in degrees
+    pub h: f32,
+}
+
+impl fmt::Display for OkLch {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            f,
+            "oklch({}% {} {})",
+            Rounded(self.l * 100.0, 2),
+            Rounded(self.c, 4),
+            Rounded(self.h, 2)
+        )
+    }
+}
+
+// Below this chroma, a color is considered neutral and its hue is undefined.
+pub(crate) const NEUTRAL_CHROMA: f32 = 0.000_001;
+
+impl OkLch {
+    /// Converts an RGB color into OkLch.
+    ///
+    /// # Example
+    /// ```
+    /// use css_colors::{rgb, OkLch};
+    ///
+    /// assert_eq!(OkLch::from_rgb(rgb(255, 0, 0)).to_css(), "oklch(62.8% 0.2577 29.23)");
+    /// ```
+    pub fn from_rgb(rgb: RGB) -> OkLch {
+        OkLch::from_oklab(OkLab::from_rgb(rgb))
+    }
+
+    /// Converts an `OkLab` color into OkLch.
+    pub fn from_oklab(oklab: OkLab) -> OkLch {
+        let c = oklab.a.hypot(oklab.b);
+
+        // The hue of a neutral color is undefined (and its angle meaningless).
+        let h = if c < NEUTRAL_CHROMA {
+            0.0
+        } else {
+            oklab.b.atan2(oklab.a).to_degrees().rem_euclid(360.0)
+        };
+
+        OkLch { l: oklab.l, c, h }
+    }
+
+    /// Converts `self` into RGB. Colors outside of the sRGB gamut are clamped
+    /// channel by channel (see `in_gamut`).
+    ///
+    /// # Example
+    /// ```
+    /// use css_colors::{oklch, rgb};
+    ///
+    /// assert_eq!(oklch(0.628, 0.2577, 29.23).to_rgb(), rgb(255, 0, 0));
+    /// ```
+    pub fn to_rgb(self) -> RGB {
+        self.to_oklab().to_rgb()
+    }
+
+    /// Converts `self` into an `OkLab` color.
+    pub fn to_oklab(self) -> OkLab {
+        let h = self.h.to_radians();
+
+        OkLab {
+            l: self.l,
+            a: self.c * h.cos(),
+            b: self.c * h.sin(),
+        }
+    }
+
+    /// Returns `true` if `self` can be represented in sRGB without clamping.
+    ///
+    /// # Example
+    /// ```
+    /// use css_colors::oklch;
+    ///
+    /// assert!(oklch(0.628, 0.2577, 29.23).in_gamut());
+    /// assert!(!oklch(0.628, 0.35, 29.23).in_gamut());
+    /// ```
+    pub fn in_gamut(&self) -> bool {
+        in_srgb_gamut(self.to_oklab().to_unclamped_rgb())
+    }
+
+    /// Returns `true` if `self` can be represented in sRGB (see `in_gamut`).
+    #[deprecated(note = "renamed to `in_gamut`")]
+    pub fn is_in_gamut(self) -> bool {
+        self.in_gamut()
+    }
+
+    /// Converts `self` into RGB, bringing colors outside of the sRGB gamut into
+    /// it with the given strategy. Reducing the chroma follows the [gamut mapping
+    /// algorithm](https://www.w3.org/TR/css-color-4/#binsearch) of CSS: it binary
+    /// searches the largest chroma that fits with the lightness and hue of `self`.
+    ///
+    /// # Example
+    /// ```
+    /// use css_colors::{oklch, rgb, GamutMap};
+    ///
+    /// let vivid = oklch(0.7, 0.4, 150.0);
+    ///
+    /// assert_eq!(vivid.map_to_gamut(GamutMap::Clip), rgb(0, 214, 0));
+    /// assert_eq!(vivid.map_to_gamut(GamutMap::ChromaReduce), rgb(0, 190, 88));
+    /// ```
+    pub fn map_to_gamut(self, method: GamutMap) -> RGB {
+        match method {
+            GamutMap::Clip => self.to_rgb(),
+            GamutMap::ChromaReduce => reduce_chroma(self),
+        }
+    }
+
+    /// Converts `self` into the `oklch()` notation of CSS. The lightness is
+    /// serialized as a percentage, the chroma as a number (with up to four
+    /// decimals), and the hue in degrees.
+    pub fn to_css(self) -> String {
+        self.to_string()
+    }
+}
+
+// The chroma that CSS maps to `100%` in `oklch()`, so that percentages of chroma
+// read like other percentages of this crate.
+pub(crate) const CHROMA_REFERENCE: f32 = 0.4;
+
+// Offsets the OkLch chroma of `color` by `delta`, keeping its lightness and hue,
+// and maps the result back into sRGB by reducing the chroma if needed. Greys are
+// left unchanged, since their hue is undefined.
+pub(crate) fn adjust_chroma(color: RGBA, delta: f32) -> RGBA {
+    let oklch = OkLch::from_rgb(color.to_rgb());
+
+    if oklch.c < NEUTRAL_CHROMA {
+        return color;
+    }
+
+    let RGB { r, g, b } = OkLch {
+        c: (oklch.c + delta).max(0.0),
+        ..oklch
+    }
+    .map_to_gamut(GamutMap::ChromaReduce);
+
+    RGBA {
+        r,
+        g,
+        b,
+        a: color.a,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use {oklab, oklch, rgb, rgba, Color, OkLch};
+
+    fn assert_oklch_eq(lhs: OkLch, rhs: OkLch) {
+        assert!(
+            (lhs.l - rhs.l).abs() < 0.0005
+                && (lhs.c - rhs.c).abs() < 0.0005
+                && (lhs.h - rhs.h).abs() < 0.05,
+            "lhs: {:?}, rhs: {:?}",
+            lhs,
+            rhs
+        );
+    }
+
+    #[test]
+    fn can_convert_rgb_to_oklch() {
+        assert_oklch_eq(rgb(255, 0, 0).to_oklch(), oklch(0.628, 0.2577, 29.23));
+        assert_oklch_eq(rgb(0, 255, 0).to_oklch(), oklch(0.8664, 0.2948, 142.5));
+        assert_oklch_eq(rgb(0, 0, 255).to_oklch(), oklch(0.452, 0.3132, 264.05));
+        assert_oklch_eq(rgba(255, 0, 0, 0.5).to_oklch(), oklch(0.628, 0.2577, 29.23));
+    }
+
+    #[test]
+    fn can_round_trip_through_oklch() {
+        let red = rgb(255, 0, 0).to_oklch().to_rgb();
+
+        for &(channel, expected) in [(red.r, 255), (red.g, 0), (red.b, 0)].iter() {
+            assert!((channel.as_u8() as i16 - expected).abs() <= 1);
+        }
+
+        for r in (0..=255).step_by(51) {
+            for g in (0..=255).step_by(51) {
+                for b in (0..=255).step_by(51) {
+                    let color = rgb(r as u8, g as u8, b as u8);
+
+                    assert_eq!(color.to_oklch().to_rgb(), color);
+                    assert!(color.to_oklch().in_gamut());
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn handles_neutral_colors() {
+        for &grey in [rgb(0, 0, 0), rgb(119, 119, 119), rgb(255, 255, 255)].iter() {
+            let OkLch { c, h, .. } = grey.to_oklch();
+
+            assert!(c < 0.0001);
+            assert_eq!(h, 0.0);
+            assert_eq!(grey.to_oklch().to_rgb(), grey);
+        }
+
+        assert_eq!(OkLch::from_oklab(oklab(0.5, 0.0, 0.0)).h, 0.0);
+        assert_eq!(rgb(255, 255, 255).to_oklch().to_css(), "oklch(100% 0 0)");
+        assert_eq!(
+            oklch(0.5, 0.0, 123.0).to_rgb(),
+            oklch(0.5, 0.0, 0.0).to_rgb()
+        );
+    }
+
+    #[test]
+    fn clamps_out_of_gamut_colors() {
+        let vivid = oklch(0.7, 0.4, 150.0);
+
+        assert!(!vivid.in_gamut());
+
+        let clamped = vivid.to_rgb();
+        assert_eq!(clamped, rgb(0, 214, 0));
+
+        // Clamping moves the color, so it doesn't round trip anymore.
+        assert!(clamped.to_oklch().c < vivid.c);
+        assert!(clamped.to_oklch().in_gamut());
+
+        assert!(!oklch(1.2, 0.0, 0.0).in_gamut());
+        assert_eq!(oklch(1.2, 0.0, 0.0).to_rgb(), rgb(255, 255, 255));
+    }
+
+    #[test]
+    fn can_serialize_to_css() {
+        assert_eq!(
+            oklch(0.628, 0.258, 29.2).to_css(),
+            "oklch(62.8% 0.258 29.2)"
+        );
+        assert_eq!(oklch(0.5, 0.1, -90.0).to_css(), "oklch(50% 0.1 270)");
+        assert_eq!(format!("{}", oklch(0.0, 0.0, 0.0)), "oklch(0% 0 0)");
+    }
+}
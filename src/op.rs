@@ -0,0 +1,589 @@
+use super::{
+    Angle, BlendMode, BlendSpace, Color, ColorAdjustment, ColorChange, HuePath, InterpolationSpace,
+    Ratio, RGBA,
+};
+
+#[derive(Debug, Copy, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "snake_case"))]
+/// An enum that records an operation of the `Color` trait along with its
+/// parameters, so that it can be stored (e.g. in an undo/redo stack), replayed
+/// with `apply`, and reverted with its `inverse`.
+///
+/// Every operation that turns a color into another color is covered, with the
+/// named blend modes (such as `multiply`) recorded as `Blend` in the gamma space,
+/// which gives the same result. The methods left out aren't replayable edits of
+/// a single color: `jitter` is random, `simulate_cvd` and `daltonize` preview a
+/// color as opaque RGB, the harmonies, tints, shades and steps return several
+/// colors, and the conversions and channel accessors don't change the color.
+///
+/// With the `serde` feature, operations can be serialized. Ratios are
+/// serialized as bytes (`0-255`), and angles as degrees (`0-359`).
+///
+/// # Example
+/// ```
+/// use css_colors::{rgba, percent, Op};
+///
+/// let tomato = rgba(255, 99, 71, 0.5);
+/// let op = Op::Fadein(percent(20));
+///
+/// let faded_in = op.apply(tomato);
+/// let undone = op.inverse().unwrap().apply(faded_in);
+///
+/// assert_eq!(faded_in, rgba(255, 99, 71, 0.7));
+/// assert_eq!(undone, tomato);
+/// ```
+pub enum Op {
+    Saturate(Ratio),
+    Desaturate(Ratio),
+    SaturateRel(Ratio),
+    DesaturateRel(Ratio),
+    SaturatePerceptual(Ratio),
+    DesaturatePerceptual(Ratio),
+    Vibrance(i8),
+    Lighten(Ratio),
+    Darken(Ratio),
+    LightenRel(Ratio),
+    DarkenRel(Ratio),
+    Fadein(Ratio),
+    Fadeout(Ratio),
+    Fade(Ratio),
+    Spin(Angle),
+    SpinF32(f32),
+    Adjust(ColorAdjustment),
+    Change(ColorChange),
+    Complement,
+    Invert,
+    InvertWeighted(u8),
+    Brightness(f32),
+    ContrastAdjust(f32),
+    HueRotate(Angle),
+    Sepia(f32),
+    Posterize(u8),
+    Mix {
+        other: RGBA,
+        weight: Ratio,
+    },
+    MixLinear {
+        other: RGBA,
+        weight: Ratio,
+    },
+    MixOklab {
+        other: RGBA,
+        weight: Ratio,
+    },
+    Lerp {
+        other: RGBA,
+        t: f32,
+        space: InterpolationSpace,
+    },
+    LerpHsl {
+        other: RGBA,
+        t: f32,
+        path: HuePath,
+    },
+    Tint(Ratio),
+    Shade(Ratio),
+    Greyscale,
+    GreyscaleLuma,
+    Pastelize(Ratio),
+    Blend {
+        other: RGBA,
+        mode: BlendMode,
+        space: BlendSpace,
+    },
+    Negation(RGBA),
+    Average(RGBA),
+    Over(RGBA),
+    EnsureContrast {
+        against: RGBA,
+        target: f32,
+    },
+    Contrast {
+        dark: RGBA,
+        light: RGBA,
+        threshold: Option<Ratio>,
+    },
+    ContrastBw,
+}
+
+impl Op {
+    /// Applies the operation to `color`.
+    pub fn apply(&self, color: RGBA) -> RGBA {
+        match *self {
+            Op::Saturate(amount) => color.saturate(amount),
+            Op::Desaturate(amount) => color.desaturate(amount),
+            Op::SaturateRel(amount) => color.saturate_rel(amount),
+            Op::DesaturateRel(amount) => color.desaturate_rel(amount),
+            Op::SaturatePerceptual(amount) => color.saturate_perceptual(amount),
+            Op::DesaturatePerceptual(amount) => color.desaturate_perceptual(amount),
+            Op::Vibrance(amount) => color.vibrance(amount),
+            Op::Lighten(amount) => color.lighten(amount),
+            Op::Darken(amount) => color.darken(amount),
+            Op::LightenRel(amount) => color.lighten_rel(amount),
+            Op::DarkenRel(amount) => color.darken_rel(amount),
+            Op::Fadein(amount) => color.fadein(amount),
+            Op::Fadeout(amount) => color.fadeout(amount),
+            Op::Fade(amount) => color.fade(amount),
+            Op::Spin(amount) => color.spin(amount),
+            Op::SpinF32(degrees) => color.spin_f32(degrees),
+            Op::Adjust(adjustment) => color.adjust(adjustment),
+            Op::Change(change) => color.change(change),
+            Op::Complement => color.complement(),
+            Op::Invert => color.invert(),
+            Op::InvertWeighted(weight) => color.invert_weighted(weight),
+            Op::Brightness(factor) => color.brightness(factor),
+            Op::ContrastAdjust(factor) => color.contrast_adjust(factor),
+            Op::HueRotate(angle) => color.hue_rotate(angle),
+            Op::Sepia(amount) => color.sepia(amount),
+            Op::Posterize(levels) => color.posterize(levels),
+            Op::Mix { other, weight } => color.mix(other, weight),
+            Op::MixLinear { other, weight } => color.mix_linear(other, weight),
+            Op::MixOklab { other, weight } => color.mix_oklab(other, weight),
+            Op::Lerp { other, t, space } => color.lerp_in(other, t, space),
+            Op::LerpHsl { other, t, path } => color.lerp_hsl(other, t, path).to_rgba(),
+            Op::Tint(weight) => color.tint(weight),
+            Op::Shade(weight) => color.shade(weight),
+            Op::Greyscale => color.greyscale(),
+            Op::GreyscaleLuma => color.greyscale_luma(),
+            Op::Pastelize(strength) => color.pastelize(strength),
+            Op::Blend { other, mode, space } => color.blend(other, mode, space),
+            Op::Negation(other) => color.negation(other),
+            Op::Average(other) => color.average(other),
+            Op::Over(backdrop) => color.over(backdrop),
+            Op::EnsureContrast { against, target } => color.ensure_contrast(against, target),
+            Op::Contrast {
+                dark,
+                light,
+                threshold,
+            } => color.contrast(dark, light, threshold),
+            Op::ContrastBw => color.contrast_bw(),
+        }
+    }
+
+    /// Returns the operation that reverts `self`, if there is one: lightening is
+    /// reverted by darkening, saturating by desaturating (also perceptually),
+    /// fading in by fading out (and vice versa), spinning and rotating hues by
+    /// spinning and rotating them backwards, and adjusting by the opposite
+    /// offsets. Complementing and inverting are their own inverses, and scaling
+    /// the brightness or the contrast by a factor is reverted by its reciprocal.
+    ///
+    /// Operations that discard information (e.g. `Greyscale` or `Fade`) have no
+    /// inverse, and neither do the relative operations, whose inverses aren't
+    /// operations of the `Color` trait. Even when there is an inverse, it only
+    /// reverts the operation exactly for some colors; see `is_lossy_at`.
+    ///
+    /// # Example
+    /// ```
+    /// use css_colors::{deg, percent, Op};
+    ///
+    /// assert_eq!(Op::Lighten(percent(10)).inverse(), Some(Op::Darken(percent(10))));
+    /// assert_eq!(Op::Spin(deg(30)).inverse(), Some(Op::Spin(deg(330))));
+    /// assert_eq!(Op::Brightness(2.0).inverse(), Some(Op::Brightness(0.5)));
+    /// assert_eq!(Op::Invert.inverse(), Some(Op::Invert));
+    /// assert_eq!(Op::Greyscale.inverse(), None);
+    /// ```
+    pub fn inverse(&self) -> Option<Op> {
+        match *self {
+            Op::Saturate(amount) => Some(Op::Desaturate(amount)),
+            Op::Desaturate(amount) => Some(Op::Saturate(amount)),
+            Op::SaturatePerceptual(amount) => Some(Op::DesaturatePerceptual(amount)),
+            Op::DesaturatePerceptual(amount) => Some(Op::SaturatePerceptual(amount)),
+            Op::Lighten(amount) => Some(Op::Darken(amount)),
+            Op::Darken(amount) => Some(Op::Lighten(amount)),
+            Op::Fadein(amount) => Some(Op::Fadeout(amount)),
+            Op::Fadeout(amount) => Some(Op::Fadein(amount)),
+            Op::Spin(amount) => Some(Op::Spin(-amount)),
+            Op::SpinF32(degrees) => Some(Op::SpinF32(-degrees)),
+            Op::HueRotate(angle) => Some(Op::HueRotate(-angle)),
+            Op::Adjust(adjustment) => opposite(adjustment).map(Op::Adjust),
+            Op::Complement => Some(Op::Complement),
+            Op::Invert => Some(Op::Invert),
+            Op::Brightness(factor) if factor > 0.0 => Some(Op::Brightness(1.0 / factor)),
+            Op::ContrastAdjust(factor) if factor > 0.0 => Some(Op::ContrastAdjust(1.0 / factor)),
+            Op::SaturateRel(_)
+            | Op::DesaturateRel(_)
+            | Op::Vibrance(_)
+            | Op::LightenRel(_)
+            | Op::DarkenRel(_)
+            | Op::Fade(_)
+            | Op::Change(_)
+            | Op::InvertWeighted(_)
+            | Op::Brightness(_)
+            | Op::ContrastAdjust(_)
+            | Op::Sepia(_)
+            | Op::Posterize(_)
+            | Op::Mix { .. }
+            | Op::MixLinear { .. }
+            | Op::MixOklab { .. }
+            | Op::Lerp { .. }
+            | Op::LerpHsl { .. }
+            | Op::Tint(_)
+            | Op::Shade(_)
+            | Op::Greyscale
+            | Op::GreyscaleLuma
+            | Op::Pastelize(_)
+            | Op::Blend { .. }
+            | Op::Negation(_)
+            | Op::Average(_)
+            | Op::Over(_)
+            | Op::EnsureContrast { .. }
+            | Op::Contrast { .. }
+            | Op::ContrastBw => None,
+        }
+    }
+
+    /// Returns `true` if applying the operation to `color` can't be reverted
+    /// exactly, either because the operation has no `inverse`, or because the
+    /// inverse doesn't restore `color`. The latter happens when the operation
+    /// is clamped (e.g. lightening a color that is already almost white), or
+    /// when the result is rounded on its way through the HSL color model.
+    ///
+    /// # Example
+    /// ```
+    /// use css_colors::{hsla, percent, Color, Op};
+    ///
+    /// let lighten = Op::Lighten(percent(20));
+    ///
+    /// assert!(!lighten.is_lossy_at(hsla(0, 0, 80, 1.0).to_rgba()));
+    /// assert!(lighten.is_lossy_at(hsla(0, 0, 90, 1.0).to_rgba()));
+    /// ```
+    pub fn is_lossy_at(&self, color: RGBA) -> bool {
+        match self.inverse() {
+            Some(inverse) => inverse.apply(self.apply(color)) != color,
+            None => true,
+        }
+    }
+}
+
+// The adjustment that offsets every channel the other way, unless an offset
+// has no opposite (such as a saturation offset of `-128`).
+fn opposite(adjustment: ColorAdjustment) -> Option<ColorAdjustment> {
+    fn negate<T>(offset: Option<T>, checked_neg: fn(T) -> Option<T>) -> Option<Option<T>> {
+        match offset {
+            Some(offset) => checked_neg(offset).map(Some),
+            None => Some(None),
+        }
+    }
+
+    Some(ColorAdjustment {
+        red: negate(adjustment.red, i16::checked_neg)?,
+        green: negate(adjustment.green, i16::checked_neg)?,
+        blue: negate(adjustment.blue, i16::checked_neg)?,
+        hue: negate(adjustment.hue, i32::checked_neg)?,
+        saturation: negate(adjustment.saturation, i8::checked_neg)?,
+        lightness: negate(adjustment.lightness, i8::checked_neg)?,
+        alpha: adjustment.alpha.map(|alpha| -alpha),
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use {
+        deg, percent, rgba, BlendMode, BlendSpace, Color, ColorAdjustment, ColorChange, HuePath,
+        InterpolationSpace, Op, Ratio, RGBA,
+    };
+
+    fn grey(byte: u8, alpha: u8) -> RGBA {
+        RGBA {
+            r: Ratio::from_u8(byte),
+            g: Ratio::from_u8(byte),
+            b: Ratio::from_u8(byte),
+            a: Ratio::from_u8(alpha),
+        }
+    }
+
+    #[test]
+    fn can_apply_operations() {
+        let tomato = rgba(255, 99, 71, 0.5);
+
+        assert_eq!(
+            Op::Lighten(percent(10)).apply(tomato),
+            tomato.lighten(percent(10))
+        );
+        assert_eq!(Op::Spin(deg(30)).apply(tomato), tomato.spin(deg(30)));
+        assert_eq!(Op::Greyscale.apply(tomato), tomato.greyscale());
+        assert_eq!(Op::Complement.apply(tomato), tomato.complement());
+        assert_eq!(Op::Sepia(0.5).apply(tomato), tomato.sepia(0.5));
+        assert_eq!(
+            Op::LerpHsl {
+                other: rgba(0, 0, 255, 1.0),
+                t: 0.5,
+                path: HuePath::Shorter
+            }
+            .apply(tomato),
+            tomato
+                .lerp_hsl(rgba(0, 0, 255, 1.0), 0.5, HuePath::Shorter)
+                .to_rgba()
+        );
+
+        // The named blend modes are recorded as `Blend`.
+        let blue = rgba(0, 0, 255, 0.5);
+
+        assert_eq!(
+            Op::Blend {
+                other: blue,
+                mode: BlendMode::Multiply,
+                space: BlendSpace::Gamma
+            }
+            .apply(tomato),
+            tomato.multiply(blue)
+        );
+        assert_eq!(
+            Op::Blend {
+                other: blue,
+                mode: BlendMode::SoftLight,
+                space: BlendSpace::Gamma
+            }
+            .apply(tomato),
+            tomato.softlight(blue)
+        );
+        assert_eq!(Op::Negation(blue).apply(tomato), tomato.negation(blue));
+        assert_eq!(
+            Op::Mix {
+                other: rgba(0, 0, 255, 1.0),
+                weight: percent(25)
+            }
+            .apply(tomato),
+            tomato.mix(rgba(0, 0, 255, 1.0), percent(25))
+        );
+    }
+
+    #[test]
+    fn inverses_revert_operations() {
+        let amount = percent(20);
+        let pairs = [
+            (Op::Lighten(amount), Op::Darken(amount)),
+            (Op::Saturate(amount), Op::Desaturate(amount)),
+            (Op::Fadein(amount), Op::Fadeout(amount)),
+        ];
+
+        for &(op, inverse) in pairs.iter() {
+            assert_eq!(op.inverse(), Some(inverse));
+            assert_eq!(inverse.inverse(), Some(op));
+        }
+
+        assert_eq!(Op::Spin(deg(-30)).inverse(), Some(Op::Spin(deg(30))));
+        assert_eq!(Op::SpinF32(12.5).inverse(), Some(Op::SpinF32(-12.5)));
+        assert_eq!(
+            Op::HueRotate(deg(90)).inverse(),
+            Some(Op::HueRotate(deg(270)))
+        );
+        assert_eq!(Op::Complement.inverse(), Some(Op::Complement));
+        assert_eq!(Op::Invert.inverse(), Some(Op::Invert));
+        assert_eq!(Op::Brightness(0.25).inverse(), Some(Op::Brightness(4.0)));
+        assert_eq!(
+            Op::ContrastAdjust(2.0).inverse(),
+            Some(Op::ContrastAdjust(0.5))
+        );
+        assert_eq!(
+            Op::SaturatePerceptual(amount).inverse(),
+            Some(Op::DesaturatePerceptual(amount))
+        );
+        assert_eq!(
+            Op::Adjust(ColorAdjustment {
+                hue: Some(30),
+                saturation: Some(-10),
+                alpha: Some(0.25),
+                ..Default::default()
+            })
+            .inverse(),
+            Some(Op::Adjust(ColorAdjustment {
+                hue: Some(-30),
+                saturation: Some(10),
+                alpha: Some(-0.25),
+                ..Default::default()
+            }))
+        );
+
+        // Inverting is exact for every color.
+        let tomato = rgba(255, 99, 71, 0.5);
+
+        assert_eq!(
+            Op::Invert
+                .inverse()
+                .unwrap()
+                .apply(Op::Invert.apply(tomato)),
+            tomato
+        );
+        assert!(!Op::Invert.is_lossy_at(tomato));
+
+        let brighten = Op::Brightness(2.0);
+        let redden = Op::Adjust(ColorAdjustment {
+            red: Some(10),
+            ..Default::default()
+        });
+
+        // Alpha and greys are never rounded, so they round-trip unless clamped.
+        for byte in 0..=127 {
+            let color = grey(byte, byte);
+            let ops = [
+                Op::Lighten(amount),
+                Op::Fadein(amount),
+                Op::Spin(deg(90)),
+                Op::SpinF32(45.5),
+                Op::HueRotate(deg(90)),
+                Op::Complement,
+                Op::Invert,
+                brighten,
+                redden,
+            ];
+
+            for op in ops.iter() {
+                let inverse = op.inverse().unwrap();
+
+                assert_eq!(inverse.apply(op.apply(color)), color, "{:?}", op);
+                assert!(!op.is_lossy_at(color), "{:?}", op);
+            }
+        }
+    }
+
+    #[test]
+    fn lossy_operations_have_no_inverse() {
+        let color = rgba(255, 99, 71, 0.5);
+        let lossy = [
+            Op::Fade(percent(20)),
+            Op::Mix {
+                other: color,
+                weight: percent(20),
+            },
+            Op::MixLinear {
+                other: color,
+                weight: percent(20),
+            },
+            Op::MixOklab {
+                other: color,
+                weight: percent(20),
+            },
+            Op::Lerp {
+                other: color,
+                t: 0.2,
+                space: InterpolationSpace::Oklab,
+            },
+            Op::LerpHsl {
+                other: color,
+                t: 0.2,
+                path: HuePath::Longer,
+            },
+            Op::Tint(percent(20)),
+            Op::Shade(percent(20)),
+            Op::Greyscale,
+            Op::GreyscaleLuma,
+            Op::Pastelize(percent(20)),
+            Op::SaturateRel(percent(20)),
+            Op::DesaturateRel(percent(20)),
+            Op::LightenRel(percent(20)),
+            Op::DarkenRel(percent(20)),
+            Op::Vibrance(20),
+            Op::Change(ColorChange {
+                hue: Some(90),
+                ..Default::default()
+            }),
+            Op::Adjust(ColorAdjustment {
+                saturation: Some(i8::MIN),
+                ..Default::default()
+            }),
+            Op::InvertWeighted(20),
+            Op::Brightness(0.0),
+            Op::ContrastAdjust(0.0),
+            Op::Sepia(0.5),
+            Op::Posterize(4),
+            Op::Blend {
+                other: color,
+                mode: BlendMode::Multiply,
+                space: BlendSpace::Gamma,
+            },
+            Op::Negation(color),
+            Op::Average(color),
+            Op::Over(color),
+            Op::EnsureContrast {
+                against: color,
+                target: 4.5,
+            },
+            Op::Contrast {
+                dark: rgba(0, 0, 0, 1.0),
+                light: rgba(255, 255, 255, 1.0),
+                threshold: None,
+            },
+            Op::ContrastBw,
+        ];
+
+        for op in lossy.iter() {
+            assert_eq!(op.inverse(), None);
+            assert!(op.is_lossy_at(color));
+        }
+    }
+
+    #[test]
+    fn detects_clamping() {
+        let lighten = Op::Lighten(percent(20));
+        let darken = Op::Darken(percent(20));
+        let fadein = Op::Fadein(percent(20));
+        let fadeout = Op::Fadeout(percent(20));
+
+        assert!(!lighten.is_lossy_at(grey(204, 255)));
+        assert!(lighten.is_lossy_at(grey(205, 255)));
+        assert!(!darken.is_lossy_at(grey(51, 255)));
+        assert!(darken.is_lossy_at(grey(50, 255)));
+
+        assert!(!fadein.is_lossy_at(grey(128, 204)));
+        assert!(fadein.is_lossy_at(grey(128, 205)));
+        assert!(!fadeout.is_lossy_at(grey(128, 51)));
+        assert!(fadeout.is_lossy_at(grey(128, 50)));
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn can_serialize_operations() {
+        use serde_json;
+
+        let ops = [
+            (Op::Lighten(percent(20)), r#"{"lighten":51}"#),
+            (Op::Spin(deg(30)), r#"{"spin":30}"#),
+            (Op::Greyscale, r#""greyscale""#),
+            (Op::GreyscaleLuma, r#""greyscale_luma""#),
+            (Op::Invert, r#""invert""#),
+            (Op::SpinF32(-12.5), r#"{"spin_f32":-12.5}"#),
+            (Op::HueRotate(deg(90)), r#"{"hue_rotate":90}"#),
+            (Op::Brightness(1.5), r#"{"brightness":1.5}"#),
+            (Op::Posterize(4), r#"{"posterize":4}"#),
+            (
+                Op::Adjust(ColorAdjustment {
+                    hue: Some(30),
+                    ..Default::default()
+                }),
+                r#"{"adjust":{"red":null,"green":null,"blue":null,"hue":30,"saturation":null,"lightness":null,"alpha":null}}"#,
+            ),
+            (
+                Op::Blend {
+                    other: rgba(0, 0, 0, 0.0),
+                    mode: BlendMode::HardLight,
+                    space: BlendSpace::Linear,
+                },
+                r#"{"blend":{"other":{"r":0,"g":0,"b":0,"a":0},"mode":"hard-light","space":"linear"}}"#,
+            ),
+            (
+                Op::Lerp {
+                    other: rgba(0, 0, 0, 1.0),
+                    t: 0.25,
+                    space: InterpolationSpace::Oklab,
+                },
+                r#"{"lerp":{"other":{"r":0,"g":0,"b":0,"a":255},"t":0.25,"space":"oklab"}}"#,
+            ),
+            (
+                Op::Mix {
+                    other: rgba(255, 99, 71, 1.0),
+                    weight: percent(50),
+                },
+                r#"{"mix":{"other":{"r":255,"g":99,"b":71,"a":255},"weight":128}}"#,
+            ),
+        ];
+
+        for &(op, json) in ops.iter() {
+            assert_eq!(serde_json::to_string(&op).unwrap(), json);
+            assert_eq!(serde_json::from_str::<Op>(json).unwrap(), op);
+        }
+
+        assert!(serde_json::from_str::<Op>(r#"{"spin":360}"#).is_err());
+    }
+}
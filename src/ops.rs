@@ -0,0 +1,373 @@
+//! Building blocks for implementing custom color operations.
+//!
+//! The operations on the `Color` trait are built from a handful of reusable
+//! primitives. This module exposes them so that extension traits outside of
+//! this crate can compose with the built-in color models just as naturally.
+//!
+//! # Examples
+//! A film-emulation style tone curve, applied in linear light and preserving
+//! both the color model and the alpha channel of the input:
+//!
+//! ```
+//! use css_colors::{ops, Color, rgb, rgba};
+//!
+//! trait ToneCurve: Color + Sized {
+//!     fn tone_curve(self) -> Self {
+//!         ops::apply_to_rgb_channels(self, |channel| {
+//!             let linear = ops::linearize(channel);
+//!             let curved = linear * linear * (3.0 - 2.0 * linear);
+//!
+//!             ops::delinearize(curved)
+//!         })
+//!     }
+//! }
+//!
+//! impl<T: Color> ToneCurve for T {}
+//!
+//! assert_eq!(rgb(0, 0, 0).tone_curve(), rgb(0, 0, 0));
+//! assert_eq!(rgb(255, 255, 255).tone_curve(), rgb(255, 255, 255));
+//! assert_eq!(rgb(64, 128, 192).tone_curve(), rgb(21, 97, 194));
+//! assert_eq!(rgba(64, 128, 192, 0.5).tone_curve(), rgba(21, 97, 194, 0.5));
+//! ```
+
+use super::{clamp_ratio, Color, Ratio, HSLA, RGBA};
+
+/// Applies `f` to the HSLA representation of `color`, and converts the result
+/// back into the color model of `color`.
+///
+/// # Examples
+/// ```
+/// use css_colors::{ops, Color, hsl, rgb, percent};
+///
+/// let hsl_color = ops::apply_to_hsl(hsl(9, 35, 50), |hsla| hsla.lighten(percent(20)));
+/// let rgb_color = ops::apply_to_hsl(rgb(172, 96, 83), |hsla| hsla.lighten(percent(20)));
+///
+/// assert_eq!(hsl_color, hsl(9, 35, 70));
+/// assert_eq!(rgb_color, rgb(206, 160, 152));
+/// ```
+pub fn apply_to_hsl<T, F>(color: T, f: F) -> T
+where
+    T: Color,
+    F: FnOnce(HSLA) -> HSLA,
+{
+    T::from_hsla(f(color.to_hsla()))
+}
+
+/// Applies `f` to each of the red, green and blue channels of `color`, and converts
+/// the result back into the color model of `color`. The alpha channel is preserved.
+///
+/// Channels are passed to `f` as floats between `0.0-1.0`. The values returned
+/// by `f` are clamped to that same range before being quantized.
+///
+/// # Examples
+/// ```
+/// use css_colors::{ops, rgb, rgba};
+///
+/// let inverted = ops::apply_to_rgb_channels(rgba(250, 128, 114, 0.5), |channel| 1.0 - channel);
+/// let boosted = ops::apply_to_rgb_channels(rgb(200, 100, 50), |channel| channel * 2.0);
+///
+/// assert_eq!(inverted, rgba(5, 127, 141, 0.5));
+/// assert_eq!(boosted, rgb(255, 200, 100));
+/// ```
+pub fn apply_to_rgb_channels<T, F>(color: T, f: F) -> T
+where
+    T: Color,
+    F: Fn(f32) -> f32,
+{
+    let RGBA { r, g, b, a } = color.to_rgba();
+
+    T::from_rgba(RGBA {
+        r: quantize(f(r.as_f32())),
+        g: quantize(f(g.as_f32())),
+        b: quantize(f(b.as_f32())),
+        a,
+    })
+}
+
+/// Quantizes a float into a `Ratio`, clamping values outside of the `0.0-1.0` range.
+///
+/// # Examples
+/// ```
+/// use css_colors::{ops, percent};
+///
+/// assert_eq!(ops::quantize(0.5), percent(50));
+/// assert_eq!(ops::quantize(1.5), percent(100));
+/// assert_eq!(ops::quantize(-0.5), percent(0));
+/// ```
+pub fn quantize(value: f32) -> Ratio {
+    clamp_ratio(value)
+}
+
+/// Converts a gamma-encoded sRGB channel (`0.0-1.0`) into linear light, using
+/// the piecewise sRGB transfer function.
+///
+/// # Examples
+/// ```
+/// use css_colors::ops;
+///
+/// assert_eq!(ops::linearize(0.0), 0.0);
+/// assert_eq!(ops::linearize(1.0), 1.0);
+/// assert!((ops::linearize(0.5) - 0.214).abs() < 0.001);
+/// ```
+pub fn linearize(channel: f32) -> f32 {
+    if channel <= 0.04045 {
+        channel / 12.92
+    } else {
+        ((channel + 0.055) / 1.055).powf(2.4)
+    }
+}
+
+/// Converts a linear light channel (`0.0-1.0`) back into gamma-encoded sRGB,
+/// using the piecewise sRGB transfer function. The inverse of `linearize`.
+///
+/// # Examples
+/// ```
+/// use css_colors::ops;
+///
+/// assert_eq!(ops::delinearize(0.0), 0.0);
+/// assert!((ops::delinearize(1.0) - 1.0).abs() < 0.0001);
+/// assert!((ops::delinearize(ops::linearize(0.5)) - 0.5).abs() < 0.0001);
+/// ```
+pub fn delinearize(channel: f32) -> f32 {
+    if channel <= 0.003_130_8 {
+        channel * 12.92
+    } else {
+        1.055 * channel.powf(1.0 / 2.4) - 0.055
+    }
+}
+
+/// Interpolates between two hues (in degrees) along the shorter arc of the
+/// color wheel, crossing `0°` when needed. Returns a hue in the `0-360°` range.
+///
+/// `t` ranges from `0.0` (returns `from`) to `1.0` (returns `to`).
+///
+/// # Examples
+/// ```
+/// use css_colors::ops;
+///
+/// assert_eq!(ops::interpolate_hue(30.0, 90.0, 0.5), 60.0);
+/// assert_eq!(ops::interpolate_hue(350.0, 10.0, 0.5), 0.0);
+/// assert_eq!(ops::interpolate_hue(10.0, 350.0, 0.25), 5.0);
+/// ```
+pub fn interpolate_hue(from: f32, to: f32, t: f32) -> f32 {
+    let mut delta = (to - from) % 360.0;
+
+    if delta > 180.0 {
+        delta -= 360.0;
+    } else if delta < -180.0 {
+        delta += 360.0;
+    }
+
+    (from + delta * t).rem_euclid(360.0)
+}
+
+/// Computes the weight given to the left hand side's channels when mixing two
+/// colors, taking into account both the user-provided `weight` and the
+/// difference between the alpha values of the two colors. The right hand side
+/// receives the remainder (`1.0 - weight`).
+///
+/// Taken from Sass's implementation (<http://sass-lang.com/documentation/Sass/Script/Functions.html#mix-instance_method>).
+///
+/// # Examples
+/// ```
+/// use css_colors::{ops, percent};
+///
+/// assert_eq!(ops::mix_weight(percent(100), percent(100), percent(100)), 1.0);
+/// assert_eq!(ops::mix_weight(percent(0), percent(100), percent(100)), 0.0);
+/// assert_eq!(ops::mix_weight(percent(50), percent(100), percent(0)), 1.0);
+/// ```
+pub fn mix_weight(weight: Ratio, alpha_lhs: Ratio, alpha_rhs: Ratio) -> f32 {
+    mix_weight_f32(weight.as_f32(), alpha_lhs.as_f32(), alpha_rhs.as_f32())
+}
+
+// The implementation of `mix_weight`, for color models whose alpha channel is
+// more precise than a `Ratio`.
+pub(crate) fn mix_weight_f32(weight: f32, alpha_lhs: f32, alpha_rhs: f32) -> f32 {
+    // Scale the weight so that it falls between a range of [-1, 1].
+    let w = (weight * 2.0) - 1.0;
+
+    // Find the difference between the left and right side's alphas (somewhere between [-1, 1]).
+    let a = alpha_lhs - alpha_rhs;
+
+    // Find the combined rgb_weight, taking into account the user's passed-in weight and alpha (range of [-1, 1]).
+    let rgb_weight = if w * a == -1.0 {
+        w
+    } else {
+        (w + a) / (1.0 + w * a)
+    };
+
+    // Find the combined rgb weight, scaling it to fall in a range bewtween [0, 1].
+    (rgb_weight + 1.0) / 2.0
+}
+
+// Clamps a mixing weight into `0.0-1.0`, following the policy documented on
+// `Color`: out of range weights are a bug of the caller, caught in debug builds.
+pub(crate) fn clamp_weight(weight: f32) -> f32 {
+    debug_assert!(
+        (0.0..=1.0).contains(&weight),
+        "Invalid weight for mix, must be between 0.0 and 1.0"
+    );
+
+    weight.clamp(0.0, 1.0)
+}
+
+// Mixes two colors like Sass (see `mix_weight`), with a weight (`0.0-1.0`)
+// finer than a `Ratio`. The channels are only quantized once, at the end.
+pub(crate) fn mix_rgba(lhs: RGBA, rhs: RGBA, weight: f32) -> RGBA {
+    mix_rgba_in(lhs, rhs, weight, |channel| channel, |channel| channel)
+}
+
+// Mixes two colors like `mix_rgba`, with their channels in linear light.
+pub(crate) fn mix_rgba_linear(lhs: RGBA, rhs: RGBA, weight: f32) -> RGBA {
+    mix_rgba_in(lhs, rhs, weight, linearize, delinearize)
+}
+
+// Mixes two colors like Sass, with their red, green and blue channels decoded
+// before they are weighted and encoded back after.
+fn mix_rgba_in(
+    lhs: RGBA,
+    rhs: RGBA,
+    weight: f32,
+    decode: fn(f32) -> f32,
+    encode: fn(f32) -> f32,
+) -> RGBA {
+    let weight = clamp_weight(weight);
+    let rgb_weight = mix_weight_f32(weight, lhs.a.as_f32(), rhs.a.as_f32());
+    let blend = |lhs: Ratio, rhs: Ratio, weight: f32| {
+        decode(lhs.as_f32()) * weight + decode(rhs.as_f32()) * (1.0 - weight)
+    };
+
+    RGBA {
+        r: quantize(encode(blend(lhs.r, rhs.r, rgb_weight))),
+        g: quantize(encode(blend(lhs.g, rhs.g, rgb_weight))),
+        b: quantize(encode(blend(lhs.b, rhs.b, rgb_weight))),
+        a: quantize(lhs.a.as_f32() * weight + rhs.a.as_f32() * (1.0 - weight)),
+    }
+}
+
+// Interpolates linearly between the four channels of two colors, with `t`
+// (`0.0-1.0`, clamped) from `from` toward `to`. The channels are only quantized
+// once, at the end.
+pub(crate) fn lerp_rgba(from: RGBA, to: RGBA, t: f32) -> RGBA {
+    let t = t.clamp(0.0, 1.0);
+    let lerp = |from: Ratio, to: Ratio| quantize(from.as_f32() + (to.as_f32() - from.as_f32()) * t);
+
+    RGBA {
+        r: lerp(from.r, to.r),
+        g: lerp(from.g, to.g),
+        b: lerp(from.b, to.b),
+        a: lerp(from.a, to.a),
+    }
+}
+
+// Applies a 3×3 color matrix (as in SVG's feColorMatrix) to the red, green and
+// blue channels of `color`, clamping the result. The alpha channel is preserved.
+pub(crate) fn apply_color_matrix<T: Color>(color: T, matrix: &[[f32; 3]; 3]) -> T {
+    let RGBA { r, g, b, a } = color.to_rgba();
+    let (r, g, b) = (r.as_f32(), g.as_f32(), b.as_f32());
+    let row = |i: usize| quantize(matrix[i][0] * r + matrix[i][1] * g + matrix[i][2] * b);
+
+    T::from_rgba(RGBA {
+        r: row(0),
+        g: row(1),
+        b: row(2),
+        a,
+    })
+}
+
+// The `hueRotate` matrix of SVG's feColorMatrix, which roughly preserves the luma.
+// See https://www.w3.org/TR/filter-effects-1/#feColorMatrixElement.
+pub(crate) fn hue_rotate_matrix(degrees: f32) -> [[f32; 3]; 3] {
+    let (sin, cos) = degrees.to_radians().sin_cos();
+
+    [
+        [
+            0.213 + cos * 0.787 - sin * 0.213,
+            0.715 - cos * 0.715 - sin * 0.715,
+            0.072 - cos * 0.072 + sin * 0.928,
+        ],
+        [
+            0.213 - cos * 0.213 + sin * 0.143,
+            0.715 + cos * 0.285 + sin * 0.140,
+            0.072 - cos * 0.072 - sin * 0.283,
+        ],
+        [
+            0.213 - cos * 0.213 - sin * 0.787,
+            0.715 - cos * 0.715 + sin * 0.715,
+            0.072 + cos * 0.928 + sin * 0.072,
+        ],
+    ]
+}
+
+// The matrix of the `sepia()` filter, which interpolates between the identity
+// (`0.0`) and a full sepia tone (`1.0`).
+// See https://www.w3.org/TR/filter-effects-1/#sepiaEquivalent.
+pub(crate) fn sepia_matrix(amount: f32) -> [[f32; 3]; 3] {
+    let rest = 1.0 - amount;
+
+    [
+        [
+            0.393 + 0.607 * rest,
+            0.769 - 0.769 * rest,
+            0.189 - 0.189 * rest,
+        ],
+        [
+            0.349 - 0.349 * rest,
+            0.686 + 0.314 * rest,
+            0.168 - 0.168 * rest,
+        ],
+        [
+            0.272 - 0.272 * rest,
+            0.534 - 0.534 * rest,
+            0.131 + 0.869 * rest,
+        ],
+    ]
+}
+
+// Converts red, green and blue channels (`0.0-1.0`) into a hue (in degrees), a
+// saturation and a lightness (`0.0-1.0`).
+pub(crate) fn rgb_to_hsl(r: f32, g: f32, b: f32) -> (f32, f32, f32) {
+    let max = r.max(g).max(b);
+    let min = r.min(g).min(b);
+    let l = (max + min) / 2.0;
+
+    // Shades of grey have no hue or saturation.
+    if max == min {
+        return (0.0, 0.0, l);
+    }
+
+    let delta = max - min;
+    let s = (delta / (1.0 - (2.0 * l - 1.0).abs())).min(1.0);
+
+    let h = if max == r {
+        60.0 * (g - b) / delta
+    } else if max == g {
+        120.0 + 60.0 * (b - r) / delta
+    } else {
+        240.0 + 60.0 * (r - g) / delta
+    };
+
+    (h.rem_euclid(360.0), s, l)
+}
+
+// The inverse of `rgb_to_hsl`.
+pub(crate) fn hsl_to_rgb(hsl: (f32, f32, f32)) -> (f32, f32, f32) {
+    let (h, s, l) = hsl;
+
+    let chroma = (1.0 - (2.0 * l - 1.0).abs()) * s;
+    let h = h / 60.0;
+    let x = chroma * (1.0 - (h % 2.0 - 1.0).abs());
+
+    let (r, g, b) = match h as u8 {
+        0 => (chroma, x, 0.0),
+        1 => (x, chroma, 0.0),
+        2 => (0.0, chroma, x),
+        3 => (0.0, x, chroma),
+        4 => (x, 0.0, chroma),
+        _ => (chroma, 0.0, x),
+    };
+
+    let m = l - chroma / 2.0;
+
+    (r + m, g + m, b + m)
+}
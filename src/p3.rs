@@ -0,0 +1,265 @@
+use super::css::write_color_function;
+use super::gamut::{in_srgb_gamut, reduce_chroma};
+use super::xyz::{clamp_rgb, multiply};
+use super::{ops, GamutMap, OkLab, OkLch, Ratio, RGB};
+use std::fmt;
+
+/// Constructs a Display P3 Color from numerical values, similar to the
+/// [`color(display-p3 ...)` function][css-color] in CSS.
+///
+/// The channels range between `0.0-1.0`, and are gamma-encoded like the
+/// channels of `RGB`.
+///
+/// # Example
+/// ```
+/// use css_colors::p3;
+///
+/// let red = p3(1.0, 0.2, 0.1);
+///
+/// assert_eq!(red.to_css(), "color(display-p3 1 0.2 0.1)");
+/// ```
+///
+/// [css-color]: https://www.w3.org/TR/css-color-4/#predefined-display-p3
+pub fn p3(r: f32, g: f32, b: f32) -> P3 {
+    P3 { r, g, b }
+}
+
+#[derive(Debug, Copy, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+/// A struct to represent a color in the Display P3 color space, which shares
+/// the white point and transfer function of sRGB but has wider primaries: its
+/// most saturated reds, greens and (to a lesser extent) blues fall outside of
+/// the sRGB gamut.
+///
+/// Colors are converted between both spaces in linear light, with the
+/// matrices of the [CSS Color Spec](https://www.w3.org/TR/css-color-4/#color-conversion-code).
+/// Converting into sRGB clips colors outside of its gamut, unless another
+/// `GamutMap` is given to `map_to_gamut`.
+pub struct P3 {
+    pub r: f32,
+    pub g: f32,
+    pub b: f32,
+}
+
+impl fmt::Display for P3 {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write_color_function(
+            f,
+            "display-p3",
+            [self.r, self.g, self.b],
+            Ratio::from_u8(255),
+        )
+    }
+}
+
+// The matrices between linear sRGB and linear Display P3.
+const SRGB_TO_P3: [[f32; 3]; 3] = [
+    [0.822_462_1, 0.177_538, 0.0],
+    [0.033_194_1, 0.966_805_8, 0.0],
+    [0.017_082_7, 0.072_397_4, 0.910_519_9],
+];
+
+const P3_TO_SRGB: [[f32; 3]; 3] = [
+    [1.224_940_1, -0.224_940_4, 0.0],
+    [-0.042_056_9, 1.042_057_1, 0.0],
+    [-0.019_637_6, -0.078_636_1, 1.098_273_5],
+];
+
+impl P3 {
+    /// Converts an RGB color into Display P3. Every sRGB color fits into P3.
+    ///
+    /// # Example
+    /// ```
+    /// use css_colors::{rgb, P3};
+    ///
+    /// assert_eq!(P3::from_rgb(rgb(255, 0, 0)).to_css(), "color(display-p3 0.9175 0.2003 0.1386)");
+    /// ```
+    pub fn from_rgb(rgb: RGB) -> P3 {
+        let linear = [
+            ops::linearize(rgb.r.as_f32()),
+            ops::linearize(rgb.g.as_f32()),
+            ops::linearize(rgb.b.as_f32()),
+        ];
+
+        let [r, g, b] = multiply(SRGB_TO_P3, linear);
+
+        P3 {
+            r: ops::delinearize(r),
+            g: ops::delinearize(g),
+            b: ops::delinearize(b),
+        }
+    }
+
+    /// Converts `self` into RGB, clipping colors outside of the sRGB gamut
+    /// channel by channel (see `in_gamut` and `map_to_gamut`).
+    ///
+    /// # Example
+    /// ```
+    /// use css_colors::{p3, rgb};
+    ///
+    /// assert_eq!(p3(1.0, 1.0, 1.0).to_rgb(), rgb(255, 255, 255));
+    /// assert_eq!(p3(1.0, 0.0, 0.0).to_rgb(), rgb(255, 0, 0));
+    /// ```
+    pub fn to_rgb(self) -> RGB {
+        self.map_to_gamut(GamutMap::Clip)
+    }
+
+    /// Converts `self` into RGB, bringing colors outside of the sRGB gamut into
+    /// it with the given strategy.
+    ///
+    /// # Example
+    /// ```
+    /// use css_colors::{p3, rgb, GamutMap};
+    ///
+    /// let red = p3(1.0, 0.0, 0.0);
+    ///
+    /// assert_eq!(red.map_to_gamut(GamutMap::Clip), rgb(255, 0, 0));
+    /// assert_eq!(red.map_to_gamut(GamutMap::ChromaReduce), rgb(255, 52, 40));
+    /// ```
+    pub fn map_to_gamut(self, method: GamutMap) -> RGB {
+        let linear = self.to_linear_srgb();
+
+        match method {
+            GamutMap::Clip => clamp_rgb([
+                ops::delinearize(linear[0]),
+                ops::delinearize(linear[1]),
+                ops::delinearize(linear[2]),
+            ]),
+            GamutMap::ChromaReduce => {
+                reduce_chroma(OkLch::from_oklab(OkLab::from_linear_rgb(linear)))
+            }
+        }
+    }
+
+    /// Returns `true` if `self` can be represented in sRGB without gamut mapping.
+    ///
+    /// # Example
+    /// ```
+    /// use css_colors::p3;
+    ///
+    /// assert!(p3(0.9176, 0.2003, 0.1386).in_gamut());
+    /// assert!(!p3(1.0, 0.0, 0.0).in_gamut());
+    /// ```
+    pub fn in_gamut(&self) -> bool {
+        in_srgb_gamut(self.to_linear_srgb())
+    }
+
+    /// Returns `true` if `self` can be represented in sRGB (see `in_gamut`).
+    #[deprecated(note = "renamed to `in_gamut`")]
+    pub fn is_in_srgb_gamut(self) -> bool {
+        self.in_gamut()
+    }
+
+    /// Converts `self` into the `color(display-p3 r g b)` notation of CSS, with
+    /// up to four decimals per channel. Formatting `self` with a precision
+    /// (e.g. `{:.2}`) sets the number of decimals instead.
+    pub fn to_css(self) -> String {
+        self.to_string()
+    }
+
+    // Converts `self` into linear sRGB channels, which fall outside of the
+    // `0.0-1.0` range for colors outside of the sRGB gamut.
+    fn to_linear_srgb(self) -> [f32; 3] {
+        let linear = [
+            ops::linearize(self.r),
+            ops::linearize(self.g),
+            ops::linearize(self.b),
+        ];
+
+        multiply(P3_TO_SRGB, linear)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use {p3, rgb, rgba, Color, GamutMap, OkLab, OkLch, P3};
+
+    fn assert_p3_eq(lhs: P3, rhs: P3) {
+        assert!(
+            (lhs.r - rhs.r).abs() < 0.000_5
+                && (lhs.g - rhs.g).abs() < 0.000_5
+                && (lhs.b - rhs.b).abs() < 0.000_5,
+            "lhs: {:?}, rhs: {:?}",
+            lhs,
+            rhs
+        );
+    }
+
+    #[test]
+    fn can_convert_rgb_to_p3() {
+        // Conversions of the CSS Color 4 spec (computed with its sample code).
+        assert_p3_eq(rgb(255, 0, 0).to_p3(), p3(0.917_5, 0.200_3, 0.138_6));
+        assert_p3_eq(rgb(0, 255, 0).to_p3(), p3(0.458_5, 0.985_3, 0.298_3));
+        assert_p3_eq(rgb(0, 0, 255).to_p3(), p3(0.0, 0.0, 0.959_1));
+        assert_p3_eq(rgba(255, 255, 255, 0.5).to_p3(), p3(1.0, 1.0, 1.0));
+        assert_p3_eq(rgb(0, 0, 0).to_p3(), p3(0.0, 0.0, 0.0));
+    }
+
+    #[test]
+    fn can_round_trip_through_p3() {
+        for r in (0..=255).step_by(15) {
+            for g in (0..=255).step_by(15) {
+                for b in (0..=255).step_by(15) {
+                    let color = rgb(r as u8, g as u8, b as u8);
+
+                    assert_eq!(color.to_p3().to_rgb(), color);
+                    assert!(color.to_p3().in_gamut());
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn maps_saturated_colors_into_srgb() {
+        let red = p3(1.0, 0.0, 0.0);
+
+        assert!(!red.in_gamut());
+
+        let clipped = red.map_to_gamut(GamutMap::Clip);
+        let reduced = red.map_to_gamut(GamutMap::ChromaReduce);
+
+        assert_eq!(clipped, rgb(255, 0, 0));
+        assert_eq!(reduced, rgb(255, 52, 40));
+
+        // Reducing the chroma keeps the lightness and hue, while clipping shifts them.
+        let original = OkLch::from_oklab(OkLab::from_linear_rgb(red.to_linear_srgb()));
+        let (reduced, clipped) = (reduced.to_oklch(), clipped.to_oklch());
+
+        assert!(reduced.c < original.c);
+        assert!((reduced.l - original.l).abs() < 0.005);
+        assert!((reduced.h - original.h).abs() < 1.0);
+        assert!((clipped.l - original.l).abs() > 0.01);
+        assert!((clipped.h - original.h).abs() > (reduced.h - original.h).abs());
+
+        let green = p3(0.0, 1.0, 0.0);
+        assert!(!green.in_gamut());
+        assert_eq!(green.to_rgb(), rgb(0, 255, 0));
+    }
+
+    #[test]
+    #[allow(deprecated)]
+    fn keeps_the_former_name_of_in_gamut() {
+        assert!(p3(0.9176, 0.2003, 0.1386).is_in_srgb_gamut());
+        assert!(!p3(1.0, 0.0, 0.0).is_in_srgb_gamut());
+        assert!(::lch(54.29, 106.84, 40.85).is_in_gamut());
+        assert!(!::oklch(0.628, 0.35, 29.23).is_in_gamut());
+    }
+
+    #[test]
+    fn can_serialize_to_css() {
+        assert_eq!(p3(1.0, 0.2, 0.1).to_css(), "color(display-p3 1 0.2 0.1)");
+        assert_eq!(
+            p3(0.917_54, 0.200_34, 0.138_59).to_css(),
+            "color(display-p3 0.9175 0.2003 0.1386)"
+        );
+        assert_eq!(format!("{}", p3(0.0, 0.0, 0.0)), "color(display-p3 0 0 0)");
+        assert_eq!(
+            format!("{:.2}", p3(0.917_54, 0.200_34, 0.138_59)),
+            "color(display-p3 0.92 0.2 0.14)"
+        );
+        assert_eq!(
+            format!("{:.0}", p3(0.917_54, 0.200_34, 0.138_59)),
+            "color(display-p3 1 0 0)"
+        );
+    }
+}
@@ -0,0 +1,1037 @@
+use super::css::{write_color_function, Alpha, Rounded};
+use super::{
+    deg, lab, oklch, Angle, Color, Lab, LinearRGB, OkLch, Ratio, WhitePoint, HSLA, HWB, P3, RGB,
+    RGBA, XYZ,
+};
+use std::error::Error;
+use std::f32::consts::PI;
+use std::fmt;
+use std::str::FromStr;
+
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "snake_case"))]
+/// An enum of the reasons a CSS color function can fail to parse.
+pub enum ParseColorError {
+    // the string isn't a call to the expected function, such as `hwb(...)`
+    UnknownFunction,
+
+    // the function doesn't have three components, optionally followed by `/ alpha`
+    WrongComponentCount,
+
+    // a component isn't a number, a percentage, an angle or `none`, or isn't
+    // allowed at its position
+    InvalidComponent,
+}
+
+impl fmt::Display for ParseColorError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let description = match self {
+            ParseColorError::UnknownFunction => "unknown color function",
+            ParseColorError::WrongComponentCount => "wrong number of color components",
+            ParseColorError::InvalidComponent => "invalid color component",
+        };
+
+        f.write_str(description)
+    }
+}
+
+impl Error for ParseColorError {}
+
+// Builds a component from the number before its unit.
+type Unit = fn(f32) -> Component;
+
+// A component of a color function, before it is interpreted for its channel.
+#[derive(Debug, Copy, Clone, PartialEq)]
+enum Component {
+    Number(f32),
+    Percentage(f32),
+    // in degrees
+    Angle(f32),
+    None,
+}
+
+impl Component {
+    fn parse(token: &str) -> Result<Component, ParseColorError> {
+        if token == "none" {
+            return Ok(Component::None);
+        }
+
+        let units: [(&str, Unit); 5] = [
+            ("%", Component::Percentage),
+            ("deg", Component::Angle),
+            ("grad", |grad| Component::Angle(grad * 0.9)),
+            ("rad", |rad| Component::Angle(rad * 180.0 / PI)),
+            ("turn", |turn| Component::Angle(turn * 360.0)),
+        ];
+
+        let (number, unit): (&str, Unit) = units
+            .iter()
+            .find(|(suffix, _)| token.ends_with(suffix))
+            .map(|&(suffix, unit)| (&token[..token.len() - suffix.len()], unit))
+            .unwrap_or((token, Component::Number));
+
+        // Rust's floats also accept `inf` and `nan`, which aren't CSS numbers.
+        match number.parse::<f32>() {
+            Ok(value) if value.is_finite() => Ok(unit(value)),
+            _ => Err(ParseColorError::InvalidComponent),
+        }
+    }
+
+    // A hue, in degrees: a number or an angle.
+    fn hue(self) -> Result<f32, ParseColorError> {
+        match self {
+            Component::Number(degrees) | Component::Angle(degrees) => Ok(degrees),
+            Component::None => Ok(0.0),
+            Component::Percentage(_) => Err(ParseColorError::InvalidComponent),
+        }
+    }
+
+    // A number, or a percentage of `reference`.
+    fn number(self, reference: f32) -> Result<f32, ParseColorError> {
+        match self {
+            Component::Number(number) => Ok(number),
+            Component::Percentage(percentage) => Ok(percentage / 100.0 * reference),
+            Component::None => Ok(0.0),
+            Component::Angle(_) => Err(ParseColorError::InvalidComponent),
+        }
+    }
+}
+
+// The three channels of a color function, and its alpha (`1.0` when omitted).
+type Arguments = ([Component; 3], f32);
+
+// Splits `function(c1 c2 c3 [/ alpha])` into its components, for the first of
+// `names` that matches. With `legacy`, the comma-separated syntax of `rgb()`
+// and `hsl()` (`function(c1, c2, c3[, alpha])`) is accepted too.
+fn parse_function(input: &str, names: &[&str], legacy: bool) -> Result<Arguments, ParseColorError> {
+    let input = input.trim().to_ascii_lowercase();
+
+    let arguments = names
+        .iter()
+        .find_map(|name| {
+            input
+                .strip_prefix(name)
+                .and_then(|rest| rest.strip_prefix('('))
+                .and_then(|rest| rest.strip_suffix(')'))
+        })
+        .ok_or(ParseColorError::UnknownFunction)?;
+
+    if legacy && arguments.contains(',') {
+        return parse_legacy_arguments(arguments);
+    }
+
+    let arguments = arguments.replace('/', " / ");
+    let tokens: Vec<&str> = arguments.split_whitespace().collect();
+
+    let (channels, alpha) = match tokens.as_slice() {
+        [channels @ .., "/", alpha] => (channels, Component::parse(alpha)?.number(1.0)?),
+        channels => (channels, 1.0),
+    };
+
+    match channels {
+        [first, second, third] if !channels.contains(&"/") => Ok((
+            [
+                Component::parse(first)?,
+                Component::parse(second)?,
+                Component::parse(third)?,
+            ],
+            alpha,
+        )),
+        _ => Err(ParseColorError::WrongComponentCount),
+    }
+}
+
+// Splits the arguments of the legacy `function(c1, c2, c3[, alpha])` syntax,
+// which doesn't allow `none`.
+fn parse_legacy_arguments(arguments: &str) -> Result<Arguments, ParseColorError> {
+    let components = arguments
+        .split(',')
+        .map(|token| match Component::parse(token.trim())? {
+            Component::None => Err(ParseColorError::InvalidComponent),
+            component => Ok(component),
+        })
+        .collect::<Result<Vec<Component>, ParseColorError>>()?;
+
+    match components.as_slice() {
+        [first, second, third] => Ok(([*first, *second, *third], 1.0)),
+        [first, second, third, alpha] => Ok(([*first, *second, *third], alpha.number(1.0)?)),
+        _ => Err(ParseColorError::WrongComponentCount),
+    }
+}
+
+// Clamps an alpha into range, like in CSS.
+fn alpha(value: f32) -> Ratio {
+    Ratio::from_f32(value.clamp(0.0, 1.0))
+}
+
+// A percentage, or a number between `0-100`, clamped into range like in CSS.
+fn percentage(component: Component) -> Result<Ratio, ParseColorError> {
+    component
+        .number(100.0)
+        .map(|value| Ratio::from_f32(value.clamp(0.0, 100.0) / 100.0))
+}
+
+// A hue, wrapped into `0-360` and rounded to a whole degree.
+fn angle(component: Component) -> Result<Angle, ParseColorError> {
+    Ok(deg(component.hue()?.rem_euclid(360.0).round() as i32))
+}
+
+// Parses the digits of a hex color: `rgb`, `rgba`, `rrggbb` or `rrggbbaa`.
+fn parse_hex(digits: &str) -> Result<RGBA, ParseColorError> {
+    if !digits.bytes().all(|digit| digit.is_ascii_hexdigit()) {
+        return Err(ParseColorError::InvalidComponent);
+    }
+
+    let channels: Vec<u8> = match digits.len() {
+        3 | 4 => digits
+            .chars()
+            .map(|digit| digit.to_digit(16).unwrap() as u8 * 17)
+            .collect(),
+        6 | 8 => (0..digits.len())
+            .step_by(2)
+            .map(|start| u8::from_str_radix(&digits[start..start + 2], 16).unwrap())
+            .collect(),
+        _ => return Err(ParseColorError::WrongComponentCount),
+    };
+
+    Ok(RGBA {
+        r: Ratio::from_u8(channels[0]),
+        g: Ratio::from_u8(channels[1]),
+        b: Ratio::from_u8(channels[2]),
+        a: Ratio::from_u8(channels.get(3).cloned().unwrap_or(255)),
+    })
+}
+
+#[derive(Debug, Copy, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "snake_case"))]
+/// An enum of the colors `parse` returns, in the notation they were written in.
+/// The colors that have no alpha channel are paired with their alpha.
+pub enum CssColor {
+    // a hex color, the `rgb()` and `rgba()` functions, or the `color()`
+    // function in the `srgb` space
+    Rgba(RGBA),
+
+    // the `hsl()` and `hsla()` functions
+    Hsla(HSLA),
+
+    // the `hwb()` function
+    Hwb(HWB, Ratio),
+
+    // the `lab()` function
+    Lab(Lab, Ratio),
+
+    // the `oklch()` function
+    OkLch(OkLch, Ratio),
+
+    // the `color()` function, in the `display-p3` space
+    P3(P3, Ratio),
+
+    // the `color()` function, in the `xyz`, `xyz-d65` and `xyz-d50` spaces
+    Xyz(XYZ, Ratio),
+
+    // the `color()` function, in the `srgb-linear` space
+    LinearRgb(LinearRGB, Ratio),
+}
+
+impl CssColor {
+    /// Converts the color into RGBA, clamping the colors outside of the sRGB
+    /// gamut into it.
+    ///
+    /// # Example
+    /// ```
+    /// use css_colors::{parse, rgba};
+    ///
+    /// assert_eq!(parse("hwb(120 0% 0% / 0.5)").unwrap().to_rgba(), rgba(0, 255, 0, 0.5));
+    /// ```
+    pub fn to_rgba(self) -> RGBA {
+        let (RGB { r, g, b }, a) = match self {
+            CssColor::Rgba(rgba) => return rgba,
+            CssColor::Hsla(hsla) => return hsla.to_rgba(),
+            CssColor::Hwb(hwb, a) => (hwb.to_rgb(), a),
+            CssColor::Lab(lab, a) => (lab.to_rgb(), a),
+            CssColor::OkLch(oklch, a) => (oklch.to_rgb(), a),
+            CssColor::P3(p3, a) => (p3.to_rgb(), a),
+            CssColor::Xyz(xyz, a) => (xyz.to_rgb(), a),
+            CssColor::LinearRgb(linear, a) => (linear.to_srgb(), a),
+        };
+
+        RGBA { r, g, b, a }
+    }
+}
+
+/// Formats the color in the notation it was parsed from, with its alpha, so
+/// that `parse` reads it back. Like `P3`, the channels of the `color()`
+/// function take the precision of the formatter (`{:.2}`), or up to four
+/// decimals.
+///
+/// # Example
+/// ```
+/// use css_colors::{parse, percent, rgba, CssColor, P3};
+///
+/// let p3 = CssColor::P3(P3 { r: 1.0, g: 0.2, b: 0.1 }, percent(50));
+///
+/// assert_eq!(p3.to_string(), "color(display-p3 1 0.2 0.1 / 0.502)");
+/// assert_eq!(parse(&p3.to_string()), Ok(p3));
+/// assert_eq!(CssColor::Rgba(rgba(255, 99, 71, 1.0)).to_string(), "rgba(255, 99, 71, 1.000)");
+/// ```
+impl fmt::Display for CssColor {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match *self {
+            CssColor::Rgba(rgba) => write!(f, "{:#}", rgba),
+            CssColor::Hsla(hsla) => write!(f, "{:#}", hsla),
+            CssColor::Hwb(hwb, a) => write!(
+                f,
+                "hwb({} {:#} {:#}{})",
+                hwb.h.degrees(),
+                hwb.w,
+                hwb.b,
+                Alpha(a)
+            ),
+            CssColor::Lab(lab, a) => {
+                let d50 = Lab::from_xyz_with_white_point(lab.to_xyz(), WhitePoint::D50);
+
+                write!(
+                    f,
+                    "lab({}% {} {}{})",
+                    Rounded(d50.l, 4),
+                    Rounded(d50.a, 4),
+                    Rounded(d50.b, 4),
+                    Alpha(a)
+                )
+            }
+            CssColor::OkLch(oklch, a) => write!(
+                f,
+                "oklch({} {} {}{})",
+                Rounded(oklch.l, 4),
+                Rounded(oklch.c, 4),
+                Rounded(oklch.h, 2),
+                Alpha(a)
+            ),
+            CssColor::P3(p3, a) => write_color_function(f, "display-p3", [p3.r, p3.g, p3.b], a),
+            CssColor::Xyz(xyz, a) => write_color_function(f, "xyz-d65", xyz.to_array(), a),
+            CssColor::LinearRgb(linear, a) => {
+                write_color_function(f, "srgb-linear", [linear.r, linear.g, linear.b], a)
+            }
+        }
+    }
+}
+
+/// Parses a CSS color: a hex color (`#rgb`, `#rgba`, `#rrggbb` or
+/// `#rrggbbaa`), or one of the `rgb()`, `rgba()`, `hsl()`, `hsla()`, `hwb()`,
+/// `lab()`, `oklch()` and `color()` functions. The functions accept the space-separated
+/// syntax of CSS Color 4, with `none` components and an `/ alpha` suffix, and
+/// `rgb()` and `hsl()` also accept the comma-separated syntax of CSS Color 3.
+///
+/// Out-of-range channels are clamped like in CSS, and hues are rounded to a
+/// whole degree.
+///
+/// # Example
+/// ```
+/// use css_colors::{hsla, parse, rgba, CssColor};
+///
+/// assert_eq!(parse("#ff6347"), Ok(CssColor::Rgba(rgba(255, 99, 71, 1.0))));
+/// assert_eq!(parse("rgb(255 99 71 / 50%)"), Ok(CssColor::Rgba(rgba(255, 99, 71, 0.5))));
+/// assert_eq!(parse("hsla(9, 100%, 64%, 1)"), Ok(CssColor::Hsla(hsla(9, 100, 64, 1.0))));
+/// assert_eq!(parse("lab(29.2345% 39.3825 20.0664)").unwrap().to_rgba(), rgba(125, 35, 41, 1.0));
+/// ```
+pub fn parse(input: &str) -> Result<CssColor, ParseColorError> {
+    let trimmed = input.trim();
+
+    if let Some(digits) = trimmed.strip_prefix('#') {
+        return parse_hex(digits).map(CssColor::Rgba);
+    }
+
+    let name = trimmed
+        .split('(')
+        .next()
+        .unwrap_or_default()
+        .trim_end()
+        .to_ascii_lowercase();
+
+    match name.as_str() {
+        "rgb" | "rgba" => {
+            let ([r, g, b], a) = parse_function(input, &["rgba", "rgb"], true)?;
+            let channel = |component: Component| {
+                component
+                    .number(255.0)
+                    .map(|value| Ratio::from_u8(value.clamp(0.0, 255.0).round() as u8))
+            };
+
+            Ok(CssColor::Rgba(RGBA {
+                r: channel(r)?,
+                g: channel(g)?,
+                b: channel(b)?,
+                a: alpha(a),
+            }))
+        }
+        "hsl" | "hsla" => {
+            let ([h, s, l], a) = parse_function(input, &["hsla", "hsl"], true)?;
+
+            Ok(CssColor::Hsla(HSLA {
+                h: angle(h)?,
+                s: percentage(s)?,
+                l: percentage(l)?,
+                a: alpha(a),
+            }))
+        }
+        "hwb" => parse_hwb(input).map(|(hwb, a)| CssColor::Hwb(hwb, alpha(a))),
+        "lab" => parse_lab(input).map(|(lab, a)| CssColor::Lab(lab, alpha(a))),
+        "oklch" => parse_oklch(input).map(|(oklch, a)| CssColor::OkLch(oklch, alpha(a))),
+        "color" => parse_color(input),
+        _ => Err(ParseColorError::UnknownFunction),
+    }
+}
+
+// Parses the `color(<space> c1 c2 c3 [/ alpha])` function, for the spaces that
+// have a type in this crate. The channels are numbers, or percentages of `1.0`,
+// and aren't clamped since the spaces can hold colors outside of sRGB.
+fn parse_color(input: &str) -> Result<CssColor, ParseColorError> {
+    let input = input.trim().to_ascii_lowercase();
+
+    let (space, channels) = input
+        .strip_prefix("color(")
+        .and_then(|arguments| arguments.trim_start().split_once(char::is_whitespace))
+        .ok_or(ParseColorError::UnknownFunction)?;
+
+    // The rest of the arguments read like the ones of a function named after the space.
+    let ([c1, c2, c3], a) = parse_function(&format!("{}({}", space, channels), &[space], false)?;
+    let [c1, c2, c3] = [c1.number(1.0)?, c2.number(1.0)?, c3.number(1.0)?];
+
+    match space {
+        "srgb" => {
+            let channel = |value: f32| Ratio::from_f32(value.clamp(0.0, 1.0));
+
+            Ok(CssColor::Rgba(RGBA {
+                r: channel(c1),
+                g: channel(c2),
+                b: channel(c3),
+                a: alpha(a),
+            }))
+        }
+        "display-p3" => Ok(CssColor::P3(
+            P3 {
+                r: c1,
+                g: c2,
+                b: c3,
+            },
+            alpha(a),
+        )),
+        "xyz" | "xyz-d65" => Ok(CssColor::Xyz(
+            XYZ {
+                x: c1,
+                y: c2,
+                z: c3,
+            },
+            alpha(a),
+        )),
+        "xyz-d50" => {
+            let xyz = XYZ {
+                x: c1,
+                y: c2,
+                z: c3,
+            }
+            .adapt(WhitePoint::D50, WhitePoint::D65);
+
+            Ok(CssColor::Xyz(xyz, alpha(a)))
+        }
+        "srgb-linear" => Ok(CssColor::LinearRgb(
+            LinearRGB {
+                r: c1,
+                g: c2,
+                b: c3,
+            },
+            alpha(a),
+        )),
+        _ => Err(ParseColorError::UnknownFunction),
+    }
+}
+
+/// Parses the [`hwb()` function](https://www.w3.org/TR/css-color-4/#the-hwb-notation)
+/// of CSS. The hue is a number (of degrees) or an angle, and the whiteness and
+/// blackness are percentages, or numbers between `0-100`. Any component can be
+/// `none`, which is zero. HWB colors have no alpha channel, so an `/ alpha`
+/// suffix is checked but not preserved (`parse` keeps it).
+///
+/// # Example
+/// ```
+/// use css_colors::{hwb, HWB};
+///
+/// assert_eq!("hwb(194 0% 0%)".parse::<HWB>(), Ok(hwb(194, 0, 0)));
+/// assert_eq!("hwb(0.5turn 20 none / 50%)".parse::<HWB>(), Ok(hwb(180, 20, 0)));
+/// ```
+impl FromStr for HWB {
+    type Err = ParseColorError;
+
+    fn from_str(input: &str) -> Result<HWB, ParseColorError> {
+        parse_hwb(input).map(|(hwb, _)| hwb)
+    }
+}
+
+fn parse_hwb(input: &str) -> Result<(HWB, f32), ParseColorError> {
+    let ([h, w, b], a) = parse_function(input, &["hwb"], false)?;
+
+    let hwb = HWB {
+        h: angle(h)?,
+        w: percentage(w)?,
+        b: percentage(b)?,
+    };
+
+    Ok((hwb, a))
+}
+
+/// Parses the [`lab()` function](https://www.w3.org/TR/css-color-4/#specifying-lab-lch)
+/// of CSS. The lightness is a percentage, or a number between `0-100`, and the
+/// `a` and `b` axes are numbers, or percentages of `125`. Any component can be
+/// `none`, which is zero. CIELAB colors have no alpha channel, so an `/ alpha`
+/// suffix is checked but not preserved (`parse` keeps it).
+///
+/// CSS' `lab()` is relative to the D50 white point, so the components are
+/// adapted to the D65 white point of `Lab`.
+///
+/// # Example
+/// ```
+/// use css_colors::{rgb, Lab};
+///
+/// let lab = "lab(52.2% 40.1 59.9)".parse::<Lab>().unwrap();
+///
+/// assert_eq!(lab.to_rgb(), rgb(198, 93, 7));
+/// ```
+impl FromStr for Lab {
+    type Err = ParseColorError;
+
+    fn from_str(input: &str) -> Result<Lab, ParseColorError> {
+        parse_lab(input).map(|(lab, _)| lab)
+    }
+}
+
+fn parse_lab(input: &str) -> Result<(Lab, f32), ParseColorError> {
+    let ([l, a, b], alpha) = parse_function(input, &["lab"], false)?;
+
+    let d50 = lab(
+        l.number(100.0)?.clamp(0.0, 100.0),
+        a.number(125.0)?,
+        b.number(125.0)?,
+    );
+
+    Ok((
+        Lab::from_xyz(d50.to_xyz_with_white_point(WhitePoint::D50)),
+        alpha,
+    ))
+}
+
+/// Parses the [`oklch()` function](https://www.w3.org/TR/css-color-4/#specifying-oklab-oklch)
+/// of CSS. The lightness is a percentage, or a number between `0.0-1.0`, the
+/// chroma is a number, or a percentage of `0.4`, and the hue is a number (of
+/// degrees) or an angle. Any component can be `none`, which is zero. OkLch
+/// colors have no alpha channel, so an `/ alpha` suffix is checked but not
+/// preserved (`parse` keeps it).
+///
+/// # Example
+/// ```
+/// use css_colors::{oklch, OkLch};
+///
+/// assert_eq!("oklch(62.8% 0.258 29.2)".parse::<OkLch>(), Ok(oklch(0.628, 0.258, 29.2)));
+/// assert_eq!("oklch(0.5 50% -90deg)".parse::<OkLch>(), Ok(oklch(0.5, 0.2, 270.0)));
+/// ```
+impl FromStr for OkLch {
+    type Err = ParseColorError;
+
+    fn from_str(input: &str) -> Result<OkLch, ParseColorError> {
+        parse_oklch(input).map(|(oklch, _)| oklch)
+    }
+}
+
+fn parse_oklch(input: &str) -> Result<(OkLch, f32), ParseColorError> {
+    let ([l, c, h], a) = parse_function(input, &["oklch"], false)?;
+
+    let oklch = oklch(
+        l.number(1.0)?.clamp(0.0, 1.0),
+        c.number(0.4)?.max(0.0),
+        h.hue()?,
+    );
+
+    Ok((oklch, a))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Component;
+    use {
+        hsla, hwb, lab, linear_rgb, oklch, parse, rgb, rgba, xyz, Color, CssColor, Lab, OkLch,
+        ParseColorError, Ratio, WhitePoint, HWB, P3, RGBA,
+    };
+
+    fn assert_lab_eq(lhs: Lab, rhs: Lab) {
+        assert!(
+            (lhs.l - rhs.l).abs() < 0.001
+                && (lhs.a - rhs.a).abs() < 0.001
+                && (lhs.b - rhs.b).abs() < 0.001,
+            "lhs: {:?}, rhs: {:?}",
+            lhs,
+            rhs
+        );
+    }
+
+    // The D65 `Lab` of CIELAB components relative to D50.
+    fn d50(l: f32, a: f32, b: f32) -> Lab {
+        Lab::from_xyz(lab(l, a, b).to_xyz_with_white_point(WhitePoint::D50))
+    }
+
+    fn assert_oklch_eq(lhs: OkLch, rhs: OkLch) {
+        assert!(
+            (lhs.l - rhs.l).abs() < 0.000_01
+                && (lhs.c - rhs.c).abs() < 0.000_01
+                && (lhs.h - rhs.h).abs() < 0.001,
+            "lhs: {:?}, rhs: {:?}",
+            lhs,
+            rhs
+        );
+    }
+
+    // Four decimals of the `color()` function can be a byte away in sRGB, for
+    // the darkest colors.
+    fn assert_rgba_close(lhs: RGBA, rhs: RGBA) {
+        let channels = [(lhs.r, rhs.r), (lhs.g, rhs.g), (lhs.b, rhs.b)];
+
+        assert!(
+            lhs.a == rhs.a
+                && channels
+                    .iter()
+                    .all(|&(lhs, rhs)| lhs.as_u8().abs_diff(rhs.as_u8()) <= 1),
+            "lhs: {:?}, rhs: {:?}",
+            lhs,
+            rhs
+        );
+    }
+
+    #[test]
+    fn can_parse_components() {
+        assert_eq!(Component::parse("12.5"), Ok(Component::Number(12.5)));
+        assert_eq!(Component::parse("-.5"), Ok(Component::Number(-0.5)));
+        assert_eq!(Component::parse("1e2"), Ok(Component::Number(100.0)));
+        assert_eq!(Component::parse("40%"), Ok(Component::Percentage(40.0)));
+        assert_eq!(Component::parse("90deg"), Ok(Component::Angle(90.0)));
+        assert_eq!(Component::parse("100grad"), Ok(Component::Angle(90.0)));
+        assert_eq!(Component::parse("0.25turn"), Ok(Component::Angle(90.0)));
+        assert_eq!(Component::parse("none"), Ok(Component::None));
+
+        match Component::parse("3.14159265rad") {
+            Ok(Component::Angle(degrees)) => assert!((degrees - 180.0).abs() < 0.001),
+            other => panic!("{:?}", other),
+        }
+
+        for invalid in ["", "%", "deg", "12px", "inf", "nan", "1 2", "nonе"].iter() {
+            assert_eq!(
+                Component::parse(invalid),
+                Err(ParseColorError::InvalidComponent),
+                "{:?}",
+                invalid
+            );
+        }
+    }
+
+    #[test]
+    fn can_parse_hwb() {
+        // Examples of the CSS Color 4 spec.
+        assert_eq!("hwb(194 0% 0%)".parse(), Ok(hwb(194, 0, 0)));
+        assert_eq!("hwb(194 0% 0% / .5)".parse(), Ok(hwb(194, 0, 0)));
+
+        assert_eq!("hwb(90deg 20 30)".parse(), Ok(hwb(90, 20, 30)));
+        assert_eq!("hwb(-90 none 100%)".parse(), Ok(hwb(270, 0, 100)));
+        assert_eq!("hwb(720.4 150% -10%)".parse(), Ok(hwb(0, 100, 0)));
+        assert_eq!("  HWB(none 10%  10%/none) ".parse(), Ok(hwb(0, 10, 10)));
+        assert_eq!("hwb(1turn 50% 50%)".parse(), Ok(hwb(0, 50, 50)));
+    }
+
+    #[test]
+    fn can_parse_lab() {
+        // Examples of the CSS Color 4 spec, whose components are relative to D50.
+        let examples = [
+            ("lab(52.2345% 40.1645 59.9971)", rgb(198, 93, 6)),
+            ("lab(29.2345% 39.3825 20.0664)", rgb(125, 35, 41)),
+            ("lab(52.2345% 40.1645 59.9971 / .5)", rgb(198, 93, 6)),
+            ("lab(52.2% 40.1 59.9)", rgb(198, 93, 7)),
+        ];
+
+        for &(input, expected) in examples.iter() {
+            assert_eq!(
+                input.parse::<Lab>().unwrap().to_rgb(),
+                expected,
+                "{:?}",
+                input
+            );
+        }
+
+        assert_lab_eq(
+            "lab(50 100% -50%)".parse().unwrap(),
+            d50(50.0, 125.0, -62.5),
+        );
+
+        // Greys are adapted to greys of the same lightness.
+        assert_lab_eq("lab(none none none)".parse().unwrap(), lab(0.0, 0.0, 0.0));
+        assert_lab_eq("lab(50 0 0)".parse().unwrap(), lab(50.0, 0.0, 0.0));
+        assert_lab_eq("lab(120% 0 0)".parse().unwrap(), lab(100.0, 0.0, 0.0));
+        assert_lab_eq("lab(-10 0 0 / 200%)".parse().unwrap(), lab(0.0, 0.0, 0.0));
+    }
+
+    #[test]
+    fn can_parse_oklch() {
+        // Examples of the CSS Color 4 spec.
+        let examples = [
+            ("oklch(62.8% 0.258 29.2)", oklch(0.628, 0.258, 29.2)),
+            (
+                "oklch(40.101% 0.12332 21.555)",
+                oklch(0.401_01, 0.123_32, 21.555),
+            ),
+            (
+                "oklch(59.686% 0.15619 49.7694)",
+                oklch(0.596_86, 0.156_19, 49.769_4),
+            ),
+            (
+                "oklch(65.125% 0.13138 104.097)",
+                oklch(0.651_25, 0.131_38, 104.097),
+            ),
+            ("oklch(42.1% 0.192 328.6 / 1)", oklch(0.421, 0.192, 328.6)),
+        ];
+
+        for &(input, expected) in examples.iter() {
+            assert_oklch_eq(input.parse().unwrap(), expected);
+        }
+
+        assert_oklch_eq(
+            "oklch(0.5 100% 0.5turn)".parse().unwrap(),
+            oklch(0.5, 0.4, 180.0),
+        );
+        assert_oklch_eq(
+            "oklch(150% -0.1 -30)".parse().unwrap(),
+            oklch(1.0, 0.0, 330.0),
+        );
+        assert_oklch_eq(
+            "oklch(none none none)".parse().unwrap(),
+            oklch(0.0, 0.0, 0.0),
+        );
+        assert_eq!(
+            "oklch(62.8% 0.258 29.2)".parse::<OkLch>().unwrap().to_rgb(),
+            OkLch::from_rgb(rgb(255, 0, 0)).to_rgb()
+        );
+    }
+
+    #[test]
+    fn rejects_invalid_functions() {
+        assert_eq!(
+            "hsl(194 0% 0%)".parse::<HWB>(),
+            Err(ParseColorError::UnknownFunction)
+        );
+        assert_eq!(
+            "hwb 194 0% 0%".parse::<HWB>(),
+            Err(ParseColorError::UnknownFunction)
+        );
+        assert_eq!(
+            "hwb(194 0% 0%".parse::<HWB>(),
+            Err(ParseColorError::UnknownFunction)
+        );
+        assert_eq!(
+            "oklab(0.5 0 0)".parse::<OkLch>(),
+            Err(ParseColorError::UnknownFunction)
+        );
+        assert_eq!(
+            "lab(50 0 0)x".parse::<Lab>(),
+            Err(ParseColorError::UnknownFunction)
+        );
+    }
+
+    #[test]
+    fn rejects_wrong_component_counts() {
+        for invalid in [
+            "lab()",
+            "lab(50 0)",
+            "lab(50 0 0 0)",
+            "lab(50 0 0 /)",
+            "lab(50 0 / 0 0)",
+            "lab(50 0 0 / 1 / 1)",
+            "lab(50, 0, 0)",
+        ]
+        .iter()
+        {
+            assert!(invalid.parse::<Lab>().is_err(), "{:?}", invalid);
+        }
+
+        assert_eq!(
+            "lab(50 0)".parse::<Lab>(),
+            Err(ParseColorError::WrongComponentCount)
+        );
+        assert_eq!(
+            "lab(50 0 0 /)".parse::<Lab>(),
+            Err(ParseColorError::WrongComponentCount)
+        );
+    }
+
+    #[test]
+    fn rejects_components_at_the_wrong_position() {
+        // Hues can't be percentages, and other channels can't be angles.
+        assert_eq!(
+            "hwb(50% 0% 0%)".parse::<HWB>(),
+            Err(ParseColorError::InvalidComponent)
+        );
+        assert_eq!(
+            "hwb(0 0deg 0%)".parse::<HWB>(),
+            Err(ParseColorError::InvalidComponent)
+        );
+        assert_eq!(
+            "lab(50 0 90deg)".parse::<Lab>(),
+            Err(ParseColorError::InvalidComponent)
+        );
+        assert_eq!(
+            "oklch(0.5 0.1 10%)".parse::<OkLch>(),
+            Err(ParseColorError::InvalidComponent)
+        );
+        assert_eq!(
+            "oklch(0.5 0.1 10 / 1deg)".parse::<OkLch>(),
+            Err(ParseColorError::InvalidComponent)
+        );
+    }
+
+    #[test]
+    fn can_parse_hex_colors() {
+        assert_eq!(parse("#ff6347"), Ok(CssColor::Rgba(rgba(255, 99, 71, 1.0))));
+        assert_eq!(
+            parse(" #FF6347 "),
+            Ok(CssColor::Rgba(rgba(255, 99, 71, 1.0)))
+        );
+        assert_eq!(parse("#f60"), Ok(CssColor::Rgba(rgba(255, 102, 0, 1.0))));
+        assert_eq!(parse("#f608"), Ok(CssColor::Rgba(rgba(255, 102, 0, 0.533))));
+        assert_eq!(
+            parse("#ff634780"),
+            Ok(CssColor::Rgba(rgba(255, 99, 71, 0.5)))
+        );
+
+        assert_eq!(parse("#ff634"), Err(ParseColorError::WrongComponentCount));
+        assert_eq!(parse("#"), Err(ParseColorError::WrongComponentCount));
+        assert_eq!(parse("#ff634g"), Err(ParseColorError::InvalidComponent));
+        assert_eq!(parse("#+f+f+f"), Err(ParseColorError::InvalidComponent));
+    }
+
+    #[test]
+    fn can_parse_rgb() {
+        let tomato = CssColor::Rgba(rgba(255, 99, 71, 1.0));
+
+        assert_eq!(parse("rgb(255, 99, 71)"), Ok(tomato));
+        assert_eq!(parse("rgb(255 99 71)"), Ok(tomato));
+        assert_eq!(parse("RGBA(100%, 38.8%, 27.8%, 1)"), Ok(tomato));
+        assert_eq!(parse("rgb(300 99 71 / 2)"), Ok(tomato));
+        assert_eq!(
+            parse("rgba(255, 99, 71, 0.5)"),
+            Ok(CssColor::Rgba(rgba(255, 99, 71, 0.5)))
+        );
+        assert_eq!(
+            parse("rgb(255 none 71 / 50%)"),
+            Ok(CssColor::Rgba(rgba(255, 0, 71, 0.5)))
+        );
+
+        // The legacy syntax doesn't allow `none`, or mixing commas and slashes.
+        assert_eq!(
+            parse("rgb(255, none, 71)"),
+            Err(ParseColorError::InvalidComponent)
+        );
+        assert_eq!(
+            parse("rgb(255, 99, 71 / 1)"),
+            Err(ParseColorError::InvalidComponent)
+        );
+        assert_eq!(
+            parse("rgb(255, 99)"),
+            Err(ParseColorError::WrongComponentCount)
+        );
+        assert_eq!(
+            parse("rgb(255 99 90deg)"),
+            Err(ParseColorError::InvalidComponent)
+        );
+    }
+
+    #[test]
+    fn can_parse_hsl() {
+        assert_eq!(
+            parse("hsl(9, 100%, 64%)"),
+            Ok(CssColor::Hsla(hsla(9, 100, 64, 1.0)))
+        );
+        assert_eq!(
+            parse("hsla(9deg 100 64 / 0.25)"),
+            Ok(CssColor::Hsla(hsla(9, 100, 64, 0.25)))
+        );
+        assert_eq!(
+            parse("hsl(-0.5turn, 150%, -5%, 10%)"),
+            Ok(CssColor::Hsla(hsla(180, 100, 0, 0.1)))
+        );
+        assert_eq!(
+            parse("hsl(9, 100%)"),
+            Err(ParseColorError::WrongComponentCount)
+        );
+    }
+
+    #[test]
+    fn can_parse_the_functions_without_alpha_channels() {
+        assert_eq!(
+            parse("hwb(194 0% 0% / .5)"),
+            Ok(CssColor::Hwb(hwb(194, 0, 0), ::percent(50)))
+        );
+        assert_eq!(
+            parse("oklch(62.8% 0.258 29.2 / 0)"),
+            Ok(CssColor::OkLch(
+                "oklch(62.8% 0.258 29.2)".parse().unwrap(),
+                ::percent(0)
+            ))
+        );
+        assert_eq!(
+            parse("lab(29.2345% 39.3825 20.0664 / 25%)")
+                .unwrap()
+                .to_rgba(),
+            rgba(125, 35, 41, 0.25)
+        );
+        assert_eq!(
+            parse("hwb(120 0% 0%)").unwrap().to_rgba(),
+            rgb(0, 255, 0).to_rgba()
+        );
+    }
+
+    #[test]
+    fn can_parse_the_color_function() {
+        assert_eq!(
+            parse("color(display-p3 1 0.2 0.1)"),
+            Ok(CssColor::P3(
+                P3 {
+                    r: 1.0,
+                    g: 0.2,
+                    b: 0.1
+                },
+                ::percent(100)
+            ))
+        );
+        assert_eq!(
+            parse("color(xyz-d65 0.42 0.21 0.02 / 50%)"),
+            Ok(CssColor::Xyz(xyz(0.42, 0.21, 0.02), ::percent(50)))
+        );
+        assert_eq!(
+            parse("color(xyz 0.42 0.21 0.02)"),
+            parse("color(xyz-d65 0.42 0.21 0.02)")
+        );
+        assert_eq!(
+            parse(" COLOR( srgb-linear 100% none -0.5 / 0) "),
+            Ok(CssColor::LinearRgb(
+                linear_rgb(1.0, 0.0, -0.5),
+                ::percent(0)
+            ))
+        );
+        assert_eq!(
+            parse("color(srgb 1 0.388 120% / .5)"),
+            Ok(CssColor::Rgba(rgba(255, 99, 255, 0.5)))
+        );
+
+        // The D50 white is adapted to the D65 white of `XYZ`.
+        assert_eq!(
+            parse("color(xyz-d50 0.9642 1 0.8251)").unwrap().to_rgba(),
+            rgba(255, 255, 255, 1.0)
+        );
+
+        assert_eq!(
+            parse("color(rec2020 1 0 0)"),
+            Err(ParseColorError::UnknownFunction)
+        );
+        assert_eq!(
+            parse("color(display-p3)"),
+            Err(ParseColorError::UnknownFunction)
+        );
+        assert_eq!(
+            parse("color(display-p3 1 0)"),
+            Err(ParseColorError::WrongComponentCount)
+        );
+        assert_eq!(
+            parse("color(display-p3 1 0 90deg)"),
+            Err(ParseColorError::InvalidComponent)
+        );
+    }
+
+    #[test]
+    fn can_round_trip_through_css() {
+        let alphas = [
+            ::percent(100),
+            ::percent(50),
+            Ratio::from_u8(1),
+            ::percent(0),
+        ];
+
+        for r in (0..=255).step_by(15) {
+            for g in (0..=255).step_by(15) {
+                for b in (0..=255).step_by(15) {
+                    let color = rgb(r, g, b);
+
+                    for &a in alphas.iter() {
+                        let colors = [
+                            CssColor::Rgba(RGBA {
+                                a,
+                                ..color.to_rgba()
+                            }),
+                            CssColor::Hsla(::HSLA {
+                                a,
+                                ..color.to_hsla()
+                            }),
+                            CssColor::Hwb(color.to_hwb(), a),
+                            CssColor::Lab(color.to_lab(), a),
+                            CssColor::OkLch(color.to_oklch(), a),
+                            CssColor::P3(color.to_p3(), a),
+                            CssColor::Xyz(color.to_xyz(), a),
+                            CssColor::LinearRgb(color.to_linear(), a),
+                        ];
+
+                        for css in colors.iter() {
+                            let parsed = parse(&css.to_string()).unwrap();
+
+                            // Adapting `lab()` from and back to D50 can move its
+                            // last decimal, with the rounding of `f32`.
+                            if !matches!(css, CssColor::Lab(..)) {
+                                assert_eq!(parsed.to_string(), css.to_string());
+                            }
+
+                            assert_rgba_close(parsed.to_rgba(), css.to_rgba());
+                        }
+                    }
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn can_round_trip_the_color_function_with_less_precision() {
+        let p3 = CssColor::P3(rgb(255, 99, 71).to_p3(), ::percent(50));
+        let css = format!("{:.2}", p3);
+
+        assert_eq!(css, "color(display-p3 0.93 0.43 0.32 / 0.502)");
+        assert_eq!(format!("{:.2}", parse(&css).unwrap()), css);
+    }
+
+    #[test]
+    fn rejects_unknown_colors() {
+        for invalid in ["", "tomato", "rgb", "cmyk(0 0 0 0)", "lab(50, 0, 0)"].iter() {
+            assert!(parse(invalid).is_err(), "{:?}", invalid);
+        }
+
+        assert_eq!(parse("hsv(0 0% 0%)"), Err(ParseColorError::UnknownFunction));
+        assert_eq!(parse("rgb(0 0 0"), Err(ParseColorError::UnknownFunction));
+    }
+
+    #[test]
+    fn describes_errors() {
+        assert_eq!(
+            ParseColorError::UnknownFunction.to_string(),
+            "unknown color function"
+        );
+        assert_eq!(
+            ParseColorError::WrongComponentCount.to_string(),
+            "wrong number of color components"
+        );
+        assert_eq!(
+            ParseColorError::InvalidComponent.to_string(),
+            "invalid color component"
+        );
+    }
+}
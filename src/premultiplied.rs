@@ -0,0 +1,225 @@
+use super::{Ratio, RGBA};
+
+#[derive(Debug, Copy, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+/// A struct to represent a color with premultiplied alpha, as expected by GPUs and
+/// compositors: the red, green and blue channels are stored already multiplied
+/// by the alpha channel, so none of them exceeds it.
+///
+/// Colors are converted with `RGBA::premultiply` and `unpremultiply`, which round
+/// each channel to the nearest byte. Premultiplying loses precision for
+/// translucent colors (a fully transparent color has no color left at all), but
+/// converting a premultiplied color back and forth is stable: premultiplying the
+/// result of `unpremultiply` gives the original premultiplied color.
+///
+/// A channel premultiplied by an alpha of `a` (between `1-255`) only has `a + 1`
+/// possible values, so a round trip through `premultiply` and `unpremultiply` is
+/// off by at most `255 / 2a` (rounded up) per channel: one unit for alphas from
+/// `85` (about `33%`) up, but as much as sixteen for an alpha of `8`.
+///
+/// # Example
+/// ```
+/// use css_colors::{rgba, Ratio};
+///
+/// let salmon = rgba(250, 128, 114, 0.5).premultiply();
+///
+/// assert_eq!(salmon.r, Ratio::from_u8(125));
+/// assert_eq!(salmon.unpremultiply(), rgba(249, 128, 114, 0.5));
+/// ```
+pub struct PremultipliedRGBA {
+    // red, multiplied by alpha
+    pub r: Ratio,
+
+    // green, multiplied by alpha
+    pub g: Ratio,
+
+    // blue, multiplied by alpha
+    pub b: Ratio,
+
+    // alpha
+    pub a: Ratio,
+}
+
+impl PremultipliedRGBA {
+    /// Multiplies the red, green and blue channels of an RGBA color by its alpha.
+    pub fn from_rgba(rgba: RGBA) -> PremultipliedRGBA {
+        let RGBA { r, g, b, a } = rgba;
+        let alpha = a.as_u8() as u32;
+
+        let premultiply = |channel: Ratio| {
+            // Rounds to the nearest byte with integer arithmetic.
+            Ratio::from_u8(((channel.as_u8() as u32 * alpha + 127) / 255) as u8)
+        };
+
+        PremultipliedRGBA {
+            r: premultiply(r),
+            g: premultiply(g),
+            b: premultiply(b),
+            a,
+        }
+    }
+
+    /// Divides the red, green and blue channels of `self` by its alpha. A fully
+    /// transparent color has no color left to recover, so it becomes transparent
+    /// black. Channels that exceed the alpha are clamped.
+    ///
+    /// # Example
+    /// ```
+    /// use css_colors::rgba;
+    ///
+    /// let transparent = rgba(250, 128, 114, 0.0).premultiply();
+    ///
+    /// assert_eq!(transparent.unpremultiply(), rgba(0, 0, 0, 0.0));
+    /// ```
+    pub fn unpremultiply(self) -> RGBA {
+        let PremultipliedRGBA { r, g, b, a } = self;
+        let alpha = a.as_u8() as u32;
+
+        if alpha == 0 {
+            return RGBA {
+                r: a,
+                g: a,
+                b: a,
+                a,
+            };
+        }
+
+        let unpremultiply = |channel: Ratio| {
+            let value = (channel.as_u8() as u32 * 255 + alpha / 2) / alpha;
+
+            Ratio::from_u8(value.min(255) as u8)
+        };
+
+        RGBA {
+            r: unpremultiply(r),
+            g: unpremultiply(g),
+            b: unpremultiply(b),
+            a,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use {rgba, PremultipliedRGBA, Ratio, RGBA};
+
+    fn premultiplied(r: u8, g: u8, b: u8, a: u8) -> PremultipliedRGBA {
+        PremultipliedRGBA {
+            r: Ratio::from_u8(r),
+            g: Ratio::from_u8(g),
+            b: Ratio::from_u8(b),
+            a: Ratio::from_u8(a),
+        }
+    }
+
+    fn color(channel: u8, alpha: u8) -> RGBA {
+        RGBA {
+            r: Ratio::from_u8(channel),
+            g: Ratio::from_u8(255 - channel),
+            b: Ratio::from_u8(channel / 2),
+            a: Ratio::from_u8(alpha),
+        }
+    }
+
+    #[test]
+    fn can_premultiply() {
+        assert_eq!(
+            rgba(255, 255, 255, 1.0).premultiply(),
+            premultiplied(255, 255, 255, 255)
+        );
+        assert_eq!(
+            rgba(250, 128, 114, 0.5).premultiply(),
+            premultiplied(125, 64, 57, 128)
+        );
+        assert_eq!(
+            rgba(255, 0, 100, 0.0).premultiply(),
+            premultiplied(0, 0, 0, 0)
+        );
+        assert_eq!(
+            PremultipliedRGBA::from_rgba(rgba(1, 2, 3, 0.5)),
+            premultiplied(1, 1, 2, 128)
+        );
+    }
+
+    #[test]
+    fn can_unpremultiply() {
+        assert_eq!(
+            premultiplied(125, 64, 57, 128).unpremultiply(),
+            rgba(249, 128, 114, 0.5)
+        );
+        assert_eq!(
+            premultiplied(10, 20, 30, 255).unpremultiply(),
+            rgba(10, 20, 30, 1.0)
+        );
+
+        // Channels that exceed the alpha aren't valid, and are clamped.
+        assert_eq!(
+            premultiplied(200, 0, 0, 100).unpremultiply().r,
+            Ratio::from_u8(255)
+        );
+    }
+
+    #[test]
+    fn unpremultiplies_transparent_colors_into_transparent_black() {
+        assert_eq!(
+            premultiplied(0, 0, 0, 0).unpremultiply(),
+            rgba(0, 0, 0, 0.0)
+        );
+        assert_eq!(
+            premultiplied(12, 34, 56, 0).unpremultiply(),
+            rgba(0, 0, 0, 0.0)
+        );
+    }
+
+    #[test]
+    fn opaque_colors_are_unchanged() {
+        for channel in 0..=255 {
+            let opaque = color(channel, 255);
+
+            assert_eq!(opaque.premultiply().unpremultiply(), opaque);
+        }
+    }
+
+    #[test]
+    fn round_trips_lose_at_most_half_a_premultiplied_step() {
+        for alpha in 1..=255u32 {
+            let tolerance = 255u32.div_ceil(2 * alpha);
+
+            for channel in (0..=255).step_by(3) {
+                let original = color(channel, alpha as u8);
+                let round_trip = original.premultiply().unpremultiply();
+
+                for &(lhs, rhs) in [
+                    (original.r, round_trip.r),
+                    (original.g, round_trip.g),
+                    (original.b, round_trip.b),
+                ]
+                .iter()
+                {
+                    let error = (lhs.as_u8() as i32 - rhs.as_u8() as i32).unsigned_abs();
+
+                    assert!(error <= tolerance, "{:?} became {:?}", original, round_trip);
+
+                    if alpha >= 85 {
+                        assert!(error <= 1, "{:?} became {:?}", original, round_trip);
+                    }
+                }
+
+                assert_eq!(round_trip.a, original.a);
+            }
+        }
+    }
+
+    #[test]
+    fn round_trips_are_stable() {
+        for alpha in 0..=255 {
+            for channel in 0..=255 {
+                let once = color(channel, alpha).premultiply();
+                let twice = once.unpremultiply().premultiply();
+
+                assert_eq!(once, twice);
+                assert!(once.r <= once.a && once.g <= once.a && once.b <= once.a);
+            }
+        }
+    }
+}
@@ -16,7 +16,8 @@ pub fn percent(percentage: u8) -> Ratio {
     Ratio::from_percentage(percentage)
 }
 
-#[derive(Debug, Copy, Clone, Ord, PartialOrd, Eq, PartialEq)]
+#[derive(Debug, Copy, Clone, Ord, PartialOrd, Eq, PartialEq, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 /// A struct that represents a ratio and determines the legal value(s) for a given type.
 /// Clamps any values that fall beyond the valid legal range for the type.
 /// Used to convert a type into a valid percentage representation.
@@ -44,6 +45,19 @@ impl Ratio {
         (self.0 as f32 / 255.0 * 100.0).round() as u8
     }
 
+    /// Returns the ratio as a percentage, without rounding to a whole percent.
+    ///
+    /// # Example
+    /// ```
+    /// use css_colors::{percent, Ratio};
+    ///
+    /// assert!((percent(50).as_percentage_f32() - 50.196).abs() < 0.001);
+    /// assert_eq!(Ratio::from_u8(255).as_percentage_f32(), 100.0);
+    /// ```
+    pub fn as_percentage_f32(self) -> f32 {
+        self.0 as f32 / 255.0 * 100.0
+    }
+
     pub fn as_u8(self) -> u8 {
         self.0
     }
@@ -53,9 +67,28 @@ impl Ratio {
     }
 }
 
+/// Formats the ratio as a whole percentage (`93%`). With the alternate flag,
+/// ratios that do not correspond to a whole percentage are formatted with one
+/// decimal place instead (`92.5%`), so that distinct ratios never format identically.
+///
+/// # Example
+/// ```
+/// use css_colors::{percent, Ratio};
+///
+/// assert_eq!(format!("{}", percent(93)), "93%");
+/// assert_eq!(format!("{:#}", percent(93)), "93%");
+/// assert_eq!(format!("{}", Ratio::from_u8(236)), "93%");
+/// assert_eq!(format!("{:#}", Ratio::from_u8(236)), "92.5%");
+/// ```
 impl fmt::Display for Ratio {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        write!(f, "{}%", self.as_percentage())
+        let percentage = self.as_percentage();
+
+        if f.alternate() && Ratio::from_percentage(percentage) != *self {
+            write!(f, "{:.1}%", self.as_percentage_f32())
+        } else {
+            write!(f, "{}%", percentage)
+        }
     }
 }
 
@@ -92,7 +125,7 @@ impl ops::Div for Ratio {
 }
 
 // A function to clamp the value of a Ratio to fall between [0.0 - 1.0].
-fn clamp_ratio(value: f32) -> Ratio {
+pub(crate) fn clamp_ratio(value: f32) -> Ratio {
     if value > 1.0 {
         Ratio::from_f32(1.0)
     } else if value >= 0.0 && value <= 1.0 {
@@ -118,6 +151,28 @@ mod tests {
         Ratio::from_f32(1.01);
     }
 
+    #[test]
+    fn can_display_precise_percentages() {
+        assert_eq!(format!("{:#}", Ratio::from_u8(0)), "0%");
+        assert_eq!(format!("{:#}", Ratio::from_u8(1)), "0.4%");
+        assert_eq!(format!("{:#}", Ratio::from_u8(128)), "50%");
+        assert_eq!(format!("{:#}", Ratio::from_u8(236)), "92.5%");
+        assert_eq!(format!("{:#}", Ratio::from_u8(237)), "93%");
+        assert_eq!(format!("{:#}", Ratio::from_u8(255)), "100%");
+    }
+
+    #[test]
+    fn precise_percentages_are_unique() {
+        let mut formatted: Vec<String> = (0..=255)
+            .map(|value| format!("{:#}", Ratio::from_u8(value)))
+            .collect();
+
+        formatted.sort();
+        formatted.dedup();
+
+        assert_eq!(formatted.len(), 256);
+    }
+
     #[test]
     fn can_clamp_percentage() {
         assert_eq!(
@@ -0,0 +1,257 @@
+use super::{Color, HSL, HSLA, RGBA};
+
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+/// An enum that describes how faithfully an HSL color survives being converted
+/// into RGB bytes.
+pub enum Representability {
+    // The saturation and lightness come back unchanged (to the nearest percent).
+    Exact,
+
+    // The saturation and/or lightness drift by up to `max_delta` percentage points.
+    Approximate { max_delta: u8 },
+
+    // The color collapses into a shade of grey, losing its hue and saturation.
+    Degenerate,
+}
+
+impl HSL {
+    /// Determines whether `self` can be represented in RGB bytes without a visible
+    /// change of saturation or lightness. See `HSLA::rgb_representable`.
+    ///
+    /// # Examples
+    /// ```
+    /// use css_colors::{hsl, Representability};
+    ///
+    /// assert_eq!(hsl(9, 100, 64).rgb_representable(), Representability::Exact);
+    /// assert_eq!(hsl(9, 100, 100).rgb_representable(), Representability::Degenerate);
+    /// ```
+    pub fn rgb_representable(self) -> Representability {
+        self.to_hsla().rgb_representable()
+    }
+}
+
+impl HSLA {
+    /// Determines whether `self` can be represented in RGB bytes without a visible
+    /// change of saturation or lightness.
+    ///
+    /// The color is converted to RGB, then converted back to HSL at full precision
+    /// (without quantizing the result), and the drift in saturation and lightness
+    /// is measured in percentage points. Colors that collapse into a shade of grey
+    /// despite having a saturation are `Degenerate`.
+    ///
+    /// # Examples
+    /// ```
+    /// use css_colors::{hsla, Representability};
+    ///
+    /// assert_eq!(hsla(9, 100, 64, 0.5).rgb_representable(), Representability::Exact);
+    /// assert_eq!(
+    ///     hsla(0, 50, 99, 0.5).rgb_representable(),
+    ///     Representability::Approximate { max_delta: 16 }
+    /// );
+    /// ```
+    pub fn rgb_representable(self) -> Representability {
+        let RGBA { r, g, b, .. } = self.to_rgba();
+
+        if r == g && g == b {
+            return if self.s.as_u8() == 0 {
+                Representability::Exact
+            } else {
+                Representability::Degenerate
+            };
+        }
+
+        let (saturation, lightness) = saturation_and_lightness(self.to_rgba());
+
+        let saturation_delta = (saturation - self.s.as_f32()).abs() * 100.0;
+        let lightness_delta = (lightness - self.l.as_f32()).abs() * 100.0;
+        let max_delta = saturation_delta.max(lightness_delta).round() as u8;
+
+        if max_delta == 0 {
+            Representability::Exact
+        } else {
+            Representability::Approximate { max_delta }
+        }
+    }
+}
+
+#[derive(Debug, Clone, Eq, PartialEq)]
+/// A struct that summarizes the `Representability` of every color in a palette.
+pub struct RepresentabilityReport {
+    // number of colors that are represented exactly
+    pub exact: usize,
+
+    // indices and drift of the colors that are represented approximately
+    pub approximate: Vec<(usize, u8)>,
+
+    // indices of the colors that collapse into a shade of grey
+    pub degenerate: Vec<usize>,
+}
+
+impl RepresentabilityReport {
+    /// Returns `true` if every color in the palette is represented exactly.
+    pub fn is_exact(&self) -> bool {
+        self.approximate.is_empty() && self.degenerate.is_empty()
+    }
+}
+
+/// Classifies every color of a palette by its `Representability` in RGB bytes.
+///
+/// # Example
+/// ```
+/// use css_colors::{hsl, representability_report};
+///
+/// let palette = [hsl(9, 100, 64), hsl(0, 50, 99), hsl(9, 100, 100)];
+/// let report = representability_report(&palette);
+///
+/// assert_eq!(report.exact, 1);
+/// assert_eq!(report.approximate, [(1, 16)]);
+/// assert_eq!(report.degenerate, [2]);
+/// ```
+pub fn representability_report<T: Color>(palette: &[T]) -> RepresentabilityReport {
+    let mut report = RepresentabilityReport {
+        exact: 0,
+        approximate: Vec::new(),
+        degenerate: Vec::new(),
+    };
+
+    for (index, color) in palette.iter().enumerate() {
+        match color.to_hsla().rgb_representable() {
+            Representability::Exact => report.exact += 1,
+            Representability::Approximate { max_delta } => {
+                report.approximate.push((index, max_delta))
+            }
+            Representability::Degenerate => report.degenerate.push(index),
+        }
+    }
+
+    report
+}
+
+// Computes the saturation and lightness of an RGB color as floats, without
+// quantizing them into a `Ratio`.
+fn saturation_and_lightness(color: RGBA) -> (f32, f32) {
+    let r = color.r.as_f32();
+    let g = color.g.as_f32();
+    let b = color.b.as_f32();
+
+    let max = r.max(g).max(b);
+    let min = r.min(g).min(b);
+    let lightness = (max + min) / 2.0;
+
+    let saturation = if max == min {
+        0.0
+    } else if lightness < 0.5 {
+        (max - min) / (max + min)
+    } else {
+        (max - min) / (2.0 - (max + min))
+    };
+
+    (saturation, lightness)
+}
+
+#[cfg(test)]
+mod tests {
+    use {hsl, hsla, representability_report, rgb, Color, Representability};
+
+    // An independent computation of the drift, using integer arithmetic on the bytes.
+    fn drift(h: i32, s: u8, l: u8) -> u8 {
+        let rgb = hsl(h, s, l).to_rgb();
+        let bytes = [
+            rgb.r.as_u8() as i32,
+            rgb.g.as_u8() as i32,
+            rgb.b.as_u8() as i32,
+        ];
+
+        let max = *bytes.iter().max().unwrap();
+        let min = *bytes.iter().min().unwrap();
+
+        let lightness = (max + min) as f64 / 510.0;
+        let saturation = if max + min <= 255 {
+            (max - min) as f64 / (max + min) as f64
+        } else {
+            (max - min) as f64 / (510 - max - min) as f64
+        };
+
+        let expected = hsl(h, s, l);
+        let saturation_delta = (saturation - expected.s.as_u8() as f64 / 255.0).abs();
+        let lightness_delta = (lightness - expected.l.as_u8() as f64 / 255.0).abs();
+
+        (saturation_delta.max(lightness_delta) * 100.0).round() as u8
+    }
+
+    #[test]
+    fn classifies_exact_colors() {
+        assert_eq!(hsl(0, 0, 0).rgb_representable(), Representability::Exact);
+        assert_eq!(hsl(0, 0, 50).rgb_representable(), Representability::Exact);
+        assert_eq!(hsl(0, 0, 100).rgb_representable(), Representability::Exact);
+        assert_eq!(hsl(9, 100, 64).rgb_representable(), Representability::Exact);
+        assert_eq!(
+            hsl(193, 68, 28).rgb_representable(),
+            Representability::Exact
+        );
+        assert_eq!(
+            hsla(100, 50, 42, 0.5).rgb_representable(),
+            Representability::Exact
+        );
+    }
+
+    #[test]
+    fn classifies_approximate_colors() {
+        for &(h, s, l) in [(300, 90, 2), (0, 25, 1), (0, 50, 99)].iter() {
+            let expected = drift(h, s, l);
+
+            assert!(expected > 0);
+            assert_eq!(
+                hsl(h, s, l).rgb_representable(),
+                Representability::Approximate {
+                    max_delta: expected
+                }
+            );
+            assert_eq!(
+                hsla(h, s, l, 0.5).rgb_representable(),
+                Representability::Approximate {
+                    max_delta: expected
+                }
+            );
+        }
+    }
+
+    #[test]
+    fn classifies_degenerate_colors() {
+        assert_eq!(
+            hsl(9, 100, 100).rgb_representable(),
+            Representability::Degenerate
+        );
+        assert_eq!(
+            hsl(120, 100, 0).rgb_representable(),
+            Representability::Degenerate
+        );
+        assert_eq!(
+            hsl(10, 2, 2).rgb_representable(),
+            Representability::Degenerate
+        );
+        assert_eq!(
+            hsla(9, 100, 100, 0.5).rgb_representable(),
+            Representability::Degenerate
+        );
+    }
+
+    #[test]
+    fn can_report_on_palettes() {
+        let palette = [
+            hsl(9, 100, 64),
+            hsl(9, 100, 100),
+            hsl(0, 50, 99),
+            hsl(0, 0, 50),
+            hsl(120, 100, 0),
+        ];
+        let report = representability_report(&palette);
+
+        assert_eq!(report.exact, 2);
+        assert_eq!(report.approximate, [(2, drift(0, 50, 99))]);
+        assert_eq!(report.degenerate, [1, 4]);
+        assert!(!report.is_exact());
+
+        assert!(representability_report(&[rgb(255, 99, 71), rgb(0, 0, 0)]).is_exact());
+    }
+}
@@ -1,4 +1,4 @@
-use super::{deg, percent, Angle, Color, Ratio, HSL, HSLA};
+use super::{deg, ops, percent, Angle, Color, PremultipliedRGBA, Ratio, HSL, HSLA};
 use std::fmt;
 
 /// Constructs a RGB Color from numerical values, similar to the
@@ -48,6 +48,7 @@ pub fn rgba(r: u8, g: u8, b: u8, a: f32) -> RGBA {
 }
 
 #[derive(Debug, Copy, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 /// A struct to represent how much red, green, and blue should be added to create a color.
 ///
 /// Valid values for r, g, and b must be a u8 between `0-255`, represented as a `Ratio`.
@@ -76,19 +77,76 @@ impl fmt::Display for RGB {
     }
 }
 
+impl RGB {
+    /// Composites translucent `layers` over `self`, from the bottom to the top,
+    /// with `Color::over`. This is how a surface is tinted by overlays, as with
+    /// elevation overlays in Material Design.
+    ///
+    /// `self` is opaque, so the result is too. See `RGBA::composite_stack` for a
+    /// translucent base.
+    ///
+    /// # Example
+    /// ```
+    /// use css_colors::{rgb, rgba};
+    ///
+    /// let surface = rgb(18, 18, 18);
+    /// let layers = [rgba(255, 255, 255, 0.08), rgba(187, 134, 252, 0.04)];
+    ///
+    /// assert_eq!(surface.composite_stack(&[]), surface);
+    /// assert_eq!(surface.composite_stack(&layers), rgb(43, 41, 45));
+    /// ```
+    pub fn composite_stack(self, layers: &[RGBA]) -> RGB {
+        self.to_rgba().composite_stack(layers).to_rgb()
+    }
+
+    /// Returns a copy of `self` with its red channel set to `red`.
+    ///
+    /// # Example
+    /// ```
+    /// use css_colors::rgb;
+    ///
+    /// assert_eq!(rgb(250, 128, 114).with_red(200), rgb(200, 128, 114));
+    /// ```
+    #[must_use]
+    pub fn with_red(self, red: u8) -> Self {
+        RGB {
+            r: Ratio::from_u8(red),
+            ..self
+        }
+    }
+
+    /// Returns a copy of `self` with its green channel set to `green`.
+    #[must_use]
+    pub fn with_green(self, green: u8) -> Self {
+        RGB {
+            g: Ratio::from_u8(green),
+            ..self
+        }
+    }
+
+    /// Returns a copy of `self` with its blue channel set to `blue`.
+    #[must_use]
+    pub fn with_blue(self, blue: u8) -> Self {
+        RGB {
+            b: Ratio::from_u8(blue),
+            ..self
+        }
+    }
+}
+
 impl Color for RGB {
     type Alpha = RGBA;
 
-    fn to_css(self) -> String {
+    fn to_css(&self) -> String {
         self.to_string()
     }
 
-    fn to_rgb(self) -> RGB {
-        self
+    fn to_rgb(&self) -> RGB {
+        *self
     }
 
-    fn to_rgba(self) -> RGBA {
-        let RGB { r, g, b } = self;
+    fn to_rgba(&self) -> RGBA {
+        let RGB { r, g, b } = *self;
 
         RGBA {
             r,
@@ -100,14 +158,18 @@ impl Color for RGB {
 
     /// The algorithm for converting from rgb to hsl format, which determines
     /// the equivalent luminosity, saturation, and hue.
-    fn to_hsl(self) -> HSL {
+    fn to_hsl(&self) -> HSL {
         self.to_rgba().to_hsl()
     }
 
-    fn to_hsla(self) -> HSLA {
+    fn to_hsla(&self) -> HSLA {
         self.to_rgba().to_hsla()
     }
 
+    fn from_rgba(rgba: RGBA) -> RGB {
+        rgba.to_rgb()
+    }
+
     fn saturate(self, amount: Ratio) -> Self {
         self.to_rgba().saturate(amount).to_rgb()
     }
@@ -158,6 +220,7 @@ impl Color for RGB {
 }
 
 #[derive(Debug, Copy, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 /// A struct to represent how much red, green, and blue should be added to create a color.
 /// Also handles alpha specifications.
 ///
@@ -181,39 +244,239 @@ pub struct RGBA {
 
 impl fmt::Display for RGBA {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        write!(
-            f,
-            "rgba({}, {}, {}, {:.02})",
-            self.r.as_u8(),
-            self.g.as_u8(),
-            self.b.as_u8(),
-            self.a.as_f32()
-        )
+        if f.alternate() {
+            write!(
+                f,
+                "rgba({}, {}, {}, {:.03})",
+                self.r.as_u8(),
+                self.g.as_u8(),
+                self.b.as_u8(),
+                self.a.as_f32()
+            )
+        } else {
+            write!(
+                f,
+                "rgba({}, {}, {}, {:.02})",
+                self.r.as_u8(),
+                self.g.as_u8(),
+                self.b.as_u8(),
+                self.a.as_f32()
+            )
+        }
     }
 }
 
+impl RGBA {
+    /// Multiplies the red, green and blue channels of `self` by its alpha (see
+    /// `PremultipliedRGBA`).
+    ///
+    /// # Example
+    /// ```
+    /// use css_colors::{rgba, Ratio};
+    ///
+    /// let premultiplied = rgba(255, 0, 100, 0.2).premultiply();
+    ///
+    /// assert_eq!(premultiplied.r, Ratio::from_u8(51));
+    /// assert_eq!(premultiplied.b, Ratio::from_u8(20));
+    /// ```
+    pub fn premultiply(self) -> PremultipliedRGBA {
+        PremultipliedRGBA::from_rgba(self)
+    }
+
+    /// Averages any number of colors in one pass, so that, unlike mixing them
+    /// pairwise, the result doesn't depend on their order. Returns `None` if
+    /// there are no colors.
+    ///
+    /// Like `mix`, the gamma-encoded channels are averaged (as Less does), which
+    /// makes the result darker than averaging the light of the colors (see
+    /// `LinearRGB`). Every channel, including alpha, is a plain mean: translucent
+    /// colors weigh as much as opaque ones.
+    ///
+    /// # Example
+    /// ```
+    /// use css_colors::{rgb, rgba, RGBA};
+    ///
+    /// let primaries = [rgb(255, 0, 0), rgb(0, 255, 0), rgb(0, 0, 255)];
+    ///
+    /// assert_eq!(RGBA::average_of(primaries.iter().copied()), Some(rgba(85, 85, 85, 1.0)));
+    /// assert_eq!(RGBA::average_of(Vec::<RGBA>::new()), None);
+    /// ```
+    pub fn average_of<I, T>(colors: I) -> Option<RGBA>
+    where
+        I: IntoIterator<Item = T>,
+        T: Color,
+    {
+        weighted_mean(colors.into_iter().map(|color| (color.to_rgba(), 1.0)))
+    }
+
+    /// Averages the colors of `pairs` in proportion to their weights, e.g. to blend
+    /// the colors of a theme according to how often they are used. The weights are
+    /// normalized, so they don't need to add up to `1.0`. Returns `None` if the
+    /// weights add up to zero, including when `pairs` is empty.
+    ///
+    /// Like `average_of`, this operates on the gamma-encoded channels. The alpha
+    /// channel is weighted exactly like the red, green and blue ones, and does not
+    /// affect their weights: unlike `mix`, a translucent color contributes as much
+    /// to the channels as an opaque one of the same weight. With two opaque colors
+    /// and weights of `w` and `1 - w`, this agrees with `mix_f32`.
+    ///
+    /// Negative weights will cause a panic.
+    ///
+    /// # Example
+    /// ```
+    /// use css_colors::{rgb, rgba, Color, RGBA};
+    ///
+    /// let navy = rgb(0, 0, 128).to_rgba();
+    /// let gold = rgb(255, 215, 0).to_rgba();
+    ///
+    /// assert_eq!(
+    ///     RGBA::blend_weighted(&[(navy, 3.0), (gold, 1.0)]),
+    ///     Some(rgba(64, 54, 96, 1.0))
+    /// );
+    /// assert_eq!(RGBA::blend_weighted(&[(navy, 3.0), (gold, 1.0)]), Some(navy.mix_f32(gold, 0.75)));
+    /// assert_eq!(RGBA::blend_weighted(&[(navy, 0.0)]), None);
+    /// ```
+    pub fn blend_weighted(pairs: &[(RGBA, f32)]) -> Option<RGBA> {
+        for &(_, weight) in pairs {
+            assert!(
+                weight >= 0.0,
+                "Invalid weight for blend, must not be negative"
+            );
+        }
+
+        weighted_mean(pairs.iter().copied())
+    }
+
+    /// Composites translucent `layers` over `self`, from the bottom to the top,
+    /// like `RGB::composite_stack`. Each layer is composited over the result of
+    /// the ones below it, so the alpha of the result only grows.
+    ///
+    /// # Example
+    /// ```
+    /// use css_colors::{rgba, Color};
+    ///
+    /// let base = rgba(0, 0, 255, 0.5);
+    /// let layers = [rgba(255, 0, 0, 0.5), rgba(0, 255, 0, 0.5)];
+    ///
+    /// assert_eq!(base.composite_stack(&layers), layers[1].over(layers[0].over(base)));
+    /// ```
+    pub fn composite_stack(self, layers: &[RGBA]) -> RGBA {
+        layers
+            .iter()
+            .fold(self, |backdrop, layer| layer.over(backdrop))
+    }
+
+    /// Composites `self` over an opaque `background` and drops the alpha channel
+    /// (see `Color::over`). This gives the color that is actually seen, as needed
+    /// to compute a contrast ratio or to use a format without transparency.
+    ///
+    /// # Example
+    /// ```
+    /// use css_colors::{rgb, rgba};
+    ///
+    /// let white = rgb(255, 255, 255);
+    ///
+    /// assert_eq!(rgba(255, 0, 0, 0.5).flatten(white), rgb(255, 127, 127));
+    /// assert_eq!(rgba(255, 0, 0, 0.0).flatten(white), white);
+    /// ```
+    pub fn flatten(self, background: RGB) -> RGB {
+        self.over(background).to_rgb()
+    }
+
+    /// Returns a copy of `self` with its red channel set to `red`.
+    #[must_use]
+    pub fn with_red(self, red: u8) -> Self {
+        RGBA {
+            r: Ratio::from_u8(red),
+            ..self
+        }
+    }
+
+    /// Returns a copy of `self` with its green channel set to `green`.
+    #[must_use]
+    pub fn with_green(self, green: u8) -> Self {
+        RGBA {
+            g: Ratio::from_u8(green),
+            ..self
+        }
+    }
+
+    /// Returns a copy of `self` with its blue channel set to `blue`.
+    #[must_use]
+    pub fn with_blue(self, blue: u8) -> Self {
+        RGBA {
+            b: Ratio::from_u8(blue),
+            ..self
+        }
+    }
+
+    /// Returns a copy of `self` with its alpha channel set to `alpha`.
+    ///
+    /// # Example
+    /// ```
+    /// use css_colors::{percent, rgba};
+    ///
+    /// assert_eq!(rgba(250, 128, 114, 1.0).with_alpha(percent(50)), rgba(250, 128, 114, 0.5));
+    /// ```
+    #[must_use]
+    pub fn with_alpha(self, alpha: Ratio) -> Self {
+        RGBA { a: alpha, ..self }
+    }
+}
+
+// Averages the channels of colors in proportion to their weights, or returns
+// `None` if the weights add up to zero.
+fn weighted_mean<I: Iterator<Item = (RGBA, f32)>>(pairs: I) -> Option<RGBA> {
+    let mut sums = [0.0; 4];
+    let mut total = 0.0;
+
+    for (RGBA { r, g, b, a }, weight) in pairs {
+        for (sum, channel) in sums.iter_mut().zip([r, g, b, a].iter()) {
+            *sum += channel.as_f32() * weight;
+        }
+        total += weight;
+    }
+
+    if total == 0.0 {
+        return None;
+    }
+
+    let mean = |sum: f32| ops::quantize(sum / total);
+
+    Some(RGBA {
+        r: mean(sums[0]),
+        g: mean(sums[1]),
+        b: mean(sums[2]),
+        a: mean(sums[3]),
+    })
+}
+
 impl Color for RGBA {
     type Alpha = Self;
 
-    fn to_css(self) -> String {
+    fn to_css(&self) -> String {
         self.to_string()
     }
 
-    fn to_rgb(self) -> RGB {
-        let RGBA { r, g, b, .. } = self;
+    fn to_rgb(&self) -> RGB {
+        let RGBA { r, g, b, .. } = *self;
         RGB { r, g, b }
     }
 
-    fn to_rgba(self) -> RGBA {
-        self
+    fn to_rgba(&self) -> RGBA {
+        *self
     }
 
-    fn to_hsl(self) -> HSL {
+    fn from_rgba(rgba: RGBA) -> RGBA {
+        rgba
+    }
+
+    fn to_hsl(&self) -> HSL {
         self.to_hsla().to_hsl()
     }
 
-    fn to_hsla(self) -> HSLA {
-        let RGBA { r, g, b, a } = self;
+    fn to_hsla(&self) -> HSLA {
+        let RGBA { r, g, b, a } = *self;
 
         // If r, g, and b are the same, the color is a shade of grey (between
         // black and white), with no hue or saturation. In that situation, there
@@ -264,6 +527,10 @@ impl Color for RGBA {
             (max - min) / (2.0 - (max + min))
         };
 
+        // Floating point error can push the saturation of fully saturated colors
+        // slightly above 1.0 (e.g. rgb(220, 27, 255)).
+        let saturation = saturation.min(1.0);
+
         // To calculate the hue, we look at which value (r, g, or b) is the max.
         // Based on that, we subtract the difference between the other two values,
         // adding 120 or 240 deg to account for the degrees on the color wheel, and
@@ -287,27 +554,31 @@ impl Color for RGBA {
     }
 
     fn saturate(self, amount: Ratio) -> Self {
-        self.to_hsla().saturate(amount).to_rgba()
+        ops::apply_to_hsl(self, |hsla| hsla.saturate(amount))
     }
 
     fn desaturate(self, amount: Ratio) -> Self {
-        self.to_hsla().desaturate(amount).to_rgba()
+        ops::apply_to_hsl(self, |hsla| hsla.desaturate(amount))
     }
 
     fn lighten(self, amount: Ratio) -> Self {
-        self.to_hsla().lighten(amount).to_rgba()
+        ops::apply_to_hsl(self, |hsla| hsla.lighten(amount))
     }
 
     fn darken(self, amount: Ratio) -> Self {
-        self.to_hsla().darken(amount).to_rgba()
+        ops::apply_to_hsl(self, |hsla| hsla.darken(amount))
     }
 
     fn fadein(self, amount: Ratio) -> Self {
-        self.fade(self.a + amount)
+        self.fade(Ratio::from_u8(
+            self.a.as_u8().saturating_add(amount.as_u8()),
+        ))
     }
 
     fn fadeout(self, amount: Ratio) -> Self {
-        self.fade(self.a - amount)
+        self.fade(Ratio::from_u8(
+            self.a.as_u8().saturating_sub(amount.as_u8()),
+        ))
     }
 
     fn fade(self, amount: Ratio) -> Self {
@@ -316,57 +587,14 @@ impl Color for RGBA {
     }
 
     fn spin(self, amount: Angle) -> Self {
-        self.to_hsla().spin(amount).to_rgba()
+        ops::apply_to_hsl(self, |hsla| hsla.spin(amount))
     }
 
-    // This algorithm takes into account both the user-provided weight (w) and
-    // the difference between the alpha values of the two colors (a) to determine
-    // the weighted average of the two colors.
-    // Taken from Sass's implementation (http://sass-lang.com/documentation/Sass/Script/Functions.html#mix-instance_method)
+    // This algorithm takes into account both the user-provided weight and the
+    // difference between the alpha values of the two colors to determine the
+    // weighted average of the two colors (see `ops::mix_weight`).
     fn mix<T: Color>(self, other: T, weight: Ratio) -> Self {
-        let RGBA {
-            r: r_lhs,
-            g: g_lhs,
-            b: b_lhs,
-            a: a_lhs,
-        } = self;
-
-        let RGBA {
-            r: r_rhs,
-            g: g_rhs,
-            b: b_rhs,
-            a: a_rhs,
-        } = other.to_rgba();
-
-        // Convert weight into a decimal, and then scale it so that it falls between a range of [-1, 1].
-        let w = (weight.as_f32() * 2.0) - 1.0;
-
-        // Find the difference between the left and right side's alphas (somewhere between [-1, 1]).
-        let a = a_lhs.as_f32() - a_rhs.as_f32();
-
-        // Find the combined rgb_weight, taking into account the user's passed-in weight and alpha (range of [-1, 1]).
-        let rgb_weight = if w * a == -1.0 {
-            w
-        } else {
-            (w + a) / (1.0 + w * a)
-        };
-
-        // Find the combined rgb weight, scaling it to fall in a range bewtween [0, 1].
-        let rgb_weight = (rgb_weight + 1.0) / 2.0;
-
-        // Convert left and right side's weights into Ratios.
-        let rgb_weight_lhs = Ratio::from_f32(rgb_weight);
-        let rgb_weight_rhs = Ratio::from_f32(1.0) - rgb_weight_lhs;
-
-        let alpha_weight_lhs = weight;
-        let alpha_weight_rhs = Ratio::from_f32(1.0) - alpha_weight_lhs;
-
-        RGBA {
-            r: (r_lhs * rgb_weight_lhs) + (r_rhs * rgb_weight_rhs),
-            g: (g_lhs * rgb_weight_lhs) + (g_rhs * rgb_weight_rhs),
-            b: (b_lhs * rgb_weight_lhs) + (b_rhs * rgb_weight_rhs),
-            a: (a_lhs * alpha_weight_lhs) + (a_rhs * alpha_weight_rhs),
-        }
+        self.mix_f32(other, weight.as_f32())
     }
 
     fn tint(self, weight: Ratio) -> Self {
@@ -378,6 +606,6 @@ impl Color for RGBA {
     }
 
     fn greyscale(self) -> Self {
-        self.to_hsla().greyscale().to_rgba()
+        ops::apply_to_hsl(self, |hsla| hsla.greyscale())
     }
 }
@@ -0,0 +1,471 @@
+use super::{deg, ops, Angle, Color, Ratio, HSL, HSLA, RGB, RGBA};
+use std::fmt;
+
+/// Constructs a RGB Color with 16 bits per channel from numerical values.
+///
+/// # Example
+/// ```
+/// use css_colors::{rgb, rgb16, Color};
+///
+/// let salmon = rgb16(64250, 32896, 29298);
+///
+/// assert_eq!(salmon.to_rgb(), rgb(250, 128, 114));
+/// ```
+pub fn rgb16(r: u16, g: u16, b: u16) -> RGB16 {
+    RGB16 { r, g, b }
+}
+
+/// Constructs a RGB Color with 16 bits per channel (including alpha) from
+/// numerical values.
+///
+/// # Example
+/// ```
+/// use css_colors::{rgba, rgba16, Color};
+///
+/// let salmon = rgba16(64250, 32896, 29298, 32896);
+///
+/// assert_eq!(salmon.to_rgba(), rgba(250, 128, 114, 0.5));
+/// ```
+pub fn rgba16(r: u16, g: u16, b: u16, a: u16) -> RGBA16 {
+    RGBA16 { r, g, b, a }
+}
+
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+/// A struct to represent how much red, green, and blue should be added to create
+/// a color, with 16 bits per channel (as in 16-bit PNGs).
+///
+/// Each channel ranges between `0-65535`. Converting from and into the 8-bit
+/// color models scales the channels by `257`, so that `255` maps to `65535`:
+/// converting an 8-bit color into 16 bits and back is lossless, and converting
+/// a 16-bit color into 8 bits rounds to the nearest value.
+///
+/// Mixing and the HSL operations are computed from the 16-bit channels, so they
+/// keep their precision. CSS has no 16-bit syntax, so `to_css` serializes the
+/// nearest 8-bit color.
+pub struct RGB16 {
+    // red
+    pub r: u16,
+
+    // green
+    pub g: u16,
+
+    // blue
+    pub b: u16,
+}
+
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+/// A struct to represent how much red, green, and blue should be added to create
+/// a color, with 16 bits per channel. Also handles alpha specifications.
+///
+/// See `RGB16` for how the channels are scaled and serialized.
+pub struct RGBA16 {
+    // red
+    pub r: u16,
+
+    // green
+    pub g: u16,
+
+    // blue
+    pub b: u16,
+
+    // alpha
+    pub a: u16,
+}
+
+impl fmt::Display for RGB16 {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        fmt::Display::fmt(&self.to_rgb(), f)
+    }
+}
+
+impl fmt::Display for RGBA16 {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        fmt::Display::fmt(&self.to_rgba(), f)
+    }
+}
+
+impl Color for RGB16 {
+    type Alpha = RGBA16;
+
+    fn to_css(&self) -> String {
+        self.to_string()
+    }
+
+    fn to_rgb(&self) -> RGB {
+        self.to_rgba16().to_rgb()
+    }
+
+    fn to_rgba(&self) -> RGBA {
+        self.to_rgba16().to_rgba()
+    }
+
+    fn to_rgba16(&self) -> RGBA16 {
+        let RGB16 { r, g, b } = *self;
+
+        RGBA16 {
+            r,
+            g,
+            b,
+            a: u16::MAX,
+        }
+    }
+
+    fn to_hsl(&self) -> HSL {
+        self.to_rgba16().to_hsl()
+    }
+
+    fn to_hsla(&self) -> HSLA {
+        self.to_rgba16().to_hsla()
+    }
+
+    fn from_rgba(rgba: RGBA) -> RGB16 {
+        let RGBA16 { r, g, b, .. } = RGBA16::from_rgba(rgba);
+
+        RGB16 { r, g, b }
+    }
+
+    fn saturate(self, amount: Ratio) -> Self {
+        self.to_rgba16().saturate(amount).to_rgb16()
+    }
+
+    fn desaturate(self, amount: Ratio) -> Self {
+        self.to_rgba16().desaturate(amount).to_rgb16()
+    }
+
+    fn lighten(self, amount: Ratio) -> Self {
+        self.to_rgba16().lighten(amount).to_rgb16()
+    }
+
+    fn darken(self, amount: Ratio) -> Self {
+        self.to_rgba16().darken(amount).to_rgb16()
+    }
+
+    fn fadein(self, amount: Ratio) -> RGBA16 {
+        self.to_rgba16().fadein(amount)
+    }
+
+    fn fadeout(self, amount: Ratio) -> RGBA16 {
+        self.to_rgba16().fadeout(amount)
+    }
+
+    fn fade(self, amount: Ratio) -> RGBA16 {
+        self.to_rgba16().fade(amount)
+    }
+
+    fn spin(self, amount: Angle) -> Self {
+        self.to_rgba16().spin(amount).to_rgb16()
+    }
+
+    fn mix<T: Color>(self, other: T, weight: Ratio) -> RGBA16 {
+        self.to_rgba16().mix(other, weight)
+    }
+
+    fn tint(self, weight: Ratio) -> Self {
+        self.to_rgba16().tint(weight).to_rgb16()
+    }
+
+    fn shade(self, weight: Ratio) -> Self {
+        self.to_rgba16().shade(weight).to_rgb16()
+    }
+
+    fn greyscale(self) -> Self {
+        self.to_rgba16().greyscale().to_rgb16()
+    }
+}
+
+impl RGBA16 {
+    fn to_rgb16(self) -> RGB16 {
+        let RGBA16 { r, g, b, .. } = self;
+
+        RGB16 { r, g, b }
+    }
+
+    // Applies `f` to the hue (in degrees), saturation and lightness of `self`,
+    // as floats, so that the channels don't lose precision to the `HSLA` model.
+    fn apply_to_hsl<F: FnOnce(f32, f32, f32) -> (f32, f32, f32)>(self, f: F) -> RGBA16 {
+        let (h, s, l) = ops::rgb_to_hsl(widen(self.r), widen(self.g), widen(self.b));
+        let (r, g, b) = ops::hsl_to_rgb(f(h, s, l));
+
+        RGBA16 {
+            r: narrow(r),
+            g: narrow(g),
+            b: narrow(b),
+            a: self.a,
+        }
+    }
+}
+
+impl Color for RGBA16 {
+    type Alpha = Self;
+
+    fn to_css(&self) -> String {
+        self.to_string()
+    }
+
+    fn to_rgb(&self) -> RGB {
+        self.to_rgba().to_rgb()
+    }
+
+    fn to_rgba(&self) -> RGBA {
+        let RGBA16 { r, g, b, a } = *self;
+
+        RGBA {
+            r: to_ratio(r),
+            g: to_ratio(g),
+            b: to_ratio(b),
+            a: to_ratio(a),
+        }
+    }
+
+    fn to_rgba16(&self) -> RGBA16 {
+        *self
+    }
+
+    fn to_hsl(&self) -> HSL {
+        self.to_hsla().to_hsl()
+    }
+
+    fn to_hsla(&self) -> HSLA {
+        let (h, s, l) = ops::rgb_to_hsl(widen(self.r), widen(self.g), widen(self.b));
+
+        HSLA {
+            h: deg(h.round() as i32),
+            s: ops::quantize(s),
+            l: ops::quantize(l),
+            a: to_ratio(self.a),
+        }
+    }
+
+    fn from_rgba(rgba: RGBA) -> RGBA16 {
+        let RGBA { r, g, b, a } = rgba;
+
+        RGBA16 {
+            r: from_ratio(r),
+            g: from_ratio(g),
+            b: from_ratio(b),
+            a: from_ratio(a),
+        }
+    }
+
+    fn saturate(self, amount: Ratio) -> Self {
+        self.apply_to_hsl(|h, s, l| (h, (s + amount.as_f32()).min(1.0), l))
+    }
+
+    fn desaturate(self, amount: Ratio) -> Self {
+        self.apply_to_hsl(|h, s, l| (h, (s - amount.as_f32()).max(0.0), l))
+    }
+
+    fn lighten(self, amount: Ratio) -> Self {
+        self.apply_to_hsl(|h, s, l| (h, s, (l + amount.as_f32()).min(1.0)))
+    }
+
+    fn darken(self, amount: Ratio) -> Self {
+        self.apply_to_hsl(|h, s, l| (h, s, (l - amount.as_f32()).max(0.0)))
+    }
+
+    fn fadein(self, amount: Ratio) -> Self {
+        RGBA16 {
+            a: self.a.saturating_add(from_ratio(amount)),
+            ..self
+        }
+    }
+
+    fn fadeout(self, amount: Ratio) -> Self {
+        RGBA16 {
+            a: self.a.saturating_sub(from_ratio(amount)),
+            ..self
+        }
+    }
+
+    fn fade(self, amount: Ratio) -> Self {
+        RGBA16 {
+            a: from_ratio(amount),
+            ..self
+        }
+    }
+
+    fn spin(self, amount: Angle) -> Self {
+        self.apply_to_hsl(|h, s, l| ((h + amount.degrees() as f32) % 360.0, s, l))
+    }
+
+    // Weighs the colors like `RGBA::mix` (see `ops::mix_weight`), without
+    // quantizing the weights or the channels to 8 bits.
+    fn mix<T: Color>(self, other: T, weight: Ratio) -> Self {
+        self.mix_f32(other, weight.as_f32())
+    }
+
+    fn mix_f32<T: Color>(self, other: T, weight: f32) -> Self {
+        let weight = ops::clamp_weight(weight);
+        let lhs = self;
+        let rhs = other.to_rgba16();
+
+        let rgb_weight = ops::mix_weight_f32(weight, widen(lhs.a), widen(rhs.a));
+
+        let blend = |lhs: u16, rhs: u16, weight: f32| {
+            narrow(widen(lhs) * weight + widen(rhs) * (1.0 - weight))
+        };
+
+        RGBA16 {
+            r: blend(lhs.r, rhs.r, rgb_weight),
+            g: blend(lhs.g, rhs.g, rgb_weight),
+            b: blend(lhs.b, rhs.b, rgb_weight),
+            a: blend(lhs.a, rhs.a, weight),
+        }
+    }
+
+    fn tint(self, weight: Ratio) -> Self {
+        self.mix(rgb16(u16::MAX, u16::MAX, u16::MAX), weight)
+    }
+
+    fn shade(self, weight: Ratio) -> Self {
+        self.mix(rgb16(0, 0, 0), weight)
+    }
+
+    fn greyscale(self) -> Self {
+        self.apply_to_hsl(|h, _, l| (h, 0.0, l))
+    }
+}
+
+// Scales a `Ratio` into 16 bits, so that `255` maps to `65535`.
+fn from_ratio(ratio: Ratio) -> u16 {
+    ratio.as_u8() as u16 * 257
+}
+
+// Scales a 16-bit channel into a `Ratio`, rounding to the nearest value.
+fn to_ratio(channel: u16) -> Ratio {
+    Ratio::from_u8((channel as f32 / 257.0).round() as u8)
+}
+
+fn widen(channel: u16) -> f32 {
+    channel as f32 / u16::MAX as f32
+}
+
+fn narrow(channel: f32) -> u16 {
+    (channel.clamp(0.0, 1.0) * u16::MAX as f32).round() as u16
+}
+
+#[cfg(test)]
+mod tests {
+    use {percent, rgb, rgb16, rgba, rgba16, Color, RGB16, RGBA16};
+
+    #[test]
+    fn can_convert_between_8_and_16_bits_losslessly() {
+        for value in 0..=255u8 {
+            let color = rgba(value, 255 - value, value / 2, value as f32 / 255.0);
+            let wide = RGBA16::from_rgba(color);
+
+            assert_eq!(wide.r, value as u16 * 257);
+            assert_eq!(wide.to_rgba(), color);
+            assert_eq!(RGB16::from_rgba(color).to_rgb(), color.to_rgb());
+        }
+
+        assert_eq!(
+            rgb(255, 255, 255).to_rgba16(),
+            rgba16(65535, 65535, 65535, 65535)
+        );
+        assert_eq!(rgb(0, 0, 0).to_rgba16(), rgba16(0, 0, 0, 65535));
+    }
+
+    #[test]
+    fn rounds_to_the_nearest_8_bit_value() {
+        // Truncating (or shifting by 8 bits) would give 127 and 129.
+        assert_eq!(rgb16(32895, 32895, 32895).to_rgb(), rgb(128, 128, 128));
+        assert_eq!(rgb16(33024, 33024, 33024).to_rgb(), rgb(128, 128, 128));
+        assert_eq!(rgb16(33025, 33025, 33025).to_rgb(), rgb(129, 129, 129));
+
+        assert_eq!(rgb16(128, 129, 65535).to_rgb(), rgb(0, 1, 255));
+        assert_eq!(rgba16(0, 0, 0, 32896).to_rgba(), rgba(0, 0, 0, 0.5));
+    }
+
+    #[test]
+    fn can_be_serialized_through_8_bits() {
+        assert_eq!(rgb16(64250, 32896, 29298).to_css(), "rgb(250, 128, 114)");
+        assert_eq!(
+            rgba16(64250, 32896, 29298, 32896).to_css(),
+            "rgba(250, 128, 114, 0.50)"
+        );
+        assert_eq!(
+            format!("{:#}", rgba16(0, 0, 0, 32896)),
+            "rgba(0, 0, 0, 0.502)"
+        );
+    }
+
+    #[test]
+    fn can_mix_without_losing_precision() {
+        let dark = rgb16(100, 200, 300);
+        let light = rgb16(300, 400, 500);
+
+        // Both colors are `rgb(0, 1, 1)` in 8 bits.
+        assert_eq!(dark.mix(light, percent(50)), rgba16(200, 300, 400, 65535));
+        assert_eq!(dark.mix(light, percent(100)), dark.to_rgba16());
+        assert_eq!(dark.mix(light, percent(0)), light.to_rgba16());
+
+        assert_eq!(
+            rgba16(65535, 0, 0, 65535).mix(rgba16(0, 0, 65535, 0), percent(50)),
+            rgba16(65535, 0, 0, 32896)
+        );
+
+        // Mixing with an 8-bit color scales it into 16 bits first.
+        assert_eq!(
+            rgb16(0, 0, 0).mix(rgb(255, 255, 255), percent(50)),
+            rgba16(32639, 32639, 32639, 65535)
+        );
+        assert_eq!(
+            rgb16(1000, 2000, 3000).tint(percent(100)),
+            rgb16(1000, 2000, 3000)
+        );
+        assert_eq!(rgb16(1000, 2000, 3000).shade(percent(0)), rgb16(0, 0, 0));
+    }
+
+    #[test]
+    fn can_convert_to_hsl() {
+        assert_eq!(
+            rgb16(64250, 32896, 29298).to_hsl(),
+            rgb(250, 128, 114).to_hsl()
+        );
+        assert_eq!(
+            rgba16(65535, 0, 0, 32896).to_hsla().to_string(),
+            "hsla(0, 100%, 50%, 0.50)"
+        );
+
+        // Rounding this color to 8 bits first would saturate it fully.
+        let dark = rgb16(300, 100, 100);
+
+        assert_eq!(dark.to_rgb(), rgb(1, 0, 0));
+        assert_eq!(dark.to_rgb().to_hsl().s, percent(100));
+        assert_eq!(dark.to_hsl().s.as_percentage(), 50);
+    }
+
+    #[test]
+    fn can_apply_hsl_operations_without_losing_precision() {
+        let color = rgb16(30000, 20000, 10000);
+
+        assert_eq!(color.lighten(percent(0)), color);
+        assert_eq!(color.saturate(percent(0)), color);
+        assert_eq!(color.spin(::deg(0)), color);
+        assert_eq!(color.spin(::deg(120)), rgb16(10000, 30000, 20000));
+        assert_eq!(color.spin(::deg(-120)), rgb16(20000, 10000, 30000));
+        assert_eq!(color.greyscale(), rgb16(20000, 20000, 20000));
+        assert_eq!(color.desaturate(percent(100)), rgb16(20000, 20000, 20000));
+        assert_eq!(color.lighten(percent(100)), rgb16(65535, 65535, 65535));
+        assert_eq!(color.darken(percent(100)), rgb16(0, 0, 0));
+
+        assert_eq!(
+            rgba16(30000, 20000, 10000, 1234).greyscale(),
+            rgba16(20000, 20000, 20000, 1234)
+        );
+    }
+
+    #[test]
+    fn can_fade() {
+        let color = rgba16(30000, 20000, 10000, 1234);
+
+        assert_eq!(color.fade(percent(50)).a, 32896);
+        assert_eq!(color.fadein(percent(100)).a, 65535);
+        assert_eq!(color.fadein(percent(10)).a, 1234 + 26 * 257);
+        assert_eq!(color.fadeout(percent(10)).a, 0);
+        assert_eq!(rgb16(1, 2, 3).fade(percent(0)), rgba16(1, 2, 3, 0));
+    }
+}
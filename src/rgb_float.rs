@@ -0,0 +1,383 @@
+use super::{deg, ops, rgb, Angle, Color, Ratio, HSL, HSLA, RGB, RGBA};
+use std::fmt;
+
+/// Constructs a RGB Color with floating point channels from numerical values.
+///
+/// The channels (including alpha) nominally range between `0.0-1.0`, but values
+/// outside of that range are kept as they are until the color is quantized.
+///
+/// # Example
+/// ```
+/// use css_colors::{rgb_float, rgba, Color};
+///
+/// let salmon = rgb_float(0.98, 0.5, 0.447, 0.5);
+///
+/// assert_eq!(salmon.to_rgba(), rgba(250, 128, 114, 0.5));
+/// ```
+pub fn rgb_float(r: f32, g: f32, b: f32, a: f32) -> RGBFloat {
+    RGBFloat { r, g, b, a }
+}
+
+#[derive(Debug, Copy, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+/// A struct to represent how much red, green, and blue should be added to create
+/// a color, with a floating point number per channel. Also handles alpha specifications.
+///
+/// The other color models store their channels as bytes (see `Ratio`), so each
+/// operation rounds its result, and a chain of operations accumulates those
+/// rounding errors. `RGBFloat` implements the same operations on unclamped
+/// floats, and only quantizes its channels when converted into another color
+/// model (see `quantize`), or serialized.
+///
+/// # Example
+/// ```
+/// use css_colors::{percent, rgba, Color, RGBFloat};
+///
+/// let cornflower_blue = rgba(100, 149, 237, 1.0);
+///
+/// let bytes = cornflower_blue.lighten(percent(10)).darken(percent(10));
+/// let floats = RGBFloat::from(cornflower_blue).lighten(percent(10)).darken(percent(10));
+///
+/// assert_eq!(bytes, rgba(100, 148, 238, 1.0));
+/// assert_eq!(floats.quantize(), cornflower_blue);
+/// ```
+pub struct RGBFloat {
+    // red
+    pub r: f32,
+
+    // green
+    pub g: f32,
+
+    // blue
+    pub b: f32,
+
+    // alpha
+    pub a: f32,
+}
+
+impl From<RGBA> for RGBFloat {
+    fn from(rgba: RGBA) -> RGBFloat {
+        let RGBA { r, g, b, a } = rgba;
+
+        RGBFloat {
+            r: r.as_f32(),
+            g: g.as_f32(),
+            b: b.as_f32(),
+            a: a.as_f32(),
+        }
+    }
+}
+
+impl fmt::Display for RGBFloat {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        fmt::Display::fmt(&self.quantize(), f)
+    }
+}
+
+impl RGBFloat {
+    /// Rounds the channels of `self` into an `RGBA` color, clamping them into
+    /// the `0.0-1.0` range.
+    ///
+    /// # Example
+    /// ```
+    /// use css_colors::{rgb_float, rgba};
+    ///
+    /// assert_eq!(rgb_float(0.5, 1.2, -0.1, 1.0).quantize(), rgba(128, 255, 0, 1.0));
+    /// ```
+    pub fn quantize(self) -> RGBA {
+        RGBA {
+            r: ops::quantize(self.r),
+            g: ops::quantize(self.g),
+            b: ops::quantize(self.b),
+            a: ops::quantize(self.a),
+        }
+    }
+
+    // Applies `f` to the hue (in degrees), saturation and lightness of `self`.
+    fn apply_to_hsl<F: FnOnce(f32, f32, f32) -> (f32, f32, f32)>(self, f: F) -> RGBFloat {
+        let (h, s, l) = ops::rgb_to_hsl(self.r, self.g, self.b);
+        let (r, g, b) = ops::hsl_to_rgb(f(h, s, l));
+
+        RGBFloat { r, g, b, a: self.a }
+    }
+}
+
+impl Color for RGBFloat {
+    type Alpha = Self;
+
+    fn to_css(&self) -> String {
+        self.to_string()
+    }
+
+    fn to_rgb(&self) -> RGB {
+        self.quantize().to_rgb()
+    }
+
+    fn to_rgba(&self) -> RGBA {
+        self.quantize()
+    }
+
+    fn to_rgb_float(&self) -> RGBFloat {
+        *self
+    }
+
+    fn to_hsl(&self) -> HSL {
+        self.to_hsla().to_hsl()
+    }
+
+    fn to_hsla(&self) -> HSLA {
+        let (h, s, l) = ops::rgb_to_hsl(self.r, self.g, self.b);
+
+        HSLA {
+            h: deg(h.round() as i32),
+            s: ops::quantize(s),
+            l: ops::quantize(l),
+            a: ops::quantize(self.a),
+        }
+    }
+
+    fn from_rgba(rgba: RGBA) -> RGBFloat {
+        RGBFloat::from(rgba)
+    }
+
+    fn saturate(self, amount: Ratio) -> Self {
+        self.apply_to_hsl(|h, s, l| (h, (s + amount.as_f32()).min(1.0), l))
+    }
+
+    fn desaturate(self, amount: Ratio) -> Self {
+        self.apply_to_hsl(|h, s, l| (h, (s - amount.as_f32()).max(0.0), l))
+    }
+
+    fn lighten(self, amount: Ratio) -> Self {
+        self.apply_to_hsl(|h, s, l| (h, s, (l + amount.as_f32()).min(1.0)))
+    }
+
+    fn darken(self, amount: Ratio) -> Self {
+        self.apply_to_hsl(|h, s, l| (h, s, (l - amount.as_f32()).max(0.0)))
+    }
+
+    fn fadein(self, amount: Ratio) -> Self {
+        RGBFloat {
+            a: (self.a + amount.as_f32()).min(1.0),
+            ..self
+        }
+    }
+
+    fn fadeout(self, amount: Ratio) -> Self {
+        RGBFloat {
+            a: (self.a - amount.as_f32()).max(0.0),
+            ..self
+        }
+    }
+
+    fn fade(self, amount: Ratio) -> Self {
+        RGBFloat {
+            a: amount.as_f32(),
+            ..self
+        }
+    }
+
+    fn spin(self, amount: Angle) -> Self {
+        self.apply_to_hsl(|h, s, l| ((h + amount.degrees() as f32) % 360.0, s, l))
+    }
+
+    fn spin_f32(self, degrees: f32) -> Self {
+        self.apply_to_hsl(|h, s, l| ((h + degrees).rem_euclid(360.0), s, l))
+    }
+
+    // Weighs the colors like `RGBA::mix` (see `ops::mix_weight`), without
+    // quantizing the weights or the channels of either color.
+    fn mix<T: Color>(self, other: T, weight: Ratio) -> Self {
+        self.mix_f32(other, weight.as_f32())
+    }
+
+    fn mix_f32<T: Color>(self, other: T, weight: f32) -> Self {
+        let weight = ops::clamp_weight(weight);
+        let lhs = self;
+        let rhs = other.to_rgb_float();
+
+        let rgb_weight = ops::mix_weight_f32(weight, lhs.a, rhs.a);
+
+        let blend = |lhs: f32, rhs: f32, weight: f32| lhs * weight + rhs * (1.0 - weight);
+
+        RGBFloat {
+            r: blend(lhs.r, rhs.r, rgb_weight),
+            g: blend(lhs.g, rhs.g, rgb_weight),
+            b: blend(lhs.b, rhs.b, rgb_weight),
+            a: blend(lhs.a, rhs.a, weight),
+        }
+    }
+
+    fn tint(self, weight: Ratio) -> Self {
+        self.mix(rgb(255, 255, 255), weight)
+    }
+
+    fn shade(self, weight: Ratio) -> Self {
+        self.mix(rgb(0, 0, 0), weight)
+    }
+
+    fn greyscale(self) -> Self {
+        self.apply_to_hsl(|h, _, l| (h, 0.0, l))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use {deg, percent, rgb, rgb_float, rgba, Color, RGBFloat, RGBA};
+
+    // The largest difference between the channels of `lhs` and `rhs`, in bytes.
+    fn max_delta(lhs: RGBFloat, rhs: RGBFloat) -> f32 {
+        [lhs.r - rhs.r, lhs.g - rhs.g, lhs.b - rhs.b, lhs.a - rhs.a]
+            .iter()
+            .map(|delta| (delta * 255.0).abs())
+            .fold(0.0, f32::max)
+    }
+
+    #[test]
+    fn can_convert_from_rgba() {
+        let salmon = rgba(250, 128, 114, 0.5);
+
+        assert_eq!(RGBFloat::from(salmon).quantize(), salmon);
+        assert_eq!(RGBFloat::from_rgba(salmon), RGBFloat::from(salmon));
+        assert_eq!(RGBFloat::from(salmon).to_rgb(), rgb(250, 128, 114));
+        assert_eq!(
+            RGBFloat::from(rgba(255, 0, 0, 1.0)),
+            rgb_float(1.0, 0.0, 0.0, 1.0)
+        );
+    }
+
+    #[test]
+    fn quantizes_only_when_converted() {
+        let color = rgb_float(1.5, -0.25, 0.501, 2.0);
+
+        // The channels are kept out of range...
+        assert_eq!(color.r, 1.5);
+        assert_eq!(color.g, -0.25);
+
+        // ...until the color is converted or serialized.
+        assert_eq!(color.quantize(), rgba(255, 0, 128, 1.0));
+        assert_eq!(color.to_rgba(), rgba(255, 0, 128, 1.0));
+        assert_eq!(color.to_css(), "rgba(255, 0, 128, 1.00)");
+        assert_eq!(
+            rgb_float(0.2, 0.4, 0.6, 1.0).to_hsl().to_string(),
+            "hsl(210, 50%, 40%)"
+        );
+    }
+
+    #[test]
+    fn implements_the_color_operations() {
+        let salmon = RGBFloat::from(rgba(250, 128, 114, 1.0));
+        let close = |lhs: RGBFloat, rhs: RGBA| max_delta(lhs, RGBFloat::from(rhs)) < 1.0;
+
+        assert!(close(
+            salmon.saturate(percent(10)),
+            rgba(250, 128, 114, 1.0).saturate(percent(10))
+        ));
+        assert!(close(
+            salmon.desaturate(percent(10)),
+            rgba(250, 128, 114, 1.0).desaturate(percent(10))
+        ));
+        assert!(close(
+            salmon.lighten(percent(10)),
+            rgba(250, 128, 114, 1.0).lighten(percent(10))
+        ));
+        assert!(close(
+            salmon.darken(percent(10)),
+            rgba(250, 128, 114, 1.0).darken(percent(10))
+        ));
+        assert!(close(
+            salmon.spin(deg(90)),
+            rgba(250, 128, 114, 1.0).spin(deg(90))
+        ));
+        assert!(close(
+            salmon.greyscale(),
+            rgba(250, 128, 114, 1.0).greyscale()
+        ));
+        assert!(close(
+            salmon.tint(percent(30)),
+            rgba(250, 128, 114, 1.0).tint(percent(30))
+        ));
+        assert!(close(
+            salmon.shade(percent(30)),
+            rgba(250, 128, 114, 1.0).shade(percent(30))
+        ));
+        assert!(close(
+            salmon.mix(rgba(0, 0, 128, 0.5), percent(25)),
+            rgba(250, 128, 114, 1.0).mix(rgba(0, 0, 128, 0.5), percent(25))
+        ));
+
+        assert_eq!(salmon.fade(percent(50)).a, percent(50).as_f32());
+        assert_eq!(salmon.fadeout(percent(100)).a, 0.0);
+        assert_eq!(salmon.fadeout(percent(50)).fadein(percent(100)).a, 1.0);
+    }
+
+    #[test]
+    fn does_not_accumulate_rounding_errors() {
+        let original = rgba(200, 90, 60, 1.0);
+
+        // Every operation is undone by another one, so the analytical result is
+        // the original color.
+        fn chain<T: Color<Alpha = T> + Copy>(color: T) -> T {
+            let color = color
+                .lighten(percent(10))
+                .spin(deg(40))
+                .desaturate(percent(15))
+                .fadeout(percent(20));
+
+            // Mixing a color with itself leaves it as it is.
+            color
+                .mix(color, percent(30))
+                .darken(percent(5))
+                .spin(deg(-40))
+                .saturate(percent(15))
+                .darken(percent(5))
+                .fadein(percent(20))
+                .spin(deg(0))
+        }
+
+        let floats = chain(RGBFloat::from(original));
+        let bytes = chain(original);
+
+        assert!(max_delta(floats, RGBFloat::from(original)) < 1.0);
+        assert!(max_delta(RGBFloat::from(bytes), RGBFloat::from(original)) >= 1.0);
+        assert_eq!(floats.quantize(), original);
+
+        // Neither color is quantized when mixing two floating point colors.
+        let between_bytes = rgb_float(0.3, 0.3, 0.3, 1.0);
+        let black = rgb_float(0.0, 0.0, 0.0, 1.0);
+
+        assert!(max_delta(floats.mix_f32(floats, 0.3), floats) < 0.001);
+        assert!(max_delta(black.mix_f32(between_bytes, 0.0), between_bytes) < 0.001);
+        assert!(
+            max_delta(
+                black.mix_f32(between_bytes, 0.5),
+                rgb_float(0.15, 0.15, 0.15, 1.0)
+            ) < 0.001
+        );
+    }
+
+    #[test]
+    fn can_spin_by_fractional_degrees() {
+        let salmon = RGBFloat::from(rgba(250, 128, 114, 1.0));
+        let mut color = salmon;
+
+        for _ in 0..720 {
+            color = color.spin_f32(0.5);
+        }
+
+        assert!(max_delta(color, salmon) < 0.01);
+        assert_eq!(color.quantize(), salmon.quantize());
+
+        // Rotations accumulate, in either direction.
+        let mut forward = salmon;
+        let mut backward = salmon;
+
+        for _ in 0..90 {
+            forward = forward.spin_f32(0.5);
+            backward = backward.spin_f32(-0.5);
+        }
+
+        assert!(max_delta(forward, salmon.spin(deg(45))) < 0.01);
+        assert!(max_delta(backward, salmon.spin(deg(-45))) < 0.01);
+    }
+}
@@ -0,0 +1,132 @@
+use super::{ops, rgb, RGB};
+
+// The range of visible wavelengths (in nanometers) that `RGB::from_wavelength` maps.
+const MIN_WAVELENGTH: f32 = 380.0;
+const MAX_WAVELENGTH: f32 = 780.0;
+
+// The exponent applied to every channel after the intensity falloff.
+const GAMMA: f32 = 0.8;
+
+// Scales the channels down towards the ends of the visible spectrum, where the eye
+// is less sensitive: from 30% at 380nm up to 100% at 420nm, and from 100% at 700nm
+// down to 30% at 780nm.
+fn intensity(wavelength: f32) -> f32 {
+    if wavelength < 420.0 {
+        0.3 + 0.7 * (wavelength - MIN_WAVELENGTH) / (420.0 - MIN_WAVELENGTH)
+    } else if wavelength > 700.0 {
+        0.3 + 0.7 * (MAX_WAVELENGTH - wavelength) / (MAX_WAVELENGTH - 700.0)
+    } else {
+        1.0
+    }
+}
+
+impl RGB {
+    /// Approximates the color of monochromatic light at the given wavelength, in
+    /// nanometers. Wavelengths outside of the visible spectrum (380-780nm) are black.
+    ///
+    /// This uses [Dan Bruton's approximation](http://www.physics.sfasu.edu/astro/color/spectra.html),
+    /// which interpolates linearly between violet, blue, cyan, green, yellow and red,
+    /// then applies two adjustments to every channel:
+    ///
+    /// - an intensity falloff towards the ends of the spectrum, from 30% at
+    ///   380nm to 100% at 420nm, and from 100% at 700nm to 30% at 780nm;
+    /// - a gamma of `0.8`.
+    ///
+    /// The result is only meant for visualizations: most of the spectrum lies
+    /// outside of the sRGB gamut.
+    ///
+    /// # Example
+    /// ```
+    /// use css_colors::{rgb, RGB};
+    ///
+    /// assert_eq!(RGB::from_wavelength(580.0), rgb(255, 255, 0));
+    /// assert_eq!(RGB::from_wavelength(900.0), rgb(0, 0, 0));
+    /// ```
+    pub fn from_wavelength(wavelength: f32) -> RGB {
+        if !(MIN_WAVELENGTH..=MAX_WAVELENGTH).contains(&wavelength) {
+            return rgb(0, 0, 0);
+        }
+
+        let (r, g, b) = if wavelength < 440.0 {
+            ((440.0 - wavelength) / (440.0 - 380.0), 0.0, 1.0)
+        } else if wavelength < 490.0 {
+            (0.0, (wavelength - 440.0) / (490.0 - 440.0), 1.0)
+        } else if wavelength < 510.0 {
+            (0.0, 1.0, (510.0 - wavelength) / (510.0 - 490.0))
+        } else if wavelength < 580.0 {
+            ((wavelength - 510.0) / (580.0 - 510.0), 1.0, 0.0)
+        } else if wavelength < 645.0 {
+            (1.0, (645.0 - wavelength) / (645.0 - 580.0), 0.0)
+        } else {
+            (1.0, 0.0, 0.0)
+        };
+
+        let intensity = intensity(wavelength);
+        let adjust = |channel: f32| ops::quantize((channel * intensity).powf(GAMMA));
+
+        RGB {
+            r: adjust(r),
+            g: adjust(g),
+            b: adjust(b),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::intensity;
+    use {rgb, RGB};
+
+    #[test]
+    fn can_convert_wavelengths_to_rgb() {
+        let wavelengths = [
+            // blue, with a little green
+            (470.0, rgb(0, 169, 255)),
+            // green, with a little red
+            (520.0, rgb(54, 255, 0)),
+            // yellow
+            (580.0, rgb(255, 255, 0)),
+            // red
+            (650.0, rgb(255, 0, 0)),
+        ];
+
+        for &(wavelength, expected) in wavelengths.iter() {
+            assert_eq!(
+                RGB::from_wavelength(wavelength),
+                expected,
+                "{}nm",
+                wavelength
+            );
+        }
+    }
+
+    #[test]
+    fn falls_off_towards_the_ends_of_the_spectrum() {
+        assert_eq!(intensity(380.0), 0.3);
+        assert_eq!(intensity(400.0), 0.65);
+        assert_eq!(intensity(420.0), 1.0);
+        assert_eq!(intensity(550.0), 1.0);
+        assert_eq!(intensity(700.0), 1.0);
+        assert_eq!(intensity(740.0), 0.65);
+        assert_eq!(intensity(780.0), 0.3);
+
+        // 0.3 ^ 0.8 ≈ 0.38
+        assert_eq!(RGB::from_wavelength(380.0), rgb(97, 0, 97));
+        assert_eq!(RGB::from_wavelength(780.0), rgb(97, 0, 0));
+        assert_eq!(RGB::from_wavelength(420.0), rgb(106, 0, 255));
+    }
+
+    #[test]
+    fn applies_gamma_to_the_channels() {
+        // Halfway between blue and cyan, 0.5 ^ 0.8 ≈ 0.57
+        assert_eq!(RGB::from_wavelength(465.0), rgb(0, 146, 255));
+    }
+
+    #[test]
+    fn invisible_wavelengths_are_black() {
+        assert_eq!(RGB::from_wavelength(379.9), rgb(0, 0, 0));
+        assert_eq!(RGB::from_wavelength(780.1), rgb(0, 0, 0));
+        assert_eq!(RGB::from_wavelength(-1.0), rgb(0, 0, 0));
+        assert_eq!(RGB::from_wavelength(1_000.0), rgb(0, 0, 0));
+    }
+}
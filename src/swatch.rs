@@ -0,0 +1,297 @@
+use super::{Color, Lab, RGB};
+
+// The largest value of the CIEDE2000 lightness weighting function, rounded up
+// to absorb floating point error. It bounds the difference of two colors from
+// below by their difference of lightness alone:
+//
+// CIEDE2000 is `sqrt((ΔL / S_L)² + q)`, where `q = x² + y² + R_T·x·y` gathers the
+// weighted differences of chroma (`x = ΔC / S_C`) and hue (`y = ΔH / S_H`). The
+// rotation term is `R_T = -sin(2Δθ)·R_C`, with `R_C = 2·sqrt(C⁷ / (C⁷ + 25⁷))`,
+// so `|R_T| < 2`, and then `q ≥ (1 - |R_T| / 2)·(x² + y²) ≥ 0`. Hence
+// `ΔE ≥ |ΔL| / S_L`.
+//
+// `S_L = 1 + 0.015·(L - 50)² / sqrt(20 + (L - 50)²)` grows with the distance of
+// the mean lightness `L` from 50, and the lightness of sRGB colors is within
+// `0-100`, so `S_L ≤ 1 + 0.015·2500 / sqrt(2520) ≈ 1.7470`. Hence
+// `ΔE ≥ |ΔL| / MAX_LIGHTNESS_WEIGHT`.
+const MAX_LIGHTNESS_WEIGHT: f32 = 1.75;
+
+#[derive(Debug, Clone)]
+struct Swatch {
+    // the position of the swatch in the library
+    index: usize,
+
+    name: String,
+    lab: Lab,
+}
+
+#[derive(Debug, Clone)]
+/// A library of named swatches, indexed for fast nearest neighbor searches by
+/// CIEDE2000 color difference (e.g. for an eyedropper).
+///
+/// The CIELAB values of the swatches are computed once, and the swatches are
+/// sorted by lightness. A search starts with the swatches closest in lightness
+/// to the query and walks outwards, stopping as soon as the difference of
+/// lightness alone guarantees that the remaining swatches are further away
+/// than the `k` nearest ones found so far.
+///
+/// The prefilter works on the difference of lightness rather than on a distance
+/// in RGB: unlike the latter, it bounds CIEDE2000 from below (by its difference
+/// of lightness divided by `1.75`), which is what makes the early exit exact.
+pub struct SwatchIndex {
+    // the swatches, sorted by lightness
+    swatches: Vec<Swatch>,
+}
+
+impl SwatchIndex {
+    /// Builds an index over a library of named swatches.
+    ///
+    /// # Example
+    /// ```
+    /// use css_colors::{rgb, SwatchIndex};
+    ///
+    /// let index = SwatchIndex::new(&[
+    ///     ("tomato".to_string(), rgb(255, 99, 71)),
+    ///     ("navy".to_string(), rgb(0, 0, 128)),
+    /// ]);
+    ///
+    /// assert_eq!(index.len(), 2);
+    /// ```
+    pub fn new(swatches: &[(String, RGB)]) -> SwatchIndex {
+        let mut swatches: Vec<Swatch> = swatches
+            .iter()
+            .enumerate()
+            .map(|(index, (name, color))| Swatch {
+                index,
+                name: name.clone(),
+                lab: color.to_lab(),
+            })
+            .collect();
+
+        swatches.sort_by(|lhs, rhs| lhs.lab.l.partial_cmp(&rhs.lab.l).unwrap());
+
+        SwatchIndex { swatches }
+    }
+
+    /// Returns the number of swatches in the index.
+    pub fn len(&self) -> usize {
+        self.swatches.len()
+    }
+
+    /// Returns `true` if the index has no swatches.
+    pub fn is_empty(&self) -> bool {
+        self.swatches.is_empty()
+    }
+
+    /// Finds the `k` swatches closest to `query`, along with their CIEDE2000
+    /// color difference, from the closest to the furthest. Swatches at the same
+    /// distance are returned in the order of the library.
+    ///
+    /// The result is always the same as comparing `query` against every swatch.
+    ///
+    /// # Example
+    /// ```
+    /// use css_colors::{rgb, SwatchIndex};
+    ///
+    /// let index = SwatchIndex::new(&[
+    ///     ("tomato".to_string(), rgb(255, 99, 71)),
+    ///     ("navy".to_string(), rgb(0, 0, 128)),
+    ///     ("salmon".to_string(), rgb(250, 128, 114)),
+    /// ]);
+    ///
+    /// let nearest = index.nearest(rgb(255, 100, 70), 2);
+    ///
+    /// assert_eq!(nearest[0].0, "tomato");
+    /// assert_eq!(nearest[1].0, "salmon");
+    /// ```
+    pub fn nearest(&self, query: RGB, k: usize) -> Vec<(&str, f32)> {
+        if k == 0 {
+            return Vec::new();
+        }
+
+        let query = query.to_lab();
+
+        // The k nearest swatches found so far, as (delta e, swatch) sorted by
+        // delta e, then by position in the library.
+        let mut nearest: Vec<(f32, &Swatch)> = Vec::with_capacity(k + 1);
+
+        // The swatches below `lower` and from `upper` onwards are yet to be visited.
+        let mut lower = self
+            .swatches
+            .partition_point(|swatch| swatch.lab.l < query.l);
+        let mut upper = lower;
+
+        loop {
+            let below = lower.checked_sub(1).map(|i| &self.swatches[i]);
+            let above = self.swatches.get(upper);
+
+            // Visit the closest swatch in lightness first, so that once the
+            // bound rules it out, it also rules out every remaining swatch.
+            let swatch = match (below, above) {
+                (Some(below), Some(above)) if query.l - below.lab.l <= above.lab.l - query.l => {
+                    lower -= 1;
+                    below
+                }
+                (_, Some(above)) => {
+                    upper += 1;
+                    above
+                }
+                (Some(below), None) => {
+                    lower -= 1;
+                    below
+                }
+                (None, None) => break,
+            };
+
+            if nearest.len() == k {
+                let bound = (swatch.lab.l - query.l).abs() / MAX_LIGHTNESS_WEIGHT;
+
+                if bound > nearest[k - 1].0 {
+                    break;
+                }
+            }
+
+            let delta_e = query.delta_e_2000(swatch.lab);
+            let position = nearest.partition_point(|&(other, candidate)| {
+                other < delta_e || (other == delta_e && candidate.index < swatch.index)
+            });
+
+            if position < k {
+                nearest.insert(position, (delta_e, swatch));
+                nearest.truncate(k);
+            }
+        }
+
+        nearest
+            .into_iter()
+            .map(|(delta_e, swatch)| (swatch.name.as_str(), delta_e))
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use {rgb, Color, SwatchIndex, RGB};
+
+    // The exhaustive search `SwatchIndex::nearest` must agree with.
+    fn exhaustive(library: &[(String, RGB)], query: RGB, k: usize) -> Vec<(&str, f32)> {
+        let query = query.to_lab();
+        let mut distances: Vec<(&str, f32)> = library
+            .iter()
+            .map(|(name, color)| (name.as_str(), query.delta_e_2000(color.to_lab())))
+            .collect();
+
+        // A stable sort keeps the swatches at the same distance in library order.
+        distances.sort_by(|lhs, rhs| lhs.1.partial_cmp(&rhs.1).unwrap());
+        distances.truncate(k);
+        distances
+    }
+
+    // A linear congruential generator, to sample colors deterministically.
+    fn next_rgb(state: &mut u32) -> RGB {
+        let mut next = || {
+            *state = state.wrapping_mul(1_664_525).wrapping_add(1_013_904_223);
+            (*state >> 24) as u8
+        };
+
+        rgb(next(), next(), next())
+    }
+
+    fn library(state: &mut u32, size: usize) -> Vec<(String, RGB)> {
+        (0..size)
+            .map(|i| (format!("swatch-{}", i), next_rgb(state)))
+            .collect()
+    }
+
+    #[test]
+    fn can_find_the_nearest_swatches() {
+        let library = [
+            ("white".to_string(), rgb(255, 255, 255)),
+            ("black".to_string(), rgb(0, 0, 0)),
+            ("tomato".to_string(), rgb(255, 99, 71)),
+            ("salmon".to_string(), rgb(250, 128, 114)),
+            ("navy".to_string(), rgb(0, 0, 128)),
+        ];
+        let index = SwatchIndex::new(&library);
+
+        let nearest = index.nearest(rgb(255, 99, 71), 3);
+
+        assert_eq!(nearest.len(), 3);
+        assert_eq!(nearest[0], ("tomato", 0.0));
+        assert_eq!(nearest[1].0, "salmon");
+        assert_eq!(nearest, exhaustive(&library, rgb(255, 99, 71), 3));
+
+        assert_eq!(index.nearest(rgb(10, 10, 10), 1)[0].0, "black");
+        assert_eq!(index.nearest(rgb(250, 250, 250), 1)[0].0, "white");
+    }
+
+    #[test]
+    fn handles_edge_cases() {
+        let library = [
+            ("first".to_string(), rgb(255, 99, 71)),
+            ("second".to_string(), rgb(255, 99, 71)),
+            ("third".to_string(), rgb(0, 0, 128)),
+        ];
+        let index = SwatchIndex::new(&library);
+
+        assert!(index.nearest(rgb(255, 99, 71), 0).is_empty());
+        assert_eq!(index.nearest(rgb(0, 0, 0), 10).len(), 3);
+
+        // Swatches at the same distance keep their order in the library.
+        let names: Vec<&str> = index
+            .nearest(rgb(255, 99, 71), 3)
+            .iter()
+            .map(|n| n.0)
+            .collect();
+        assert_eq!(names, ["first", "second", "third"]);
+
+        let empty = SwatchIndex::new(&[]);
+        assert!(empty.is_empty());
+        assert!(empty.nearest(rgb(0, 0, 0), 5).is_empty());
+    }
+
+    #[test]
+    fn agrees_with_the_exhaustive_search() {
+        let mut state = 7;
+
+        for &size in [1, 2, 17, 250, 1000].iter() {
+            let library = library(&mut state, size);
+            let index = SwatchIndex::new(&library);
+
+            for _ in 0..50 {
+                let query = next_rgb(&mut state);
+
+                for &k in [1, 2, 5, 20, size, size + 1].iter() {
+                    assert_eq!(
+                        index.nearest(query, k),
+                        exhaustive(&library, query, k),
+                        "size: {}, query: {:?}, k: {}",
+                        size,
+                        query,
+                        k
+                    );
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn agrees_with_the_exhaustive_search_with_duplicates() {
+        // A small palette of colors drawn many times, so that ties are common.
+        let mut state = 11;
+        let palette: Vec<RGB> = (0..8).map(|_| next_rgb(&mut state)).collect();
+        let library: Vec<(String, RGB)> = (0..200)
+            .map(|i| (format!("swatch-{}", i), palette[i * 7 % 8]))
+            .collect();
+        let index = SwatchIndex::new(&library);
+
+        for query in palette
+            .iter()
+            .chain([rgb(0, 0, 0), rgb(255, 255, 255)].iter())
+        {
+            for &k in [1, 3, 30, 100].iter() {
+                assert_eq!(index.nearest(*query, k), exhaustive(&library, *query, k));
+            }
+        }
+    }
+}
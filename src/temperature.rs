@@ -0,0 +1,186 @@
+use super::{ops, RGB, XYZ};
+
+// The range of temperatures (in kelvin) that `RGB::from_kelvin` is fitted to.
+const MIN_KELVIN: f32 = 1_000.0;
+const MAX_KELVIN: f32 = 40_000.0;
+
+// The largest distance from the Planckian locus (in the CIE 1960 UCS) at which
+// `XYZ::cct` estimates a temperature, as recommended by the CIE.
+const MAX_DUV: f32 = 0.05;
+
+// Approximates the chromaticity (in the CIE 1960 UCS) of a black body at the
+// given temperature, with Krystek's rational approximation of the Planckian locus.
+fn planckian_locus(temperature: f32) -> (f32, f32) {
+    let t = temperature as f64;
+
+    let u = (0.860_117_757 + 1.541_182_54e-4 * t + 1.286_412_12e-7 * t * t)
+        / (1.0 + 8.424_202_35e-4 * t + 7.081_451_63e-7 * t * t);
+    let v = (0.317_398_726 + 4.228_062_45e-5 * t + 4.204_816_91e-8 * t * t)
+        / (1.0 - 2.897_418_16e-5 * t + 1.614_560_53e-7 * t * t);
+
+    (u as f32, v as f32)
+}
+
+impl RGB {
+    /// Approximates the color of a black body (such as a light bulb) at the given
+    /// temperature, in kelvin. Candle light is around 1900K, tungsten light around
+    /// 3200K, and daylight between 5500K and 6500K.
+    ///
+    /// This uses [Tanner Helland's approximation](https://tannerhelland.com/2012/09/18/convert-temperature-rgb-algorithm-code.html)
+    /// of the Planckian locus, a curve fit over 1000K-40000K. Temperatures outside
+    /// of that range are clamped into it.
+    ///
+    /// # Example
+    /// ```
+    /// use css_colors::{rgb, RGB};
+    ///
+    /// assert_eq!(RGB::from_kelvin(3200.0), rgb(255, 184, 123));
+    /// assert_eq!(RGB::from_kelvin(6600.0), rgb(255, 255, 255));
+    /// ```
+    pub fn from_kelvin(temperature: f32) -> RGB {
+        let temperature = temperature.clamp(MIN_KELVIN, MAX_KELVIN) / 100.0;
+
+        let r = if temperature <= 66.0 {
+            255.0
+        } else {
+            329.698_73 * (temperature - 60.0).powf(-0.133_204_76)
+        };
+
+        let g = if temperature <= 66.0 {
+            99.470_8 * temperature.ln() - 161.119_57
+        } else {
+            288.122_17 * (temperature - 60.0).powf(-0.075_514_85)
+        };
+
+        let b = if temperature >= 66.0 {
+            255.0
+        } else if temperature <= 19.0 {
+            0.0
+        } else {
+            138.517_73 * (temperature - 10.0).ln() - 305.044_8
+        };
+
+        RGB {
+            r: ops::quantize(r / 255.0),
+            g: ops::quantize(g / 255.0),
+            b: ops::quantize(b / 255.0),
+        }
+    }
+}
+
+impl XYZ {
+    /// Estimates the correlated color temperature of `self` (in kelvin): the
+    /// temperature of the black body whose color is the closest to it, such as
+    /// `RGB::from_kelvin`.
+    ///
+    /// This uses [McCamy's approximation](https://en.wikipedia.org/wiki/Color_temperature#Approximation)
+    /// from the xy chromaticity of `self`. Colors further than a Duv of `0.05`
+    /// from the Planckian locus (in the CIE 1960 UCS, measured at the estimated
+    /// temperature) are too saturated for a temperature to be meaningful, and
+    /// return `None`, as does black.
+    ///
+    /// # Example
+    /// ```
+    /// use css_colors::XYZ;
+    ///
+    /// assert_eq!(XYZ::D65.cct().map(f32::round), Some(6503.0));
+    /// ```
+    pub fn cct(self) -> Option<f32> {
+        let XYZ { x, y, z } = self;
+        let sum = x + y + z;
+
+        if sum <= 0.0 {
+            return None;
+        }
+
+        let n = (x / sum - 0.3320) / (0.1858 - y / sum);
+        let temperature = 449.0 * n.powi(3) + 3525.0 * n.powi(2) + 6823.3 * n + 5520.33;
+
+        let denominator = x + 15.0 * y + 3.0 * z;
+        let (u, v) = (4.0 * x / denominator, 6.0 * y / denominator);
+        let (locus_u, locus_v) = planckian_locus(temperature);
+        let duv = (u - locus_u).hypot(v - locus_v);
+
+        if temperature.is_finite() && duv <= MAX_DUV {
+            Some(temperature)
+        } else {
+            None
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use {rgb, rgba, Color, RGB, XYZ};
+
+    #[test]
+    fn can_convert_kelvin_to_rgb() {
+        let temperatures = [
+            // candle light
+            (1900.0, rgb(255, 132, 0)),
+            // tungsten light
+            (3200.0, rgb(255, 184, 123)),
+            // daylight
+            (5500.0, rgb(255, 237, 222)),
+            // D65, which is almost white
+            (6500.0, rgb(255, 254, 250)),
+            (10000.0, rgb(202, 218, 255)),
+        ];
+
+        for &(temperature, expected) in temperatures.iter() {
+            assert_eq!(RGB::from_kelvin(temperature), expected, "{}K", temperature);
+        }
+    }
+
+    #[test]
+    fn clamps_temperatures_out_of_range() {
+        assert_eq!(RGB::from_kelvin(500.0), RGB::from_kelvin(1000.0));
+        assert_eq!(RGB::from_kelvin(-10.0), rgb(255, 68, 0));
+        assert_eq!(RGB::from_kelvin(50000.0), RGB::from_kelvin(40000.0));
+        assert_eq!(RGB::from_kelvin(40000.0), rgb(152, 186, 255));
+    }
+
+    #[test]
+    fn gets_bluer_as_temperature_rises() {
+        let mut previous = RGB::from_kelvin(1000.0);
+
+        for temperature in (1100..=40000).step_by(100) {
+            let color = RGB::from_kelvin(temperature as f32);
+
+            assert!(color.b >= previous.b, "{}K", temperature);
+            assert!(color.r <= previous.r, "{}K", temperature);
+            previous = color;
+        }
+    }
+
+    #[test]
+    fn can_estimate_the_temperature_of_a_color() {
+        // `from_kelvin` is an approximation itself, and drifts by up to a few percents.
+        for &temperature in [1900.0, 3200.0, 5500.0, 6500.0, 10000.0].iter() {
+            let estimate = RGB::from_kelvin(temperature).cct().unwrap();
+
+            assert!(
+                (estimate - temperature).abs() / temperature < 0.04,
+                "{}K: {}K",
+                temperature,
+                estimate
+            );
+        }
+
+        // Every grey has the temperature of the white point of sRGB.
+        let d65 = XYZ::D65.cct().map(f32::round);
+
+        assert_eq!(rgb(255, 255, 255).cct().map(f32::round), d65);
+        assert_eq!(rgba(128, 128, 128, 0.5).cct().map(f32::round), d65);
+        assert!(rgb(255, 200, 150).cct().unwrap() < 4000.0);
+    }
+
+    #[test]
+    fn cannot_estimate_the_temperature_of_saturated_colors() {
+        assert_eq!(rgb(0, 255, 0).cct(), None);
+        assert_eq!(rgb(255, 0, 0).cct(), None);
+        assert_eq!(rgb(0, 0, 255).cct(), None);
+        assert_eq!(rgb(255, 0, 255).cct(), None);
+        assert_eq!(rgb(0, 0, 0).cct(), None);
+    }
+}
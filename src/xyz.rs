@@ -0,0 +1,390 @@
+use super::css::write_color_function;
+use super::gamut::{in_srgb_gamut, reduce_chroma};
+use super::{clamp_ratio, ops, GamutMap, OkLab, OkLch, Ratio, RGB};
+use std::fmt;
+
+/// Constructs a CIE XYZ Color from numerical values.
+///
+/// The components are relative to a reference white with a luminance (`y`)
+/// of `1.0`, such as `XYZ::D65`.
+///
+/// # Example
+/// ```
+/// use css_colors::{rgb, xyz};
+///
+/// assert_eq!(xyz(0.950_47, 1.0, 1.088_83).to_rgb(), rgb(255, 255, 255));
+/// ```
+pub fn xyz(x: f32, y: f32, z: f32) -> XYZ {
+    XYZ { x, y, z }
+}
+
+#[derive(Debug, Copy, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+/// A struct to represent a color in the CIE 1931 XYZ color space, the device
+/// independent space that `Lab`, `LCH` and the other perceptual models are
+/// derived from. `y` is the luminance of the color.
+///
+/// Conversions from and into sRGB use the D65 white point, and go through
+/// linear light with the piecewise sRGB transfer function (see
+/// `ops::linearize` and `ops::delinearize`), then through the matrix of the
+/// sRGB primaries:
+///
+/// ```text
+/// | x |   | 0.4124564  0.3575761  0.1804375 |   | r |
+/// | y | = | 0.2126729  0.7151522  0.0721750 | × | g |
+/// | z |   | 0.0193339  0.1191920  0.9503041 |   | b |
+/// ```
+///
+/// and its inverse:
+///
+/// ```text
+/// | r |   |  3.2404542 -1.5371385 -0.4985314 |   | x |
+/// | g | = | -0.9692660  1.8760108  0.0415560 | × | y |
+/// | b |   |  0.0556434 -0.2040259  1.0572252 |   | z |
+/// ```
+///
+/// For more, see [Bruce Lindbloom's reference](http://www.brucelindbloom.com/index.html?Eqn_RGB_XYZ_Matrix.html).
+pub struct XYZ {
+    pub x: f32,
+    pub y: f32,
+    pub z: f32,
+}
+
+impl fmt::Display for XYZ {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write_color_function(f, "xyz-d65", self.to_array(), Ratio::from_u8(255))
+    }
+}
+
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "snake_case"))]
+/// An enum of the standard reference whites that CIE XYZ colors can be relative to.
+pub enum WhitePoint {
+    // horizon light, used by ICC profiles, print and CSS' `lab()` and `lch()`
+    D50,
+
+    // noon daylight, used by sRGB, Display P3 and `Lab`
+    D65,
+}
+
+impl WhitePoint {
+    /// Returns the color of `self`, with a luminance of `1.0` (see `XYZ::D50`
+    /// and `XYZ::D65`).
+    pub fn to_xyz(self) -> XYZ {
+        match self {
+            WhitePoint::D50 => XYZ::D50,
+            WhitePoint::D65 => XYZ::D65,
+        }
+    }
+}
+
+// The Bradford chromatic adaptation matrices between the D65 and D50 white
+// points, from http://www.brucelindbloom.com/index.html?Eqn_ChromAdapt.html.
+const D65_TO_D50: [[f32; 3]; 3] = [
+    [1.047_811_2, 0.022_886_6, -0.050_127],
+    [0.029_542_4, 0.990_484_4, -0.017_049_1],
+    [-0.009_234_5, 0.015_043_6, 0.752_131_6],
+];
+
+const D50_TO_D65: [[f32; 3]; 3] = [
+    [0.955_576_6, -0.023_039_3, 0.063_163_6],
+    [-0.028_289_5, 1.009_941_6, 0.021_007_7],
+    [0.012_298_2, -0.020_483, 1.329_909_8],
+];
+
+// The matrices between linear sRGB and CIE XYZ (D65).
+const RGB_TO_XYZ: [[f32; 3]; 3] = [
+    [0.412_456_4, 0.357_576_1, 0.180_437_5],
+    [0.212_672_9, 0.715_152_2, 0.072_175],
+    [0.019_333_9, 0.119_192, 0.950_304_1],
+];
+
+const XYZ_TO_RGB: [[f32; 3]; 3] = [
+    [3.240_454_2, -1.537_138_5, -0.498_531_4],
+    [-0.969_266, 1.876_010_8, 0.041_556],
+    [0.055_643_4, -0.204_025_9, 1.057_225_2],
+];
+
+impl XYZ {
+    /// The D65 reference white (noon daylight), used by sRGB and `Lab`.
+    pub const D65: XYZ = XYZ {
+        x: 0.950_47,
+        y: 1.0,
+        z: 1.088_83,
+    };
+
+    /// The D50 reference white (horizon light), used by CSS' `lab()` and `lch()`.
+    pub const D50: XYZ = XYZ {
+        x: 0.964_22,
+        y: 1.0,
+        z: 0.825_21,
+    };
+
+    /// Converts an RGB color into CIE XYZ, relative to the D65 white point.
+    ///
+    /// # Example
+    /// ```
+    /// use css_colors::{rgb, XYZ};
+    ///
+    /// let red = XYZ::from_rgb(rgb(255, 0, 0));
+    ///
+    /// assert!((red.y - 0.2127).abs() < 0.0001);
+    /// ```
+    pub fn from_rgb(rgb: RGB) -> XYZ {
+        let linear = [
+            ops::linearize(rgb.r.as_f32()),
+            ops::linearize(rgb.g.as_f32()),
+            ops::linearize(rgb.b.as_f32()),
+        ];
+
+        XYZ::from_array(multiply(RGB_TO_XYZ, linear))
+    }
+
+    /// Converts `self` into RGB, assuming the D65 white point. Colors outside of
+    /// the sRGB gamut are clamped channel by channel.
+    ///
+    /// # Example
+    /// ```
+    /// use css_colors::{rgb, xyz};
+    ///
+    /// assert_eq!(xyz(0.4124, 0.2126, 0.0193).to_rgb(), rgb(255, 0, 0));
+    /// assert_eq!(xyz(0.5, 0.2, 0.0).to_rgb(), rgb(255, 0, 0));
+    /// ```
+    pub fn to_rgb(self) -> RGB {
+        clamp_rgb(self.to_unclamped_rgb())
+    }
+
+    /// Converts `self` from a color relative to the `from` white point into the
+    /// corresponding color relative to the `to` white point (the color that
+    /// looks the same under that light), with the Bradford chromatic adaptation
+    /// transform.
+    ///
+    /// # Example
+    /// ```
+    /// use css_colors::{WhitePoint, XYZ};
+    ///
+    /// let white = XYZ::D65.adapt(WhitePoint::D65, WhitePoint::D50);
+    ///
+    /// assert!((white.x - XYZ::D50.x).abs() < 0.0001);
+    /// assert!((white.z - XYZ::D50.z).abs() < 0.0001);
+    /// ```
+    pub fn adapt(self, from: WhitePoint, to: WhitePoint) -> XYZ {
+        match (from, to) {
+            (WhitePoint::D65, WhitePoint::D50) => {
+                XYZ::from_array(multiply(D65_TO_D50, self.to_array()))
+            }
+            (WhitePoint::D50, WhitePoint::D65) => {
+                XYZ::from_array(multiply(D50_TO_D65, self.to_array()))
+            }
+            _ => self,
+        }
+    }
+
+    /// Returns `true` if `self` can be represented in sRGB without gamut mapping.
+    ///
+    /// # Example
+    /// ```
+    /// use css_colors::{xyz, XYZ};
+    ///
+    /// assert!(XYZ::D65.in_gamut());
+    /// assert!(!xyz(0.0, 1.0, 0.0).in_gamut());
+    /// ```
+    pub fn in_gamut(&self) -> bool {
+        in_srgb_gamut(self.to_linear_rgb())
+    }
+
+    /// Converts `self` into RGB, bringing colors outside of the sRGB gamut into
+    /// it with the given strategy.
+    ///
+    /// # Example
+    /// ```
+    /// use css_colors::{rgb, xyz, GamutMap};
+    ///
+    /// let vivid = xyz(0.2, 0.4, 0.1);
+    ///
+    /// assert_eq!(vivid.map_to_gamut(GamutMap::Clip), rgb(0, 197, 53));
+    /// assert_eq!(vivid.map_to_gamut(GamutMap::ChromaReduce), rgb(0, 196, 62));
+    /// ```
+    pub fn map_to_gamut(self, method: GamutMap) -> RGB {
+        match method {
+            GamutMap::Clip => self.to_rgb(),
+            GamutMap::ChromaReduce => reduce_chroma(OkLch::from_oklab(OkLab::from_linear_rgb(
+                self.to_linear_rgb(),
+            ))),
+        }
+    }
+
+    /// Converts `self` into the `color(xyz-d65 x y z)` notation of CSS, with up
+    /// to four decimals per component. Formatting `self` with a precision (e.g.
+    /// `{:.2}`) sets the number of decimals instead.
+    ///
+    /// # Example
+    /// ```
+    /// use css_colors::{rgb, XYZ};
+    ///
+    /// let red = XYZ::from_rgb(rgb(255, 0, 0));
+    ///
+    /// assert_eq!(red.to_css(), "color(xyz-d65 0.4125 0.2127 0.0193)");
+    /// assert_eq!(format!("{:.2}", red), "color(xyz-d65 0.41 0.21 0.02)");
+    /// ```
+    pub fn to_css(self) -> String {
+        self.to_string()
+    }
+
+    // Converts `self` into gamma-encoded sRGB channels. The channels of colors
+    // outside of the sRGB gamut fall outside of the `0.0-1.0` range.
+    pub(crate) fn to_unclamped_rgb(self) -> [f32; 3] {
+        let [r, g, b] = self.to_linear_rgb();
+
+        [
+            ops::delinearize(r),
+            ops::delinearize(g),
+            ops::delinearize(b),
+        ]
+    }
+
+    // Converts `self` into linear sRGB channels, which also fall outside of the
+    // `0.0-1.0` range for colors outside of the sRGB gamut.
+    pub(crate) fn to_linear_rgb(self) -> [f32; 3] {
+        multiply(XYZ_TO_RGB, self.to_array())
+    }
+
+    pub(crate) fn from_array(xyz: [f32; 3]) -> XYZ {
+        let [x, y, z] = xyz;
+        XYZ { x, y, z }
+    }
+
+    pub(crate) fn to_array(self) -> [f32; 3] {
+        [self.x, self.y, self.z]
+    }
+}
+
+// Multiplies a 3×3 matrix with a column vector.
+pub(crate) fn multiply(matrix: [[f32; 3]; 3], vector: [f32; 3]) -> [f32; 3] {
+    let row =
+        |i: usize| matrix[i][0] * vector[0] + matrix[i][1] * vector[1] + matrix[i][2] * vector[2];
+
+    [row(0), row(1), row(2)]
+}
+
+// Quantizes gamma-encoded sRGB channels, clamping them into the sRGB gamut.
+pub(crate) fn clamp_rgb(channels: [f32; 3]) -> RGB {
+    let [r, g, b] = channels;
+
+    RGB {
+        r: clamp_ratio(r),
+        g: clamp_ratio(g),
+        b: clamp_ratio(b),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{multiply, RGB_TO_XYZ, XYZ_TO_RGB};
+    use {ops, rgb, xyz, Color, WhitePoint, XYZ};
+
+    fn assert_xyz_eq(lhs: XYZ, rhs: XYZ) {
+        assert!(
+            (lhs.x - rhs.x).abs() < 0.000_1
+                && (lhs.y - rhs.y).abs() < 0.000_1
+                && (lhs.z - rhs.z).abs() < 0.000_1,
+            "lhs: {:?}, rhs: {:?}",
+            lhs,
+            rhs
+        );
+    }
+
+    #[test]
+    fn matrices_are_inverses() {
+        for &column in [[1.0, 0.0, 0.0], [0.0, 1.0, 0.0], [0.0, 0.0, 1.0]].iter() {
+            let round_trip = multiply(XYZ_TO_RGB, multiply(RGB_TO_XYZ, column));
+
+            for i in 0..3 {
+                assert!(
+                    (round_trip[i] - column[i]).abs() < 0.000_01,
+                    "{:?}",
+                    round_trip
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn can_convert_rgb_to_xyz() {
+        assert_xyz_eq(rgb(255, 255, 255).to_xyz(), XYZ::D65);
+        assert_xyz_eq(rgb(0, 0, 0).to_xyz(), xyz(0.0, 0.0, 0.0));
+        assert_xyz_eq(
+            rgb(255, 0, 0).to_xyz(),
+            xyz(0.412_456_4, 0.212_672_9, 0.019_333_9),
+        );
+        assert_xyz_eq(
+            rgb(0, 255, 0).to_xyz(),
+            xyz(0.357_576_1, 0.715_152_2, 0.119_192),
+        );
+        assert_xyz_eq(
+            rgb(0, 0, 255).to_xyz(),
+            xyz(0.180_437_5, 0.072_175, 0.950_304_1),
+        );
+
+        // The luminance of a grey is the linearized value of its channels.
+        assert!((rgb(128, 128, 128).to_xyz().y - ops::linearize(128.0 / 255.0)).abs() < 0.000_1);
+    }
+
+    #[test]
+    fn can_round_trip_through_xyz() {
+        for r in (0..=255).step_by(15) {
+            for g in (0..=255).step_by(15) {
+                for b in (0..=255).step_by(15) {
+                    let color = rgb(r as u8, g as u8, b as u8);
+                    let channels = color.to_xyz().to_unclamped_rgb();
+
+                    assert_eq!(color.to_xyz().to_rgb(), color);
+
+                    for &(channel, expected) in
+                        [(channels[0], r), (channels[1], g), (channels[2], b)].iter()
+                    {
+                        assert!((channel * 255.0 - expected as f32).abs() < 0.01);
+                    }
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn clamps_out_of_gamut_colors() {
+        assert_eq!(xyz(1.2, 1.2, 1.2).to_rgb(), rgb(255, 255, 255));
+        assert_eq!(xyz(-0.1, -0.1, -0.1).to_rgb(), rgb(0, 0, 0));
+        assert_eq!(xyz(0.0, 1.0, 0.0).to_rgb().g.as_u8(), 255);
+    }
+
+    #[test]
+    fn can_serialize_to_css() {
+        assert_eq!(
+            xyz(0.42, 0.21, 0.02).to_css(),
+            "color(xyz-d65 0.42 0.21 0.02)"
+        );
+        assert_eq!(XYZ::D65.to_css(), "color(xyz-d65 0.9505 1 1.0888)");
+        assert_eq!(xyz(-0.000_01, 0.0, 1.5).to_css(), "color(xyz-d65 0 0 1.5)");
+        assert_eq!(format!("{:.1}", XYZ::D65), "color(xyz-d65 1 1 1.1)");
+        assert_eq!(
+            format!("{:.6}", XYZ::D65),
+            "color(xyz-d65 0.95047 1 1.08883)"
+        );
+    }
+
+    #[test]
+    fn can_adapt_between_white_points() {
+        assert_xyz_eq(XYZ::D65.adapt(WhitePoint::D65, WhitePoint::D50), XYZ::D50);
+        assert_xyz_eq(XYZ::D50.adapt(WhitePoint::D50, WhitePoint::D65), XYZ::D65);
+        assert_eq!(XYZ::D50.adapt(WhitePoint::D50, WhitePoint::D50), XYZ::D50);
+
+        for &color in [XYZ::D65, xyz(0.2, 0.4, 0.1), rgb(250, 128, 114).to_xyz()].iter() {
+            let round_trip = color
+                .adapt(WhitePoint::D65, WhitePoint::D50)
+                .adapt(WhitePoint::D50, WhitePoint::D65);
+
+            assert_xyz_eq(round_trip, color);
+        }
+    }
+}
@@ -0,0 +1,306 @@
+use super::{Ratio, RGB};
+
+/// Constructs a YCbCr Color from numerical values.
+///
+/// # Example
+/// ```
+/// use css_colors::{rgb, ycbcr, YCbCrRange, YCbCrStandard};
+///
+/// let red = ycbcr(76, 85, 255);
+///
+/// assert_eq!(red.to_rgb(YCbCrStandard::Bt601, YCbCrRange::Full), rgb(254, 0, 0));
+/// ```
+pub fn ycbcr(y: u8, cb: u8, cr: u8) -> YCbCr {
+    YCbCr { y, cb, cr }
+}
+
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "snake_case"))]
+/// An enum of the ITU-R recommendations that define how RGB is encoded into YCbCr.
+pub enum YCbCrStandard {
+    // standard definition video (and JPEG), ITU-R BT.601
+    Bt601,
+
+    // high definition video, ITU-R BT.709
+    Bt709,
+}
+
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Hash, Default)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "snake_case"))]
+/// An enum of the ranges that the channels of a YCbCr color can be quantized into.
+pub enum YCbCrRange {
+    // every channel spans `0-255` (as in JPEG)
+    #[default]
+    Full,
+
+    // `y` spans `16-235`, and `cb` and `cr` span `16-240` (as in broadcast video)
+    Studio,
+}
+
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+/// A struct to represent a color as a luma (`y`) and two chroma differences
+/// (`cb` for blue, and `cr` for red), as used by video and image pipelines.
+///
+/// The channels don't carry the `YCbCrStandard` and `YCbCrRange` they were
+/// encoded with, so the same ones must be used to decode them (see `to_rgb`).
+/// Both conversions saturate channels that fall outside of their range.
+///
+/// A round trip from RGB is off by at most one byte per channel. The studio
+/// range has fewer steps than RGB, so rounding each channel on its own can be
+/// off by two: `from_rgb` then picks the neighbouring encoding that decodes the
+/// closest to the original color.
+///
+/// For more, see [ITU-R BT.601](https://www.itu.int/rec/R-REC-BT.601) and
+/// [ITU-R BT.709](https://www.itu.int/rec/R-REC-BT.709).
+pub struct YCbCr {
+    // luma
+    pub y: u8,
+
+    // blue difference
+    pub cb: u8,
+
+    // red difference
+    pub cr: u8,
+}
+
+impl YCbCrStandard {
+    // The weights of the red and blue channels in the luma.
+    fn coefficients(self) -> (f32, f32) {
+        match self {
+            YCbCrStandard::Bt601 => (0.299, 0.114),
+            YCbCrStandard::Bt709 => (0.2126, 0.0722),
+        }
+    }
+}
+
+impl YCbCrRange {
+    // The offset and span of the luma, and the span of the chroma differences.
+    fn scale(self) -> (f32, f32, f32) {
+        match self {
+            YCbCrRange::Full => (0.0, 255.0, 255.0),
+            YCbCrRange::Studio => (16.0, 219.0, 224.0),
+        }
+    }
+}
+
+impl YCbCr {
+    /// Encodes an RGB color into YCbCr.
+    ///
+    /// # Example
+    /// ```
+    /// use css_colors::{rgb, ycbcr, YCbCrRange, YCbCrStandard, YCbCr};
+    ///
+    /// let white = rgb(255, 255, 255);
+    ///
+    /// assert_eq!(YCbCr::from_rgb(white, YCbCrStandard::Bt709, YCbCrRange::Full), ycbcr(255, 128, 128));
+    /// assert_eq!(YCbCr::from_rgb(white, YCbCrStandard::Bt709, YCbCrRange::Studio), ycbcr(235, 128, 128));
+    /// ```
+    pub fn from_rgb(rgb: RGB, standard: YCbCrStandard, range: YCbCrRange) -> YCbCr {
+        let rounded = YCbCr::encode(rgb, standard, range);
+        let delta = |color: YCbCr| max_delta(color.to_rgb(standard, range), rgb);
+
+        if delta(rounded) <= 1 {
+            return rounded;
+        }
+
+        // One of the encodings a step away from the rounded one always decodes
+        // within a byte of `rgb`.
+        let nudge = |channel: u8, offset: i16| (channel as i16 + offset).clamp(0, 255) as u8;
+        let offsets = [-1, 0, 1];
+
+        offsets
+            .iter()
+            .flat_map(|&y| offsets.iter().map(move |&cb| (y, cb)))
+            .flat_map(|(y, cb)| offsets.iter().map(move |&cr| (y, cb, cr)))
+            .map(|(y, cb, cr)| YCbCr {
+                y: nudge(rounded.y, y),
+                cb: nudge(rounded.cb, cb),
+                cr: nudge(rounded.cr, cr),
+            })
+            .min_by_key(|&candidate| delta(candidate))
+            .unwrap()
+    }
+
+    // Encodes an RGB color by rounding each channel to the closest step.
+    fn encode(rgb: RGB, standard: YCbCrStandard, range: YCbCrRange) -> YCbCr {
+        let (kr, kb) = standard.coefficients();
+        let (offset, luma_span, chroma_span) = range.scale();
+
+        let (r, g, b) = (rgb.r.as_f32(), rgb.g.as_f32(), rgb.b.as_f32());
+
+        // The luma and chroma differences, respectively in the `0.0-1.0` and `-0.5-0.5` ranges.
+        let y = kr * r + (1.0 - kr - kb) * g + kb * b;
+        let cb = (b - y) / (2.0 * (1.0 - kb));
+        let cr = (r - y) / (2.0 * (1.0 - kr));
+
+        YCbCr {
+            y: saturate(offset + y * luma_span),
+            cb: saturate(128.0 + cb * chroma_span),
+            cr: saturate(128.0 + cr * chroma_span),
+        }
+    }
+
+    /// Decodes `self` into RGB, with the `YCbCrStandard` and `YCbCrRange` it was encoded with.
+    ///
+    /// # Example
+    /// ```
+    /// use css_colors::{rgb, ycbcr, YCbCrRange, YCbCrStandard};
+    ///
+    /// assert_eq!(ycbcr(235, 128, 128).to_rgb(YCbCrStandard::Bt709, YCbCrRange::Studio), rgb(255, 255, 255));
+    /// assert_eq!(ycbcr(255, 128, 128).to_rgb(YCbCrStandard::Bt709, YCbCrRange::Studio), rgb(255, 255, 255));
+    /// ```
+    pub fn to_rgb(self, standard: YCbCrStandard, range: YCbCrRange) -> RGB {
+        let (kr, kb) = standard.coefficients();
+        let (offset, luma_span, chroma_span) = range.scale();
+
+        let y = (self.y as f32 - offset) / luma_span;
+        let cb = (self.cb as f32 - 128.0) / chroma_span;
+        let cr = (self.cr as f32 - 128.0) / chroma_span;
+
+        let r = y + 2.0 * (1.0 - kr) * cr;
+        let b = y + 2.0 * (1.0 - kb) * cb;
+        let g = (y - kr * r - kb * b) / (1.0 - kr - kb);
+
+        RGB {
+            r: Ratio::from_u8(saturate(r * 255.0)),
+            g: Ratio::from_u8(saturate(g * 255.0)),
+            b: Ratio::from_u8(saturate(b * 255.0)),
+        }
+    }
+}
+
+// The largest difference between the channels of `lhs` and `rhs`, in bytes.
+fn max_delta(lhs: RGB, rhs: RGB) -> u8 {
+    let channels = [(lhs.r, rhs.r), (lhs.g, rhs.g), (lhs.b, rhs.b)];
+
+    channels
+        .iter()
+        .map(|&(lhs, rhs)| lhs.as_u8().abs_diff(rhs.as_u8()))
+        .max()
+        .unwrap()
+}
+
+// Rounds a channel into a byte, saturating values outside of `0-255` instead of wrapping them.
+fn saturate(value: f32) -> u8 {
+    value.round().clamp(0.0, 255.0) as u8
+}
+
+#[cfg(test)]
+mod tests {
+    use {rgb, rgba, ycbcr, Color, YCbCr, YCbCrRange, YCbCrStandard, RGB};
+
+    const STANDARDS: [YCbCrStandard; 2] = [YCbCrStandard::Bt601, YCbCrStandard::Bt709];
+
+    fn assert_rgb_close(lhs: RGB, rhs: RGB, tolerance: i16) {
+        let channels = [(lhs.r, rhs.r), (lhs.g, rhs.g), (lhs.b, rhs.b)];
+
+        assert!(
+            channels
+                .iter()
+                .all(|&(lhs, rhs)| (lhs.as_u8() as i16 - rhs.as_u8() as i16).abs() <= tolerance),
+            "lhs: {:?}, rhs: {:?}",
+            lhs,
+            rhs
+        );
+    }
+
+    #[test]
+    fn can_convert_rgb_to_ycbcr() {
+        let red = rgb(255, 0, 0);
+
+        assert_eq!(
+            red.to_ycbcr(YCbCrStandard::Bt601, YCbCrRange::Full),
+            ycbcr(76, 85, 255)
+        );
+        assert_eq!(
+            red.to_ycbcr(YCbCrStandard::Bt601, YCbCrRange::Studio),
+            ycbcr(81, 90, 240)
+        );
+        assert_eq!(
+            red.to_ycbcr(YCbCrStandard::Bt709, YCbCrRange::Full),
+            ycbcr(54, 99, 255)
+        );
+        assert_eq!(
+            red.to_ycbcr(YCbCrStandard::Bt709, YCbCrRange::Studio),
+            ycbcr(63, 102, 240)
+        );
+
+        assert_eq!(
+            rgba(0, 0, 0, 0.5).to_ycbcr(YCbCrStandard::Bt601, YCbCrRange::Full),
+            ycbcr(0, 128, 128)
+        );
+        assert_eq!(
+            rgb(0, 0, 0).to_ycbcr(YCbCrStandard::Bt601, YCbCrRange::Studio),
+            ycbcr(16, 128, 128)
+        );
+        assert_eq!(
+            rgb(128, 128, 128).to_ycbcr(YCbCrStandard::Bt709, YCbCrRange::Full),
+            ycbcr(128, 128, 128)
+        );
+        assert_eq!(
+            rgb(0, 0, 255).to_ycbcr(YCbCrStandard::Bt709, YCbCrRange::Studio),
+            ycbcr(32, 240, 118)
+        );
+    }
+
+    #[test]
+    fn can_round_trip_through_ycbcr() {
+        for &standard in STANDARDS.iter() {
+            for &range in [YCbCrRange::Full, YCbCrRange::Studio].iter() {
+                for r in (0..=255).step_by(3) {
+                    for g in (0..=255).step_by(3) {
+                        for b in (0..=255).step_by(3) {
+                            let color = rgb(r as u8, g as u8, b as u8);
+                            let round_trip =
+                                color.to_ycbcr(standard, range).to_rgb(standard, range);
+
+                            assert_rgb_close(round_trip, color, 1);
+                        }
+                    }
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn picks_the_closest_studio_range_encoding() {
+        // Rounding each channel on its own decodes 2 bytes away from this color.
+        let color = rgb(0, 1, 20);
+        let (standard, range) = (YCbCrStandard::Bt601, YCbCrRange::Studio);
+        let rounded = YCbCr::encode(color, standard, range);
+
+        assert_eq!(super::max_delta(rounded.to_rgb(standard, range), color), 2);
+        assert_rgb_close(
+            color.to_ycbcr(standard, range).to_rgb(standard, range),
+            color,
+            1,
+        );
+    }
+
+    #[test]
+    fn saturates_out_of_range_channels() {
+        // Luma and chroma values below and above the studio range.
+        let studio = |color: YCbCr| color.to_rgb(YCbCrStandard::Bt601, YCbCrRange::Studio);
+
+        assert_eq!(studio(ycbcr(0, 128, 128)), rgb(0, 0, 0));
+        assert_eq!(studio(ycbcr(255, 128, 128)), rgb(255, 255, 255));
+
+        // Combinations of channels that fall outside of the RGB cube.
+        assert_eq!(
+            ycbcr(255, 0, 255).to_rgb(YCbCrStandard::Bt709, YCbCrRange::Full),
+            rgb(255, 220, 17)
+        );
+        assert_eq!(
+            ycbcr(0, 255, 0).to_rgb(YCbCrStandard::Bt601, YCbCrRange::Full),
+            rgb(0, 48, 225)
+        );
+    }
+
+    #[test]
+    fn defaults_to_full_range() {
+        assert_eq!(YCbCrRange::default(), YCbCrRange::Full);
+    }
+}
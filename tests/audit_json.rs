@@ -0,0 +1,97 @@
+//! Schema stability tests for the JSON audit reports.
+//!
+//! The committed fixture is deserialized into the report types, so renaming
+//! or removing a field fails these tests. If the schema is changed on purpose,
+//! regenerate the fixture with `UPDATE_FIXTURES=1 cargo test --features serde`.
+#![cfg(feature = "serde")]
+
+extern crate css_colors;
+extern crate serde_json;
+
+use css_colors::{audit_palette, rgba, AuditReport, RGBA};
+use serde_json::Value;
+use std::env;
+use std::fs;
+use std::path::PathBuf;
+
+fn palette() -> [RGBA; 6] {
+    [
+        rgba(255, 255, 255, 1.0),
+        rgba(51, 51, 51, 1.0),
+        rgba(220, 20, 60, 1.0),
+        rgba(34, 139, 34, 1.0),
+        rgba(0, 0, 0, 0.5),
+        rgba(51, 51, 51, 1.0),
+    ]
+}
+
+fn fixture_path() -> PathBuf {
+    PathBuf::from(env!("CARGO_MANIFEST_DIR"))
+        .join("tests")
+        .join("fixtures")
+        .join("audit_report.json")
+}
+
+// Compares two JSON documents field by field, with a tolerance for numbers so
+// that changes to how floats are formatted don't fail the comparison.
+fn assert_json_close(lhs: &Value, rhs: &Value, path: &str) {
+    match (lhs, rhs) {
+        (Value::Number(lhs), Value::Number(rhs)) => {
+            let (lhs, rhs) = (lhs.as_f64().unwrap(), rhs.as_f64().unwrap());
+
+            assert!((lhs - rhs).abs() < 0.000_1, "{}: {} != {}", path, lhs, rhs);
+        }
+        (Value::Array(lhs), Value::Array(rhs)) => {
+            assert_eq!(lhs.len(), rhs.len(), "{}", path);
+
+            for (index, (lhs, rhs)) in lhs.iter().zip(rhs).enumerate() {
+                assert_json_close(lhs, rhs, &format!("{}[{}]", path, index));
+            }
+        }
+        (Value::Object(lhs), Value::Object(rhs)) => {
+            let keys = |object: &serde_json::Map<String, Value>| {
+                object.keys().cloned().collect::<Vec<String>>()
+            };
+
+            assert_eq!(keys(lhs), keys(rhs), "{}", path);
+
+            for (key, value) in lhs {
+                assert_json_close(value, &rhs[key], &format!("{}.{}", path, key));
+            }
+        }
+        (lhs, rhs) => assert_eq!(lhs, rhs, "{}", path),
+    }
+}
+
+#[test]
+fn audit_reports_match_the_fixture() {
+    let json = audit_palette(&palette(), 4.5).to_json();
+
+    if env::var_os("UPDATE_FIXTURES").is_some() {
+        fs::write(fixture_path(), &json).unwrap();
+    }
+
+    let fixture = fs::read_to_string(fixture_path()).unwrap();
+
+    assert_json_close(
+        &serde_json::from_str(&json).unwrap(),
+        &serde_json::from_str(&fixture).unwrap(),
+        "report",
+    );
+}
+
+#[test]
+fn fixture_deserializes_into_audit_reports() {
+    let json = fs::read_to_string(fixture_path()).unwrap();
+    let report: AuditReport = serde_json::from_str(&json).unwrap();
+
+    assert_eq!(report, audit_palette(&palette(), 4.5));
+}
+
+#[test]
+fn renamed_fields_are_rejected() {
+    let json = fs::read_to_string(fixture_path()).unwrap();
+    let renamed = json.replace("\"failing_pairs\"", "\"failures\"");
+
+    assert!(serde_json::from_str::<AuditReport>(&renamed).is_err());
+}
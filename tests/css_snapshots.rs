@@ -0,0 +1,354 @@
+//! Snapshot tests guarding the exact string output of `Color::to_css()`.
+//!
+//! Every color in the matrices below is serialized and compared against the
+//! committed golden files in `tests/golden/`, one per color model with a CSS
+//! serialization (`Lab` and `OkLab` have none of their own, and are written
+//! through `LCH` and `OkLch`). Any difference fails the test and
+//! prints the changed lines. Changes to the golden files are changes to the
+//! public output of this crate: see "Output stability" in the README.
+//!
+//! To regenerate the golden files after an intentional change, run:
+//!
+//! ```text
+//! UPDATE_GOLDEN=1 cargo test --test css_snapshots
+//! ```
+
+extern crate css_colors;
+
+use css_colors::{
+    cmyk, hsi, hsl, hsla, hsv, hsva, hwb, lch, linear_rgb, oklch, p3, rgb, xyz, Color, Ratio, RGBA,
+};
+use std::env;
+use std::fs;
+use std::path::PathBuf;
+
+const CHANNELS: [u8; 5] = [0, 1, 128, 254, 255];
+const ALPHAS: [u8; 6] = [0, 1, 127, 128, 254, 255];
+const HUES: [i32; 7] = [-1, 0, 1, 90, 180, 359, 360];
+const PERCENTAGES: [u8; 5] = [0, 1, 50, 99, 100];
+
+// Floats around the edges of the ranges, and of the rounding of their output.
+const LIGHTNESSES: [f32; 5] = [0.0, 0.004, 50.0, 99.996, 100.0];
+const CHROMAS: [f32; 4] = [0.0, 0.004, 50.0, 150.0];
+const FLOAT_HUES: [f32; 6] = [-1.0, 0.0, 0.004, 90.0, 359.996, 360.0];
+const FLOAT_CHANNELS: [f32; 7] = [-0.1, 0.0, 0.000_04, 0.5, 0.999_96, 1.0, 1.2];
+
+fn rgb_matrix() -> Vec<String> {
+    let mut lines = Vec::new();
+
+    for &r in CHANNELS.iter() {
+        for &g in CHANNELS.iter() {
+            for &b in CHANNELS.iter() {
+                let color = rgb(r, g, b);
+
+                lines.push(format!("rgb({}, {}, {}) => {}", r, g, b, color.to_css()));
+
+                for &a in ALPHAS.iter() {
+                    let color = RGBA {
+                        r: Ratio::from_u8(r),
+                        g: Ratio::from_u8(g),
+                        b: Ratio::from_u8(b),
+                        a: Ratio::from_u8(a),
+                    };
+
+                    lines.push(format!(
+                        "rgba({}, {}, {}, Ratio({})) => {}",
+                        r,
+                        g,
+                        b,
+                        a,
+                        color.to_css()
+                    ));
+                }
+            }
+        }
+    }
+
+    lines
+}
+
+fn hsl_matrix() -> Vec<String> {
+    let mut lines = Vec::new();
+
+    for &h in HUES.iter() {
+        for &s in PERCENTAGES.iter() {
+            for &l in PERCENTAGES.iter() {
+                let color = hsl(h, s, l);
+
+                lines.push(format!("hsl({}, {}, {}) => {}", h, s, l, color.to_css()));
+
+                for &a in ALPHAS.iter() {
+                    let alpha = Ratio::from_u8(a).as_f32();
+                    let color = hsla(h, s, l, alpha);
+
+                    lines.push(format!(
+                        "hsla({}, {}, {}, Ratio({})) => {}",
+                        h,
+                        s,
+                        l,
+                        a,
+                        color.to_css()
+                    ));
+                }
+            }
+        }
+    }
+
+    lines
+}
+
+fn hsv_matrix() -> Vec<String> {
+    let mut lines = Vec::new();
+
+    for &h in HUES.iter() {
+        for &s in PERCENTAGES.iter() {
+            for &v in PERCENTAGES.iter() {
+                let color = hsv(h, s, v);
+
+                lines.push(format!("hsv({}, {}, {}) => {}", h, s, v, color.to_css()));
+
+                for &a in ALPHAS.iter() {
+                    let alpha = Ratio::from_u8(a).as_f32();
+                    let color = hsva(h, s, v, alpha);
+
+                    lines.push(format!(
+                        "hsva({}, {}, {}, Ratio({})) => {}",
+                        h,
+                        s,
+                        v,
+                        a,
+                        color.to_css()
+                    ));
+                }
+            }
+        }
+    }
+
+    lines
+}
+
+fn hwb_matrix() -> Vec<String> {
+    let mut lines = Vec::new();
+
+    for &h in HUES.iter() {
+        for &w in PERCENTAGES.iter() {
+            for &b in PERCENTAGES.iter() {
+                let color = hwb(h, w, b);
+
+                lines.push(format!("hwb({}, {}, {}) => {}", h, w, b, color.to_css()));
+            }
+        }
+    }
+
+    lines
+}
+
+fn hsi_matrix() -> Vec<String> {
+    let mut lines = Vec::new();
+
+    for &h in HUES.iter() {
+        for &s in PERCENTAGES.iter() {
+            for &i in PERCENTAGES.iter() {
+                let color = hsi(h, s, i);
+
+                lines.push(format!("hsi({}, {}, {}) => {}", h, s, i, color.to_css()));
+            }
+        }
+    }
+
+    lines
+}
+
+fn cmyk_matrix() -> Vec<String> {
+    let mut lines = Vec::new();
+
+    for &c in PERCENTAGES.iter() {
+        for &m in PERCENTAGES.iter() {
+            for &y in PERCENTAGES.iter() {
+                for &k in PERCENTAGES.iter() {
+                    let color = cmyk(c, m, y, k);
+
+                    lines.push(format!(
+                        "cmyk({}, {}, {}, {}) => {}",
+                        c,
+                        m,
+                        y,
+                        k,
+                        color.to_css()
+                    ));
+                }
+            }
+        }
+    }
+
+    lines
+}
+
+fn lch_matrix() -> Vec<String> {
+    let mut lines = Vec::new();
+
+    for &l in LIGHTNESSES.iter() {
+        for &c in CHROMAS.iter() {
+            for &h in FLOAT_HUES.iter() {
+                let color = lch(l, c, h);
+
+                lines.push(format!("lch({}, {}, {}) => {}", l, c, h, color.to_css()));
+            }
+        }
+    }
+
+    lines
+}
+
+fn oklch_matrix() -> Vec<String> {
+    let mut lines = Vec::new();
+
+    for &l in LIGHTNESSES.iter() {
+        for &c in CHROMAS.iter() {
+            for &h in FLOAT_HUES.iter() {
+                // The lightness of Oklch ranges between `0.0-1.0`, and its chroma
+                // between `0.0-0.4` (rather than `0-150` in LCH).
+                let color = oklch(l / 100.0, c / 375.0, h);
+
+                lines.push(format!(
+                    "oklch({}, {}, {}) => {}",
+                    l / 100.0,
+                    c / 375.0,
+                    h,
+                    color.to_css()
+                ));
+            }
+        }
+    }
+
+    lines
+}
+
+fn color_function_matrix() -> Vec<String> {
+    let mut lines = Vec::new();
+
+    for &r in FLOAT_CHANNELS.iter() {
+        for &g in FLOAT_CHANNELS.iter() {
+            for &b in FLOAT_CHANNELS.iter() {
+                lines.push(format!(
+                    "p3({}, {}, {}) => {}",
+                    r,
+                    g,
+                    b,
+                    p3(r, g, b).to_css()
+                ));
+                lines.push(format!(
+                    "xyz({}, {}, {}) => {}",
+                    r,
+                    g,
+                    b,
+                    xyz(r, g, b).to_css()
+                ));
+                lines.push(format!(
+                    "linear_rgb({}, {}, {}) => {}",
+                    r,
+                    g,
+                    b,
+                    linear_rgb(r, g, b).to_css()
+                ));
+            }
+        }
+    }
+
+    lines
+}
+
+fn golden_path(name: &str) -> PathBuf {
+    PathBuf::from(env!("CARGO_MANIFEST_DIR"))
+        .join("tests")
+        .join("golden")
+        .join(name)
+}
+
+fn assert_matches_golden(name: &str, lines: Vec<String>) {
+    let path = golden_path(name);
+    let actual = lines.join("\n") + "\n";
+
+    if env::var_os("UPDATE_GOLDEN").is_some() {
+        fs::write(&path, &actual).expect("unable to write golden file");
+        return;
+    }
+
+    let expected = fs::read_to_string(&path).expect("unable to read golden file");
+
+    if expected == actual {
+        return;
+    }
+
+    let expected_lines: Vec<&str> = expected.lines().collect();
+    let actual_lines: Vec<&str> = actual.lines().collect();
+    let mut diff = Vec::new();
+
+    for i in 0..expected_lines.len().max(actual_lines.len()) {
+        let lhs = expected_lines.get(i);
+        let rhs = actual_lines.get(i);
+
+        if lhs != rhs {
+            if let Some(line) = lhs {
+                diff.push(format!("{:>5} - {}", i + 1, line));
+            }
+            if let Some(line) = rhs {
+                diff.push(format!("{:>5} + {}", i + 1, line));
+            }
+        }
+    }
+
+    panic!(
+        "CSS output differs from tests/golden/{} ({} changed lines):\n{}\n\n\
+         If this change is intentional, regenerate the golden file with \
+         `UPDATE_GOLDEN=1 cargo test --test css_snapshots` and record it in the changelog.",
+        name,
+        diff.len(),
+        diff.join("\n")
+    );
+}
+
+#[test]
+fn rgb_output_is_stable() {
+    assert_matches_golden("rgb.txt", rgb_matrix());
+}
+
+#[test]
+fn hsl_output_is_stable() {
+    assert_matches_golden("hsl.txt", hsl_matrix());
+}
+
+#[test]
+fn hsv_output_is_stable() {
+    assert_matches_golden("hsv.txt", hsv_matrix());
+}
+
+#[test]
+fn hwb_output_is_stable() {
+    assert_matches_golden("hwb.txt", hwb_matrix());
+}
+
+#[test]
+fn hsi_output_is_stable() {
+    assert_matches_golden("hsi.txt", hsi_matrix());
+}
+
+#[test]
+fn cmyk_output_is_stable() {
+    assert_matches_golden("cmyk.txt", cmyk_matrix());
+}
+
+#[test]
+fn lch_output_is_stable() {
+    assert_matches_golden("lch.txt", lch_matrix());
+}
+
+#[test]
+fn oklch_output_is_stable() {
+    assert_matches_golden("oklch.txt", oklch_matrix());
+}
+
+#[test]
+fn color_function_output_is_stable() {
+    assert_matches_golden("color.txt", color_function_matrix());
+}